@@ -0,0 +1,114 @@
+//! Implement documented guards for [`actix_web::guard`].
+//!
+//! Guards registered with [`Scope::guard_documented`][guard_documented] are translated into
+//! OpenAPI parameters required by every operation of the [`Scope`][scope].
+//!
+//! [guard_documented]: crate::scope::Scope::guard_documented
+//! [scope]: crate::scope::Scope
+
+use actix_web::guard::{Guard, GuardContext};
+use fastapi::openapi::path::{Parameter, ParameterBuilder, ParameterIn};
+use fastapi::openapi::{ObjectBuilder, Required, Type};
+
+/// Extends [`actix_web::guard::Guard`] with OpenAPI [`Parameter`]s describing the requirements
+/// the guard places on the request.
+pub trait DocumentedGuard: Guard {
+    /// Get [`Parameter`]s required by the guard.
+    fn parameters(&self) -> Vec<Parameter>;
+}
+
+/// Guard which matches requests having header _`name`_ with exact _`value`_.
+///
+/// This behaves same way as [`actix_web::guard::Header`] but documents the header as required
+/// [`Parameter`] with the expected value.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fastapi_actix_web::{guard, scope};
+/// let _: scope::Scope<_> = scope::scope("/api")
+///     .guard_documented(guard::Header("x-api-version", "2"));
+/// ```
+#[allow(non_snake_case)]
+pub fn Header(name: &'static str, value: &'static str) -> HeaderGuard {
+    HeaderGuard { name, value }
+}
+
+/// Documented header guard created with [`Header`][fn@Header].
+#[derive(Clone, Copy)]
+pub struct HeaderGuard {
+    name: &'static str,
+    value: &'static str,
+}
+
+impl Guard for HeaderGuard {
+    fn check(&self, ctx: &GuardContext<'_>) -> bool {
+        ctx.head()
+            .headers()
+            .get(self.name)
+            .is_some_and(|value| value.as_bytes() == self.value.as_bytes())
+    }
+}
+
+impl DocumentedGuard for HeaderGuard {
+    fn parameters(&self) -> Vec<Parameter> {
+        vec![ParameterBuilder::new()
+            .name(self.name)
+            .parameter_in(ParameterIn::Header)
+            .required(Required::True)
+            .schema(Some(
+                ObjectBuilder::new()
+                    .schema_type(Type::String)
+                    .enum_values(Some([self.value])),
+            ))
+            .build()]
+    }
+}
+
+/// Wrapper for any [`Guard`] documented with given [`Parameter`]s.
+///
+/// # Examples
+///
+/// _**Document custom guard requiring `tenant` query parameter.**_
+/// ```rust
+/// # use actix_web::guard;
+/// # use fastapi::openapi::path::{ParameterBuilder, ParameterIn};
+/// # use fastapi::openapi::Required;
+/// # use fastapi_actix_web::{guard::Documented, scope};
+/// let tenant = Documented::new(
+///     guard::fn_guard(|ctx| ctx.head().uri.query().is_some_and(|query| query.contains("tenant="))),
+///     [ParameterBuilder::new()
+///         .name("tenant")
+///         .parameter_in(ParameterIn::Query)
+///         .required(Required::True)
+///         .build()],
+/// );
+///
+/// let _: scope::Scope<_> = scope::scope("/api").guard_documented(tenant);
+/// ```
+pub struct Documented<G> {
+    guard: G,
+    parameters: Vec<Parameter>,
+}
+
+impl<G: Guard> Documented<G> {
+    /// Construct new [`Documented`] guard from _`guard`_ and _`parameters`_ it requires.
+    pub fn new<I: IntoIterator<Item = P>, P: Into<Parameter>>(guard: G, parameters: I) -> Self {
+        Self {
+            guard,
+            parameters: parameters.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl<G: Guard> Guard for Documented<G> {
+    fn check(&self, ctx: &GuardContext<'_>) -> bool {
+        self.guard.check(ctx)
+    }
+}
+
+impl<G: Guard> DocumentedGuard for Documented<G> {
+    fn parameters(&self) -> Vec<Parameter> {
+        self.parameters.clone()
+    }
+}
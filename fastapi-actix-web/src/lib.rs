@@ -3,7 +3,8 @@
 //! OpenAPI specification without the need to declare `paths` and `schemas` to `#[openapi(...)]` attribute of `OpenApi` derive.
 //!
 //! Currently only `service(...)` calls supports automatic collection of schemas and paths. Manual routes via `route(...)` or
-//! `Route::new().to(...)` is not supported.
+//! `Route::new().to(...)` is not supported. Such routes are recorded and can be listed with
+//! [`FastapiApp::undocumented_routes`] or rejected altogether with [`FastapiApp::strict`].
 //!
 //! ## Install
 //!
@@ -50,14 +51,18 @@ use std::future::Future;
 use actix_service::{IntoServiceFactory, ServiceFactory};
 use actix_web::dev::{HttpServiceFactory, ServiceRequest, ServiceResponse};
 use actix_web::Error;
+use fastapi::openapi::path::Paths;
 use fastapi::openapi::PathItem;
+use fastapi::routing::RouteRecord;
 use fastapi::OpenApi;
 
 use self::service_config::ServiceConfig;
 
+pub mod guard;
 pub mod scope;
 pub mod service_config;
 
+pub use fastapi::routing::RegisteredRoute;
 pub use scope::scope;
 
 /// This trait is used to unify OpenAPI items collection from types implementing this trait.
@@ -72,6 +77,11 @@ pub trait OpenApiFactory {
             fastapi::openapi::RefOr<fastapi::openapi::schema::Schema>,
         )>,
     );
+    /// Get routes registered to the factory that are not part of the documented
+    /// [`OpenApiFactory::paths`]. By default there are none.
+    fn routes(&self) -> RouteRecord {
+        RouteRecord::new()
+    }
 }
 
 impl<'t, T: fastapi::Path + fastapi::__dev::SchemaReferences + fastapi::__dev::Tags<'t>>
@@ -153,13 +163,13 @@ impl<T> AppExt<T> for actix_web::App<T> {
 /// # use actix_web::App;
 /// let a: FastapiApp<_> = actix_web::App::new().into();
 /// ```
-pub struct FastapiApp<T>(actix_web::App<T>, fastapi::openapi::OpenApi);
+pub struct FastapiApp<T>(actix_web::App<T>, fastapi::openapi::OpenApi, RouteRecord);
 
 impl<T> From<actix_web::App<T>> for FastapiApp<T> {
     fn from(value: actix_web::App<T>) -> Self {
         #[derive(OpenApi)]
         struct Api;
-        FastapiApp(value, Api::openapi())
+        FastapiApp(value, Api::openapi(), RouteRecord::default())
     }
 }
 
@@ -194,7 +204,7 @@ where
     /// Passthrough implementation for [`actix_web::App::app_data`].
     pub fn app_data<U: 'static>(self, data: U) -> Self {
         let app = self.0.app_data(data);
-        Self(app, self.1)
+        Self(app, self.1, self.2)
     }
 
     /// Passthrough implementation for [`actix_web::App::data_factory`].
@@ -207,7 +217,7 @@ where
     {
         let app = self.0.data_factory(data);
 
-        Self(app, self.1)
+        Self(app, self.1, self.2)
     }
 
     /// Extended version of [`actix_web::App::configure`] which handles _`schema`_ and _`path`_
@@ -217,6 +227,7 @@ where
         F: FnOnce(&mut ServiceConfig),
     {
         let mut openapi = self.1;
+        let mut routes = self.2;
 
         let app = self.0.configure(|config| {
             let mut service_config = ServiceConfig::new(config);
@@ -224,6 +235,8 @@ where
             f(&mut service_config);
 
             let paths = service_config.1.take();
+            routes.record(registered_routes(&paths));
+            routes.merge(service_config.3.take());
             openapi.paths.merge(paths);
             let schemas = service_config.2.take();
             let components = openapi
@@ -232,19 +245,24 @@ where
             components.schemas.extend(schemas);
         });
 
-        Self(app, openapi)
+        Self(app, openapi, routes)
     }
 
     /// Passthrough implementation for [`actix_web::App::route`].
-    pub fn route(self, path: &str, route: actix_web::Route) -> Self {
+    ///
+    /// The route is recorded as a route of any method since methods of [`actix_web::Route`]
+    /// cannot be inspected. See [`FastapiApp::undocumented_routes`] for more details.
+    pub fn route(mut self, path: &str, route: actix_web::Route) -> Self {
+        self.2
+            .record([RegisteredRoute::new(path_template(path), None)]);
         let app = self.0.route(path, route);
 
-        Self(app, self.1)
+        Self(app, self.1, self.2)
     }
 
     /// Extended version of [`actix_web::App::service`] method which handles _`schema`_ and _`path`_
     /// collection from [`HttpServiceFactory`].
-    pub fn service<F>(self, factory: F) -> Self
+    pub fn service<F>(mut self, factory: F) -> Self
     where
         F: HttpServiceFactory + OpenApiFactory + 'static,
     {
@@ -255,6 +273,8 @@ where
 
         factory.schemas(&mut schemas);
         let paths = factory.paths();
+        self.2.record(registered_routes(&paths));
+        self.2.merge(factory.routes());
 
         let mut openapi = self.1;
 
@@ -266,7 +286,7 @@ where
 
         let app = self.0.service(factory);
 
-        Self(app, openapi)
+        Self(app, openapi, self.2)
     }
 
     /// Helper method to serve wrapped [`fastapi::openapi::OpenApi`] via [`HttpServiceFactory`].
@@ -280,18 +300,19 @@ where
     {
         let service = factory(self.1.clone());
         let app = self.0.service(service);
-        Self(app, self.1)
+        Self(app, self.1, self.2)
     }
 
     /// Passthrough implementation for [`actix_web::App::default_service`].
-    pub fn default_service<F, U>(self, svc: F) -> Self
+    pub fn default_service<F, U>(mut self, svc: F) -> Self
     where
         F: IntoServiceFactory<U, ServiceRequest>,
         U: ServiceFactory<ServiceRequest, Config = (), Response = ServiceResponse, Error = Error>
             + 'static,
         U::InitError: fmt::Debug,
     {
-        Self(self.0.default_service(svc), self.1)
+        self.2.record([RegisteredRoute::new("/*", None)]);
+        Self(self.0.default_service(svc), self.1, self.2)
     }
 
     /// Passthrough implementation for [`actix_web::App::external_resource`].
@@ -300,7 +321,7 @@ where
         N: AsRef<str>,
         U: AsRef<str>,
     {
-        Self(self.0.external_resource(name, url), self.1)
+        Self(self.0.external_resource(name, url), self.1, self.2)
     }
 
     /// Convenience method to add custom configuration to [`actix_web::App`] that is not directly
@@ -337,27 +358,162 @@ where
         op: F,
     ) -> FastapiApp<NF> {
         let app = op(self.0);
-        FastapiApp(app, self.1)
+        FastapiApp(app, self.1, self.2)
+    }
+
+    /// Enable strict mode for the [`FastapiApp`].
+    ///
+    /// In strict mode [`FastapiApp::split_for_parts`], [`FastapiApp::into_app`] and conversion
+    /// to [`actix_web::App`] with [`Into::into`] panic if the app has
+    /// [undocumented routes][FastapiApp::undocumented_routes]. Routes that are intentionally
+    /// left out from the OpenAPI documentation must be marked with [`FastapiApp::undocumented`].
+    ///
+    /// # Examples
+    ///
+    /// _**Allow undocumented health check route in strict mode.**_
+    /// ```rust
+    /// # use actix_web::{get, web, App};
+    /// # use fastapi_actix_web::AppExt;
+    ///  #[fastapi::path()]
+    ///  #[get("/user")]
+    ///  async fn get_user() -> &'static str {
+    ///      "OK"
+    ///  }
+    ///
+    /// let (app, api) = App::new()
+    ///     .into_fastapi_app()
+    ///     .strict()
+    ///     .service(get_user)
+    ///     .route("/health", web::get().to(|| async { "OK" }))
+    ///     .undocumented()
+    ///     .split_for_parts();
+    /// ```
+    pub fn strict(mut self) -> Self {
+        self.2.set_strict(true);
+
+        self
+    }
+
+    /// Mark the route registered by the previous [`FastapiApp::route`] or
+    /// [`FastapiApp::default_service`] call as intentionally undocumented.
+    ///
+    /// Undocumented routes are not reported by [`FastapiApp::undocumented_routes`] and are
+    /// allowed in [strict mode][FastapiApp::strict]. Routes of [`Scope`][scope::Scope]s and
+    /// [`ServiceConfig`]s are marked with their own `undocumented` method, so calling this after
+    /// [`FastapiApp::service`] or [`FastapiApp::configure`] does nothing.
+    pub fn undocumented(mut self) -> Self {
+        self.2.undocumented();
+
+        self
+    }
+
+    /// Get routes registered to the app that have no matching [`Operation`][operation] in the
+    /// [`fastapi::openapi::OpenApi`] of the app.
+    ///
+    /// Routes registered with `route` of [`FastapiApp`], [`Scope`][scope::Scope] or
+    /// [`ServiceConfig`] are considered documented if the path has any operation since the method
+    /// guards of [`actix_web::Route`] cannot be inspected. Default services are reported with path
+    /// `/*` under the scope they are registered to. Routes marked as
+    /// [undocumented][FastapiApp::undocumented] are not reported.
+    ///
+    /// # Examples
+    ///
+    /// _**Find undocumented route.**_
+    /// ```rust
+    /// # use actix_web::{web, App};
+    /// # use fastapi_actix_web::AppExt;
+    /// let app = App::new()
+    ///     .into_fastapi_app()
+    ///     .route("/user/{id:\\d+}", web::delete().to(|| async { "OK" }));
+    ///
+    /// let undocumented = app.undocumented_routes();
+    /// assert_eq!(undocumented.len(), 1);
+    /// assert_eq!(undocumented[0].path, "/user/{id}");
+    /// ```
+    ///
+    /// [operation]: fastapi::openapi::path::Operation
+    pub fn undocumented_routes(&self) -> Vec<RegisteredRoute> {
+        self.2.undocumented_routes(&self.1.paths).cloned().collect()
     }
 
     /// Split this [`FastapiApp`] into parts returning tuple of [`actix_web::App`] and
     /// [`fastapi::openapi::OpenApi`] of this instance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the app is [strict][FastapiApp::strict] and has undocumented routes.
     pub fn split_for_parts(self) -> (actix_web::App<T>, fastapi::openapi::OpenApi) {
+        self.2.assert_documented(&self.1, "FastapiApp");
         (self.0, self.1)
     }
 
     /// Converts this [`FastapiApp`] into the wrapped [`actix_web::App`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the app is [strict][FastapiApp::strict] and has undocumented routes.
     pub fn into_app(self) -> actix_web::App<T> {
+        self.2.assert_documented(&self.1, "FastapiApp");
         self.0
     }
 }
 
 impl<T> From<FastapiApp<T>> for actix_web::App<T> {
+    /// # Panics
+    ///
+    /// Panics if the app is [strict][FastapiApp::strict] and has undocumented routes.
     fn from(value: FastapiApp<T>) -> Self {
+        value.2.assert_documented(&value.1, "FastapiApp");
         value.0
     }
 }
 
+/// Get [`RegisteredRoute`] for each operation of the `paths`.
+fn registered_routes(paths: &Paths) -> Vec<RegisteredRoute> {
+    paths
+        .paths
+        .iter()
+        .flat_map(|(path, item)| {
+            item.operations()
+                .map(move |(method, _)| RegisteredRoute::new(path, Some(method)))
+        })
+        .collect()
+}
+
+/// Convert actix path to OpenAPI path template by removing regex of the dynamic segments e.g.
+/// `/user/{id:\d+}` to `/user/{id}`.
+pub(crate) fn path_template(path: &str) -> String {
+    let mut template = String::with_capacity(path.len());
+    let mut depth = 0;
+    let mut in_regex = false;
+    for c in path.chars() {
+        match c {
+            '{' => {
+                depth += 1;
+                if in_regex {
+                    continue;
+                }
+            }
+            '}' => {
+                depth -= 1;
+                if in_regex && depth > 0 {
+                    continue;
+                }
+                in_regex = false;
+            }
+            ':' if depth == 1 => {
+                in_regex = true;
+                continue;
+            }
+            _ if in_regex => continue,
+            _ => (),
+        }
+        template.push(c);
+    }
+
+    template
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(unused)]
@@ -483,4 +639,185 @@ mod tests {
         let expected = include_str!("../testdata/app_generated_openapi");
         assert_eq!(json.trim(), expected.trim());
     }
+
+    #[test]
+    fn test_scope_nest_options() {
+        use fastapi::openapi::path::{HttpMethod, ParameterBuilder, ParameterIn};
+        use fastapi::openapi::{Required, ResponseBuilder, SecurityRequirement};
+
+        let (_, api) = App::new()
+            .into_fastapi_app()
+            .service(handler)
+            .service(
+                scope::scope("/admin")
+                    .tag("admin")
+                    .security(SecurityRequirement::new("admin_auth", Vec::<String>::new()))
+                    .parameter(
+                        ParameterBuilder::new()
+                            .name("X-Tenant")
+                            .parameter_in(ParameterIn::Header)
+                            .required(Required::True),
+                    )
+                    .response("403", ResponseBuilder::new().description("Forbidden"))
+                    .service(handler2),
+            )
+            .split_for_parts();
+
+        let admin = api.paths.get_path_item("/admin/handler2").unwrap();
+        assert_eq!(admin.parameters.as_ref().unwrap()[0].name, "X-Tenant");
+        let operation = admin.get.as_ref().unwrap();
+        assert_eq!(operation.tags, Some(vec!["admin".to_string()]));
+        assert_eq!(
+            operation.security,
+            Some(vec![SecurityRequirement::new(
+                "admin_auth",
+                Vec::<String>::new()
+            )])
+        );
+        assert!(operation.responses.responses.contains_key("403"));
+
+        let operation = api
+            .paths
+            .get_path_operation("/handler", HttpMethod::Get)
+            .unwrap();
+        assert!(operation.security.is_none());
+        assert!(!operation.responses.responses.contains_key("403"));
+    }
+
+    #[test]
+    fn test_scope_documented_guard() {
+        use fastapi::openapi::path::{ParameterBuilder, ParameterIn};
+        use fastapi::openapi::Required;
+
+        let tenant = guard::Documented::new(
+            actix_web::guard::Any(actix_web::guard::Get()),
+            [ParameterBuilder::new()
+                .name("tenant")
+                .parameter_in(ParameterIn::Query)],
+        );
+        let (_, api) = App::new()
+            .into_fastapi_app()
+            .service(
+                scope::scope("/api")
+                    .guard_documented(guard::Header("x-api-version", "2"))
+                    .guard_documented(tenant)
+                    .service(handler2),
+            )
+            .split_for_parts();
+
+        let parameters = api
+            .paths
+            .get_path_item("/api/handler2")
+            .unwrap()
+            .parameters
+            .as_ref()
+            .unwrap();
+        assert_eq!(parameters.len(), 2);
+        assert_eq!(parameters[0].name, "x-api-version");
+        assert_eq!(parameters[0].parameter_in, ParameterIn::Header);
+        assert_eq!(parameters[0].required, Required::True);
+        assert_eq!(parameters[1].name, "tenant");
+        assert_eq!(parameters[1].parameter_in, ParameterIn::Query);
+    }
+
+    #[test]
+    fn test_app_undocumented_routes() {
+        let app = App::new()
+            .into_fastapi_app()
+            .service(handler)
+            .route("/handler", web::post().to(|| async { "OK" }))
+            .route("/users/{id:\\d+}/{name}", web::get().to(|| async { "OK" }))
+            .route("/health", web::get().to(|| async { "OK" }))
+            .undocumented()
+            .default_service(web::to(HttpResponse::NotFound));
+
+        assert_eq!(
+            app.undocumented_routes()
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            ["/users/{id}/{name}", "/*"]
+        );
+    }
+
+    #[test]
+    fn test_scope_undocumented_routes() {
+        let app = App::new()
+            .into_fastapi_app()
+            .service(
+                scope::scope("/api")
+                    .service(handler)
+                    .route("/status", web::get().to(|| async { "OK" }))
+                    .route("/health", web::get().to(|| async { "OK" }))
+                    .undocumented()
+                    .configure(|config| {
+                        config.route("/metrics", web::get().to(|| async { "OK" }));
+                    })
+                    .default_service(web::to(HttpResponse::NotFound)),
+            )
+            .undocumented();
+
+        assert_eq!(
+            app.undocumented_routes()
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            ["/api/status", "/api/metrics", "/api/*"]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "undocumented routes: /health")]
+    fn test_strict_app_panics_on_undocumented_routes() {
+        let _ = App::new()
+            .into_fastapi_app()
+            .strict()
+            .service(handler)
+            .route("/health", web::get().to(|| async { "OK" }))
+            .split_for_parts();
+    }
+
+    #[test]
+    fn test_problem_error_response() {
+        use actix_web::body::MessageBody;
+        use actix_web::ResponseError;
+
+        let problem = fastapi::problem::Problem::new(409).detail("Pet already exists");
+        let response = problem.error_response();
+
+        assert_eq!(response.status(), actix_web::http::StatusCode::CONFLICT);
+        assert_eq!(
+            response.headers().get(CONTENT_TYPE).unwrap(),
+            "application/problem+json"
+        );
+        let body = response.into_body().try_into_bytes().unwrap();
+        assert_eq!(
+            &body[..],
+            br#"{"status":409,"detail":"Pet already exists"}"#
+        );
+    }
+
+    #[test]
+    fn test_paged_response_responder() {
+        use actix_web::body::MessageBody;
+        use actix_web::test::TestRequest;
+        use actix_web::Responder;
+        use fastapi::pagination::{Page, PagedResponse};
+
+        let page = Page::cursor(vec![1, 2], Some("c2".to_string()));
+        let response = PagedResponse::cursor(page, "/items", 2)
+            .respond_to(&TestRequest::default().to_http_request());
+
+        assert_eq!(response.status(), actix_web::http::StatusCode::OK);
+        assert_eq!(
+            response
+                .headers()
+                .get(actix_web::http::header::LINK)
+                .unwrap(),
+            r#"</items?cursor=c2&limit=2>; rel="next""#
+        );
+        assert!(response.headers().get("x-total-count").is_none());
+        let body = response.into_body().try_into_bytes().unwrap();
+        assert_eq!(&body[..], br#"{"items":[1,2],"next_cursor":"c2"}"#);
+    }
 }
@@ -3,16 +3,18 @@
 //! See usage from [`scope`][fn@scope].
 
 use core::fmt;
-use std::cell::{Cell, RefCell};
+use std::cell::RefCell;
 
 use actix_service::{IntoServiceFactory, ServiceFactory, Transform};
 use actix_web::body::MessageBody;
 use actix_web::dev::{AppService, HttpServiceFactory, ServiceRequest, ServiceResponse};
 use actix_web::guard::Guard;
 use actix_web::{Error, Route};
+use fastapi::routing::{RegisteredRoute, RouteRecord};
 
+use crate::guard::DocumentedGuard;
 use crate::service_config::ServiceConfig;
-use crate::OpenApiFactory;
+use crate::{path_template, OpenApiFactory};
 
 /// Wrapper type for [`actix_web::Scope`] and [`fastapi::openapi::OpenApi`] with additional path
 /// prefix created with `scope::scope("path-prefix")` call.
@@ -21,7 +23,9 @@ use crate::OpenApiFactory;
 pub struct Scope<T>(
     actix_web::Scope<T>,
     RefCell<fastapi::openapi::OpenApi>,
-    Cell<String>,
+    String,
+    fastapi::openapi::NestOptions,
+    RouteRecord,
 );
 
 impl<T> From<actix_web::Scope<T>> for Scope<T>
@@ -32,7 +36,9 @@ where
         Self(
             value,
             RefCell::new(fastapi::openapi::OpenApiBuilder::new().build()),
-            Cell::new(String::new()),
+            String::new(),
+            fastapi::openapi::NestOptions::new(),
+            RouteRecord::new(),
         )
     }
 }
@@ -45,7 +51,7 @@ where
     fn from(value: &'s str) -> Self {
         let scope = actix_web::Scope::new(value);
         let s: Scope<T> = scope.into();
-        Scope(s.0, s.1, Cell::new(String::from(value)))
+        Scope(s.0, s.1, String::from(value), s.3, s.4)
     }
 }
 
@@ -89,12 +95,45 @@ where
     /// Passthrough implementation for [`actix_web::Scope::guard`].
     pub fn guard<G: Guard + 'static>(self, guard: G) -> Self {
         let scope = self.0.guard(guard);
-        Self(scope, self.1, self.2)
+        Self(scope, self.1, self.2, self.3, self.4)
+    }
+
+    /// Add documented [`Guard`] to the [`Scope`].
+    ///
+    /// This behaves same way as [`Scope::guard`] but in addition the [`Parameter`][parameter]s
+    /// required by the guard are added to every path of the [`Scope`].
+    ///
+    /// # Examples
+    ///
+    /// _**Require `x-api-version` header on every operation of the scope.**_
+    /// ```rust
+    /// # use actix_web::{get, App};
+    /// # use fastapi_actix_web::{guard, scope, AppExt};
+    /// #[fastapi::path()]
+    /// #[get("/items")]
+    /// async fn list_items() -> &'static str {
+    ///     ""
+    /// }
+    ///
+    /// let (_, api) = App::new()
+    ///     .into_fastapi_app()
+    ///     .service(
+    ///         scope::scope("/api")
+    ///             .guard_documented(guard::Header("x-api-version", "2"))
+    ///             .service(list_items),
+    ///     )
+    ///     .split_for_parts();
+    /// ```
+    ///
+    /// [parameter]: fastapi::openapi::path::Parameter
+    pub fn guard_documented<G: DocumentedGuard + 'static>(self, guard: G) -> Self {
+        let options = self.3.parameters(Some(guard.parameters()));
+        Self(self.0.guard(guard), self.1, self.2, options, self.4)
     }
 
     /// Passthrough implementation for [`actix_web::Scope::app_data`].
     pub fn app_data<U: 'static>(self, data: U) -> Self {
-        Self(self.0.app_data(data), self.1, self.2)
+        Self(self.0.app_data(data), self.1, self.2, self.3, self.4)
     }
 
     /// Passthrough implementation for [`actix_web::Scope::wrap`].
@@ -121,17 +160,18 @@ where
         B: MessageBody,
     {
         let scope = self.0.wrap(middleware);
-        Scope(scope, self.1, self.2)
+        Scope(scope, self.1, self.2, self.3, self.4)
     }
 
     /// Synonymous for [`FastapiApp::configure`][fastapi_app_configure]
     ///
     /// [fastapi_app_configure]: ../struct.FastapiApp.html#method.configure
-    pub fn configure<F>(self, cfg_fn: F) -> Self
+    pub fn configure<F>(mut self, cfg_fn: F) -> Self
     where
         F: FnOnce(&mut ServiceConfig),
     {
         let mut openapi = self.1.borrow_mut();
+        let routes = &mut self.4;
 
         let scope = self.0.configure(|config| {
             let mut service_config = ServiceConfig::new(config);
@@ -140,6 +180,7 @@ where
 
             let other_paths = service_config.1.take();
             openapi.paths.merge(other_paths);
+            routes.merge(service_config.3.take());
             let schemas = service_config.2.take();
             let components = openapi
                 .components
@@ -148,16 +189,18 @@ where
         });
         drop(openapi);
 
-        Self(scope, self.1, self.2)
+        Self(scope, self.1, self.2, self.3, self.4)
     }
 
     /// Synonymous for [`FastapiApp::service`][fastapi_app_service]
     ///
     /// [fastapi_app_service]: ../struct.FastapiApp.html#method.service
-    pub fn service<F>(self, factory: F) -> Self
+    pub fn service<F>(mut self, factory: F) -> Self
     where
         F: HttpServiceFactory + OpenApiFactory + 'static,
     {
+        self.4.merge(factory.routes());
+
         let mut schemas = Vec::<(
             String,
             fastapi::openapi::RefOr<fastapi::openapi::schema::Schema>,
@@ -175,16 +218,97 @@ where
 
         let app = self.0.service(factory);
 
-        Self(app, self.1, self.2)
+        Self(app, self.1, self.2, self.3, self.4)
+    }
+
+    /// Add tag to every operation of the [`Scope`].
+    ///
+    /// See [`NestOptions`][nest_options] for more details on how the scope options are applied.
+    ///
+    /// # Examples
+    ///
+    /// _**Tag all operations of `/admin` scope with `admin` and require `admin_auth` security.**_
+    /// ```rust
+    /// # use actix_web::{get, App};
+    /// # use fastapi::openapi::{ResponseBuilder, SecurityRequirement};
+    /// # use fastapi_actix_web::{AppExt, scope};
+    /// #
+    ///  #[fastapi::path()]
+    ///  #[get("/users")]
+    ///  pub async fn list_users() -> &'static str {
+    ///      "OK"
+    ///  }
+    /// let _ = App::new().into_fastapi_app().service(
+    ///     scope::scope("/admin")
+    ///         .tag("admin")
+    ///         .security(SecurityRequirement::new("admin_auth", Vec::<String>::new()))
+    ///         .response("403", ResponseBuilder::new().description("Forbidden"))
+    ///         .service(list_users),
+    /// );
+    /// ```
+    ///
+    /// [nest_options]: fastapi::openapi::NestOptions
+    pub fn tag<S: Into<String>>(self, tag: S) -> Self {
+        Self(self.0, self.1, self.2, self.3.tag(tag), self.4)
+    }
+
+    /// Add [`SecurityRequirement`][security] to every operation of the [`Scope`] that does not
+    /// define security requirements of its own.
+    ///
+    /// [security]: fastapi::openapi::SecurityRequirement
+    pub fn security(self, security: fastapi::openapi::SecurityRequirement) -> Self {
+        Self(self.0, self.1, self.2, self.3.security(security), self.4)
+    }
+
+    /// Add [`Parameter`][parameter] shared by every path of the [`Scope`].
+    ///
+    /// [parameter]: fastapi::openapi::path::Parameter
+    pub fn parameter<P: Into<fastapi::openapi::path::Parameter>>(self, parameter: P) -> Self {
+        Self(self.0, self.1, self.2, self.3.parameter(parameter), self.4)
+    }
+
+    /// Add [`Response`][response] with status `code` to every operation of the [`Scope`] that
+    /// does not define response for the same status code.
+    ///
+    /// [response]: fastapi::openapi::Response
+    pub fn response<
+        S: Into<String>,
+        R: Into<fastapi::openapi::RefOr<fastapi::openapi::Response>>,
+    >(
+        self,
+        code: S,
+        response: R,
+    ) -> Self {
+        Self(
+            self.0,
+            self.1,
+            self.2,
+            self.3.response(code, response),
+            self.4,
+        )
+    }
+
+    /// Add alternative [`Server`][server] for every path of the [`Scope`].
+    ///
+    /// [server]: fastapi::openapi::Server
+    pub fn server(self, server: fastapi::openapi::Server) -> Self {
+        Self(self.0, self.1, self.2, self.3.server(server), self.4)
     }
 
     /// Passthrough implementation for [`actix_web::Scope::route`].
-    pub fn route(self, path: &str, route: Route) -> Self {
-        Self(self.0.route(path, route), self.1, self.2)
+    ///
+    /// See [`FastapiApp::undocumented_routes`][fastapi_app_undocumented_routes] how the route is
+    /// recorded.
+    ///
+    /// [fastapi_app_undocumented_routes]: ../struct.FastapiApp.html#method.undocumented_routes
+    pub fn route(mut self, path: &str, route: Route) -> Self {
+        self.4
+            .record([RegisteredRoute::new(path_template(path), None)]);
+        Self(self.0.route(path, route), self.1, self.2, self.3, self.4)
     }
 
     /// Passthrough implementation for [`actix_web::Scope::default_service`].
-    pub fn default_service<F, U>(self, f: F) -> Self
+    pub fn default_service<F, U>(mut self, f: F) -> Self
     where
         F: IntoServiceFactory<U, ServiceRequest>,
         U: ServiceFactory<
@@ -195,7 +319,20 @@ where
             > + 'static,
         U::InitError: fmt::Debug,
     {
-        Self(self.0.default_service(f), self.1, self.2)
+        self.4.record([RegisteredRoute::new("/*", None)]);
+        Self(self.0.default_service(f), self.1, self.2, self.3, self.4)
+    }
+
+    /// Mark the route registered by the previous [`Scope::route`] or [`Scope::default_service`]
+    /// call as intentionally undocumented.
+    ///
+    /// Counterpart for [`FastapiApp::undocumented`][fastapi_app_undocumented].
+    ///
+    /// [fastapi_app_undocumented]: ../struct.FastapiApp.html#method.undocumented
+    pub fn undocumented(mut self) -> Self {
+        self.4.undocumented();
+
+        self
     }
 
     /// Synonymous for [`FastapiApp::map`][fastapi_app_map]
@@ -209,7 +346,7 @@ where
         op: F,
     ) -> Scope<NF> {
         let scope = op(self.0);
-        Scope(scope, self.1, self.2)
+        Scope(scope, self.1, self.2, self.3, self.4)
     }
 }
 
@@ -232,9 +369,10 @@ where
 
 impl<T> OpenApiFactory for Scope<T> {
     fn paths(&self) -> fastapi::openapi::path::Paths {
-        let prefix = self.2.take();
+        let prefix = &self.2;
         let mut openapi = self.1.borrow_mut();
         let mut paths = std::mem::take(&mut openapi.paths);
+        self.3.apply(&mut paths);
 
         let prefixed_paths = paths
             .paths
@@ -262,4 +400,11 @@ impl<T> OpenApiFactory for Scope<T> {
             schemas.extend(std::mem::take(&mut components.schemas));
         }
     }
+
+    fn routes(&self) -> RouteRecord {
+        let mut routes = RouteRecord::new();
+        routes.nest(self.4.clone(), |path| format!("{}{path}", self.2));
+
+        routes
+    }
 }
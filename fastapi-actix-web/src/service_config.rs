@@ -5,11 +5,12 @@ use std::cell::Cell;
 use actix_service::{IntoServiceFactory, ServiceFactory};
 use actix_web::dev::{HttpServiceFactory, ServiceRequest, ServiceResponse};
 use actix_web::{Error, Route};
+use fastapi::routing::{RegisteredRoute, RouteRecord};
 
-use crate::OpenApiFactory;
+use crate::{path_template, OpenApiFactory};
 
-/// Wrapper type for [`actix_web::web::ServiceConfig`], [`fastapi::openapi::path::Paths`] and
-/// vec of [`fastapi::openapi::schema::Schema`] references.
+/// Wrapper type for [`actix_web::web::ServiceConfig`], [`fastapi::openapi::path::Paths`],
+/// vec of [`fastapi::openapi::schema::Schema`] references and [`RouteRecord`] of the routes.
 pub struct ServiceConfig<'s>(
     pub(super) &'s mut actix_web::web::ServiceConfig,
     pub(super) Cell<fastapi::openapi::path::Paths>,
@@ -19,6 +20,7 @@ pub struct ServiceConfig<'s>(
             fastapi::openapi::RefOr<fastapi::openapi::schema::Schema>,
         )>,
     >,
+    pub(super) Cell<RouteRecord>,
 );
 
 impl<'s> ServiceConfig<'s> {
@@ -28,6 +30,7 @@ impl<'s> ServiceConfig<'s> {
             conf,
             Cell::new(fastapi::openapi::path::Paths::new()),
             Cell::new(Vec::new()),
+            Cell::new(RouteRecord::new()),
         )
     }
 
@@ -45,6 +48,7 @@ impl<'s> ServiceConfig<'s> {
             + 'static,
         U::InitError: std::fmt::Debug,
     {
+        self.3.get_mut().record([RegisteredRoute::new("/*", None)]);
         self.0.default_service(f);
         self
     }
@@ -59,11 +63,30 @@ impl<'s> ServiceConfig<'s> {
     }
 
     /// Passthrough implementation for [`actix_web::web::ServiceConfig::route`].
+    ///
+    /// See [`FastapiApp::undocumented_routes`][fastapi_app_undocumented_routes] how the route is
+    /// recorded.
+    ///
+    /// [fastapi_app_undocumented_routes]: ../struct.FastapiApp.html#method.undocumented_routes
     pub fn route(&mut self, path: &str, route: Route) -> &mut Self {
+        self.3
+            .get_mut()
+            .record([RegisteredRoute::new(path_template(path), None)]);
         self.0.route(path, route);
         self
     }
 
+    /// Mark the route registered by the previous [`ServiceConfig::route`] or
+    /// [`ServiceConfig::default_service`] call as intentionally undocumented.
+    ///
+    /// Counterpart for [`FastapiApp::undocumented`][fastapi_app_undocumented].
+    ///
+    /// [fastapi_app_undocumented]: ../struct.FastapiApp.html#method.undocumented
+    pub fn undocumented(&mut self) -> &mut Self {
+        self.3.get_mut().undocumented();
+        self
+    }
+
     /// Counterpart for [`FastapiApp::service`][fastapi_app_service].
     ///
     /// [fastapi_app_service]: ../struct.FastapiApp.html#method.service
@@ -79,6 +102,8 @@ impl<'s> ServiceConfig<'s> {
         factory.schemas(&mut schemas);
         self.2.set(schemas);
 
+        self.3.get_mut().merge(factory.routes());
+
         self.0.service(factory);
         self.1.set(paths);
 
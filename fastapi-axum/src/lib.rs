@@ -9,6 +9,8 @@
 //! ## Crate features
 //!
 //! - **`debug`**: Implement debug traits for types.
+//! - **`security`**: Enables [`SecurityLayer`][security_layer] to enforce security requirements of
+//!   the OpenAPI operations.
 //!
 //! ## Install
 //!
@@ -43,8 +45,12 @@
 //! ```
 //!
 //! [router]: router/struct.OpenApiRouter.html
+//! [security_layer]: security/struct.SecurityLayer.html
 
 pub mod router;
+#[cfg(feature = "security")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "security")))]
+pub mod security;
 
 use axum::routing::MethodFilter;
 use fastapi::openapi::HttpMethod;
@@ -121,72 +127,79 @@ pub use paste::paste;
 ///
 ///  let _: OpenApiRouter = OpenApiRouter::new().routes(routes!(get_user, post_user));
 /// ```
+///
+/// _**Register generic handler with concrete type.**_ The schemas of the given types are
+/// substituted to the documented operation.
+/// ```rust
+/// # use fastapi_axum::{routes, router::OpenApiRouter};
+/// # use fastapi::ToSchema;
+///  #[derive(ToSchema)]
+///  struct User {
+///      id: i32,
+///  }
+///
+///  #[fastapi::path(get, path = "/users", responses((status = 200, body = Vec<T>)))]
+///  async fn list<T: ToSchema + 'static>() -> String {
+///      T::name().into_owned()
+///  }
+///
+///  let _: OpenApiRouter = OpenApiRouter::new().routes(routes!(list::<User>));
+/// ```
+///
+/// _**Register closure with separately declared operation.**_ The operation, tags and schemas are
+/// taken from the type given to `doc` while the HTTP method and path are defined explicitly.
+/// ```rust
+/// # use fastapi_axum::{routes, router::OpenApiRouter};
+///  struct HealthDoc;
+///
+///  #[fastapi::path(get, path = "/health", impl_for = HealthDoc, responses((status = 200)))]
+///  #[allow(unused)]
+///  fn health() {}
+///
+///  let _: OpenApiRouter = OpenApiRouter::new()
+///      .routes(routes!(get "/health" => || async { "OK" }, doc = HealthDoc));
+/// ```
 #[macro_export]
 macro_rules! routes {
-    ( $handler:path $(, $tail:path)* ) => {
+    ( $method:ident $path:literal => $handler:expr, doc = $doc:ty $(,)? ) => {
         {
             use $crate::PathItemExt;
             let mut paths = fastapi::openapi::path::Paths::new();
             let mut schemas = Vec::<(String, fastapi::openapi::RefOr<fastapi::openapi::schema::Schema>)>::new();
-            let (path, item, types) = $crate::routes!(@resolve_types $handler : schemas);
-            #[allow(unused_mut)]
-            let mut method_router = types.iter().by_ref().fold(axum::routing::MethodRouter::new(), |router, path_type| {
-                router.on(path_type.to_method_filter(), $handler)
-            });
-            paths.add_path_operation(&path, types, item);
-            $( method_router = $crate::routes!( schemas: method_router: paths: $tail ); )*
+            let method = $crate::paste!(fastapi::openapi::path::HttpMethod::[<$method:camel>]);
+            let method_router = axum::routing::MethodRouter::new().on(method.to_method_filter(), $handler);
+            let operation = $crate::routes!( @resolve_doc $doc : schemas );
+            paths.add_path_operation($path, vec![method], operation);
             (schemas, paths, method_router)
         }
     };
-    ( $schemas:tt: $router:ident: $paths:ident: $handler:path $(, $tail:tt)* ) => {
+    ( $( $handler:path ),+ $(,)? ) => {
         {
-            let (path, item, types) = $crate::routes!(@resolve_types $handler : $schemas);
-            let router = types.iter().by_ref().fold($router, |router, path_type| {
-                router.on(path_type.to_method_filter(), $handler)
-            });
-            $paths.add_path_operation(&path, types, item);
-            router
+            use $crate::PathItemExt;
+            let mut paths = fastapi::openapi::path::Paths::new();
+            let mut schemas = Vec::<(String, fastapi::openapi::RefOr<fastapi::openapi::schema::Schema>)>::new();
+            #[allow(unused_mut)]
+            let mut method_router = axum::routing::MethodRouter::new();
+            $(
+                let (path, item, types) = $crate::routes!( @resolve_types $handler : schemas );
+                method_router = types.iter().by_ref().fold(method_router, |router, path_type| {
+                    router.on(path_type.to_method_filter(), $handler)
+                });
+                paths.add_path_operation(&path, types, item);
+            )+
+            (schemas, paths, method_router)
         }
     };
     ( @resolve_types $handler:path : $schemas:tt ) => {
         {
-            $crate::paste! {
-                let path = $crate::routes!( @path [path()] of $handler );
-                let mut operation = $crate::routes!( @path [operation()] of $handler );
-                let types = $crate::routes!( @path [methods()] of $handler );
-                let tags = $crate::routes!( @path [tags()] of $handler );
-                $crate::routes!( @path [schemas(&mut $schemas)] of $handler );
-                if !tags.is_empty() {
-                    let operation_tags = operation.tags.get_or_insert(Vec::new());
-                    operation_tags.extend(tags.iter().map(ToString::to_string));
-                }
-                (path, operation, types)
-            }
+            let operation = $crate::routes!( @resolve_doc fastapi::__path_type!($handler) : $schemas );
+            let path = <fastapi::__path_type!($handler) as fastapi::Path>::path();
+            let types = <fastapi::__path_type!($handler) as fastapi::Path>::methods();
+            (path, operation, types)
         }
     };
-    ( @path $op:tt of $part:ident $( :: $tt:tt )* ) => {
-        $crate::routes!( $op : [ $part $( $tt )*] )
-    };
-    ( $op:tt : [ $first:tt $( $rest:tt )* ] $( $rev:tt )* ) => {
-        $crate::routes!( $op : [ $( $rest )* ] $first $( $rev)* )
-    };
-    ( $op:tt : [] $first:tt $( $rest:tt )* ) => {
-        $crate::routes!( @inverse $op : $first $( $rest )* )
-    };
-    ( @inverse $op:tt : $tt:tt $( $rest:tt )* ) => {
-        $crate::routes!( @rev $op : $tt [$($rest)*] )
-    };
-    ( @rev $op:tt : $tt:tt [ $first:tt $( $rest:tt)* ] $( $reversed:tt )* ) => {
-        $crate::routes!( @rev $op : $tt [ $( $rest )* ] $first $( $reversed )* )
-    };
-    ( @rev [$op:ident $( $args:tt )* ] : $handler:tt [] $($tt:tt)* ) => {
-        {
-            #[allow(unused_imports)]
-            use fastapi::{Path, __dev::{Tags, SchemaReferences}};
-            $crate::paste! {
-                $( $tt :: )* [<__path_ $handler>]::$op $( $args )*
-            }
-        }
+    ( @resolve_doc $doc:ty : $schemas:tt ) => {
+        fastapi::routing::__operation::<$doc>(&mut $schemas)
     };
     ( ) => {};
 }
@@ -194,9 +207,11 @@ macro_rules! routes {
 #[cfg(test)]
 mod tests {
     use std::collections::BTreeMap;
+    use std::convert::Infallible;
 
     use super::*;
     use axum::extract::State;
+    use fastapi::openapi::path::Operation;
     use fastapi::openapi::{Content, Ref, ResponseBuilder};
     use fastapi::PartialSchema;
     use router::*;
@@ -289,6 +304,26 @@ mod tests {
         assert_eq!(expected_paths.build(), paths);
     }
 
+    #[test]
+    fn openapi_router_split_for_shared_openapi() {
+        use fastapi_ui::shared::SharedOpenApi;
+
+        let shared = SharedOpenApi::new(fastapi::openapi::OpenApiBuilder::new().build());
+
+        let _: axum::Router = OpenApiRouter::new()
+            .routes(routes!(get_user))
+            .split_for_shared(&shared);
+        assert!(shared.load().paths.get_path_item("/search").is_none());
+
+        let _: axum::Router = OpenApiRouter::new()
+            .routes(routes!(search_user))
+            .split_for_shared(&shared);
+
+        let openapi = shared.load();
+        assert!(openapi.paths.get_path_item("/").is_some());
+        assert!(openapi.paths.get_path_item("/search").is_some());
+    }
+
     #[test]
     fn openapi_router_nest_openapi() {
         use fastapi::OpenApi;
@@ -375,6 +410,310 @@ mod tests {
         assert_eq!(expected_schemas, schemas);
     }
 
+    #[test]
+    fn openapi_routes_with_generic_handler() {
+        #[derive(fastapi::ToSchema)]
+        #[allow(unused)]
+        struct Todo {
+            id: i32,
+        }
+
+        #[fastapi::path(get, path = "/items", responses((status = 200, body = Vec<T>)))]
+        async fn list<T: fastapi::ToSchema + 'static>() -> String {
+            T::name().into_owned()
+        }
+
+        let mut router: router::OpenApiRouter =
+            router::OpenApiRouter::new().routes(routes!(list::<Todo>));
+
+        let openapi = router.to_openapi();
+        let schemas = openapi
+            .components
+            .expect("Router must have auto collected schemas")
+            .schemas;
+
+        let expected_paths = fastapi::openapi::path::PathsBuilder::new().path(
+            "/items",
+            fastapi::openapi::PathItem::new(
+                fastapi::openapi::path::HttpMethod::Get,
+                fastapi::openapi::path::OperationBuilder::new()
+                    .operation_id(Some("list"))
+                    .response(
+                        "200",
+                        ResponseBuilder::new().content(
+                            "application/json",
+                            Content::builder()
+                                .schema(Some(
+                                    fastapi::openapi::schema::ArrayBuilder::new()
+                                        .items(Ref::from_schema_name("Todo")),
+                                ))
+                                .build(),
+                        ),
+                    ),
+            ),
+        );
+        let expected_schemas =
+            BTreeMap::from_iter(std::iter::once(("Todo".to_string(), Todo::schema())));
+        assert_eq!(expected_paths.build(), openapi.paths);
+        assert_eq!(expected_schemas, schemas);
+    }
+
+    #[fastapi::path(get, path = "/state")]
+    async fn get_state<S: Clone + Send + Sync + 'static>(State(_s): State<S>) {}
+
+    fn state_router<S: Clone + Send + Sync + 'static>() -> router::OpenApiRouter<S> {
+        router::OpenApiRouter::new().routes(routes!(get_state::<S>))
+    }
+
+    #[test]
+    fn openapi_routes_with_handler_generic_over_state() {
+        let (router, openapi) = state_router::<String>().split_for_parts();
+        let _: axum::Router = router.with_state(String::new());
+
+        let expected_paths = fastapi::openapi::path::PathsBuilder::new().path(
+            "/state",
+            fastapi::openapi::PathItem::new(
+                fastapi::openapi::path::HttpMethod::Get,
+                fastapi::openapi::path::OperationBuilder::new().operation_id(Some("get_state")),
+            ),
+        );
+        assert_eq!(expected_paths.build(), openapi.paths);
+    }
+
+    #[test]
+    fn openapi_routes_closure_with_separate_doc() {
+        #[derive(fastapi::ToSchema)]
+        #[allow(unused)]
+        struct Health {
+            status: String,
+        }
+
+        struct HealthDoc;
+
+        #[fastapi::path(
+            get,
+            path = "/health",
+            impl_for = HealthDoc,
+            tag = "health",
+            responses((status = 200, description = "Service is healthy", body = Health))
+        )]
+        #[allow(unused)]
+        fn health() {}
+
+        let mut router: router::OpenApiRouter = router::OpenApiRouter::new()
+            .routes(routes!(get "/health" => || async { "OK" }, doc = HealthDoc));
+
+        let openapi = router.to_openapi();
+        let schemas = openapi
+            .components
+            .expect("Router must have auto collected schemas")
+            .schemas;
+
+        let expected_paths = fastapi::openapi::path::PathsBuilder::new().path(
+            "/health",
+            fastapi::openapi::PathItem::new(
+                fastapi::openapi::path::HttpMethod::Get,
+                fastapi::openapi::path::OperationBuilder::new()
+                    .operation_id(Some("health"))
+                    .tag("health")
+                    .response(
+                        "200",
+                        ResponseBuilder::new()
+                            .description("Service is healthy")
+                            .content(
+                                "application/json",
+                                Content::builder()
+                                    .schema(Some(Ref::from_schema_name("Health")))
+                                    .build(),
+                            ),
+                    ),
+            ),
+        );
+        let expected_schemas =
+            BTreeMap::from_iter(std::iter::once(("Health".to_string(), Health::schema())));
+        assert_eq!(expected_paths.build(), openapi.paths);
+        assert_eq!(expected_schemas, schemas);
+    }
+
+    #[test]
+    fn openapi_router_layer_documented() {
+        use fastapi::openapi::path::{HttpMethod, Parameter, ParameterIn};
+        use fastapi::openapi::security::SecurityRequirement;
+        use fastapi::openapi::Required;
+        use tower_layer::Identity;
+
+        let auth = Documented::new(Identity::new(), |operation: &mut Operation| {
+            operation
+                .security
+                .get_or_insert_with(Vec::new)
+                .push(SecurityRequirement::new("api_key", Vec::<String>::new()));
+            operation.responses.responses.insert(
+                "401".to_string(),
+                ResponseBuilder::new().description("Unauthorized").into(),
+            );
+        });
+        let tenant = Documented::new(Identity::new(), |operation: &mut Operation| {
+            operation.parameters.get_or_insert_with(Vec::new).push(
+                Parameter::builder()
+                    .name("x-tenant")
+                    .parameter_in(ParameterIn::Header)
+                    .required(Required::True)
+                    .build(),
+            );
+        });
+
+        let router: OpenApiRouter = OpenApiRouter::new()
+            .routes(routes!(get_user, post_user).layer_documented(tenant))
+            .routes(routes!(search_user))
+            .route_layer_documented(auth)
+            .routes(routes!(delete_customer));
+        let paths = &router.get_openapi().paths;
+
+        for method in [HttpMethod::Get, HttpMethod::Post] {
+            let operation = paths.get_path_operation("/", method).unwrap();
+            assert_eq!(operation.security.as_ref().map(Vec::len), Some(1));
+            assert!(operation.responses.responses.contains_key("401"));
+            assert_eq!(operation.parameters.as_ref().unwrap()[0].name, "x-tenant");
+        }
+
+        let search = paths
+            .get_path_operation("/search", HttpMethod::Get)
+            .unwrap();
+        assert!(search.responses.responses.contains_key("401"));
+        assert!(search.parameters.is_none());
+
+        let delete = paths.get_path_operation("/", HttpMethod::Delete).unwrap();
+        assert!(delete.security.is_none());
+    }
+
+    #[test]
+    fn openapi_router_nest_with_options() {
+        use fastapi::openapi::path::HttpMethod;
+        use fastapi::openapi::{NestOptions, SecurityRequirement};
+
+        let admin_router: OpenApiRouter = OpenApiRouter::new()
+            .routes(routes!(get_user))
+            .routes(routes!(search_user));
+
+        let router: OpenApiRouter = OpenApiRouter::new()
+            .routes(routes!(get_customer))
+            .nest_with(
+                "/admin",
+                admin_router,
+                NestOptions::new()
+                    .tag("admin")
+                    .security(SecurityRequirement::new("admin_auth", Vec::<String>::new()))
+                    .response("403", ResponseBuilder::new().description("Forbidden")),
+            );
+        let paths = &router.get_openapi().paths;
+
+        for path in ["/admin", "/admin/search"] {
+            let operation = paths.get_path_operation(path, HttpMethod::Get).unwrap();
+            assert_eq!(operation.tags, Some(vec!["admin".to_string()]));
+            assert_eq!(
+                operation.security,
+                Some(vec![SecurityRequirement::new(
+                    "admin_auth",
+                    Vec::<String>::new()
+                )])
+            );
+            assert!(operation.responses.responses.contains_key("403"));
+        }
+
+        let customer = paths.get_path_operation("/", HttpMethod::Get).unwrap();
+        assert!(customer.tags.is_none());
+        assert!(customer.security.is_none());
+    }
+
+    #[test]
+    fn openapi_router_undocumented_routes() {
+        let nested: OpenApiRouter = OpenApiRouter::new()
+            .routes(routes!(get_user))
+            .route("/:id", axum::routing::get(|| async {}))
+            .fallback(|| async {});
+
+        let router: OpenApiRouter = OpenApiRouter::new()
+            .routes(routes!(search_user))
+            .route("/search", axum::routing::post(|| async {}))
+            .route("/health", axum::routing::get(|| async {}))
+            .undocumented()
+            .route_service(
+                "/metrics",
+                tower::service_fn(|_| async {
+                    Ok::<_, Infallible>(axum::response::Response::new(axum::body::Body::empty()))
+                }),
+            )
+            .nest("/api/user", nested);
+
+        assert_eq!(
+            router
+                .undocumented_routes()
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            [
+                "POST /search",
+                "/metrics",
+                "GET /api/user/{id}",
+                "/api/user/*"
+            ]
+        );
+    }
+
+    #[test]
+    fn openapi_router_undocumented_after_nest_and_merge_marks_nothing() {
+        let nested: OpenApiRouter =
+            OpenApiRouter::new().route("/health", axum::routing::get(|| async {}));
+        let merged: OpenApiRouter =
+            OpenApiRouter::new().route("/metrics", axum::routing::put(|| async {}));
+
+        let router: OpenApiRouter = OpenApiRouter::new()
+            .route("/status", axum::routing::get(|| async {}).head(|| async {}))
+            .nest("/api", nested)
+            .undocumented()
+            .merge(merged)
+            .undocumented();
+
+        assert_eq!(
+            router
+                .undocumented_routes()
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            [
+                "GET /status",
+                "HEAD /status",
+                "GET /api/health",
+                "PUT /metrics"
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "undocumented routes: GET /health")]
+    fn openapi_router_strict_panics_on_undocumented_routes() {
+        let nested: OpenApiRouter = OpenApiRouter::new()
+            .strict()
+            .routes(routes!(get_user))
+            .route("/health", axum::routing::get(|| async {}));
+
+        let _: axum::Router = OpenApiRouter::new().merge(nested).into();
+    }
+
+    #[test]
+    fn openapi_router_strict_allows_undocumented_opt_out() {
+        let (_, api): (axum::Router, _) = OpenApiRouter::new()
+            .strict()
+            .routes(routes!(get_user))
+            .route("/health", axum::routing::get(|| async {}))
+            .undocumented()
+            .fallback(|| async {})
+            .undocumented()
+            .split_for_parts();
+
+        assert!(api.paths.get_path_item("/").is_some());
+    }
+
     mod pets {
 
         #[fastapi::path(get, path = "/")]
@@ -418,4 +757,74 @@ mod tests {
             );
         assert_eq!(expected_paths.build(), paths);
     }
+
+    #[test]
+    fn openapi_routes_from_qualified_path() {
+        let mut router: OpenApiRouter =
+            OpenApiRouter::new().routes(routes!(crate::tests::pets::get_pet, self::pets::post_pet));
+        let paths = router.to_openapi().paths;
+
+        let expected_paths = fastapi::openapi::path::PathsBuilder::new()
+            .path(
+                "/",
+                fastapi::openapi::PathItem::new(
+                    fastapi::openapi::path::HttpMethod::Get,
+                    fastapi::openapi::path::OperationBuilder::new().operation_id(Some("get_pet")),
+                ),
+            )
+            .path(
+                "/",
+                fastapi::openapi::PathItem::new(
+                    fastapi::openapi::path::HttpMethod::Post,
+                    fastapi::openapi::path::OperationBuilder::new().operation_id(Some("post_pet")),
+                ),
+            );
+        assert_eq!(expected_paths.build(), paths);
+    }
+
+    #[tokio::test]
+    async fn problem_into_response() {
+        use axum::response::IntoResponse;
+
+        let response = fastapi::problem::Problem::new(404)
+            .title("Pet not found")
+            .into_response();
+
+        assert_eq!(response.status(), axum::http::StatusCode::NOT_FOUND);
+        assert_eq!(
+            response.headers()[axum::http::header::CONTENT_TYPE],
+            "application/problem+json"
+        );
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert_eq!(&body[..], br#"{"title":"Pet not found","status":404}"#);
+    }
+
+    #[tokio::test]
+    async fn paged_response_into_response() {
+        use axum::response::IntoResponse;
+        use fastapi::pagination::{OffsetParams, Page, PagedResponse};
+
+        let params = OffsetParams {
+            page: Some(2),
+            per_page: Some(1),
+        };
+        let response =
+            PagedResponse::offset(Page::offset(vec!["b"], &params, 2), "/items").into_response();
+
+        assert_eq!(response.status(), axum::http::StatusCode::OK);
+        assert_eq!(
+            response.headers()[axum::http::header::LINK],
+            r#"</items?page=1&per_page=1>; rel="first", </items?page=1&per_page=1>; rel="prev", </items?page=2&per_page=1>; rel="last""#
+        );
+        assert_eq!(response.headers()["x-total-count"], "2");
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert_eq!(
+            &body[..],
+            br#"{"items":["b"],"total":2,"page":2,"per_page":1}"#
+        );
+    }
 }
@@ -1,5 +1,4 @@
 //! Implements Router for composing handlers and collecting OpenAPI information.
-use std::borrow::Cow;
 use std::convert::Infallible;
 
 use axum::extract::Request;
@@ -7,9 +6,13 @@ use axum::handler::Handler;
 use axum::response::IntoResponse;
 use axum::routing::{MethodRouter, Route, RouterAsService};
 use axum::Router;
+use fastapi::openapi::path::{HttpMethod, Operation, Paths};
+use fastapi::routing::{path_for_nested_route, RouteRecord};
 use tower_layer::Layer;
 use tower_service::Service;
 
+pub use fastapi::routing::RegisteredRoute;
+
 #[inline]
 fn colonized_params<S: AsRef<str>>(path: S) -> String
 where
@@ -19,7 +22,9 @@ where
 }
 
 #[inline]
-fn path_template<S: AsRef<str>>(path: S) -> String {
+pub(crate) fn path_template<S: AsRef<str>>(path: S) -> String {
+    use std::borrow::Cow;
+
     path.as_ref()
         .split('/')
         .map(|segment| {
@@ -72,6 +77,21 @@ where
         S: 'static,
         NewError: 'static;
 
+    /// Apply the `layer` with [`axum::routing::MethodRouter::layer`] and document its effect to
+    /// every [`Operation`] of the [`FastapiMethodRouter`] with [`DocumentedLayer::document`].
+    ///
+    /// See [`DocumentedLayer`] for more details.
+    fn layer_documented<L, NewError>(self, layer: L) -> FastapiMethodRouter<S, NewError>
+    where
+        L: Layer<Route<E>> + DocumentedLayer + Clone + Send + 'static,
+        L::Service: Service<Request> + Clone + Send + 'static,
+        <L::Service as Service<Request>>::Response: IntoResponse + 'static,
+        <L::Service as Service<Request>>::Error: Into<NewError> + 'static,
+        <L::Service as Service<Request>>::Future: Send + 'static,
+        E: 'static,
+        S: 'static,
+        NewError: 'static;
+
     /// Pass through method for [`axum::routing::MethodRouter::with_state`].
     ///
     /// Allows quick state definition for underlying [`axum::routing::MethodRouter`].
@@ -115,6 +135,23 @@ where
         (self.0, self.1, self.2.layer(layer))
     }
 
+    fn layer_documented<L, NewError>(self, layer: L) -> FastapiMethodRouter<S, NewError>
+    where
+        L: Layer<Route<E>> + DocumentedLayer + Clone + Send + 'static,
+        L::Service: Service<Request> + Clone + Send + 'static,
+        <L::Service as Service<Request>>::Response: IntoResponse + 'static,
+        <L::Service as Service<Request>>::Error: Into<NewError> + 'static,
+        <L::Service as Service<Request>>::Future: Send + 'static,
+        E: 'static,
+        S: 'static,
+        NewError: 'static,
+    {
+        let (schemas, mut paths, method_router) = self;
+        document_paths(&mut paths, &layer);
+
+        (schemas, paths, method_router.layer(layer))
+    }
+
     fn with_state<S2>(self, state: S) -> FastapiMethodRouter<S2, E> {
         (self.0, self.1, self.2.with_state(state))
     }
@@ -127,6 +164,177 @@ where
     }
 }
 
+/// Layer that is able to describe its effect on the OpenAPI [`Operation`]s it is applied to.
+///
+/// Layers such as authentication or rate limiting change the behavior of the routes they cover
+/// but are invisible to the OpenAPI documentation. Implementing this trait allows the layer to
+/// add e.g. [`SecurityRequirement`][security], [`Response`][response] or required header
+/// [`Parameter`][parameter] to each documented operation when the layer is added with
+/// [`OpenApiRouter::layer_documented`], [`OpenApiRouter::route_layer_documented`] or
+/// [`FastapiMethodRouterExt::layer_documented`].
+///
+/// Layers of other crates can be documented by wrapping them with [`Documented`].
+///
+/// # Examples
+///
+/// _**Document authentication layer requiring `api_key` security and responding `401`.**_
+/// ```rust
+/// # use fastapi::openapi::path::Operation;
+/// # use fastapi::openapi::security::SecurityRequirement;
+/// # use fastapi::openapi::ResponseBuilder;
+/// # use fastapi_axum::router::{DocumentedLayer, OpenApiRouter};
+/// # use fastapi_axum::routes;
+/// #[derive(Clone)]
+/// struct AuthLayer;
+///
+/// impl<S> tower_layer::Layer<S> for AuthLayer {
+///     type Service = S;
+///
+///     fn layer(&self, inner: S) -> Self::Service {
+///         // wrap the inner service with authentication here
+///         inner
+///     }
+/// }
+///
+/// impl DocumentedLayer for AuthLayer {
+///     fn document(&self, operation: &mut Operation) {
+///         operation
+///             .security
+///             .get_or_insert_with(Vec::new)
+///             .push(SecurityRequirement::new("api_key", Vec::<String>::new()));
+///         operation.responses.responses.insert(
+///             "401".to_string(),
+///             ResponseBuilder::new().description("Unauthorized").into(),
+///         );
+///     }
+/// }
+///
+/// #[fastapi::path(get, path = "/user")]
+/// async fn get_user() {}
+///
+/// let router: OpenApiRouter = OpenApiRouter::new()
+///     .routes(routes!(get_user))
+///     .layer_documented(AuthLayer);
+///
+/// let operation = router.get_openapi().paths.get_path_operation("/user", fastapi::openapi::HttpMethod::Get);
+/// assert!(operation.unwrap().responses.responses.contains_key("401"));
+/// ```
+///
+/// [security]: fastapi::openapi::security::SecurityRequirement
+/// [response]: fastapi::openapi::Response
+/// [parameter]: fastapi::openapi::path::Parameter
+pub trait DocumentedLayer {
+    /// Describe the effect of the layer to the `operation` of a route covered by the layer.
+    fn document(&self, operation: &mut Operation);
+}
+
+/// Wrapper for any [`Layer`] implementing [`DocumentedLayer`] with the given `document` function.
+///
+/// # Examples
+///
+/// _**Document `429` response of rate limiting layer.**_
+/// ```rust
+/// # use fastapi::openapi::ResponseBuilder;
+/// # use fastapi_axum::router::{Documented, OpenApiRouter};
+/// # use tower_layer::Identity;
+/// # let rate_limit_layer = Identity::new();
+/// let router: OpenApiRouter = OpenApiRouter::new()
+///     .layer_documented(Documented::new(rate_limit_layer, |operation| {
+///         operation.responses.responses.insert(
+///             "429".to_string(),
+///             ResponseBuilder::new().description("Too many requests").into(),
+///         );
+///     }));
+/// ```
+#[derive(Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct Documented<L, F> {
+    layer: L,
+    document: F,
+}
+
+impl<L, F> Documented<L, F>
+where
+    F: Fn(&mut Operation),
+{
+    /// Construct new [`Documented`] layer from `layer` and `document` function describing the
+    /// effect of the layer to the OpenAPI [`Operation`]s.
+    pub fn new(layer: L, document: F) -> Self {
+        Self { layer, document }
+    }
+}
+
+impl<S, L, F> Layer<S> for Documented<L, F>
+where
+    L: Layer<S>,
+{
+    type Service = L::Service;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        self.layer.layer(inner)
+    }
+}
+
+impl<L, F> DocumentedLayer for Documented<L, F>
+where
+    F: Fn(&mut Operation),
+{
+    fn document(&self, operation: &mut Operation) {
+        (self.document)(operation)
+    }
+}
+
+/// Document every [`Operation`] of the `paths` with the `layer`.
+fn document_paths<L: DocumentedLayer>(paths: &mut Paths, layer: &L) {
+    for item in paths.paths.values_mut() {
+        item.operations_mut()
+            .for_each(|(_, operation)| layer.document(operation));
+    }
+}
+
+/// Get [`HttpMethod`]s routed by the `method_router`.
+///
+/// Methods of [`axum::routing::MethodRouter`] are not exposed, so they are read from its debug
+/// representation where methods without handler are formatted as `None`.
+fn routed_methods<S, E>(method_router: &MethodRouter<S, E>) -> Vec<HttpMethod> {
+    let debug = format!("{method_router:?}");
+    [
+        ("get", HttpMethod::Get),
+        ("head", HttpMethod::Head),
+        ("delete", HttpMethod::Delete),
+        ("options", HttpMethod::Options),
+        ("patch", HttpMethod::Patch),
+        ("post", HttpMethod::Post),
+        ("put", HttpMethod::Put),
+        ("trace", HttpMethod::Trace),
+    ]
+    .into_iter()
+    .filter(|(name, _)| {
+        debug
+            .split_once(&format!(" {name}: "))
+            .is_some_and(|(_, endpoint)| !endpoint.starts_with("None"))
+    })
+    .map(|(_, method)| method)
+    .collect()
+}
+
+/// Get [`RegisteredRoute`]s of `path` for each of the `methods` or single route of any method if
+/// `methods` is empty.
+fn registered_routes(path: String, methods: Vec<HttpMethod>) -> Vec<RegisteredRoute> {
+    if methods.is_empty() {
+        vec![RegisteredRoute::new(path, None)]
+    } else {
+        methods
+            .into_iter()
+            .map(|method| RegisteredRoute::new(path.clone(), Some(method)))
+            .collect()
+    }
+}
+
+fn fallback_route() -> RegisteredRoute {
+    RegisteredRoute::new("/*", None)
+}
+
 /// A wrapper struct for [`axum::Router`] and [`fastapi::openapi::OpenApi`] for composing handlers
 /// and services with collecting OpenAPI information from the handlers.
 ///
@@ -150,7 +358,7 @@ where
 /// ```
 #[derive(Clone)]
 #[cfg_attr(feature = "debug", derive(Debug))]
-pub struct OpenApiRouter<S = ()>(Router<S>, fastapi::openapi::OpenApi);
+pub struct OpenApiRouter<S = ()>(Router<S>, fastapi::openapi::OpenApi, RouteRecord);
 
 impl<S> OpenApiRouter<S>
 where
@@ -191,7 +399,7 @@ where
     /// let mut router: OpenApiRouter = OpenApiRouter::with_openapi(Api::openapi());
     /// ```
     pub fn with_openapi(openapi: fastapi::openapi::OpenApi) -> Self {
-        Self(Router::new(), openapi)
+        Self(Router::new(), openapi, RouteRecord::default())
     }
 
     /// Pass through method for [`axum::Router::as_service`].
@@ -200,22 +408,24 @@ where
     }
 
     /// Pass through method for [`axum::Router::fallback`].
-    pub fn fallback<H, T>(self, handler: H) -> Self
+    pub fn fallback<H, T>(mut self, handler: H) -> Self
     where
         H: Handler<T, S>,
         T: 'static,
     {
-        Self(self.0.fallback(handler), self.1)
+        self.2.record([fallback_route()]);
+        Self(self.0.fallback(handler), self.1, self.2)
     }
 
     /// Pass through method for [`axum::Router::fallback_service`].
-    pub fn fallback_service<T>(self, service: T) -> Self
+    pub fn fallback_service<T>(mut self, service: T) -> Self
     where
         T: Service<Request, Error = Infallible> + Clone + Send + 'static,
         T::Response: IntoResponse,
         T::Future: Send + 'static,
     {
-        Self(self.0.fallback_service(service), self.1)
+        self.2.record([fallback_route()]);
+        Self(self.0.fallback_service(service), self.1, self.2)
     }
 
     /// Pass through method for [`axum::Router::layer`].
@@ -227,7 +437,26 @@ where
         <L::Service as Service<Request>>::Error: Into<Infallible> + 'static,
         <L::Service as Service<Request>>::Future: Send + 'static,
     {
-        Self(self.0.layer(layer), self.1)
+        Self(self.0.layer(layer), self.1, self.2)
+    }
+
+    /// Apply the `layer` with [`axum::Router::layer`] and document its effect to every
+    /// [`Operation`] currently registered to the [`OpenApiRouter`] with
+    /// [`DocumentedLayer::document`].
+    ///
+    /// Like [`OpenApiRouter::layer`] the layer only covers routes added before calling this
+    /// method. See [`DocumentedLayer`] for more details.
+    pub fn layer_documented<L>(mut self, layer: L) -> Self
+    where
+        L: Layer<Route> + DocumentedLayer + Clone + Send + 'static,
+        L::Service: Service<Request> + Clone + Send + 'static,
+        <L::Service as Service<Request>>::Response: IntoResponse + 'static,
+        <L::Service as Service<Request>>::Error: Into<Infallible> + 'static,
+        <L::Service as Service<Request>>::Future: Send + 'static,
+    {
+        document_paths(&mut self.1.paths, &layer);
+
+        Self(self.0.layer(layer), self.1, self.2)
     }
 
     /// Register [`FastapiMethodRouter`] content created with [`routes`][routes] macro to `self`.
@@ -253,6 +482,12 @@ where
             })
         };
 
+        self.2.record(paths.paths.iter().flat_map(|(path, item)| {
+            let path = if path.is_empty() { "/" } else { path };
+            item.operations()
+                .map(move |(method, _)| RegisteredRoute::new(path, Some(method)))
+        }));
+
         // add or merge current paths to the OpenApi
         for (path, item) in paths.paths {
             if let Some(it) = self.1.paths.paths.get_mut(&path) {
@@ -268,12 +503,21 @@ where
             .get_or_insert(fastapi::openapi::Components::new());
         components.schemas.extend(schemas);
 
-        Self(router, self.1)
+        Self(router, self.1, self.2)
     }
 
     /// Pass through method for [`axum::Router<S>::route`].
-    pub fn route(self, path: &str, method_router: MethodRouter<S>) -> Self {
-        Self(self.0.route(&colonized_params(path), method_router), self.1)
+    ///
+    /// The route is recorded for each method of the [`axum::routing::MethodRouter`] or as a
+    /// route of any method if it only has a fallback. See [`OpenApiRouter::undocumented_routes`]
+    /// for more details.
+    pub fn route(mut self, path: &str, method_router: MethodRouter<S>) -> Self {
+        let path = colonized_params(path);
+        self.2.record(registered_routes(
+            path_template(&path),
+            routed_methods(&method_router),
+        ));
+        Self(self.0.route(&path, method_router), self.1, self.2)
     }
 
     /// Pass through method for [`axum::Router::route_layer`].
@@ -285,17 +529,39 @@ where
         <L::Service as Service<Request>>::Error: Into<Infallible> + 'static,
         <L::Service as Service<Request>>::Future: Send + 'static,
     {
-        Self(self.0.route_layer(layer), self.1)
+        Self(self.0.route_layer(layer), self.1, self.2)
+    }
+
+    /// Apply the `layer` with [`axum::Router::route_layer`] and document its effect to every
+    /// [`Operation`] currently registered to the [`OpenApiRouter`] with
+    /// [`DocumentedLayer::document`].
+    ///
+    /// See [`DocumentedLayer`] for more details.
+    pub fn route_layer_documented<L>(mut self, layer: L) -> Self
+    where
+        L: Layer<Route> + DocumentedLayer + Clone + Send + 'static,
+        L::Service: Service<Request> + Clone + Send + 'static,
+        <L::Service as Service<Request>>::Response: IntoResponse + 'static,
+        <L::Service as Service<Request>>::Error: Into<Infallible> + 'static,
+        <L::Service as Service<Request>>::Future: Send + 'static,
+    {
+        document_paths(&mut self.1.paths, &layer);
+
+        Self(self.0.route_layer(layer), self.1, self.2)
     }
 
     /// Pass through method for [`axum::Router<S>::route_service`].
-    pub fn route_service<T>(self, path: &str, service: T) -> Self
+    pub fn route_service<T>(mut self, path: &str, service: T) -> Self
     where
         T: Service<Request, Error = Infallible> + Clone + Send + 'static,
         T::Response: IntoResponse,
         T::Future: Send + 'static,
     {
-        Self(self.0.route_service(path, service), self.1)
+        self.2.record([RegisteredRoute::new(
+            path_template(colonized_params(path)),
+            None,
+        )]);
+        Self(self.0.route_service(path, service), self.1, self.2)
     }
 
     /// Nest `router` to `self` under given `path`. Router routes will be nested with
@@ -319,40 +585,73 @@ where
     /// let router: OpenApiRouter = OpenApiRouter::new()
     ///     .nest("/api", search_router);
     /// ```
-    pub fn nest(self, path: &str, router: OpenApiRouter<S>) -> Self {
-        // from axum::routing::path_router::path_for_nested_route
-        // method is private, so we need to replicate it here
-        fn path_for_nested_route<'a>(prefix: &'a str, path: &'a str) -> String {
-            debug_assert!(prefix.starts_with('/'));
-            debug_assert!(path.starts_with('/'));
-
-            if prefix.ends_with('/') {
-                format!("{prefix}{}", path.trim_start_matches('/'))
-            } else if path == "/" {
-                prefix.into()
-            } else {
-                format!("{prefix}{path}")
-            }
-        }
-
-        let api = self.1.nest_with_path_composer(
-            path_for_nested_route(path, "/"),
-            router.1,
-            |a: &str, b: &str| path_for_nested_route(a, b),
-        );
+    pub fn nest(mut self, path: &str, router: OpenApiRouter<S>) -> Self {
+        let prefix = path_for_nested_route(path, "/");
+        self.2
+            .nest(router.2, |route| path_for_nested_route(&prefix, route));
+        let api = self
+            .1
+            .nest_with_path_composer(prefix, router.1, |a: &str, b: &str| {
+                path_for_nested_route(a, b)
+            });
         let router = self.0.nest(&colonized_params(path), router.0);
 
-        Self(router, api)
+        Self(router, api, self.2)
+    }
+
+    /// Nest `router` to `self` under given `path` like [`OpenApiRouter::nest`] applying given
+    /// [`NestOptions`][nest_options] to all OpenApi paths of the nested `router`.
+    ///
+    /// This allows sharing tags, security requirements, parameters, responses and servers
+    /// between all routes of the nested router.
+    ///
+    /// # Examples
+    ///
+    /// _**Nest admin routes tagged with `admin` and requiring `admin_auth` security.**_
+    /// ```rust
+    /// # use fastapi::openapi::{NestOptions, SecurityRequirement};
+    /// # use fastapi_axum::{routes, router::OpenApiRouter};
+    /// #[fastapi::path(get, path = "/users")]
+    /// async fn list_users() {}
+    ///
+    /// let admin_router = OpenApiRouter::new()
+    ///     .routes(routes!(list_users));
+    ///
+    /// let router: OpenApiRouter = OpenApiRouter::new().nest_with(
+    ///     "/admin",
+    ///     admin_router,
+    ///     NestOptions {
+    ///         tags: vec!["admin".to_string()],
+    ///         security: vec![SecurityRequirement::new("admin_auth", Vec::<String>::new())],
+    ///         ..Default::default()
+    ///     },
+    /// );
+    /// ```
+    ///
+    /// [nest_options]: fastapi::openapi::NestOptions
+    pub fn nest_with(
+        self,
+        path: &str,
+        mut router: OpenApiRouter<S>,
+        options: fastapi::openapi::NestOptions,
+    ) -> Self {
+        options.apply(&mut router.1.paths);
+
+        self.nest(path, router)
     }
 
     /// Pass through method for [`axum::Router::nest_service`]. _**This does nothing for OpenApi paths.**_
-    pub fn nest_service<T>(self, path: &str, service: T) -> Self
+    pub fn nest_service<T>(mut self, path: &str, service: T) -> Self
     where
         T: Service<Request, Error = Infallible> + Clone + Send + 'static,
         T::Response: IntoResponse,
         T::Future: Send + 'static,
     {
-        Self(self.0.nest_service(path, service), self.1)
+        self.2.record([RegisteredRoute::new(
+            path_template(colonized_params(path)),
+            None,
+        )]);
+        Self(self.0.nest_service(path, service), self.1, self.2)
     }
 
     /// Merge [`fastapi::openapi::path::Paths`] from `router` to `self` and merge [`Router`] routes
@@ -378,13 +677,14 @@ where
     /// ```
     pub fn merge(mut self, router: OpenApiRouter<S>) -> Self {
         self.1.merge(router.1);
+        self.2.merge(router.2);
 
-        Self(self.0.merge(router.0), self.1)
+        Self(self.0.merge(router.0), self.1, self.2)
     }
 
     /// Pass through method for [`axum::Router::with_state`].
     pub fn with_state<S2>(self, state: S) -> OpenApiRouter<S2> {
-        OpenApiRouter(self.0.with_state(state), self.1)
+        OpenApiRouter(self.0.with_state(state), self.1, self.2)
     }
 
     /// Consume `self` returning the [`fastapi::openapi::OpenApi`] instance of the
@@ -403,11 +703,132 @@ where
         &self.1
     }
 
+    /// Enable strict mode for the [`OpenApiRouter`].
+    ///
+    /// In strict mode converting the router to [`axum::Router`] with [`Into::into`],
+    /// [`OpenApiRouter::split_for_parts`] or [`OpenApiRouter::split_for_shared`] panics if the
+    /// router has [undocumented routes][OpenApiRouter::undocumented_routes]. Routes that are
+    /// intentionally left out from the OpenAPI documentation must be marked with
+    /// [`OpenApiRouter::undocumented`].
+    ///
+    /// Strict mode is inherited from nested and merged routers.
+    ///
+    /// # Examples
+    ///
+    /// _**Allow undocumented health check route in strict mode.**_
+    /// ```rust
+    /// # use fastapi_axum::{routes, router::OpenApiRouter};
+    /// #[fastapi::path(get, path = "/user")]
+    /// async fn get_user() {}
+    ///
+    /// let (router, api): (axum::Router, _) = OpenApiRouter::new()
+    ///     .strict()
+    ///     .routes(routes!(get_user))
+    ///     .route("/health", axum::routing::get(|| async { "OK" }))
+    ///     .undocumented()
+    ///     .split_for_parts();
+    /// ```
+    pub fn strict(mut self) -> Self {
+        self.2.set_strict(true);
+
+        self
+    }
+
+    /// Mark the routes registered by the previous method call as intentionally undocumented.
+    ///
+    /// Undocumented routes are not reported by [`OpenApiRouter::undocumented_routes`] and are
+    /// allowed in [strict mode][OpenApiRouter::strict]. This applies to routes registered with
+    /// [`OpenApiRouter::route`], [`OpenApiRouter::route_service`],
+    /// [`OpenApiRouter::nest_service`], [`OpenApiRouter::fallback`] and
+    /// [`OpenApiRouter::fallback_service`]. Routes of nested and merged routers are marked in the
+    /// router they are registered to, so calling this after [`OpenApiRouter::nest`] or
+    /// [`OpenApiRouter::merge`] does nothing.
+    pub fn undocumented(mut self) -> Self {
+        self.2.undocumented();
+
+        self
+    }
+
+    /// Get routes registered to the router that have no matching
+    /// [`Operation`][operation] in the [`fastapi::openapi::OpenApi`] of the router.
+    ///
+    /// Routes registered with [`OpenApiRouter::route`] are recorded per method of the
+    /// [`axum::routing::MethodRouter`]. Routes registered with [`OpenApiRouter::route_service`]
+    /// and [`OpenApiRouter::nest_service`] are considered documented if the path has any
+    /// operation since methods of the service cannot be inspected. Fallback routes are reported with path
+    /// `/*` under the path they are nested to. Routes marked with
+    /// [`OpenApiRouter::undocumented`] and routes of [`axum::Router`]s converted with
+    /// [`From::from`] are not reported.
+    ///
+    /// # Examples
+    ///
+    /// _**Find undocumented route.**_
+    /// ```rust
+    /// # use fastapi_axum::{routes, router::OpenApiRouter};
+    /// #[fastapi::path(get, path = "/user")]
+    /// async fn get_user() {}
+    ///
+    /// let router: OpenApiRouter = OpenApiRouter::new()
+    ///     .routes(routes!(get_user))
+    ///     .route("/user/:id", axum::routing::delete(|| async {}));
+    ///
+    /// let undocumented = router.undocumented_routes();
+    /// assert_eq!(undocumented.len(), 1);
+    /// assert_eq!(undocumented[0].path, "/user/{id}");
+    /// ```
+    ///
+    /// [operation]: fastapi::openapi::path::Operation
+    pub fn undocumented_routes(&self) -> Vec<RegisteredRoute> {
+        self.2.undocumented_routes(&self.1.paths).cloned().collect()
+    }
+
     /// Split the content of the [`OpenApiRouter`] to parts. Method will return a tuple of
     /// inner [`axum::Router`] and [`fastapi::openapi::OpenApi`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the router is [strict][OpenApiRouter::strict] and has undocumented routes.
     pub fn split_for_parts(self) -> (axum::Router<S>, fastapi::openapi::OpenApi) {
+        self.2.assert_documented(&self.1, "OpenApiRouter");
         (self.0, self.1)
     }
+
+    /// Split the content of the [`OpenApiRouter`] merging the [`fastapi::openapi::OpenApi`] of
+    /// the router to the given `shared` document. Method will return the inner [`axum::Router`].
+    ///
+    /// This is useful when routes are added to the application at runtime, e.g. by plugins. The
+    /// paths of the router become visible in the UI integrations serving the `shared` document
+    /// as soon as the method returns.
+    ///
+    /// # Examples
+    ///
+    /// _**Add routes of a plugin to running application.**_
+    /// ```rust
+    /// # use fastapi::openapi::OpenApiBuilder;
+    /// # use fastapi_ui::shared::SharedOpenApi;
+    /// # use fastapi_axum::router::OpenApiRouter;
+    /// # use fastapi_axum::routes;
+    /// #[fastapi::path(get, path = "/plugin")]
+    /// async fn plugin() {}
+    ///
+    /// let shared = SharedOpenApi::new(OpenApiBuilder::new().build());
+    ///
+    /// let plugin_router: axum::Router = OpenApiRouter::new()
+    ///     .routes(routes!(plugin))
+    ///     .split_for_shared(&shared);
+    ///
+    /// assert!(shared.load().paths.get_path_item("/plugin").is_some());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the router is [strict][OpenApiRouter::strict] and has undocumented routes.
+    pub fn split_for_shared(self, shared: &fastapi_ui::shared::SharedOpenApi) -> axum::Router<S> {
+        self.2.assert_documented(&self.1, "OpenApiRouter");
+        shared.update(|openapi| openapi.merge(self.1));
+
+        self.0
+    }
 }
 
 impl<S> Default for OpenApiRouter<S>
@@ -420,13 +841,21 @@ where
 }
 
 impl<S> From<OpenApiRouter<S>> for Router<S> {
+    /// # Panics
+    ///
+    /// Panics if the router is [strict][OpenApiRouter::strict] and has undocumented routes.
     fn from(value: OpenApiRouter<S>) -> Self {
+        value.2.assert_documented(&value.1, "OpenApiRouter");
         value.0
     }
 }
 
 impl<S> From<Router<S>> for OpenApiRouter<S> {
     fn from(value: Router<S>) -> Self {
-        OpenApiRouter(value, fastapi::openapi::OpenApiBuilder::new().build())
+        OpenApiRouter(
+            value,
+            fastapi::openapi::OpenApiBuilder::new().build(),
+            RouteRecord::default(),
+        )
     }
 }
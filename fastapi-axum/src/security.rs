@@ -0,0 +1,748 @@
+//! Implements enforcement of OpenAPI security requirements for [`axum::Router`] routes.
+//!
+//! [`SecurityLayer`] resolves the security requirements of the matched operation from the
+//! [`fastapi::openapi::OpenApi`] and authenticates requests with user supplied [`Authenticator`].
+//! This way the enforced security cannot disagree with the documented security.
+//!
+//! Security requirements of an operation are resolved as follows.
+//! * [`Operation::security`][operation_security] of the matched operation if defined.
+//! * Otherwise global [`OpenApi::security`][openapi_security] if defined.
+//! * Otherwise the operation is not protected.
+//!
+//! `HEAD` requests are served by `GET` handlers in [`axum::Router`], thus `HEAD` requests without
+//! documented `HEAD` operation are enforced with the security requirements of the `GET`
+//! operation.
+//!
+//! Only one of the resolved [`SecurityRequirement`]s need to be satisfied, but all schemes within
+//! single requirement must be satisfied simultaneously. Empty [`SecurityRequirement::default`]
+//! makes the security optional and always lets the request through.
+//!
+//! Requests are rejected with `401 Unauthorized` when no requirement is satisfied and with
+//! `403 Forbidden` when credentials were recognized but lacked required scopes e.g. the
+//! [`Authenticator`] returned [`AuthOutcome::Forbidden`].
+//!
+//! [operation_security]: fastapi::openapi::path::Operation::security
+//! [openapi_security]: fastapi::openapi::OpenApi::security
+use std::collections::{BTreeMap, HashMap};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use axum::extract::{MatchedPath, Request};
+use axum::http::request::Parts;
+use axum::http::{header, HeaderValue, Method, StatusCode};
+use axum::response::{IntoResponse, Response};
+use fastapi::openapi::security::{
+    ApiKey, Http, HttpAuthScheme, SecurityRequirement, SecurityScheme,
+};
+use fastapi::openapi::OpenApi;
+use tower_layer::Layer;
+use tower_service::Service;
+
+use crate::router::path_template;
+
+/// Outcome of authenticating a request against a single security scheme.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AuthOutcome {
+    /// Credentials are valid and have all the required scopes.
+    Authenticated,
+    /// Credentials are missing or invalid. Results `401 Unauthorized` if no other security
+    /// requirement is satisfied.
+    Unauthenticated,
+    /// Credentials are valid but lack the required scopes. Results `403 Forbidden` if no other
+    /// security requirement is satisfied.
+    Forbidden,
+}
+
+/// Credentials extracted from the request for a single security scheme of a security requirement.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug)]
+pub struct Credentials<'a> {
+    /// Name of the security scheme in [`Components::security_schemes`][security_schemes].
+    ///
+    /// [security_schemes]: fastapi::openapi::Components::security_schemes
+    pub name: &'a str,
+    /// Scopes required by the security requirement.
+    pub scopes: &'a [String],
+    /// Credential value extracted from the request. E.g. the token part of `Authorization:
+    /// Bearer <token>` header or the value of the api key.
+    pub value: &'a str,
+}
+
+/// User supplied authenticator used by [`SecurityLayer`] to validate credentials of the request.
+///
+/// Authenticator is called per security scheme type. Credentials are only extracted from the
+/// request and validation of them is left for the authenticator. Each method defaults to
+/// [`AuthOutcome::Unauthenticated`] so only the methods for used security schemes need to be
+/// implemented.
+///
+/// # Examples
+///
+/// _**Authenticate bearer tokens of [`Http`] security scheme.**_
+/// ```rust
+/// # use fastapi::openapi::security::Http;
+/// # use fastapi_axum::security::{Authenticator, AuthOutcome, Credentials};
+/// #[derive(Clone)]
+/// struct TokenAuthenticator;
+///
+/// impl Authenticator for TokenAuthenticator {
+///     async fn http(&self, _: &Http, credentials: Credentials<'_>) -> AuthOutcome {
+///         if credentials.value == "secret" {
+///             AuthOutcome::Authenticated
+///         } else {
+///             AuthOutcome::Unauthenticated
+///         }
+///     }
+/// }
+/// ```
+pub trait Authenticator: Send + Sync + 'static {
+    /// Authenticate [`Http`] security scheme credentials from `Authorization` header. The
+    /// `Authorization` header auth scheme must match to the [`Http::scheme`].
+    fn http(
+        &self,
+        http: &Http,
+        credentials: Credentials<'_>,
+    ) -> impl Future<Output = AuthOutcome> + Send {
+        let _ = (http, credentials);
+        async { AuthOutcome::Unauthenticated }
+    }
+
+    /// Authenticate [`ApiKey`] security scheme credentials from header, query or cookie
+    /// depending on the [`ApiKey`] location.
+    fn api_key(
+        &self,
+        api_key: &ApiKey,
+        credentials: Credentials<'_>,
+    ) -> impl Future<Output = AuthOutcome> + Send {
+        let _ = (api_key, credentials);
+        async { AuthOutcome::Unauthenticated }
+    }
+
+    /// Authenticate [`OAuth2`][oauth2] and [`OpenIdConnect`][open_id_connect] security scheme
+    /// bearer token from `Authorization` header. Authenticator should check that the token
+    /// grants the [`Credentials::scopes`] and return [`AuthOutcome::Forbidden`] if not.
+    ///
+    /// [oauth2]: fastapi::openapi::security::OAuth2
+    /// [open_id_connect]: fastapi::openapi::security::OpenIdConnect
+    fn oauth2(&self, credentials: Credentials<'_>) -> impl Future<Output = AuthOutcome> + Send {
+        let _ = credentials;
+        async { AuthOutcome::Unauthenticated }
+    }
+}
+
+/// Resolved security requirements of documented operations. Operations without security
+/// requirements have empty requirements.
+#[cfg_attr(feature = "debug", derive(Debug))]
+struct SecurityRules {
+    operations: HashMap<(String, Method), Vec<SecurityRequirement>>,
+    schemes: BTreeMap<String, SecurityScheme>,
+}
+
+impl SecurityRules {
+    fn new(openapi: &OpenApi) -> Self {
+        let operations = openapi
+            .paths
+            .paths
+            .iter()
+            .flat_map(|(path, item)| {
+                [
+                    (Method::GET, &item.get),
+                    (Method::PUT, &item.put),
+                    (Method::POST, &item.post),
+                    (Method::DELETE, &item.delete),
+                    (Method::OPTIONS, &item.options),
+                    (Method::HEAD, &item.head),
+                    (Method::PATCH, &item.patch),
+                    (Method::TRACE, &item.trace),
+                ]
+                .into_iter()
+                .filter_map(move |(method, operation)| {
+                    let security = operation
+                        .as_ref()?
+                        .security
+                        .as_ref()
+                        .or(openapi.security.as_ref())
+                        .cloned()
+                        .unwrap_or_default();
+                    Some(((path.clone(), method), security))
+                })
+            })
+            .collect();
+        let schemes = openapi
+            .components
+            .as_ref()
+            .map(|components| components.security_schemes.clone())
+            .unwrap_or_default();
+
+        Self {
+            operations,
+            schemes,
+        }
+    }
+
+    /// Get security requirements of the operation matching to the request. Returns `None` if
+    /// the request did not match to a route or the route has no documented operation.
+    fn requirements(&self, parts: &Parts) -> Option<&[SecurityRequirement]> {
+        let matched_path = parts.extensions.get::<MatchedPath>()?;
+
+        self.operation(matched_path, &parts.method)
+            .or_else(|| {
+                // axum routes HEAD requests to GET handlers
+                if parts.method == Method::HEAD {
+                    self.operation(matched_path, &Method::GET)
+                } else {
+                    None
+                }
+            })
+            .map(Vec::as_slice)
+    }
+
+    fn operation(
+        &self,
+        matched_path: &MatchedPath,
+        method: &Method,
+    ) -> Option<&Vec<SecurityRequirement>> {
+        let path = path_template(matched_path.as_str());
+
+        self.operations.get(&(path, method.clone())).or_else(|| {
+            // paths with empty path are routed to "/"
+            if matched_path.as_str() == "/" {
+                self.operations.get(&(String::new(), method.clone()))
+            } else {
+                None
+            }
+        })
+    }
+}
+
+/// [`Layer`] enforcing security requirements of [`fastapi::openapi::OpenApi`] operations for
+/// routes of [`axum::Router`].
+///
+/// Layer must be added with [`axum::Router::layer`] or [`OpenApiRouter::layer`][router_layer] so
+/// that the [`MatchedPath`] of the route is available. Requests to routes without documented
+/// operation are let through as is unless [`SecurityLayer::deny_undocumented`] is set.
+///
+/// See the [module documentation][self] for how the security requirements are resolved.
+///
+/// # Examples
+///
+/// _**Protect routes with security requirements of the handlers.**_
+/// ```rust
+/// # use fastapi_axum::router::OpenApiRouter;
+/// # use fastapi_axum::routes;
+/// # use fastapi_axum::security::{Authenticator, SecurityLayer};
+/// # #[derive(Clone)]
+/// # struct TokenAuthenticator;
+/// # impl Authenticator for TokenAuthenticator {}
+/// #[fastapi::path(post, path = "/orders", security(("oauth" = ["orders:write"])))]
+/// async fn create_order() {}
+///
+/// let (router, api) = OpenApiRouter::new()
+///     .routes(routes!(create_order))
+///     .split_for_parts();
+///
+/// let router: axum::Router = router.layer(SecurityLayer::new(&api, TokenAuthenticator));
+/// ```
+///
+/// [router_layer]: crate::router::OpenApiRouter::layer
+pub struct SecurityLayer<A> {
+    rules: Arc<SecurityRules>,
+    authenticator: Arc<A>,
+    deny_undocumented: bool,
+}
+
+impl<A: Authenticator> SecurityLayer<A> {
+    /// Construct new [`SecurityLayer`] from security requirements and security schemes of the
+    /// given `openapi` using `authenticator` to authenticate the requests.
+    pub fn new(openapi: &OpenApi, authenticator: A) -> Self {
+        Self {
+            rules: Arc::new(SecurityRules::new(openapi)),
+            authenticator: Arc::new(authenticator),
+            deny_undocumented: false,
+        }
+    }
+
+    /// Reject requests to matched routes without documented operation with
+    /// `401 Unauthorized` instead of letting them through.
+    ///
+    /// This makes the layer fail closed for routes added with e.g. [`axum::Router::route`]
+    /// that are not documented in the [`fastapi::openapi::OpenApi`]. Requests that did not match
+    /// to any route are still let through so the router can respond with `404 Not Found`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fastapi::openapi::OpenApi;
+    /// # use fastapi_axum::security::{Authenticator, SecurityLayer};
+    /// # #[derive(Clone)]
+    /// # struct TokenAuthenticator;
+    /// # impl Authenticator for TokenAuthenticator {}
+    /// let router: axum::Router = axum::Router::new()
+    ///     .route("/health", axum::routing::get(|| async { "OK" }))
+    ///     .layer(SecurityLayer::new(&OpenApi::default(), TokenAuthenticator).deny_undocumented());
+    /// ```
+    pub fn deny_undocumented(mut self) -> Self {
+        self.deny_undocumented = true;
+
+        self
+    }
+}
+
+impl<A> Clone for SecurityLayer<A> {
+    fn clone(&self) -> Self {
+        Self {
+            rules: Arc::clone(&self.rules),
+            authenticator: Arc::clone(&self.authenticator),
+            deny_undocumented: self.deny_undocumented,
+        }
+    }
+}
+
+impl<S, A> Layer<S> for SecurityLayer<A> {
+    type Service = SecurityService<S, A>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        SecurityService {
+            inner,
+            rules: Arc::clone(&self.rules),
+            authenticator: Arc::clone(&self.authenticator),
+            deny_undocumented: self.deny_undocumented,
+        }
+    }
+}
+
+/// [`Service`] created by [`SecurityLayer`].
+pub struct SecurityService<S, A> {
+    inner: S,
+    rules: Arc<SecurityRules>,
+    authenticator: Arc<A>,
+    deny_undocumented: bool,
+}
+
+impl<S: Clone, A> Clone for SecurityService<S, A> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            rules: Arc::clone(&self.rules),
+            authenticator: Arc::clone(&self.authenticator),
+            deny_undocumented: self.deny_undocumented,
+        }
+    }
+}
+
+impl<S, A> Service<Request> for SecurityService<S, A>
+where
+    S: Service<Request, Response = Response> + Clone + Send + 'static,
+    S::Future: Send + 'static,
+    A: Authenticator,
+{
+    type Response = Response;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request) -> Self::Future {
+        // take the service that was driven to readiness and leave a clone in its place
+        let clone = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, clone);
+        let rules = Arc::clone(&self.rules);
+        let authenticator = Arc::clone(&self.authenticator);
+        let deny_undocumented = self.deny_undocumented;
+
+        Box::pin(async move {
+            let (parts, body) = request.into_parts();
+
+            match rules.requirements(&parts) {
+                Some(requirements) => {
+                    if let Err(rejection) =
+                        authorize(&parts, requirements, &rules.schemes, authenticator.as_ref())
+                            .await
+                    {
+                        return Ok(rejection);
+                    }
+                }
+                None if deny_undocumented && parts.extensions.get::<MatchedPath>().is_some() => {
+                    return Ok(StatusCode::UNAUTHORIZED.into_response());
+                }
+                None => (),
+            }
+
+            inner.call(Request::from_parts(parts, body)).await
+        })
+    }
+}
+
+async fn authorize<A: Authenticator>(
+    parts: &Parts,
+    requirements: &[SecurityRequirement],
+    schemes: &BTreeMap<String, SecurityScheme>,
+    authenticator: &A,
+) -> Result<(), Response> {
+    if requirements.is_empty() {
+        return Ok(());
+    }
+
+    let mut forbidden = false;
+    for requirement in requirements {
+        let mut satisfied = true;
+        for (name, scopes) in requirement.iter() {
+            let outcome = match schemes.get(name) {
+                Some(scheme) => authenticate(parts, name, scopes, scheme, authenticator).await,
+                None => AuthOutcome::Unauthenticated,
+            };
+            match outcome {
+                AuthOutcome::Authenticated => continue,
+                AuthOutcome::Forbidden => forbidden = true,
+                AuthOutcome::Unauthenticated => (),
+            }
+            satisfied = false;
+            break;
+        }
+
+        if satisfied {
+            return Ok(());
+        }
+    }
+
+    if forbidden {
+        Err(StatusCode::FORBIDDEN.into_response())
+    } else {
+        let challenges = requirements
+            .iter()
+            .flat_map(SecurityRequirement::iter)
+            .filter_map(|(name, _)| match schemes.get(name)? {
+                SecurityScheme::Http(http) => Some(http_auth_scheme_name(&http.scheme)),
+                SecurityScheme::OAuth2(_) | SecurityScheme::OpenIdConnect(_) => Some("Bearer"),
+                _ => None,
+            })
+            .fold(Vec::<&str>::new(), |mut challenges, challenge| {
+                if !challenges.contains(&challenge) {
+                    challenges.push(challenge);
+                }
+                challenges
+            });
+
+        let mut response = StatusCode::UNAUTHORIZED.into_response();
+        if let Ok(challenges) = HeaderValue::from_str(&challenges.join(", ")) {
+            if !challenges.is_empty() {
+                response
+                    .headers_mut()
+                    .insert(header::WWW_AUTHENTICATE, challenges);
+            }
+        }
+        Err(response)
+    }
+}
+
+async fn authenticate<A: Authenticator>(
+    parts: &Parts,
+    name: &str,
+    scopes: &[String],
+    scheme: &SecurityScheme,
+    authenticator: &A,
+) -> AuthOutcome {
+    let credentials = |value| Credentials {
+        name,
+        scopes,
+        value,
+    };
+
+    match scheme {
+        SecurityScheme::Http(http) => {
+            match authorization(parts, http_auth_scheme_name(&http.scheme)) {
+                Some(value) => authenticator.http(http, credentials(value)).await,
+                None => AuthOutcome::Unauthenticated,
+            }
+        }
+        SecurityScheme::ApiKey(api_key) => match api_key_value(parts, api_key) {
+            Some(value) => authenticator.api_key(api_key, credentials(&value)).await,
+            None => AuthOutcome::Unauthenticated,
+        },
+        SecurityScheme::OAuth2(_) | SecurityScheme::OpenIdConnect(_) => {
+            match authorization(parts, "Bearer") {
+                Some(value) => authenticator.oauth2(credentials(value)).await,
+                None => AuthOutcome::Unauthenticated,
+            }
+        }
+        // client certificates are not visible to the http layer
+        SecurityScheme::MutualTls { .. } => AuthOutcome::Unauthenticated,
+    }
+}
+
+/// Get credentials of `Authorization` header if the auth scheme of the header matches to the
+/// given `scheme`.
+fn authorization<'p>(parts: &'p Parts, scheme: &str) -> Option<&'p str> {
+    parts
+        .headers
+        .get_all(header::AUTHORIZATION)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .filter_map(|value| value.trim().split_once(' '))
+        .find(|(auth_scheme, _)| auth_scheme.eq_ignore_ascii_case(scheme))
+        .map(|(_, credentials)| credentials.trim())
+}
+
+fn api_key_value(parts: &Parts, api_key: &ApiKey) -> Option<String> {
+    match api_key {
+        ApiKey::Header(key) => parts
+            .headers
+            .get(key.name.as_str())
+            .and_then(|value| value.to_str().ok())
+            .map(ToString::to_string),
+        ApiKey::Query(key) => parts.uri.query().and_then(|query| {
+            form_urlencoded::parse(query.as_bytes())
+                .find(|(name, _)| *name == key.name)
+                .map(|(_, value)| value.into_owned())
+        }),
+        ApiKey::Cookie(key) => parts
+            .headers
+            .get_all(header::COOKIE)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|cookies| cookies.split(';'))
+            .filter_map(|cookie| cookie.trim().split_once('='))
+            .find(|(name, _)| *name == key.name)
+            .map(|(_, value)| value.to_string()),
+    }
+}
+
+/// Name of the auth scheme registered in
+/// <https://www.iana.org/assignments/http-authschemes/http-authschemes.xhtml>.
+fn http_auth_scheme_name(scheme: &HttpAuthScheme) -> &'static str {
+    match scheme {
+        HttpAuthScheme::Basic => "Basic",
+        HttpAuthScheme::Bearer => "Bearer",
+        HttpAuthScheme::Digest => "Digest",
+        HttpAuthScheme::Hoba => "HOBA",
+        HttpAuthScheme::Mutual => "Mutual",
+        HttpAuthScheme::Negotiate => "Negotiate",
+        HttpAuthScheme::OAuth => "OAuth",
+        HttpAuthScheme::ScramSha1 => "SCRAM-SHA-1",
+        HttpAuthScheme::ScramSha256 => "SCRAM-SHA-256",
+        HttpAuthScheme::Vapid => "vapid",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::http::{header, Request, StatusCode};
+    use axum::routing::get;
+    use fastapi::openapi::security::{ApiKey, Http};
+    use fastapi::OpenApi;
+    use tower::ServiceExt;
+
+    use super::*;
+    use crate::router::OpenApiRouter;
+    use crate::routes;
+
+    struct TestAuthenticator;
+
+    impl Authenticator for TestAuthenticator {
+        async fn http(&self, _: &Http, credentials: Credentials<'_>) -> AuthOutcome {
+            if credentials.value == "secret" {
+                AuthOutcome::Authenticated
+            } else {
+                AuthOutcome::Unauthenticated
+            }
+        }
+
+        async fn api_key(&self, _: &ApiKey, credentials: Credentials<'_>) -> AuthOutcome {
+            if credentials.value == "key" {
+                AuthOutcome::Authenticated
+            } else {
+                AuthOutcome::Unauthenticated
+            }
+        }
+
+        async fn oauth2(&self, credentials: Credentials<'_>) -> AuthOutcome {
+            let granted: &[&str] = match credentials.value {
+                "reader" => &["orders:read"],
+                "writer" => &["orders:read", "orders:write"],
+                _ => return AuthOutcome::Unauthenticated,
+            };
+
+            if credentials
+                .scopes
+                .iter()
+                .all(|scope| granted.contains(&scope.as_str()))
+            {
+                AuthOutcome::Authenticated
+            } else {
+                AuthOutcome::Forbidden
+            }
+        }
+    }
+
+    #[fastapi::path(get, path = "/orders", security(("bearer" = []), ("oauth" = ["orders:read"])))]
+    async fn get_orders() {}
+
+    #[fastapi::path(post, path = "/orders", security(("oauth" = ["orders:write"])))]
+    async fn create_order() {}
+
+    #[fastapi::path(get, path = "/orders/{id}", security((), ("bearer" = [])))]
+    async fn get_order() {}
+
+    #[fastapi::path(get, path = "/status")]
+    async fn get_status() {}
+
+    #[fastapi::path(get, path = "/items", security(("session" = [], "query_key" = [])))]
+    async fn get_items() {}
+
+    #[derive(OpenApi)]
+    #[openapi(
+        components(security_schemes(
+            ("bearer" = Http(scheme = Bearer)),
+            ("oauth" = OAuth2(flows(ClientCredentials(token_url = "https://localhost/token")))),
+            ("api_key" = ApiKey(Header("x-api-key"))),
+            ("query_key" = ApiKey(Query("api_key"))),
+            ("session" = ApiKey(Cookie("session"))),
+        )),
+        security(("api_key" = []))
+    )]
+    struct Api;
+
+    fn router() -> axum::Router {
+        let (router, api) = api_router();
+
+        router.layer(SecurityLayer::new(&api, TestAuthenticator))
+    }
+
+    fn api_router() -> (axum::Router, fastapi::openapi::OpenApi) {
+        OpenApiRouter::with_openapi(Api::openapi())
+            .routes(routes!(get_orders, create_order))
+            .routes(routes!(get_order))
+            .routes(routes!(get_status))
+            .routes(routes!(get_items))
+            .route("/undocumented", get(|| async {}))
+            .split_for_parts()
+    }
+
+    async fn status(request: Request<Body>) -> (StatusCode, Option<String>) {
+        let response = router().oneshot(request).await.unwrap();
+        let www_authenticate = response
+            .headers()
+            .get(header::WWW_AUTHENTICATE)
+            .map(|value| value.to_str().unwrap().to_string());
+
+        (response.status(), www_authenticate)
+    }
+
+    fn request(method: &str, uri: &str, headers: &[(&str, &str)]) -> Request<Body> {
+        headers
+            .iter()
+            .fold(
+                Request::builder().method(method).uri(uri),
+                |builder, (name, value)| builder.header(*name, *value),
+            )
+            .body(Body::empty())
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn missing_credentials_is_unauthorized_with_challenges() {
+        assert_eq!(
+            status(request("GET", "/orders", &[])).await,
+            (StatusCode::UNAUTHORIZED, Some("Bearer".to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn any_alternative_requirement_is_accepted() {
+        let bearer = request("GET", "/orders", &[("authorization", "Bearer secret")]);
+        let oauth = request("GET", "/orders", &[("authorization", "Bearer reader")]);
+
+        assert_eq!(status(bearer).await.0, StatusCode::OK);
+        assert_eq!(status(oauth).await.0, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn missing_scopes_is_forbidden() {
+        let reader = request("POST", "/orders", &[("authorization", "Bearer reader")]);
+        let writer = request("POST", "/orders", &[("authorization", "Bearer writer")]);
+        let unknown = request("POST", "/orders", &[("authorization", "Bearer unknown")]);
+
+        assert_eq!(status(reader).await.0, StatusCode::FORBIDDEN);
+        assert_eq!(status(writer).await.0, StatusCode::OK);
+        assert_eq!(status(unknown).await.0, StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn empty_security_requirement_is_optional() {
+        assert_eq!(
+            status(request("GET", "/orders/1", &[])).await.0,
+            StatusCode::OK
+        );
+    }
+
+    #[tokio::test]
+    async fn global_security_requirement_is_used_as_fallback() {
+        let without_key = request("GET", "/status", &[]);
+        let with_key = request("GET", "/status", &[("x-api-key", "key")]);
+
+        assert_eq!(status(without_key).await, (StatusCode::UNAUTHORIZED, None));
+        assert_eq!(status(with_key).await.0, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn all_schemes_of_requirement_must_be_satisfied() {
+        let only_cookie = request("GET", "/items", &[("cookie", "theme=dark; session=key")]);
+        let both = request(
+            "GET",
+            "/items?api_key=key",
+            &[("cookie", "theme=dark; session=key")],
+        );
+
+        assert_eq!(status(only_cookie).await.0, StatusCode::UNAUTHORIZED);
+        assert_eq!(status(both).await.0, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn undocumented_route_is_not_enforced() {
+        assert_eq!(
+            status(request("GET", "/undocumented", &[])).await.0,
+            StatusCode::OK
+        );
+    }
+
+    #[tokio::test]
+    async fn head_request_uses_get_operation_security() {
+        let without_token = request("HEAD", "/orders", &[]);
+        let with_token = request("HEAD", "/orders", &[("authorization", "Bearer secret")]);
+
+        assert_eq!(
+            status(without_token).await,
+            (StatusCode::UNAUTHORIZED, Some("Bearer".to_string()))
+        );
+        assert_eq!(status(with_token).await.0, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn deny_undocumented_rejects_undocumented_route() {
+        let (router, api) = api_router();
+        let router = router.layer(SecurityLayer::new(&api, TestAuthenticator).deny_undocumented());
+
+        let undocumented = router
+            .clone()
+            .oneshot(request("GET", "/undocumented", &[]))
+            .await
+            .unwrap();
+        let not_found = router
+            .clone()
+            .oneshot(request("GET", "/not-found", &[]))
+            .await
+            .unwrap();
+        let documented = router
+            .oneshot(request("GET", "/orders/1", &[]))
+            .await
+            .unwrap();
+
+        assert_eq!(undocumented.status(), StatusCode::UNAUTHORIZED);
+        assert_eq!(not_found.status(), StatusCode::NOT_FOUND);
+        assert_eq!(documented.status(), StatusCode::OK);
+    }
+}
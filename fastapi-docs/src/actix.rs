@@ -0,0 +1,249 @@
+#![cfg(feature = "actix-web")]
+
+use std::fmt::{self, Display};
+
+use actix_web::dev::{AppService, HttpServiceFactory, Service, ServiceRequest};
+use actix_web::guard::Get;
+use actix_web::http::{header, StatusCode};
+use actix_web::web::Data;
+use actix_web::{HttpResponse, Resource, ResponseError};
+use fastapi_ui::guard::{Guard, GuardRequest, Rejection};
+
+use crate::{with_guard, Docs, Ui};
+
+impl HttpServiceFactory for Docs {
+    fn register(self, config: &mut AppService) {
+        let guard = self.guard.clone();
+        register_file(
+            self.landing_path(),
+            "text/html; charset=utf-8",
+            self.to_html(),
+            guard.clone(),
+            config,
+        );
+
+        for document in self.documents() {
+            register_file(
+                &document.json_path,
+                "application/json",
+                document.json,
+                guard.clone(),
+                config,
+            );
+            register_file(
+                &document.yaml_path,
+                "application/yaml",
+                document.yaml,
+                guard.clone(),
+                config,
+            );
+
+            for (ui, path) in document.uis {
+                let guard = guard.as_ref();
+                match ui {
+                    #[cfg(feature = "swagger-ui")]
+                    Ui::Swagger => with_guard(
+                        fastapi_swagger_ui::SwaggerUi::new(format!("{path}/{{_:.*}}")).config(
+                            fastapi_swagger_ui::Config::new([document.json_path.clone()]),
+                        ),
+                        guard,
+                        fastapi_swagger_ui::SwaggerUi::guard,
+                    )
+                    .register(config),
+                    #[cfg(feature = "redoc")]
+                    Ui::Redoc => {
+                        use fastapi_redoc::{Redoc, Servable};
+                        with_guard(
+                            Redoc::with_url(path, document.openapi.clone()),
+                            guard,
+                            Redoc::guard,
+                        )
+                        .register(config)
+                    }
+                    #[cfg(feature = "rapidoc")]
+                    Ui::RapiDoc => with_guard(
+                        fastapi_rapidoc::RapiDoc::new(document.json_path.clone()).path(path),
+                        guard,
+                        fastapi_rapidoc::RapiDoc::guard,
+                    )
+                    .register(config),
+                    #[cfg(feature = "scalar")]
+                    Ui::Scalar => {
+                        use fastapi_scalar::{Scalar, Servable};
+                        with_guard(
+                            Scalar::with_url(path, document.openapi.clone()),
+                            guard,
+                            Scalar::guard,
+                        )
+                        .register(config)
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn register_file(
+    path: &str,
+    content_type: &'static str,
+    body: String,
+    guard: Option<Guard>,
+    config: &mut AppService,
+) {
+    async fn serve_file(file: Data<(&'static str, String)>) -> HttpResponse {
+        HttpResponse::Ok().content_type(file.0).body(file.1.clone())
+    }
+
+    Resource::new(path)
+        .guard(Get())
+        .app_data(Data::new((content_type, body)))
+        .to(serve_file)
+        .wrap_fn(move |request, service| {
+            let response = check(guard.as_ref(), &request).map(|()| service.call(request));
+            async move { response?.await }
+        })
+        .register(config);
+}
+
+/// Check the `request` against the `guard` if any.
+fn check(guard: Option<&Guard>, request: &ServiceRequest) -> Result<(), actix_web::Error> {
+    let Some(guard) = guard else {
+        return Ok(());
+    };
+    let authorization = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok());
+
+    guard
+        .check(&GuardRequest::new(
+            authorization,
+            request.peer_addr().map(|addr| addr.ip()),
+        ))
+        .map_err(|rejection| GuardRejection(rejection).into())
+}
+
+/// [`Rejection`] of the [`Guard`] as `actix-web` error.
+#[derive(Debug)]
+struct GuardRejection(Rejection);
+
+impl Display for GuardRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl ResponseError for GuardRejection {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::from_u16(self.0.status_code()).unwrap_or(StatusCode::FORBIDDEN)
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        let mut response = HttpResponse::build(self.status_code());
+        if let Some(challenge) = self.0.www_authenticate() {
+            response.insert_header((header::WWW_AUTHENTICATE, challenge));
+        }
+
+        response.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{test, App};
+    use fastapi::openapi::{InfoBuilder, OpenApiBuilder};
+
+    use super::*;
+
+    #[actix_web::test]
+    async fn serve_docs() {
+        let app = test::init_service(
+            App::new().service(
+                Docs::new("/docs").api(
+                    "public",
+                    OpenApiBuilder::new()
+                        .info(InfoBuilder::new().title("Public API").version("1.0.0"))
+                        .build(),
+                ),
+            ),
+        )
+        .await;
+
+        let response =
+            test::call_service(&app, test::TestRequest::get().uri("/docs").to_request()).await;
+        assert!(response.status().is_success());
+        let html = test::read_body(response).await;
+        assert!(std::str::from_utf8(&html)
+            .unwrap()
+            .contains("/docs/public/swagger-ui/"));
+
+        for (path, content_type) in [
+            ("/docs/public/openapi.json", "application/json"),
+            ("/docs/public/openapi.yaml", "application/yaml"),
+        ] {
+            let response =
+                test::call_service(&app, test::TestRequest::get().uri(path).to_request()).await;
+            assert!(response.status().is_success(), "{path} should be served");
+            assert_eq!(
+                response.headers().get(header::CONTENT_TYPE).unwrap(),
+                content_type
+            );
+        }
+
+        for path in [
+            "/docs/public/swagger-ui/",
+            "/docs/public/redoc",
+            "/docs/public/rapidoc",
+            "/docs/public/scalar",
+        ] {
+            let response =
+                test::call_service(&app, test::TestRequest::get().uri(path).to_request()).await;
+            assert!(response.status().is_success(), "{path} should be served");
+        }
+    }
+
+    #[actix_web::test]
+    async fn guard_protects_all_routes() {
+        let app = test::init_service(
+            App::new().service(
+                Docs::new("/docs")
+                    .api("public", OpenApiBuilder::new().build())
+                    .guard(Guard::ip_allow_list(["10.0.0.0/8".parse().unwrap()])),
+            ),
+        )
+        .await;
+
+        for path in [
+            "/docs",
+            "/docs/public/openapi.json",
+            "/docs/public/openapi.yaml",
+            "/docs/public/swagger-ui/",
+            "/docs/public/redoc",
+            "/docs/public/rapidoc",
+            "/docs/public/scalar",
+        ] {
+            let status = |addr: &str| {
+                let request = test::TestRequest::get()
+                    .uri(path)
+                    .peer_addr(addr.parse().unwrap())
+                    .to_request();
+                let app = &app;
+                async move {
+                    match test::try_call_service(app, request).await {
+                        Ok(response) => response.status(),
+                        Err(error) => error.error_response().status(),
+                    }
+                }
+            };
+            assert_eq!(
+                status("192.168.0.1:40000").await,
+                StatusCode::FORBIDDEN,
+                "{path} should be forbidden"
+            );
+            assert!(
+                status("10.0.0.1:40000").await.is_success(),
+                "{path} should be served"
+            );
+        }
+    }
+}
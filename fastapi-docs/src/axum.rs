@@ -0,0 +1,236 @@
+#![cfg(feature = "axum")]
+
+use std::net::SocketAddr;
+
+use axum::{
+    body::{Body, Bytes},
+    extract::{ConnectInfo, Request},
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    routing::{get, MethodRouter},
+    Router,
+};
+use fastapi_ui::guard::{Guard, GuardRequest};
+
+use crate::{with_guard, Docs, Ui};
+
+impl<S> From<Docs> for Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    fn from(docs: Docs) -> Self {
+        let guard = docs.guard.clone();
+        let router = Router::<S>::new().route(
+            docs.landing_path(),
+            serve("text/html; charset=utf-8", docs.to_html(), guard.clone()),
+        );
+
+        docs.documents()
+            .into_iter()
+            .fold(router, |router, document| {
+                let router = router
+                    .route(
+                        &document.json_path,
+                        serve("application/json", document.json, guard.clone()),
+                    )
+                    .route(
+                        &document.yaml_path,
+                        serve("application/yaml", document.yaml, guard.clone()),
+                    );
+
+                document.uis.into_iter().fold(router, |router, (ui, path)| {
+                    let guard = guard.as_ref();
+                    let ui_router: Router<S> = match ui {
+                        #[cfg(feature = "swagger-ui")]
+                        Ui::Swagger => with_guard(
+                            fastapi_swagger_ui::SwaggerUi::new(path).config(
+                                fastapi_swagger_ui::Config::new([document.json_path.clone()]),
+                            ),
+                            guard,
+                            fastapi_swagger_ui::SwaggerUi::guard,
+                        )
+                        .into(),
+                        #[cfg(feature = "redoc")]
+                        Ui::Redoc => {
+                            use fastapi_redoc::{Redoc, Servable};
+                            with_guard(
+                                Redoc::with_url(path, document.openapi.clone()),
+                                guard,
+                                Redoc::guard,
+                            )
+                            .into()
+                        }
+                        #[cfg(feature = "rapidoc")]
+                        Ui::RapiDoc => with_guard(
+                            fastapi_rapidoc::RapiDoc::new(document.json_path.clone()).path(path),
+                            guard,
+                            fastapi_rapidoc::RapiDoc::guard,
+                        )
+                        .into(),
+                        #[cfg(feature = "scalar")]
+                        Ui::Scalar => {
+                            use fastapi_scalar::{Scalar, Servable};
+                            with_guard(
+                                Scalar::with_url(path, document.openapi.clone()),
+                                guard,
+                                Scalar::guard,
+                            )
+                            .into()
+                        }
+                    };
+
+                    router.merge(ui_router)
+                })
+            })
+    }
+}
+
+fn serve<S>(content_type: &'static str, body: String, guard: Option<Guard>) -> MethodRouter<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    let body = Bytes::from(body);
+    get(move |request: Request| {
+        let body = body.clone();
+        let rejected = guard.as_ref().and_then(|guard| check(guard, &request));
+        async move {
+            match rejected {
+                Some(response) => response,
+                None => ([(header::CONTENT_TYPE, content_type)], body).into_response(),
+            }
+        }
+    })
+}
+
+/// Check the `request` against the `guard` returning response of the rejection if any.
+fn check(guard: &Guard, request: &Request) -> Option<Response> {
+    let authorization = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok());
+    let peer_addr = request
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(addr)| addr.ip());
+
+    guard
+        .check(&GuardRequest::new(authorization, peer_addr))
+        .err()
+        .map(|rejection| {
+            let builder = Response::builder().status(
+                StatusCode::from_u16(rejection.status_code()).unwrap_or(StatusCode::FORBIDDEN),
+            );
+            match rejection.www_authenticate() {
+                Some(challenge) => builder.header(header::WWW_AUTHENTICATE, challenge),
+                None => builder,
+            }
+            .body(Body::empty())
+            .expect("Guard rejection response must be valid")
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use axum_test::TestServer;
+    use fastapi::openapi::{InfoBuilder, OpenApiBuilder};
+
+    use super::*;
+
+    fn server() -> TestServer {
+        let app = Router::<()>::from(
+            Docs::new("/docs")
+                .api(
+                    "public",
+                    OpenApiBuilder::new()
+                        .info(InfoBuilder::new().title("Public API").version("1.0.0"))
+                        .build(),
+                )
+                .api(
+                    "admin",
+                    OpenApiBuilder::new()
+                        .info(InfoBuilder::new().title("Admin API").version("2.0.0"))
+                        .build(),
+                ),
+        );
+
+        TestServer::new(app).unwrap()
+    }
+
+    #[tokio::test]
+    async fn serve_landing_page() {
+        let response = server().get("/docs").await;
+
+        response.assert_status_ok();
+        assert_eq!(response.header("Content-Type"), "text/html; charset=utf-8");
+        let html = response.text();
+        assert!(html.contains("Public API"));
+        assert!(html.contains("/docs/admin/scalar"));
+    }
+
+    #[tokio::test]
+    async fn serve_json_and_yaml() {
+        let server = server();
+
+        let response = server.get("/docs/public/openapi.json").await;
+        response.assert_status_ok();
+        assert_eq!(response.header("Content-Type"), "application/json");
+        assert!(response.text().contains(r#""title":"Public API""#));
+
+        let response = server.get("/docs/admin/openapi.yaml").await;
+        response.assert_status_ok();
+        assert_eq!(response.header("Content-Type"), "application/yaml");
+        assert!(response.text().contains("title: Admin API"));
+    }
+
+    #[tokio::test]
+    async fn guard_protects_all_routes() {
+        let app = Router::<()>::from(
+            Docs::new("/docs")
+                .api("public", OpenApiBuilder::new().build())
+                .guard(Guard::bearer(|token| token == "token")),
+        );
+        let server = TestServer::new(app).unwrap();
+
+        for path in [
+            "/docs",
+            "/docs/public/openapi.json",
+            "/docs/public/openapi.yaml",
+            "/docs/public/swagger-ui/",
+            "/docs/public/swagger-ui/swagger-initializer.js",
+            "/docs/public/redoc",
+            "/docs/public/rapidoc",
+            "/docs/public/scalar",
+        ] {
+            let response = server.get(path).await;
+            response.assert_status(StatusCode::UNAUTHORIZED);
+            assert_eq!(response.header("WWW-Authenticate"), "Bearer");
+
+            server
+                .get(path)
+                .authorization_bearer("token")
+                .await
+                .assert_status_ok();
+        }
+    }
+
+    #[tokio::test]
+    async fn serve_uis() {
+        let server = server();
+
+        for path in [
+            "/docs/public/swagger-ui/",
+            "/docs/public/swagger-ui/swagger-initializer.js",
+            "/docs/public/redoc",
+            "/docs/public/rapidoc",
+            "/docs/admin/scalar",
+        ] {
+            server.get(path).await.assert_status_ok();
+        }
+
+        let initializer = server
+            .get("/docs/admin/swagger-ui/swagger-initializer.js")
+            .await
+            .text();
+        assert!(initializer.contains("/docs/admin/openapi.json"));
+    }
+}
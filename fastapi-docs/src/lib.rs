@@ -0,0 +1,388 @@
+#![warn(missing_docs)]
+#![warn(rustdoc::broken_intra_doc_links)]
+#![cfg_attr(doc_cfg, feature(doc_cfg))]
+//! This crate provides a single documentation portal for multiple [fastapi](https://docs.rs/fastapi/latest/fastapi/)
+//! OpenAPI documents.
+//!
+//! [`Docs`] takes named [`OpenApi`] documents e.g. _public_, _admin_ and _internal_ API and
+//! serves a landing page linking to each document rendered with Swagger UI, Redoc, RapiDoc and
+//! Scalar. Each document is served as JSON and YAML as well. UIs are served with the framework
+//! integrations of the respective UI crates so the UIs behave exactly as if they were registered
+//! one by one.
+//!
+//! You may find fullsize examples from fastapi's Github [repository][examples].
+//!
+//! # Crate Features
+//!
+//! * **swagger-ui** Serve documents with [Swagger UI](https://docs.rs/fastapi-swagger-ui/). Enabled by default.
+//! * **redoc** Serve documents with [Redoc](https://docs.rs/fastapi-redoc/). Enabled by default.
+//! * **rapidoc** Serve documents with [RapiDoc](https://docs.rs/fastapi-rapidoc/). Enabled by default.
+//! * **scalar** Serve documents with [Scalar](https://docs.rs/fastapi-scalar/). Enabled by default.
+//! * **actix-web** Allows serving [`Docs`] via _**`actix-web`**_.
+//! * **axum** Allows serving [`Docs`] via _**`axum`**_.
+//!
+//! # Install
+//!
+//! Serve all UIs with `axum`.
+//! ```text
+//! [dependencies]
+//! fastapi-docs = { version = "0.1", features = ["axum"] }
+//! ```
+//!
+//! Serve only Swagger UI and Scalar with `actix-web`.
+//! ```text
+//! [dependencies]
+//! fastapi-docs = { version = "0.1", default-features = false, features = ["actix-web", "swagger-ui", "scalar"] }
+//! ```
+//!
+//! # Routes
+//!
+//! [`Docs`] served from `/docs` with document named `public` serves following routes.
+//!
+//! * `/docs` Landing page linking to all documents and UIs.
+//! * `/docs/public/openapi.json` The document as JSON.
+//! * `/docs/public/openapi.yaml` The document as YAML.
+//! * `/docs/public/swagger-ui/` The document in Swagger UI.
+//! * `/docs/public/redoc` The document in Redoc.
+//! * `/docs/public/rapidoc` The document in RapiDoc.
+//! * `/docs/public/scalar` The document in Scalar.
+//!
+//! All the routes can be protected with a single [`Guard`] with [`Docs::guard`].
+//!
+//! # Examples
+//!
+//! _**Serve public and admin API documentation via `axum`.**_
+//! ```no_run
+//! use axum::Router;
+//! use fastapi::OpenApi;
+//! use fastapi_docs::Docs;
+//!
+//! #[derive(OpenApi)]
+//! #[openapi(info(title = "Public API"))]
+//! struct PublicApi;
+//!
+//! #[derive(OpenApi)]
+//! #[openapi(info(title = "Admin API"))]
+//! struct AdminApi;
+//!
+//! let app: Router = Router::new().merge(
+//!     Docs::new("/docs")
+//!         .api("public", PublicApi::openapi())
+//!         .api("admin", AdminApi::openapi()),
+//! );
+//! ```
+//!
+//! _**Serve public and admin API documentation via `actix-web`.**_
+//! ```no_run
+//! use actix_web::App;
+//! use fastapi::OpenApi;
+//! use fastapi_docs::Docs;
+//! # #[derive(OpenApi)]
+//! # #[openapi()]
+//! # struct PublicApi;
+//! # #[derive(OpenApi)]
+//! # #[openapi()]
+//! # struct AdminApi;
+//!
+//! App::new().service(
+//!     Docs::new("/docs")
+//!         .api("public", PublicApi::openapi())
+//!         .api("admin", AdminApi::openapi()),
+//! );
+//! ```
+//!
+//! [examples]: <https://github.com/nxpkg/fastapi/tree/master/examples>
+
+use std::borrow::Cow;
+
+use fastapi::openapi::OpenApi;
+use fastapi_ui::guard::Guard;
+use fastapi_ui::html::{escape_html, render};
+
+mod actix;
+mod axum;
+
+const DEFAULT_HTML: &str = include_str!("../res/docs.html");
+
+/// Documentation portal serving multiple named [`OpenApi`] documents with all enabled UIs.
+///
+/// [`Docs`] can be served with predefined `axum` and `actix-web` integrations or standalone by
+/// serving [`Docs::to_html`] as landing page. See [crate level documentation][crate] for the
+/// served routes.
+///
+/// # Examples
+///
+/// _**Create [`Docs`] served from `/docs` with two documents.**_
+/// ```rust
+/// # use fastapi::openapi::OpenApiBuilder;
+/// # use fastapi_docs::Docs;
+/// let docs = Docs::new("/docs")
+///     .title("Acme APIs")
+///     .api("public", OpenApiBuilder::new().build())
+///     .api("internal", OpenApiBuilder::new().build());
+/// ```
+#[non_exhaustive]
+#[derive(Clone)]
+pub struct Docs {
+    path: Cow<'static, str>,
+    title: Cow<'static, str>,
+    apis: Vec<(Cow<'static, str>, OpenApi)>,
+    guard: Option<Guard>,
+}
+
+impl Docs {
+    /// Construct a new [`Docs`] served from given `path` e.g. `/docs`.
+    pub fn new<P: Into<Cow<'static, str>>>(path: P) -> Self {
+        Self {
+            path: path.into(),
+            title: Cow::Borrowed("API Documentation"),
+            apis: Vec::new(),
+            guard: None,
+        }
+    }
+
+    /// Set the title of the landing page. Default is `API Documentation`.
+    pub fn title<T: Into<Cow<'static, str>>>(mut self, title: T) -> Self {
+        self.title = title.into();
+
+        self
+    }
+
+    /// Add named [`OpenApi`] document to the [`Docs`].
+    ///
+    /// The `name` is used as path segment of the routes of the document and must be unique within
+    /// the [`Docs`]. The landing page shows title and version from the [`Info`][info] of the
+    /// document.
+    ///
+    /// [info]: fastapi::openapi::Info
+    pub fn api<N: Into<Cow<'static, str>>>(mut self, name: N, openapi: OpenApi) -> Self {
+        self.apis.push((name.into(), openapi));
+
+        self
+    }
+
+    /// Restrict access to all routes of the [`Docs`] with [`Guard`].
+    ///
+    /// The guard is checked for the landing page, the JSON and YAML documents and all the UIs
+    /// including their assets. See [`Guard`] for available guards.
+    ///
+    /// # Examples
+    ///
+    /// _**Allow access only for staff with HTTP Basic authentication.**_
+    /// ```rust
+    /// # use fastapi::openapi::OpenApiBuilder;
+    /// # use fastapi_ui::guard::Guard;
+    /// # use fastapi_docs::Docs;
+    /// let docs = Docs::new("/docs")
+    ///     .api("internal", OpenApiBuilder::new().build())
+    ///     .guard(Guard::basic("docs", |username, password| {
+    ///         username == "staff" && password == "secret"
+    ///     }));
+    /// ```
+    pub fn guard(mut self, guard: Guard) -> Self {
+        self.guard = Some(guard);
+
+        self
+    }
+
+    /// Converts the landing page of this [`Docs`] to servable HTML.
+    ///
+    /// The landing page lists all documents with links to their JSON and YAML representations and
+    /// to each enabled UI.
+    pub fn to_html(&self) -> String {
+        let apis = self
+            .apis
+            .iter()
+            .map(|(name, openapi)| {
+                let links = [
+                    (self.api_path(name, "openapi.json"), "JSON"),
+                    (self.api_path(name, "openapi.yaml"), "YAML"),
+                ]
+                .into_iter()
+                .chain(
+                    Ui::ALL
+                        .iter()
+                        .map(|ui| (self.api_path(name, ui.link_path()), ui.title())),
+                )
+                .map(|(href, title)| {
+                    format!(r#"<a href="{}">{title}</a>"#, escape_html(&href))
+                })
+                .collect::<Vec<_>>()
+                .join("\n        ");
+
+                format!(
+                    "    <section>\n      <h2>{} <small>{}</small></h2>\n      <p>\n        {links}\n      </p>\n    </section>",
+                    escape_html(&openapi.info.title),
+                    escape_html(&openapi.info.version),
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        render(
+            DEFAULT_HTML,
+            &[("$title", &escape_html(&self.title)), ("$apis", &apis)],
+        )
+    }
+
+    /// Get path of the landing page.
+    #[cfg(any(feature = "actix-web", feature = "axum"))]
+    fn landing_path(&self) -> &str {
+        let path = self.path.trim_end_matches('/');
+        if path.is_empty() {
+            "/"
+        } else {
+            path
+        }
+    }
+
+    /// Get path of the `tail` under the document with given `name`.
+    fn api_path(&self, name: &str, tail: &str) -> String {
+        format!("{}/{name}/{tail}", self.path.trim_end_matches('/'))
+    }
+
+    /// Get documents of the [`Docs`] with their JSON and YAML representations.
+    #[cfg(any(feature = "actix-web", feature = "axum"))]
+    fn documents(self) -> Vec<Document> {
+        self.apis
+            .iter()
+            .map(|(name, openapi)| Document {
+                json_path: self.api_path(name, "openapi.json"),
+                yaml_path: self.api_path(name, "openapi.yaml"),
+                json: openapi.to_json().expect("OpenApi must serialize to JSON"),
+                yaml: openapi.to_yaml().expect("OpenApi must serialize to YAML"),
+                uis: Ui::ALL
+                    .iter()
+                    .map(|ui| (*ui, self.api_path(name, ui.path())))
+                    .collect(),
+                #[cfg(any(feature = "redoc", feature = "scalar"))]
+                openapi: openapi.clone(),
+            })
+            .collect()
+    }
+}
+
+/// Apply the `guard` to the `ui` with the `apply` function of the UI if any.
+#[cfg(any(feature = "actix-web", feature = "axum"))]
+fn with_guard<T>(ui: T, guard: Option<&Guard>, apply: fn(T, Guard) -> T) -> T {
+    match guard {
+        Some(guard) => apply(ui, guard.clone()),
+        None => ui,
+    }
+}
+
+/// Named document of the [`Docs`] ready to be served.
+#[cfg(any(feature = "actix-web", feature = "axum"))]
+struct Document {
+    #[cfg(any(feature = "redoc", feature = "scalar"))]
+    openapi: OpenApi,
+    json_path: String,
+    json: String,
+    yaml_path: String,
+    yaml: String,
+    uis: Vec<(Ui, String)>,
+}
+
+/// UIs enabled with crate features.
+#[derive(Clone, Copy)]
+enum Ui {
+    #[cfg(feature = "swagger-ui")]
+    Swagger,
+    #[cfg(feature = "redoc")]
+    Redoc,
+    #[cfg(feature = "rapidoc")]
+    RapiDoc,
+    #[cfg(feature = "scalar")]
+    Scalar,
+}
+
+impl Ui {
+    const ALL: &'static [Ui] = &[
+        #[cfg(feature = "swagger-ui")]
+        Ui::Swagger,
+        #[cfg(feature = "redoc")]
+        Ui::Redoc,
+        #[cfg(feature = "rapidoc")]
+        Ui::RapiDoc,
+        #[cfg(feature = "scalar")]
+        Ui::Scalar,
+    ];
+
+    fn title(&self) -> &'static str {
+        match *self {
+            #[cfg(feature = "swagger-ui")]
+            Ui::Swagger => "Swagger UI",
+            #[cfg(feature = "redoc")]
+            Ui::Redoc => "Redoc",
+            #[cfg(feature = "rapidoc")]
+            Ui::RapiDoc => "RapiDoc",
+            #[cfg(feature = "scalar")]
+            Ui::Scalar => "Scalar",
+        }
+    }
+
+    /// Path segment the UI is served from.
+    fn path(&self) -> &'static str {
+        match *self {
+            #[cfg(feature = "swagger-ui")]
+            Ui::Swagger => "swagger-ui",
+            #[cfg(feature = "redoc")]
+            Ui::Redoc => "redoc",
+            #[cfg(feature = "rapidoc")]
+            Ui::RapiDoc => "rapidoc",
+            #[cfg(feature = "scalar")]
+            Ui::Scalar => "scalar",
+        }
+    }
+
+    /// Path segment linked from the landing page. Swagger UI is linked with trailing slash to
+    /// avoid the redirect.
+    fn link_path(&self) -> &'static str {
+        #[cfg(feature = "swagger-ui")]
+        if matches!(self, Ui::Swagger) {
+            return "swagger-ui/";
+        }
+
+        self.path()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fastapi::openapi::{InfoBuilder, OpenApiBuilder};
+
+    use super::*;
+
+    #[test]
+    fn to_html_links_documents_and_uis() {
+        let html = Docs::new("/docs/")
+            .title("Acme <APIs>")
+            .api(
+                "public",
+                OpenApiBuilder::new()
+                    .info(InfoBuilder::new().title("Public $apis").version("1.0.0"))
+                    .build(),
+            )
+            .to_html();
+
+        assert!(html.contains("<title>Acme &lt;APIs&gt;</title>"));
+        assert!(html.contains("<h2>Public $apis <small>1.0.0</small></h2>"));
+        for link in [
+            r#"<a href="/docs/public/openapi.json">JSON</a>"#,
+            r#"<a href="/docs/public/openapi.yaml">YAML</a>"#,
+            r#"<a href="/docs/public/swagger-ui/">Swagger UI</a>"#,
+            r#"<a href="/docs/public/redoc">Redoc</a>"#,
+            r#"<a href="/docs/public/rapidoc">RapiDoc</a>"#,
+            r#"<a href="/docs/public/scalar">Scalar</a>"#,
+        ] {
+            assert!(html.contains(link), "{html} should contain {link}");
+        }
+    }
+
+    #[test]
+    fn landing_path_of_root() {
+        assert_eq!(Docs::new("/").landing_path(), "/");
+        assert_eq!(Docs::new("/").api_path("public", "redoc"), "/public/redoc");
+        assert_eq!(Docs::new("/docs/").landing_path(), "/docs");
+    }
+}
@@ -11,7 +11,9 @@ use syn::{
 };
 
 use crate::doc_comment::CommentAttributes;
-use crate::schema_type::{KnownFormat, PrimitiveType, SchemaTypeInner};
+use crate::schema_type::{
+    check_unqualified_binary_path, KnownFormat, PrimitiveType, SchemaTypeInner,
+};
 use crate::{
     as_tokens_or_diagnostics, Array, AttributesExt, Diagnostics, GenericsExt, OptionExt,
     ToTokensDiagnostics,
@@ -921,6 +923,7 @@ impl ComponentSchema {
         let default = pop_feature!(features => Feature::Default(_));
         let default_tokens = as_tokens_or_diagnostics!(&default);
         let deprecated = pop_feature!(features => Feature::Deprecated(_)).try_to_token_stream()?;
+        let extensions = pop_feature!(features => Feature::Extensions(_));
 
         let additional_properties = additional_properties
             .as_ref()
@@ -988,6 +991,7 @@ impl ComponentSchema {
                 #default_tokens
         });
 
+        extensions.to_tokens(tokens)?;
         example.to_tokens(tokens)
     }
 
@@ -1010,6 +1014,7 @@ impl ComponentSchema {
         let deprecated = pop_feature!(features => Feature::Deprecated(_)).try_to_token_stream()?;
         let content_encoding = pop_feature!(features => Feature::ContentEncoding(_));
         let content_media_type = pop_feature!(features => Feature::ContentMediaType(_));
+        let extensions = pop_feature!(features => Feature::Extensions(_));
 
         let child = type_tree
             .children
@@ -1094,6 +1099,7 @@ impl ComponentSchema {
         title.to_tokens(tokens)?;
         example.to_tokens(tokens)?;
         xml.to_tokens(tokens)?;
+        extensions.to_tokens(tokens)?;
 
         Ok(())
     }
@@ -1113,6 +1119,8 @@ impl ComponentSchema {
             .map(|nullable| nullable.value())
             .unwrap_or_default();
         let deprecated = pop_feature!(features => Feature::Deprecated(_)).try_to_token_stream()?;
+        let extensions = pop_feature!(features => Feature::Extensions(_));
+        let extensions_tokens = as_tokens_or_diagnostics!(&extensions);
 
         match type_tree.value_type {
             ValueType::Primitive => {
@@ -1151,6 +1159,7 @@ impl ComponentSchema {
                 }
                 let _ = pop_feature!(features => Feature::NoRecursion(_)); // primitive types are not recursive
                 tokens.extend(features.to_token_stream()?);
+                tokens.extend(extensions_tokens);
             }
             ValueType::Value => {
                 // since OpenAPI 3.1 the type is an array, thus nullable should not be necessary
@@ -1159,7 +1168,7 @@ impl ComponentSchema {
                     tokens.extend(quote! {
                         fastapi::openapi::ObjectBuilder::new()
                             .schema_type(fastapi::openapi::schema::SchemaType::AnyValue)
-                            #description_stream #deprecated
+                            #description_stream #deprecated #extensions_tokens
                     })
                 }
             }
@@ -1174,7 +1183,7 @@ impl ComponentSchema {
                     tokens.extend(quote! {
                         fastapi::openapi::ObjectBuilder::new()
                             #nullable_schema_type
-                            #description_stream #deprecated
+                            #description_stream #deprecated #extensions_tokens
                     })
                 } else {
                     fn nullable_one_of_item(nullable: bool) -> Option<TokenStream> {
@@ -1187,6 +1196,7 @@ impl ComponentSchema {
                         }
                     }
                     let type_path = &**type_tree.path.as_ref().unwrap();
+                    check_unqualified_binary_path(type_path)?;
                     let rewritten_path = type_path.rewrite_path()?;
                     let nullable_item = nullable_one_of_item(nullable);
                     let mut object_schema_reference = SchemaReference {
@@ -1263,6 +1273,7 @@ impl ComponentSchema {
                         let schema = if default.is_some()
                             || nullable
                             || title.is_some()
+                            || extensions.is_some()
                             || !description_tokens.is_empty()
                         {
                             quote_spanned! {type_path.span()=>
@@ -1272,6 +1283,7 @@ impl ComponentSchema {
                                 #title_tokens
                                 #default_tokens
                                 #description_stream
+                                #extensions_tokens
                             }
                         } else {
                             items_tokens
@@ -1331,7 +1343,11 @@ impl ComponentSchema {
                         // TODO: refs support `summary` field but currently there is no such field
                         // on schemas more over there is no way to distinct the `summary` from
                         // `description` of the ref. Should we consider supporting the summary?
-                        let schema = if default.is_some() || nullable || title.is_some() {
+                        let schema = if default.is_some()
+                            || nullable
+                            || title.is_some()
+                            || extensions.is_some()
+                        {
                             composed_or_ref(quote_spanned! {type_path.span()=>
                                 fastapi::openapi::schema::OneOfBuilder::new()
                                     #nullable_item
@@ -1341,6 +1357,7 @@ impl ComponentSchema {
                                     )
                                     #title_tokens
                                     #default_tokens
+                                    #extensions_tokens
                             })
                         } else {
                             composed_or_ref(quote_spanned! {type_path.span()=>
@@ -1399,6 +1416,7 @@ impl ComponentSchema {
                                 .prefix_items(#prefix_items)
                                 #description_stream
                                 #deprecated
+                                #extensions_tokens
                         })
                     })?
                     .unwrap_or_else(|| quote!(fastapi::openapi::schema::empty())) // TODO should
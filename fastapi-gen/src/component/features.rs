@@ -104,6 +104,7 @@ pub enum Feature {
     Bound(attributes::Bound),
     Ignore(attributes::Ignore),
     NoRecursion(attributes::NoRecursion),
+    Extensions(attributes::Extensions),
     MultipleOf(validation::MultipleOf),
     Maximum(validation::Maximum),
     Minimum(validation::Minimum),
@@ -215,6 +216,7 @@ impl ToTokensDiagnostics for Feature {
             Feature::ContentEncoding(content_encoding) => quote! { .content_encoding(#content_encoding) },
             Feature::ContentMediaType(content_media_type) => quote! { .content_media_type(#content_media_type) },
             Feature::Discriminator(discriminator) => quote! { .discriminator(Some(#discriminator)) },
+            Feature::Extensions(extensions) => quote! { .extensions(Some(#extensions)) },
             Feature::Bound(_) => {
                 // specially handled on generating impl blocks.
                 TokenStream::new()
@@ -306,6 +308,7 @@ impl Display for Feature {
             Feature::Bound(bound) => bound.fmt(f),
             Feature::Ignore(ignore) => ignore.fmt(f),
             Feature::NoRecursion(no_recursion) => no_recursion.fmt(f),
+            Feature::Extensions(extensions) => extensions.fmt(f),
         }
     }
 }
@@ -357,6 +360,7 @@ impl Validatable for Feature {
             Feature::Bound(bound) => bound.is_validatable(),
             Feature::Ignore(ignore) => ignore.is_validatable(),
             Feature::NoRecursion(no_recursion) => no_recursion.is_validatable(),
+            Feature::Extensions(extensions) => extensions.is_validatable(),
         }
     }
 }
@@ -406,6 +410,7 @@ is_validatable! {
     attributes::Bound,
     attributes::Ignore,
     attributes::NoRecursion,
+    attributes::Extensions,
     validation::MultipleOf = true,
     validation::Maximum = true,
     validation::Minimum = true,
@@ -636,6 +641,7 @@ impl_feature_into_inner! {
     attributes::Bound,
     attributes::Ignore,
     attributes::NoRecursion,
+    attributes::Extensions,
     validation::MultipleOf,
     validation::Maximum,
     validation::Minimum,
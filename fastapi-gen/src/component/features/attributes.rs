@@ -474,6 +474,12 @@ impl ToTokens for Explode {
     }
 }
 
+impl From<bool> for Explode {
+    fn from(value: bool) -> Self {
+        Self(value)
+    }
+}
+
 impl From<Explode> for Feature {
     fn from(value: Explode) -> Self {
         Feature::Explode(value)
@@ -1039,3 +1045,31 @@ impl From<NoRecursion> for Feature {
         Self::NoRecursion(value)
     }
 }
+
+// extensions(("x-name" = json!(...)), ...)
+impl_feature! {
+    #[derive(Clone)]
+    #[cfg_attr(feature = "debug", derive(Debug))]
+    pub struct Extensions(crate::Extensions);
+}
+
+impl Parse for Extensions {
+    fn parse(input: ParseStream, _: Ident) -> syn::Result<Self>
+    where
+        Self: std::marker::Sized,
+    {
+        crate::Extensions::parse_parenthesized(input).map(Self)
+    }
+}
+
+impl ToTokens for Extensions {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        self.0.to_tokens(tokens)
+    }
+}
+
+impl From<Extensions> for Feature {
+    fn from(value: Extensions) -> Self {
+        Self::Extensions(value)
+    }
+}
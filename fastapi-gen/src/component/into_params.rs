@@ -13,8 +13,8 @@ use crate::{
         features::{
             self,
             attributes::{
-                AdditionalProperties, AllowReserved, Example, Explode, Format, Ignore, Inline,
-                IntoParamsNames, Nullable, ReadOnly, Rename, RenameAll, SchemaWith, Style,
+                AdditionalProperties, AllowReserved, Example, Explode, Extensions, Format, Ignore,
+                Inline, IntoParamsNames, Nullable, ReadOnly, Rename, RenameAll, SchemaWith, Style,
                 WriteOnly, XmlAttr,
             },
             validation::{
@@ -301,7 +301,8 @@ impl Parse for FieldFeatures {
             MaxItems,
             MinItems,
             AdditionalProperties,
-            Ignore
+            Ignore,
+            Extensions
         )))
     }
 }
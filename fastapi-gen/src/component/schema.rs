@@ -697,6 +697,12 @@ impl UnnamedStructSchema {
         let fields_len = fields.len();
         let first_field = fields.first().unwrap();
         let first_part = &TypeTree::from_type(&first_field.ty)?;
+        // extensions belong to the array schema of multi field struct, not to its items
+        let extensions = if fields_len > 1 {
+            pop_feature!(features => Feature::Extensions(_))
+        } else {
+            None
+        };
 
         let all_fields_are_same = fields_len == 1
             || fields
@@ -787,7 +793,8 @@ impl UnnamedStructSchema {
                 .max_items(Some(#fields_len))
                 .min_items(Some(#fields_len))
                 #description
-            })
+            });
+            extensions.to_tokens(&mut tokens)?;
         }
 
         Ok(UnnamedStructSchema {
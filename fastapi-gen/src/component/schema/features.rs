@@ -7,9 +7,9 @@ use crate::{
     component::features::{
         attributes::{
             AdditionalProperties, As, Bound, ContentEncoding, ContentMediaType, Deprecated,
-            Description, Discriminator, Example, Examples, Format, Ignore, Inline, NoRecursion,
-            Nullable, ReadOnly, Rename, RenameAll, Required, SchemaWith, Title, ValueType,
-            WriteOnly, XmlAttr,
+            Description, Discriminator, Example, Examples, Extensions, Format, Ignore, Inline,
+            NoRecursion, Nullable, ReadOnly, Rename, RenameAll, Required, SchemaWith, Title,
+            ValueType, WriteOnly, XmlAttr,
         },
         impl_into_inner, impl_merge, parse_features,
         validation::{
@@ -39,7 +39,8 @@ impl Parse for NamedFieldStructFeatures {
             Deprecated,
             Description,
             Bound,
-            NoRecursion
+            NoRecursion,
+            Extensions
         )))
     }
 }
@@ -64,7 +65,8 @@ impl Parse for UnnamedFieldStructFeatures {
             ContentEncoding,
             ContentMediaType,
             Bound,
-            NoRecursion
+            NoRecursion,
+            Extensions
         )))
     }
 }
@@ -84,7 +86,8 @@ impl Parse for EnumFeatures {
             As,
             Deprecated,
             Description,
-            Bound
+            Bound,
+            Extensions
         )))
     }
 }
@@ -105,7 +108,8 @@ impl Parse for MixedEnumFeatures {
             Deprecated,
             Description,
             Discriminator,
-            NoRecursion
+            NoRecursion,
+            Extensions
         )))
     }
 }
@@ -145,7 +149,8 @@ impl Parse for NamedFieldFeatures {
             ContentEncoding,
             ContentMediaType,
             Ignore,
-            NoRecursion
+            NoRecursion,
+            Extensions
         )))
     }
 }
@@ -1,7 +1,7 @@
 use std::borrow::Cow;
 
 use proc_macro2::TokenStream;
-use quote::ToTokens;
+use quote::{quote, ToTokens};
 use syn::spanned::Spanned;
 use syn::Generics;
 use syn::{punctuated::Punctuated, token::Comma, ItemFn};
@@ -11,7 +11,7 @@ use crate::path::media_type::MediaTypePathExt;
 use crate::path::{HttpMethod, PathTypeTree};
 use crate::{Diagnostics, ToTokensDiagnostics};
 
-#[cfg(feature = "auto_into_responses")]
+#[cfg(feature = "infer_responses")]
 pub mod auto_types;
 
 #[cfg(feature = "actix_extras")]
@@ -93,8 +93,12 @@ impl<'i> From<(Option<Cow<'i, syn::Path>>, TokenStream)> for IntoParamsType<'i>
 #[derive(PartialEq, Eq)]
 pub enum ArgumentIn {
     Path,
-    #[cfg(feature = "rocket_extras")]
+    #[cfg(any(feature = "rocket_extras", feature = "axum_extras"))]
     Query,
+    /// Typed header whose name is defined by implementation of the given header trait, e.g.
+    /// `headers::Header`.
+    #[cfg(any(feature = "actix_extras", feature = "axum_extras"))]
+    Header(syn::Path),
 }
 
 #[cfg_attr(feature = "debug", derive(Debug))]
@@ -106,6 +110,32 @@ impl<'t> From<TypeTree<'t>> for ExtSchema<'t> {
     }
 }
 
+impl<'a> ExtSchema<'a> {
+    /// Split request body of type `Either<L, R>` to its variants which are documented as
+    /// alternative contents of the request body. Other request bodies are returned as is.
+    #[cfg(any(
+        feature = "actix_extras",
+        feature = "rocket_extras",
+        feature = "axum_extras"
+    ))]
+    pub fn into_variants(self) -> Vec<ExtSchema<'a>> {
+        let is_either = self
+            .0
+            .path
+            .as_ref()
+            .and_then(|path| path.segments.last())
+            .is_some_and(|segment| segment.ident == "Either");
+
+        match self.0.children {
+            Some(children) if is_either => children
+                .into_iter()
+                .flat_map(|variant| ExtSchema(variant).into_variants())
+                .collect(),
+            children => vec![ExtSchema(TypeTree { children, ..self.0 })],
+        }
+    }
+}
+
 impl ExtSchema<'_> {
     fn get_actual_body(&self) -> Cow<'_, TypeTree<'_>> {
         let actual_body_type = get_actual_body_type(&self.0);
@@ -130,6 +160,11 @@ impl ExtSchema<'_> {
         Ok(Some(Cow::Borrowed(&self.0)))
     }
 
+    #[cfg(feature = "client")]
+    pub fn get_actual_body_type_tree(&self) -> Cow<'_, TypeTree<'_>> {
+        self.get_actual_body()
+    }
+
     pub fn get_default_content_type(&self) -> Result<Cow<'static, str>, Diagnostics> {
         let type_tree = &self.0;
 
@@ -137,6 +172,8 @@ impl ExtSchema<'_> {
             Cow::Borrowed("application/octet-stream")
         } else if type_tree.is("Form") {
             Cow::Borrowed("application/x-www-form-urlencoded")
+        } else if is_multipart(type_tree) {
+            Cow::Borrowed("multipart/form-data")
         } else {
             let get_actual_body = self.get_actual_body();
             let actual_body = get_actual_body.as_ref();
@@ -151,7 +188,9 @@ impl ExtSchema<'_> {
         use crate::OptionExt;
 
         let type_tree = &self.0;
-        let actual_body_type = get_actual_body_type(type_tree);
+        let actual_body_type = get_actual_body_type(type_tree)
+            // untyped multipart and raw bytes do not have a schema to be collected
+            .filter(|body_type| !body_type.is("Multipart") && !body_type.is("Bytes"));
 
         actual_body_type.and_then_try(|body_type| body_type.get_component_schema())
     }
@@ -159,9 +198,29 @@ impl ExtSchema<'_> {
 
 impl ToTokensDiagnostics for ExtSchema<'_> {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) -> Result<(), Diagnostics> {
+        if self.0.is("Multipart") {
+            // parts of untyped multipart are only known at runtime
+            tokens.extend(quote! {
+                fastapi::openapi::schema::Object::with_type(fastapi::openapi::schema::Type::Object)
+            });
+            return Ok(());
+        }
+
         let get_actual_body = self.get_actual_body();
         let type_tree = get_actual_body.as_ref();
 
+        if type_tree.is("Bytes") {
+            // raw bytes extractor is documented as binary string regardless of its path
+            tokens.extend(quote! {
+                fastapi::openapi::schema::ObjectBuilder::new()
+                    .schema_type(fastapi::openapi::schema::Type::String)
+                    .format(Some(fastapi::openapi::schema::SchemaFormat::KnownFormat(
+                        fastapi::openapi::schema::KnownFormat::Binary
+                    )))
+            });
+            return Ok(());
+        }
+
         let component_tokens = ComponentSchema::new(ComponentSchemaProps {
             type_tree,
             features: Vec::new(),
@@ -204,7 +263,14 @@ fn get_actual_body_type<'t>(ty: &'t TypeTree<'t>) -> Option<&'t TypeTree<'t>> {
                     .first()
                     .expect("Option must have one child"),
             ),
-            "Bytes" => Some(ty),
+            "MultipartForm" => Some(
+                ty.children
+                    .as_deref()
+                    .expect("MultipartForm must have children")
+                    .first()
+                    .expect("MultipartForm must have one child"),
+            ),
+            "Bytes" | "Multipart" => Some(ty),
             _ => match ty.children {
                 Some(ref children) => get_actual_body_type(children.first().expect(
                     "Must have first child when children has been defined in get_actual_body_type",
@@ -214,6 +280,12 @@ fn get_actual_body_type<'t>(ty: &'t TypeTree<'t>) -> Option<&'t TypeTree<'t>> {
         })
 }
 
+/// Check whether the request body type is either axum or actix-web `Multipart` or actix-web
+/// `MultipartForm<T>`.
+fn is_multipart(ty: &TypeTree) -> bool {
+    ty.is("Multipart") || ty.is("MultipartForm")
+}
+
 fn find_option_type_tree<'t>(ty: &'t TypeTree) -> Option<&'t TypeTree<'t>> {
     let eq = ty.generic_type == Some(crate::component::GenericType::Option);
 
@@ -503,6 +575,66 @@ pub mod fn_arg {
         Some((type_path, parameter_in_provider))
     }
 
+    /// Get the type tree without possible `Option` wrapper.
+    #[cfg(any(feature = "actix_extras", feature = "axum_extras"))]
+    pub(super) fn without_option<'t>(ty: &'t TypeTree<'t>) -> &'t TypeTree<'t> {
+        if ty.is_option() {
+            ty.children
+                .as_ref()
+                .and_then(|children| children.first())
+                .expect("Option must have one child")
+        } else {
+            ty
+        }
+    }
+
+    /// Check whether the last segment of the type path is the given _`ident`_. Unlike
+    /// [`TypeTree::is`] this does not look into the generic arguments of the type.
+    #[cfg(any(feature = "actix_extras", feature = "axum_extras"))]
+    pub(super) fn is_ident(ty: &TypeTree, ident: &str) -> bool {
+        ty.path
+            .as_ref()
+            .and_then(|path| path.segments.last())
+            .is_some_and(|segment| segment.ident == ident)
+    }
+
+    /// Check whether argument is typed header e.g. `Header<T>` or `Option<Header<T>>` where the
+    /// _`header`_ is the name of the typed header wrapper type.
+    #[cfg(any(feature = "actix_extras", feature = "axum_extras"))]
+    pub(super) fn is_typed_header(arg: &FnArg, header: &str) -> bool {
+        is_ident(without_option(&arg.ty), header)
+    }
+
+    /// Resolve header parameter from typed header argument e.g. `Header<T>`. The documented name
+    /// of the header is taken from the `header_trait` implementation of `T` in the generated
+    /// code. Name of the type `T` in kebab case identifies the parameter within the macro, e.g.
+    /// as name of the client argument.
+    #[cfg(any(feature = "actix_extras", feature = "axum_extras"))]
+    pub(super) fn to_header_argument(
+        arg: FnArg<'_>,
+        header_trait: syn::Path,
+    ) -> super::ValueArgument<'_> {
+        use crate::component::serde::RenameRule;
+
+        let header = without_option(&arg.ty)
+            .children
+            .as_ref()
+            .and_then(|children| children.first())
+            .and_then(|header| header.path.as_ref())
+            .and_then(|path| path.segments.last())
+            .map(|segment| match &*segment.ident.to_string() {
+                "ETag" => String::from("etag"),
+                name => RenameRule::Kebab.rename_variant(name),
+            })
+            .expect("typed header must have one child");
+
+        super::ValueArgument {
+            name: Some(std::borrow::Cow::Owned(header)),
+            argument_in: super::ArgumentIn::Header(header_trait),
+            type_tree: Some(arg.ty),
+        }
+    }
+
     // if type is either Path or Query with direct children as Object types without generics
     #[cfg(any(feature = "actix_extras", feature = "axum_extras"))]
     pub(super) fn is_into_params(fn_arg: &FnArg) -> bool {
@@ -26,37 +26,33 @@ impl ArgumentResolver for PathOperations {
         let (into_params_args, value_args): (Vec<FnArg>, Vec<FnArg>) =
             fn_arg::get_fn_args(fn_args)?.partition(fn_arg::is_into_params);
 
-        if let Some(macro_args) = macro_args {
-            let (primitive_args, body) = split_path_args_and_request(value_args);
+        let (primitive_args, header_args, body) = split_path_args_and_request(value_args);
+        let into_params = Some(
+            into_params_args
+                .into_iter()
+                .flat_map(fn_arg::with_parameter_in)
+                .map(Into::into)
+                .collect(),
+        );
 
+        if let Some(macro_args) = macro_args {
             Ok((
                 Some(
                     macro_args
                         .into_iter()
                         .zip(primitive_args)
                         .map(into_value_argument)
+                        .chain(header_args)
                         .collect(),
                 ),
-                Some(
-                    into_params_args
-                        .into_iter()
-                        .flat_map(fn_arg::with_parameter_in)
-                        .map(Into::into)
-                        .collect(),
-                ),
+                into_params,
                 body.into_iter().next().map(Into::into),
             ))
         } else {
-            let (_, body) = split_path_args_and_request(value_args);
+            let header_args = header_args.collect::<Vec<_>>();
             Ok((
-                None,
-                Some(
-                    into_params_args
-                        .into_iter()
-                        .flat_map(fn_arg::with_parameter_in)
-                        .map(Into::into)
-                        .collect(),
-                ),
+                (!header_args.is_empty()).then_some(header_args),
+                into_params,
                 body.into_iter().next().map(Into::into),
             ))
         }
@@ -67,12 +63,21 @@ fn split_path_args_and_request(
     value_args: Vec<FnArg>,
 ) -> (
     impl Iterator<Item = TypeTree>,
+    impl Iterator<Item = ValueArgument>,
     impl Iterator<Item = TypeTree>,
 ) {
+    let is_header = |arg: &FnArg| fn_arg::is_typed_header(arg, "Header");
+    let (header_args, value_args): (Vec<FnArg>, Vec<FnArg>) =
+        value_args.into_iter().partition(is_header);
     let (path_args, body_types): (Vec<FnArg>, Vec<FnArg>) = value_args
         .into_iter()
         .filter(|arg| {
-            arg.ty.is("Path") || arg.ty.is("Json") || arg.ty.is("Form") || arg.ty.is("Bytes")
+            arg.ty.is("Path")
+                || arg.ty.is("Json")
+                || arg.ty.is("Form")
+                || arg.ty.is("Bytes")
+                || arg.ty.is("Multipart")
+                || arg.ty.is("MultipartForm")
         })
         .partition(|arg| arg.ty.is("Path"));
 
@@ -94,6 +99,9 @@ fn split_path_args_and_request(
                     unreachable!("Value arguments does not have ValueType::Object arguments")
                 }
             }),
+        header_args.into_iter().map(|arg| {
+            fn_arg::to_header_argument(arg, syn::parse_quote!(::actix_web::http::header::Header))
+        }),
         body_types.into_iter().map(|json| json.ty),
     )
 }
@@ -1,15 +1,357 @@
-use syn::{ItemFn, TypePath};
+use proc_macro2::Literal;
+use syn::visit::{self, Visit};
+use syn::{parse_quote, Expr, GenericArgument, ItemFn, PathArguments, Type, TypePath};
+
+use crate::path::response::Response;
+use crate::path::status::{reason_phrase, STATUS_CODES};
+
+/// Infer responses of the handler function from its return type.
+///
+/// * `Json<T>` is documented as `200` response with `T` as `application/json` body.
+/// * `String`, `&str` and `Html<T>` are documented as `200` text responses.
+/// * `Option<T>` is documented as responses of `T` and `404` response.
+/// * `Result<T, E>` is documented as responses of `T` and `E`.
+/// * Tuples with `StatusCode` are documented with the status codes found from the tuple
+///   expressions the function returns, e.g. `(StatusCode::CREATED, Json(item))`.
+/// * `impl Trait` is documented with the status codes found from the returned expressions.
+/// * Other types are documented with `fastapi::IntoResponses` if the type implements it.
+pub fn parse_fn_operation_responses(fn_op: &ItemFn) -> Vec<Response<'static>> {
+    // `Sse<impl Stream<...>>` cannot implement `IntoResponses`, it is resolved by axum extension
+    #[cfg(feature = "axum_extras")]
+    if super::axum::is_server_sent_events(&fn_op.sig.output) {
+        return Vec::new();
+    }
 
-pub fn parse_fn_operation_responses(fn_op: &ItemFn) -> Option<&TypePath> {
     match &fn_op.sig.output {
-        syn::ReturnType::Type(_, item) => get_type_path(item.as_ref()),
-        syn::ReturnType::Default => None, // default return type () should result no responses
+        syn::ReturnType::Type(_, ty) => {
+            let statuses = StatusCodes::from_block(&fn_op.block);
+
+            infer_responses(ty, &statuses, Branch::Any)
+        }
+        syn::ReturnType::Default => Vec::new(), // default return type () should result no responses
     }
 }
 
-fn get_type_path(ty: &syn::Type) -> Option<&TypePath> {
+/// Branch of `Result` the type is in. Bodies of error responses are not inferred because their
+/// status code cannot be known without `StatusCode` in the type.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Branch {
+    Any,
+    Ok,
+    Err,
+}
+
+fn infer_responses(ty: &Type, statuses: &StatusCodes, branch: Branch) -> Vec<Response<'static>> {
     match ty {
-        syn::Type::Path(ty_path) => Some(ty_path),
+        Type::Paren(paren) => infer_responses(&paren.elem, statuses, branch),
+        Type::Group(group) => infer_responses(&group.elem, statuses, branch),
+        Type::ImplTrait(_) => statuses
+            .get(branch)
+            .into_iter()
+            .map(|status| response(status, None))
+            .collect(),
+        Type::Tuple(tuple) if tuple.elems.iter().any(is_status_code) => {
+            let content = tuple
+                .elems
+                .iter()
+                .rev()
+                .filter(|ty| !is_status_code(ty))
+                .find_map(Content::from_type);
+            let mut codes = statuses.get(branch);
+            if codes.is_empty() && branch != Branch::Err {
+                codes.push(200);
+            }
+
+            codes
+                .into_iter()
+                .map(|status| response(status, content.as_ref()))
+                .collect()
+        }
+        Type::Tuple(tuple) => tuple
+            .elems
+            .last()
+            .map(|ty| infer_responses(ty, statuses, branch))
+            .unwrap_or_default(),
+        Type::Path(type_path) => {
+            let Some(segment) = type_path.path.segments.last() else {
+                return Vec::new();
+            };
+            let mut arguments = generic_types(&segment.arguments);
+
+            match (segment.ident.to_string().as_str(), arguments.next()) {
+                ("Result", Some(ok)) => {
+                    let mut responses = infer_responses(ok, statuses, Branch::Ok);
+                    if let Some(err) = arguments.next() {
+                        responses.extend(infer_responses(err, statuses, Branch::Err));
+                    }
+                    responses
+                }
+                ("Option", Some(inner)) => {
+                    let mut responses = infer_responses(inner, statuses, branch);
+                    responses.push(response(404, None));
+                    responses
+                }
+                ("Sse", _) => Vec::new(),
+                _ => match Content::from_type(ty) {
+                    Some(content) if branch != Branch::Err => vec![response(200, Some(&content))],
+                    Some(_) => Vec::new(),
+                    None if is_resolvable(ty) => vec![Response::ReturnType(ty.clone())],
+                    None => Vec::new(),
+                },
+            }
+        }
+        Type::Reference(_) if branch != Branch::Err => Content::from_type(ty)
+            .map(|content| vec![response(200, Some(&content))])
+            .unwrap_or_default(),
+        _ => Vec::new(),
+    }
+}
+
+/// Create inferred response with the reason phrase of the `status` as description.
+fn response(status: i16, content: Option<&Content>) -> Response<'static> {
+    let description = reason_phrase(status).unwrap_or_default();
+    let status = Literal::i16_unsuffixed(status);
+    match content {
+        Some(Content::Json(ty)) => parse_quote! {
+            (status = #status, description = #description, body = #ty, content_type = "application/json")
+        },
+        Some(Content::Text) => parse_quote! {
+            (status = #status, description = #description, body = String, content_type = "text/plain")
+        },
+        Some(Content::Html) => parse_quote! {
+            (status = #status, description = #description, body = String, content_type = "text/html")
+        },
+        None => parse_quote! {
+            (status = #status, description = #description)
+        },
+    }
+}
+
+/// Content of a response which can be known from the type.
+enum Content {
+    Json(Box<Type>),
+    Text,
+    Html,
+}
+
+impl Content {
+    fn from_type(ty: &Type) -> Option<Self> {
+        match ty {
+            Type::Path(TypePath { path, .. }) => {
+                let segment = path.segments.last()?;
+                match segment.ident.to_string().as_str() {
+                    "Json" => generic_types(&segment.arguments)
+                        .next()
+                        .map(|ty| Content::Json(Box::new(ty.clone()))),
+                    "String" => Some(Content::Text),
+                    "Html" => Some(Content::Html),
+                    _ => None,
+                }
+            }
+            Type::Reference(reference) => matches!(
+                reference.elem.as_ref(),
+                Type::Path(TypePath { path, .. }) if path.is_ident("str")
+            )
+            .then_some(Content::Text),
+            _ => None,
+        }
+    }
+}
+
+fn generic_types(arguments: &PathArguments) -> impl Iterator<Item = &Type> {
+    let arguments = match arguments {
+        PathArguments::AngleBracketed(arguments) => Some(&arguments.args),
         _ => None,
+    };
+
+    arguments
+        .into_iter()
+        .flatten()
+        .filter_map(|argument| match argument {
+            GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        })
+}
+
+fn is_status_code(ty: &Type) -> bool {
+    matches!(
+        ty,
+        Type::Path(TypePath { path, .. }) if path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "StatusCode")
+    )
+}
+
+/// Check whether the type can be used outside of the handler function signature, that is it
+/// has no `impl Trait`, inferred types or lifetimes other than `'static`.
+fn is_resolvable(ty: &Type) -> bool {
+    struct Resolvable(bool);
+
+    impl<'ast> Visit<'ast> for Resolvable {
+        fn visit_type_impl_trait(&mut self, _: &'ast syn::TypeImplTrait) {
+            self.0 = false;
+        }
+
+        fn visit_type_infer(&mut self, _: &'ast syn::TypeInfer) {
+            self.0 = false;
+        }
+
+        fn visit_lifetime(&mut self, lifetime: &'ast syn::Lifetime) {
+            if lifetime.ident != "static" {
+                self.0 = false;
+            }
+        }
     }
+
+    let mut resolvable = Resolvable(true);
+    resolvable.visit_type(ty);
+
+    resolvable.0
+}
+
+/// Status codes of `(StatusCode::CREATED, ...)` like tuple expressions the handler function
+/// returns. Only the tail expression of the function body, `return` expressions and errors
+/// propagated with `?` are considered. Closures, async blocks and nested items are not part of
+/// the handler result and are not looked into, except closures given to `map_err` and
+/// `ok_or_else` which produce the error.
+///
+/// Tuples within `Err(...)` calls and `map_err`, `ok_or` and `ok_or_else` arguments, and
+/// errors propagated with `?` are considered as error responses.
+#[derive(Default)]
+struct StatusCodes {
+    ok: Vec<i16>,
+    err: Vec<i16>,
+}
+
+impl StatusCodes {
+    fn from_block(block: &syn::Block) -> Self {
+        let mut statuses = Self::default();
+        statuses.visit_block(block);
+        if let Some(syn::Stmt::Expr(tail, None)) = block.stmts.last() {
+            statuses.result(tail, false);
+        }
+
+        statuses
+    }
+
+    fn get(&self, branch: Branch) -> Vec<i16> {
+        match branch {
+            Branch::Ok => self.ok.clone(),
+            Branch::Err => self.err.clone(),
+            Branch::Any => self
+                .ok
+                .iter()
+                .chain(&self.err)
+                .fold(Vec::new(), |mut codes, code| {
+                    if !codes.contains(code) {
+                        codes.push(*code);
+                    }
+                    codes
+                }),
+        }
+    }
+
+    /// Collect status codes of expression which is the result of the handler function.
+    fn result(&mut self, expr: &Expr, is_err: bool) {
+        match expr {
+            Expr::Tuple(tuple) => {
+                if let Some(status) = tuple.elems.iter().find_map(status_code) {
+                    let statuses = if is_err { &mut self.err } else { &mut self.ok };
+                    if !statuses.contains(&status) {
+                        statuses.push(status);
+                    }
+                }
+            }
+            Expr::Paren(paren) => self.result(&paren.expr, is_err),
+            Expr::Group(group) => self.result(&group.expr, is_err),
+            Expr::Block(block) => self.result_block(&block.block, is_err),
+            Expr::If(expr_if) => {
+                self.result_block(&expr_if.then_branch, is_err);
+                if let Some((_, else_branch)) = &expr_if.else_branch {
+                    self.result(else_branch, is_err);
+                }
+            }
+            Expr::Match(expr_match) => expr_match
+                .arms
+                .iter()
+                .for_each(|arm| self.result(&arm.body, is_err)),
+            Expr::Call(call) => {
+                let is_err = match call.func.as_ref() {
+                    Expr::Path(path) if path.path.is_ident("Err") => true,
+                    Expr::Path(path) if path.path.is_ident("Ok") || path.path.is_ident("Some") => {
+                        is_err
+                    }
+                    _ => return,
+                };
+                call.args
+                    .iter()
+                    .for_each(|argument| self.result(argument, is_err));
+            }
+            Expr::MethodCall(call)
+                if ["map_err", "ok_or", "ok_or_else"]
+                    .iter()
+                    .any(|method| call.method == method) =>
+            {
+                self.result(&call.receiver, is_err);
+                call.args.iter().for_each(|argument| match argument {
+                    Expr::Closure(closure) => self.result(&closure.body, true),
+                    argument => self.result(argument, true),
+                });
+            }
+            Expr::MethodCall(call) if call.method == "into" || call.method == "into_response" => {
+                self.result(&call.receiver, is_err)
+            }
+            Expr::Try(expr_try) => self.result(&expr_try.expr, is_err),
+            _ => (),
+        }
+    }
+
+    fn result_block(&mut self, block: &syn::Block, is_err: bool) {
+        if let Some(syn::Stmt::Expr(tail, None)) = block.stmts.last() {
+            self.result(tail, is_err);
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for StatusCodes {
+    fn visit_expr_return(&mut self, expr_return: &'ast syn::ExprReturn) {
+        if let Some(expr) = &expr_return.expr {
+            self.result(expr, false);
+        }
+
+        visit::visit_expr_return(self, expr_return);
+    }
+
+    fn visit_expr_try(&mut self, expr_try: &'ast syn::ExprTry) {
+        // only the error is propagated from the handler function with `?`
+        self.result(&expr_try.expr, true);
+
+        visit::visit_expr_try(self, expr_try);
+    }
+
+    // closures and async blocks have their own result
+    fn visit_expr_closure(&mut self, _: &'ast syn::ExprClosure) {}
+
+    fn visit_expr_async(&mut self, _: &'ast syn::ExprAsync) {}
+
+    // nested items are not part of the handler function
+    fn visit_item(&mut self, _: &'ast syn::Item) {}
+}
+
+/// Get status code of `StatusCode::CREATED` like path expression.
+fn status_code(expr: &Expr) -> Option<i16> {
+    let Expr::Path(path) = expr else {
+        return None;
+    };
+    let mut segments = path.path.segments.iter().rev();
+    let status = segments.next()?;
+    if segments.next()?.ident != "StatusCode" {
+        return None;
+    }
+
+    STATUS_CODES
+        .iter()
+        .find(|(_, name)| status.ident == name)
+        .map(|(code, _)| *code)
 }
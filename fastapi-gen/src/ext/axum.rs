@@ -1,5 +1,7 @@
 use std::borrow::Cow;
 
+use proc_macro2::{TokenStream, TokenTree};
+use quote::ToTokens;
 use regex::Captures;
 use syn::{punctuated::Punctuated, token::Comma};
 
@@ -10,8 +12,8 @@ use crate::{
 
 use super::{
     fn_arg::{self, FnArg, FnArgType},
-    ArgValue, ArgumentResolver, Arguments, MacroArg, MacroPath, PathOperations, PathResolver,
-    ValueArgument,
+    ArgValue, ArgumentIn, ArgumentResolver, Arguments, MacroArg, MacroPath, PathOperations,
+    PathResolver, ValueArgument,
 };
 
 // axum framework is only able to resolve handler function arguments.
@@ -26,10 +28,14 @@ impl ArgumentResolver for PathOperations {
             fn_arg::get_fn_args(args)?.partition(fn_arg::is_into_params);
 
         let (value_args, body) = split_value_args_and_request_body(value_args);
+        // only path arguments are named after the path template arguments
+        let (path_args, other_args): (Vec<ValueArgument>, Vec<ValueArgument>) =
+            value_args.partition(|arg| arg.argument_in == ArgumentIn::Path);
 
         Ok((
             Some(
-                value_args
+                path_args
+                    .into_iter()
                     .zip(macro_args.unwrap_or_default())
                     .map(|(value_arg, macro_arg)| ValueArgument {
                         name: match macro_arg {
@@ -40,6 +46,7 @@ impl ArgumentResolver for PathOperations {
                         argument_in: value_arg.argument_in,
                         type_tree: value_arg.type_tree,
                     })
+                    .chain(other_args)
                     .collect(),
             ),
             Some(
@@ -60,57 +67,145 @@ fn split_value_args_and_request_body(
     impl Iterator<Item = super::ValueArgument<'_>>,
     impl Iterator<Item = TypeTree<'_>>,
 ) {
-    let (path_args, body_types): (Vec<FnArg>, Vec<FnArg>) = value_args
+    let is_value_arg = |arg: &FnArg| arg.ty.is("Path") || is_typed_header(arg) || is_query_map(arg);
+    let (value_args, body_types): (Vec<FnArg>, Vec<FnArg>) = value_args
         .into_iter()
         .filter(|arg| {
-            arg.ty.is("Path") || arg.ty.is("Json") || arg.ty.is("Form") || arg.ty.is("Bytes")
+            is_value_arg(arg)
+                || arg.ty.is("Json")
+                || arg.ty.is("Form")
+                || arg.ty.is("Bytes")
+                || arg.ty.is("Multipart")
         })
-        .partition(|arg| arg.ty.is("Path"));
+        .partition(is_value_arg);
 
     (
-        path_args
-            .into_iter()
-            .filter(|arg| arg.ty.is("Path"))
-            .flat_map(|path_arg| {
-                match (
-                    path_arg.arg_type,
-                    path_arg.ty.children.expect("Path must have children"),
-                ) {
-                    (FnArgType::Single(name), path_children) => path_children
-                        .into_iter()
-                        .flat_map(|ty| match ty.value_type {
-                            ValueType::Tuple => ty
-                                .children
-                                .expect("ValueType::Tuple will always have children")
-                                .into_iter()
-                                .map(|ty| to_value_argument(None, ty))
-                                .collect(),
-                            ValueType::Primitive => {
-                                vec![to_value_argument(Some(Cow::Owned(name.to_string())), ty)]
-                            }
-                            ValueType::Object | ValueType::Value => unreachable!("Cannot get here"),
-                        })
-                        .collect::<Vec<_>>(),
-                    (FnArgType::Destructed(tuple), path_children) => tuple
-                        .iter()
-                        .zip(path_children.into_iter().flat_map(|child| {
-                            child
-                                .children
-                                .expect("ValueType::Tuple will always have children")
-                        }))
-                        .map(|(name, ty)| to_value_argument(Some(Cow::Owned(name.to_string())), ty))
-                        .collect::<Vec<_>>(),
-                }
-            }),
+        value_args.into_iter().flat_map(|arg| {
+            if is_typed_header(&arg) {
+                return vec![fn_arg::to_header_argument(
+                    arg,
+                    syn::parse_quote!(::axum_extra::headers::Header),
+                )];
+            }
+            if is_query_map(&arg) {
+                return vec![to_query_map_argument(arg)];
+            }
+
+            match (
+                arg.arg_type,
+                arg.ty.children.expect("Path must have children"),
+            ) {
+                (FnArgType::Single(name), path_children) => path_children
+                    .into_iter()
+                    .flat_map(|ty| match ty.value_type {
+                        ValueType::Tuple => ty
+                            .children
+                            .expect("ValueType::Tuple will always have children")
+                            .into_iter()
+                            .map(|ty| to_value_argument(None, ty))
+                            .collect(),
+                        ValueType::Primitive => {
+                            vec![to_value_argument(Some(Cow::Owned(name.to_string())), ty)]
+                        }
+                        ValueType::Object | ValueType::Value => unreachable!("Cannot get here"),
+                    })
+                    .collect::<Vec<_>>(),
+                (FnArgType::Destructed(tuple), path_children) => tuple
+                    .iter()
+                    .zip(path_children.into_iter().flat_map(|child| {
+                        child
+                            .children
+                            .expect("ValueType::Tuple will always have children")
+                    }))
+                    .map(|(name, ty)| to_value_argument(Some(Cow::Owned(name.to_string())), ty))
+                    .collect::<Vec<_>>(),
+            }
+        }),
         body_types.into_iter().map(|body| body.ty),
     )
 }
 
+/// Check whether argument is `TypedHeader<T>` or `Option<TypedHeader<T>>`.
+fn is_typed_header(arg: &FnArg) -> bool {
+    fn_arg::is_typed_header(arg, "TypedHeader")
+}
+
+/// Check whether argument is a `Query<T>` or `Option<Query<T>>` of map type `T`.
+fn is_query_map(arg: &FnArg) -> bool {
+    let ty = fn_arg::without_option(&arg.ty);
+    fn_arg::is_ident(ty, "Query")
+        && ty
+            .children
+            .as_ref()
+            .and_then(|children| children.first())
+            .is_some_and(TypeTree::is_map)
+}
+
+/// Resolve a single `form` and `explode` object query parameter from `Query<T>` of map type `T`.
+/// The name of the parameter is the name of the argument.
+fn to_query_map_argument(arg: FnArg<'_>) -> ValueArgument<'_> {
+    let name = match arg.arg_type {
+        FnArgType::Single(name) => name.to_string(),
+        FnArgType::Destructed(names) => names.first().map(ToString::to_string).unwrap_or_default(),
+    };
+    let mut ty = arg.ty;
+    if ty.is_option() {
+        ty = ty
+            .children
+            .and_then(|children| children.into_iter().next())
+            .expect("Option must have one child");
+    }
+    let map = ty
+        .children
+        .and_then(|children| children.into_iter().next())
+        .expect("Query must have one child");
+
+    ValueArgument {
+        name: Some(Cow::Owned(name)),
+        argument_in: ArgumentIn::Query,
+        type_tree: Some(map),
+    }
+}
+
+/// Get types of the handler function arguments which can be documented with `FastapiExtractor`.
+///
+/// Types referring to lifetimes of the handler function or `impl Trait` types cannot be named
+/// outside the function and thus are not included.
+pub fn extractor_types(args: &Punctuated<syn::FnArg, Comma>) -> Vec<syn::Type> {
+    fn is_nameable(tokens: TokenStream) -> bool {
+        let mut tokens = tokens.into_iter().peekable();
+        while let Some(token) = tokens.next() {
+            match token {
+                TokenTree::Group(group) if !is_nameable(group.stream()) => return false,
+                TokenTree::Ident(ident) if ident == "impl" => return false,
+                TokenTree::Punct(punct) if punct.as_char() == '\'' => {
+                    if matches!(tokens.peek(), Some(TokenTree::Ident(lifetime)) if lifetime != "static" && lifetime != "_")
+                    {
+                        return false;
+                    }
+                }
+                _ => (),
+            }
+        }
+
+        true
+    }
+
+    args.iter()
+        .filter_map(|arg| match arg {
+            syn::FnArg::Typed(pat_type) => Some(pat_type.ty.as_ref()),
+            syn::FnArg::Receiver(_) => None,
+        })
+        .filter(|ty| is_nameable(ty.to_token_stream()))
+        .cloned()
+        .collect()
+}
+
 fn to_value_argument<'a>(name: Option<Cow<'a, str>>, ty: TypeTree<'a>) -> ValueArgument<'a> {
     ValueArgument {
         name,
         type_tree: Some(ty),
-        argument_in: super::ArgumentIn::Path,
+        argument_in: ArgumentIn::Path,
     }
 }
 
@@ -139,3 +234,34 @@ impl PathResolver for PathOperations {
         })
     }
 }
+
+/// Resolve `text/event-stream` response from `Sse<...>` return type of the handler function.
+/// The data of axum `Event` is always text thus the stream items are documented as `String`.
+#[cfg(feature = "infer_responses")]
+pub fn server_sent_events_response(
+    fn_op: &syn::ItemFn,
+) -> Option<crate::path::response::Response<'static>> {
+    if !is_server_sent_events(&fn_op.sig.output) {
+        return None;
+    }
+
+    Some(syn::parse_quote! {
+        (status = 200, description = "OK", body = stream(String), content_type = "text/event-stream")
+    })
+}
+
+/// Check whether return type of the handler function is axum `Sse<...>`.
+#[cfg(feature = "infer_responses")]
+pub fn is_server_sent_events(output: &syn::ReturnType) -> bool {
+    match output {
+        syn::ReturnType::Type(_, ty) => matches!(
+            ty.as_ref(),
+            syn::Type::Path(ty_path) if ty_path
+                .path
+                .segments
+                .last()
+                .is_some_and(|segment| segment.ident == "Sse")
+        ),
+        syn::ReturnType::Default => false,
+    }
+}
@@ -134,6 +134,8 @@ static CONFIG: once_cell::sync::Lazy<fastapi_config::Config> =
 ///   contain. Value must be a number.
 /// * `min_properties = ...` Can be used to define minimum number of properties this struct can
 ///   contain. Value must be a number.
+/// * `extensions(...)` Can be used to add [specification extensions][extensions] to the schema.
+///   See the [extensions syntax][derive@ToSchema#extensions-syntax].
 ///* `no_recursion` Is used to break from recursion in case of looping schema tree e.g. `Pet` ->
 ///  `Owner` -> `Pet`. _`no_recursion`_ attribute must be used within `Ower` type not to allow
 ///  recurring into `Pet`. Failing to do so will cause infinite loop and runtime **panic**. On
@@ -199,6 +201,8 @@ static CONFIG: once_cell::sync::Lazy<fastapi_config::Config> =
 ///   See [`Object::content_encoding`][schema_object_encoding]
 /// * `content_media_type = ...` Can be used to define MIME type of a string for underlying schema object.
 ///   See [`Object::content_media_type`][schema_object_media_type]
+/// * `extensions(...)` Can be used to add [specification extensions][extensions] to the schema.
+///   See the [extensions syntax][derive@ToSchema#extensions-syntax].
 ///* `ignore` or `ignore = ...` Can be used to skip the field from being serialized to OpenAPI schema. It accepts either a literal `bool` value
 ///   or a path to a function that returns `bool` (`Fn() -> bool`).
 ///* `no_recursion` Is used to break from recursion in case of looping schema tree e.g. `Pet` ->
@@ -266,6 +270,8 @@ static CONFIG: once_cell::sync::Lazy<fastapi_config::Config> =
 ///   See [`Object::content_encoding`][schema_object_encoding]
 /// * `content_media_type = ...` Can be used to define MIME type of a string for underlying schema object.
 ///   See [`Object::content_media_type`][schema_object_media_type]
+/// * `extensions(...)` Can be used to add [specification extensions][extensions] to the schema.
+///   See the [extensions syntax][derive@ToSchema#extensions-syntax].
 ///* `no_recursion` Is used to break from recursion in case of looping schema tree e.g. `Pet` ->
 ///  `Owner` -> `Pet`. _`no_recursion`_ attribute must be used within `Ower` type not to allow
 ///  recurring into `Pet`. Failing to do so will cause infinite loop and runtime **panic**.
@@ -298,6 +304,8 @@ static CONFIG: once_cell::sync::Lazy<fastapi_config::Config> =
 /// * `deprecated` Can be used to mark the enum as deprecated in the generated OpenAPI spec but
 ///   not in the code. If you'd like to mark the enum as deprecated in the code as well use
 ///   Rust's own `#[deprecated]` attribute instead.
+/// * `extensions(...)` Can be used to add [specification extensions][extensions] to the schema.
+///   See the [extensions syntax][derive@ToSchema#extensions-syntax].
 ///
 /// ### Plain Enum Variant Optional Configuration Options for `#[schema(...)]`
 ///
@@ -334,6 +342,8 @@ static CONFIG: once_cell::sync::Lazy<fastapi_config::Config> =
 /// * `discriminator = ...` or `discriminator(...)` Can be used to define OpenAPI discriminator
 ///   field for enums with single unnamed _`ToSchema`_ reference field. See the [discriminator
 ///   syntax][derive@ToSchema#schemadiscriminator-syntax].
+/// * `extensions(...)` Can be used to add [specification extensions][extensions] to the schema.
+///   See the [extensions syntax][derive@ToSchema#extensions-syntax].
 ///* `no_recursion` Is used to break from recursion in case of looping schema tree e.g. `Pet` ->
 ///  `Owner` -> `Pet`. _`no_recursion`_ attribute must be used within `Ower` type not to allow
 ///  recurring into `Pet`. Failing to do so will cause infinite loop and runtime **panic**. On
@@ -469,6 +479,24 @@ static CONFIG: once_cell::sync::Lazy<fastapi_config::Config> =
 ///   not in the code. If you'd like to mark the field as deprecated in the code as well use
 ///   Rust's own `#[deprecated]` attribute instead.
 ///
+/// # `extensions(...)` syntax
+///
+/// Extensions are comma separated list of parenthesized _`"x-name" = json!(...)`_ pairs. Name
+/// must be literal string starting with _`x-`_ and value must be _`json!(...)`_ which is what
+/// _`serde_json::json!`_ can parse as _`serde_json::Value`_. Same syntax is used with
+/// [`fastapi::path(...)`][path_macro], [`IntoParams`][into_params], response derives and
+/// [`OpenApi`][openapi_derive] _`info(...)`_ and _`tags(...)`_.
+///
+/// ```rust
+/// # use fastapi::ToSchema;
+/// #[derive(ToSchema)]
+/// #[schema(extensions(("x-internal" = json!(true))))]
+/// struct Pet {
+///     #[schema(extensions(("x-order" = json!(1)), ("x-go-name" = json!("PetName"))))]
+///     name: String,
+/// }
+/// ```
+///
 /// # Partial `#[serde(...)]` attributes support
 ///
 /// ToSchema derive has partial support for [serde attributes]. These supported attributes will reflect to the
@@ -946,6 +974,7 @@ static CONFIG: once_cell::sync::Lazy<fastapi_config::Config> =
 /// [into_params]: derive.IntoParams.html
 /// [primitive]: https://doc.rust-lang.org/std/primitive/index.html
 /// [serde attributes]: https://serde.rs/attributes.html
+/// [extensions]: https://spec.openapis.org/oas/latest.html#specification-extensions
 /// [discriminator]: openapi/schema/struct.Discriminator.html
 /// [enum_schema]: derive.ToSchema.html#enum-optional-configuration-options-for-schema
 /// [openapi_derive]: derive.OpenApi.html
@@ -1007,6 +1036,9 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 /// * `impl_for = ...` Optional type to implement the [`Path`][path] trait. By default a new type
 ///   is used for the implementation.
 ///
+/// * `client_name = ...` Optional name of the client enum generated with `client` feature. See
+///   [typed client](#typed-client-with-client-feature).
+///
 /// * `operation_id = ...` Unique operation id for the endpoint. By default this is mapped to function name.
 ///   The operation_id can be any valid expression (e.g. string literals, macro invocations, variables) so long
 ///   as its result can be converted to a `String` using `String::from`.
@@ -1032,6 +1064,10 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 ///
 /// * `security(...)` List of [`SecurityRequirement`][security]s local to the path operation.
 ///
+/// * `extensions(...)` List of [specification extensions][extensions] added to the operation e.g.
+///   _`extensions(("x-codegen-request-body-name" = json!("pet")))`_. See the
+///   [extensions syntax][derive@ToSchema#extensions-syntax].
+///
 /// # Request Body Attributes
 ///
 /// ## Simple format definition by `request_body = ...`
@@ -1058,6 +1094,11 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 ///   This has same syntax as _`examples(...)`_ in [Response Attributes](#response-attributes)
 ///   _examples(...)_
 ///
+/// * `encoding(...)` Define [`Encoding`][encoding] for a single property (part) of the request body.
+///   The attribute can be defined multiple times, once for each part. Encoding only applies to
+///   _`multipart`_ and _`application/x-www-form-urlencoded`_ request bodies. See
+///   [encoding syntax](#request-body-encoding-syntax).
+///
 /// ### Single request body content
 ///
 /// * `content = ...` Can be _`content = Type`_, _`content = inline(Type)`_ or _`content = ref("...")`_. The
@@ -1071,8 +1112,9 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 /// * `content_type = "..."` Can be used to override the default behavior
 ///   of auto resolving the content type from the `content` attribute. If defined the value should be valid
 ///   content type such as _`application/json`_ . By default the content type is _`text/plain`_
-///   for [primitive Rust types][primitive], `application/octet-stream` for _`[u8]`_ and _`application/json`_
-///   for struct and mixed enum types.
+///   for [primitive Rust types][primitive], `application/octet-stream` for _`[u8]`_,
+///   _`fastapi::UploadFile`_ and _`bytes::Bytes`_ (also as _`axum::body::Bytes`_ or
+///   _`actix_web::web::Bytes`_) and _`application/json`_ for struct and mixed enum types.
 ///
 /// _**Example of single request body definitions.**_
 /// ```text
@@ -1123,6 +1165,53 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 ///  ),
 /// ```
 ///
+/// ### Request body `encoding(...)` syntax
+///
+/// * `part = "..."` Name of the property the encoding is applied to. This is mandatory.
+///
+/// * `content_type = "..."` Content type of the part e.g. _`application/json`_ for JSON metadata
+///   part or _`image/png, image/jpeg`_ for a file part. This can also be an expression.
+///
+/// * `headers(...)` Slice of headers of the part e.g. _`Content-Disposition`_. Has same syntax as
+///   response _`headers(...)`_ in [Response Attributes](#response-attributes).
+///
+/// * `style = ...` Defines how the part is serialized by [`ParameterStyle`][style]. Only applies
+///   to _`application/x-www-form-urlencoded`_ request body.
+///
+/// * `explode` Defines whether array and object parts generate separate parameters. Only applies
+///   to _`application/x-www-form-urlencoded`_ request body.
+///
+/// * `allow_reserved` Defines whether reserved characters are allowed without percent-encoding.
+///   Only applies to _`application/x-www-form-urlencoded`_ request body.
+///
+/// File parts can be documented with [`UploadFile`][upload_file] type which is rendered as
+/// _`string`_ with _`binary`_ format. Binary types must be used with their qualified path
+/// _`fastapi::UploadFile`_ or _`bytes::Bytes`_ because bare _`UploadFile`_ and _`Bytes`_ cannot be
+/// told apart from user defined types of the same name. Bare names fail to compile, user defined
+/// types of the same name can be referenced with qualified path e.g. _`crate::UploadFile`_.
+///
+/// _**Bare imported binary type fails to compile.**_
+/// ```compile_fail
+/// use fastapi::UploadFile;
+///
+/// #[fastapi::path(post, path = "/upload", request_body = UploadFile)]
+/// fn upload() {}
+/// ```
+///
+/// _**Example of multipart request body with JSON metadata part and a file part.**_
+/// ```text
+///  request_body(
+///     content = Upload,
+///     content_type = "multipart/form-data",
+///     encoding(part = "metadata", content_type = "application/json"),
+///     encoding(
+///         part = "file",
+///         content_type = "image/png",
+///         headers(("x-checksum" = String, description = "Checksum of the file"))
+///     )
+///  ),
+/// ```
+///
 /// # Response Attributes
 ///
 /// * `status = ...` Is either a valid http status code integer. E.g. _`200`_ or a string value representing
@@ -1140,11 +1229,14 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 ///   that free form _`ref`_ is accessible via OpenAPI doc or Swagger UI, users are responsible for making
 ///   these guarantees.
 ///
+///   Streamed response body can be defined with _`body = stream(Type)`_. See
+///   [streaming responses](#streaming-responses).
+///
 /// * `content_type = "..."` Can be used to override the default behavior
 ///   of auto resolving the content type from the `body` attribute. If defined the value should be valid
 ///   content type such as _`application/json`_ . By default the content type is _`text/plain`_
-///   for [primitive Rust types][primitive], `application/octet-stream` for _`[u8]`_ and _`application/json`_
-///   for struct and mixed enum types.
+///   for [primitive Rust types][primitive], `application/octet-stream` for _`[u8]`_, _`text/event-stream`_
+///   for _`stream(...)`_ and _`application/json`_ for struct and mixed enum types.
 ///
 /// * `headers(...)` Slice of response headers that are returned back to a caller.
 ///
@@ -1162,6 +1254,37 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 ///
 /// * `links(...)` Define a map of operations links that can be followed from the response.
 ///
+/// * `extensions(...)` List of [specification extensions][extensions] added to the response. See
+///   the [extensions syntax][derive@ToSchema#extensions-syntax].
+///
+/// ## Streaming responses
+///
+/// Streamed response body is defined with _`body = stream(...)`_. Instead of _`schema`_ the content
+/// will have _`itemSchema`_ describing a single item of the stream. The syntax is either
+/// _`stream(Type)`_ or _`stream("event" = Type, "event2" = Type2)`_ where _`Type`_ supports same
+/// syntax as _`body`_ e.g. _`inline(Type)`_.
+///
+/// * With _`text/event-stream`_ content type, which is the default, each item is documented as a
+///   Server-Sent Event with _`data`_, _`event`_, _`id`_ and _`retry`_ fields. Named events are
+///   combined with _`oneOf`_ and each event name is mapped to its own data schema.
+///
+/// * With other content types such as _`application/x-ndjson`_ the item schema is the given type
+///   as is. Event names are not supported.
+///
+/// With **axum_extras** and **infer_responses** features a handler returning _`Sse<...>`_ is
+/// documented as _`text/event-stream`_ response with _`String`_ data unless other _`200`_ response
+/// is defined.
+///
+/// _**Example of Server-Sent Events and NDJSON streams.**_
+/// ```text
+///  responses(
+///     (status = 200, body = stream("created" = Pet, "deleted" = inline(u64))),
+///  )
+///  responses(
+///     (status = 200, body = stream(Pet), content_type = "application/x-ndjson"),
+///  )
+/// ```
+///
 /// ## Response `examples(...)` syntax
 ///
 /// * `name = ...` This is first attribute and value must be literal string.
@@ -1289,6 +1412,57 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 /// responses(MyResponse)
 /// ```
 ///
+/// ## Problem responses
+///
+/// _**Responses with [RFC 9457][rfc9457] Problem Details can be defined with `problem(...)`
+/// shorthand.**_ The response has [`Problem`][problem] body with _`application/problem+json`_
+/// content type and an example built from the given attributes.
+///
+/// * `status = ...` Same as _`status`_ of a response tuple. This is mandatory.
+/// * `type = "..."` URI reference identifying the problem type.
+/// * `title = "..."` Short summary of the problem type. Used as description unless
+///   _`description`_ is defined.
+/// * `description = "..."` Description of the response.
+///
+/// ```text
+/// responses(
+///     problem(status = 404, type = "https://example.com/probs/not-found", title = "Pet not found"),
+/// )
+/// ```
+///
+/// [rfc9457]: https://www.rfc-editor.org/rfc/rfc9457
+/// [problem]: problem/struct.Problem.html
+///
+/// ## Inferred responses
+///
+/// With **infer_responses** feature responses are also inferred from the return type of the
+/// handler function. Explicitly defined responses with the same status override the inferred ones.
+/// Inferred responses are described with the reason phrase of their status e.g. _`Created`_ or
+/// _`Not Found`_.
+///
+/// * _`Json<T>`_ is documented as _`200`_ response with _`T`_ as _`application/json`_ body.
+/// * _`String`_, _`&str`_ and _`Html<T>`_ are documented as _`200`_ text responses.
+/// * _`Option<T>`_ is documented as responses of _`T`_ and an empty _`404`_ response.
+/// * _`Result<T, E>`_ is documented as responses of both _`T`_ and _`E`_.
+/// * Tuples with _`StatusCode`_ such as _`(StatusCode, Json<T>)`_ are documented with the status
+///   codes of the tuple expressions the function returns e.g.
+///   _`(StatusCode::CREATED, Json(item))`_. Returned expressions are the tail expression of the
+///   function body, _`return`_ expressions and errors propagated with _`?`_. Tuples within
+///   _`Err(...)`_ or _`map_err(...)`_ are considered responses of the error type. Closures and
+///   nested functions are not looked into.
+/// * Any other type implementing [`IntoResponses`][into_responses_trait] is documented with its
+///   responses.
+///
+/// _**Inferred `201` and `404` responses.**_
+/// ```text
+/// async fn create_pet(...) -> Result<(StatusCode, Json<Pet>), (StatusCode, String)> {
+///     ...
+///     Ok((StatusCode::CREATED, Json(pet)))
+///     ...
+///     Err((StatusCode::NOT_FOUND, "owner not found".to_string()))
+/// }
+/// ```
+///
 /// # Response Header Attributes
 ///
 /// * `name` Name of the header. E.g. _`x-csrf-token`_
@@ -1438,7 +1612,9 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 /// # Security Requirement Attributes
 ///
 /// * `name` Define the name for security requirement. This must match to name of existing
-///   [`SecurityScheme`][security_scheme].
+///   [`SecurityScheme`][security_scheme]. If the [`OpenApi`][openapi] listing the path
+///   declares `components(security_schemes(...))` the name must be one of them or the compilation
+///   will fail.
 /// * `scopes = [...]` Define the list of scopes needed. These must be scopes defined already in
 ///   existing [`SecurityScheme`][security_scheme].
 ///
@@ -1474,6 +1650,10 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 /// 2. Ability to parse [`std::primitive`]  or [`String`] or [`tuple`] typed `path` parameters from **actix-web** _`web::Path<...>`_.
 /// 3. Ability to parse `path` and `query` parameters form **actix-web** _`web::Path<...>`_, _`web::Query<...>`_ types
 ///    with [`IntoParams`][into_params] trait.
+/// 4. Ability to resolve _`multipart/form-data`_ request body from **actix-multipart** _`Multipart`_ and
+///    _`MultipartForm<...>`_ types. The type of _`MultipartForm<...>`_ must implement [`ToSchema`][to_schema].
+/// 5. Ability to resolve header parameter from **actix-web** _`web::Header<T>`_ type. The name of the
+///    header is taken from _`actix_web::http::header::Header::name`_ of _`T`_.
 ///
 /// See the **actix_extras** in action in examples [todo-actix](https://github.com/nxpkg/fastapi/tree/master/examples/todo-actix).
 ///
@@ -1536,6 +1716,14 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 ///    parameter names and types from it.
 /// 2. It enhances [`IntoParams` derive][into_params_derive] functionality by automatically resolving _`parameter_in`_ from
 ///     _`Path<...>`_ or _`Query<...>`_ handler function arguments.
+/// 3. It resolves _`multipart/form-data`_ request body from _`Multipart`_ handler function argument.
+/// 4. It resolves header parameter from **axum-extra** _`TypedHeader<T>`_ handler function argument.
+///    The name of the header is taken from _`axum_extra::headers::Header::name`_ of _`T`_.
+///    Optional _`Option<TypedHeader<T>>`_ headers are not required.
+/// 5. It resolves a single _`form`_ and _`explode`_ object query parameter from
+///    _`Query<HashMap<String, V>>`_ handler function argument named after the argument.
+/// 6. It documents handler function arguments implementing [`FastapiExtractor`][extractor] with the
+///    parameters, request body and security requirements provided by the extractor.
 ///
 /// _**Resole path argument types from tuple style handler arguments.**_
 /// ```rust
@@ -1590,6 +1778,15 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// _**Resolve free form query parameters from `Query<HashMap<..>>`.**_
+/// ```rust
+/// # use std::collections::HashMap;
+/// # use axum::extract::Query;
+/// /// Search todos with arbitrary filters.
+/// #[fastapi::path(get, path = "/todo/filter", responses((status = 200)))]
+/// async fn filter_todos(Query(filters): Query<HashMap<String, String>>) {}
+/// ```
+///
 /// # Defining file uploads
 ///
 /// File uploads can be defined in accordance to Open API specification [file uploads][file_uploads].
@@ -1657,6 +1854,32 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 /// async fn test_png(_body: MyPng) {}
 /// ```
 ///
+/// # Typed client with `client` feature
+///
+/// When `client` feature is enabled the macro also generates a typed client for the operation.
+/// The client is an enum named after the handler function suffixed with `Response` e.g.
+/// `get_pet` produces `GetPetResponse` with the same visibility as the handler function. Use
+/// `client_name = ...` to give the enum another name e.g. when it would collide with an existing
+/// type.
+///
+/// * Each response tuple in `responses(...)` becomes a variant named after the status code e.g.
+///   `200` becomes `Ok` and `"4XX"` becomes `ClientError`. Variants hold the deserialized `body`
+///   of the response if one is defined.
+/// * The enum has `async fn send(transport, ...)` which takes a `fastapi::client::Transport`
+///   followed by the inline `params(...)` in declaration order, the [`IntoParams`][into_params]
+///   types and finally the `request_body` type. Operations with multiple methods get
+///   `send_{method}` function per method instead e.g. `send_get` and `send_head`.
+/// * Statuses not declared for the operation are returned as
+///   `fastapi::client::Error::UnexpectedStatus`. Responses defined with `IntoResponses` types or
+///   with `response = ...` do not produce variants.
+///
+/// Parameter and body types must implement `serde::Serialize` and response body types
+/// `serde::de::DeserializeOwned` for `send` to be callable.
+///
+/// See [`fastapi::client`][client] for complete example.
+///
+/// [client]: ../fastapi/client/index.html
+///
 /// # Examples
 ///
 /// _**More complete example.**_
@@ -1852,11 +2075,15 @@ pub fn derive_to_schema(input: TokenStream) -> TokenStream {
 /// [security_scheme]: openapi/security/enum.SecurityScheme.html
 /// [primitive]: https://doc.rust-lang.org/std/primitive/index.html
 /// [into_params]: trait.IntoParams.html
+/// [extensions]: https://spec.openapis.org/oas/latest.html#specification-extensions
 /// [style]: openapi/path/enum.ParameterStyle.html
 /// [into_responses_trait]: trait.IntoResponses.html
 /// [into_params_derive]: derive.IntoParams.html
+/// [extractor]: trait.FastapiExtractor.html
 /// [to_response_trait]: trait.ToResponse.html
 /// [known_format]: openapi/schema/enum.KnownFormat.html
+/// [encoding]: openapi/encoding/struct.Encoding.html
+/// [upload_file]: struct.UploadFile.html
 /// [xml]: openapi/xml/struct.Xml.html
 /// [to_schema_xml]: macro@ToSchema#xml-attribute-configuration-options
 /// [relative_references]: https://spec.openapis.org/oas/latest.html#relative-references-in-uris
@@ -1874,7 +2101,7 @@ pub fn path(attr: TokenStream, item: TokenStream) -> TokenStream {
         feature = "actix_extras",
         feature = "rocket_extras",
         feature = "axum_extras",
-        feature = "auto_into_responses"
+        feature = "infer_responses"
     ))]
     let mut path_attribute = path_attribute;
 
@@ -1883,11 +2110,15 @@ pub fn path(attr: TokenStream, item: TokenStream) -> TokenStream {
         Err(error) => return error.into_compile_error().into_token_stream().into(),
     };
 
-    #[cfg(feature = "auto_into_responses")]
+    #[cfg(feature = "infer_responses")]
     {
-        if let Some(responses) = ext::auto_types::parse_fn_operation_responses(&ast_fn) {
-            path_attribute.responses_from_into_responses(responses);
-        };
+        path_attribute
+            .responses_from_return_type(ext::auto_types::parse_fn_operation_responses(&ast_fn));
+
+        #[cfg(feature = "axum_extras")]
+        if let Some(response) = ext::axum::server_sent_events_response(&ast_fn) {
+            path_attribute.responses_from_server_sent_events(response);
+        }
     }
 
     let mut resolved_methods = match PathOperations::resolve_operation(&ast_fn) {
@@ -1942,7 +2173,12 @@ pub fn path(attr: TokenStream, item: TokenStream) -> TokenStream {
         .ext_methods(resolved_methods.map(|operation| operation.methods))
         .path(resolved_path.map(|path| path.path))
         .doc_comments(CommentAttributes::from_attributes(&ast_fn.attrs).0)
-        .deprecated(ast_fn.attrs.has_deprecated());
+        .deprecated(ast_fn.attrs.has_deprecated())
+        .generics(&ast_fn.sig.generics);
+    #[cfg(feature = "axum_extras")]
+    let path = path.extractors(ext::axum::extractor_types(&ast_fn.sig.inputs));
+    #[cfg(feature = "client")]
+    let path = path.vis(&ast_fn.vis);
 
     let handler = path::handler::Handler {
         path,
@@ -1960,10 +2196,12 @@ pub fn path(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// # OpenApi `#[openapi(...)]` attributes
 ///
 /// * `paths(...)`  List of method references having attribute [`#[fastapi::path]`][path] macro.
-/// * `components(schemas(...), responses(...))` Takes available _`component`_ configurations. Currently only
-///    _`schema`_ and _`response`_ components are supported.
+/// * `components(schemas(...), responses(...), security_schemes(...))` Takes available _`component`_
+///    configurations. Currently _`schema`_, _`response`_ and _`security scheme`_ components are supported.
 ///    * `schemas(...)` List of [`ToSchema`][to_schema]s in OpenAPI schema.
 ///    * `responses(...)` List of types that implement [`ToResponse`][to_response_trait].
+///    * `security_schemes(...)` List of named [`SecurityScheme`][security_scheme]s. See
+///      [security_schemes attribute syntax][security_schemes_syntax].
 /// * `modifiers(...)` List of items implementing [`Modify`][modify] trait for runtime OpenApi modification.
 ///   See the [trait documentation][modify] for more details.
 /// * `security(...)` List of [`SecurityRequirement`][security]s global to all operations.
@@ -2015,6 +2253,8 @@ pub fn path(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// * `license(...)` Used to override the whole license generated from environment variables.
 ///     * `name = ...` License name of the API. It can be a literal string.
 ///     * `url = ...` Define optional URL of the license. It must be URL formatted string.
+/// * `extensions(...)` List of [specification extensions][extensions] added to the info object
+///   e.g. _`extensions(("x-logo" = json!({"url": "https://example.com/logo.png"})))`_.
 ///
 /// # `tags(...)` attribute syntax
 ///
@@ -2025,6 +2265,8 @@ pub fn path(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// * `external_docs(...)` Optional links to external documents.
 ///      * `url = ...` Mandatory URL for external documentation.
 ///      * `description = ...` Optional description for the _`url`_ link.
+/// * `extensions(...)` List of [specification extensions][extensions] added to the tag. See the
+///   [extensions syntax][derive@ToSchema#extensions-syntax].
 ///
 /// # `servers(...)` attribute syntax
 ///
@@ -2045,6 +2287,50 @@ pub fn path(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// ("port" = (enum_values("8080", "5000", "4545")))
 /// ```
 ///
+/// # `security_schemes(...)` attribute syntax
+///
+/// Security schemes are declared as comma separated list of tuples `("name" = Scheme(...))` where
+/// the _`name`_ is the name used to reference the scheme from `security(...)` requirements.
+///
+/// * `Http(...)` Http authentication scheme.
+///     * `scheme = ...` Mandatory [`HttpAuthScheme`][http_auth_scheme] variant e.g. _`Bearer`_,
+///       _`Basic`_ or _`Digest`_.
+///     * `bearer_format = ...` Optional format of the bearer token e.g. _`"JWT"`_.
+///     * `description = ...` Optional description of the scheme.
+/// * `ApiKey(...)` Api key authentication scheme. First argument is the location of the key
+///   _`Header("name")`_, _`Query("name")`_ or _`Cookie("name")`_ followed by optional
+///   `description = ...`.
+/// * `OAuth2(...)` OAuth2 authentication scheme.
+///     * `flows(...)` Comma separated list of flows _`Implicit(...)`_, _`Password(...)`_,
+///       _`ClientCredentials(...)`_ and _`AuthorizationCode(...)`_. Flows accept
+///       `authorization_url = ...` (_`Implicit`_ and _`AuthorizationCode`_), `token_url = ...`
+///       (_`Password`_, _`ClientCredentials`_ and _`AuthorizationCode`_), optional
+///       `refresh_url = ...` and optional `scopes("scope" = "description", ...)`.
+///     * `description = ...` Optional description of the scheme.
+/// * `OpenIdConnect(...)` Open id connect authentication scheme.
+///     * `url = ...` Mandatory url to discover OpenID configuration values.
+///     * `description = ...` Optional description of the scheme.
+/// * `MutualTls` or `MutualTls(description = ...)` Mutual TLS authentication scheme.
+/// * `External` Scheme which is added to the _`OpenApi`_ elsewhere e.g. with
+///   [`Modify`][modify] implementation given in `modifiers(...)`. No scheme is generated for
+///   it, it only declares the name for the security requirement check below.
+///
+/// When `security_schemes(...)` are declared, every security requirement in `security(...)` of
+/// the _`OpenApi`_ and of the [`#[fastapi::path]`][path]s listed in `paths(...)` must reference
+/// a declared scheme. Referencing an undefined scheme is a compile error. Schemes added at
+/// runtime must therefore be declared as `External` e.g. _`("api_key" = External)`_. The check
+/// is not done when `security_schemes(...)` is not used at all.
+///
+/// _**Example of security schemes definition**_
+/// ```text
+/// ("bearer" = Http(scheme = Bearer, bearer_format = "JWT")),
+/// ("api_key" = ApiKey(Header("x-api-key"), description = "Api key")),
+/// ("oauth" = OAuth2(flows(
+///     Implicit(authorization_url = "https://localhost/authorize", scopes("read:items" = "read items")),
+///     ClientCredentials(token_url = "https://localhost/token")
+/// )))
+/// ```
+///
 /// # `nest(...)` attribute syntax
 ///
 /// * `path = ...` Define mandatory path for nesting the [`OpenApi`][openapi_struct].
@@ -2052,13 +2338,28 @@ pub fn path(attr: TokenStream, item: TokenStream) -> TokenStream {
 ///    The fully qualified path (_`path::to`_) will become the default _`tag`_ for the nested
 ///    `OpenApi` endpoints if provided.
 /// * `tags = [...]` Define optional tags what are appended to the existing list of tags.
+/// * `security(...)` Define optional security requirements for the nested endpoints that do not
+///   define security requirements of their own. Syntax is same as in
+///   _[`#[fastapi::path(security(...))]`][path_security]_.
+/// * `params(...)` Define optional parameters shared by all nested paths. Syntax is same as in
+///   _[`#[fastapi::path(params(...))]`][path_params]_.
+/// * `responses(...)` Define optional responses added to the nested endpoints that do not define
+///   response for the same status code. Syntax is same as in
+///   _[`#[fastapi::path(responses(...))]`][path_responses]_.
+/// * `servers(...)` Define optional alternative servers for all nested paths. Syntax is same as
+///   in `servers(...)` of the [`OpenApi`][openapi_struct] above.
 ///
 ///  _**Example of nest definition**_
 ///  ```text
 ///  (path = "path/to/nest", api = path::to::NestableApi),
 ///  (path = "path/to/nest", api = path::to::NestableApi, tags = ["nestableapi", ...])
+///  (path = "/admin", api = admin::AdminApi, tags = ["admin"], security(("admin_auth" = [])),
+///      params(("X-Tenant" = String, Header)), responses((status = 403, description = "Forbidden")))
 ///  ```
 ///
+/// [path_params]: attr.path.html#params-attributes
+/// [path_responses]: attr.path.html#response-attributes
+///
 /// # Examples
 ///
 /// _**Define OpenApi schema with some paths and components.**_
@@ -2170,6 +2471,35 @@ pub fn path(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// struct ApiDoc;
 /// ```
 ///
+/// _**Declare security schemes referenced by path security requirements.**_
+/// ```rust
+/// #[fastapi::path(get, path = "/items", security(("bearer" = [])))]
+/// fn get_items() {}
+///
+/// #[derive(fastapi::OpenApi)]
+/// #[openapi(
+///     paths(get_items),
+///     components(security_schemes(
+///         ("bearer" = Http(scheme = Bearer, bearer_format = "JWT")),
+///         ("api_key" = ApiKey(Header("x-api-key")))
+///     ))
+/// )]
+/// struct ApiDoc;
+/// ```
+///
+/// _**Referencing undefined security scheme fails to compile.**_
+/// ```compile_fail
+/// #[fastapi::path(get, path = "/items", security(("token" = [])))]
+/// fn get_items() {}
+///
+/// #[derive(fastapi::OpenApi)]
+/// #[openapi(
+///     paths(get_items),
+///     components(security_schemes(("bearer" = Http(scheme = Bearer))))
+/// )]
+/// struct ApiDoc;
+/// ```
+///
 /// _**Nest _`UserApi`_ to the current api doc instance.**_
 /// ```rust
 /// # use fastapi::OpenApi;
@@ -2211,7 +2541,11 @@ pub fn path(attr: TokenStream, item: TokenStream) -> TokenStream {
 /// [tags_syntax]: #tags-attribute-syntax
 /// [info_syntax]: #info-attribute-syntax
 /// [servers_syntax]: #servers-attribute-syntax
+/// [security_schemes_syntax]: #security_schemes-attribute-syntax
+/// [security_scheme]: openapi/security/enum.SecurityScheme.html
+/// [http_auth_scheme]: openapi/security/enum.HttpAuthScheme.html
 /// [include_str]: https://doc.rust-lang.org/std/macro.include_str.html
+/// [extensions]: https://spec.openapis.org/oas/latest.html#specification-extensions
 pub fn openapi(input: TokenStream) -> TokenStream {
     let DeriveInput { attrs, ident, .. } = syn::parse_macro_input!(input);
 
@@ -2361,6 +2695,9 @@ pub fn openapi(input: TokenStream) -> TokenStream {
 /// * `ignore` or `ignore = ...` Can be used to skip the field from being serialized to OpenAPI schema. It accepts either a literal `bool` value
 ///   or a path to a function that returns `bool` (`Fn() -> bool`).
 ///
+/// * `extensions(...)` List of [specification extensions][extensions] added to the parameter. See
+///   the [extensions syntax][derive@ToSchema#extensions-syntax].
+///
 /// #### Field nullability and required rules
 ///
 /// Same rules for nullability and required status apply for _`IntoParams`_ field attributes as for
@@ -2569,6 +2906,7 @@ pub fn openapi(input: TokenStream) -> TokenStream {
 /// [primitive]: https://doc.rust-lang.org/std/primitive/index.html
 /// [serde attributes]: https://serde.rs/attributes.html
 /// [to_schema_xml]: macro@ToSchema#xml-attribute-configuration-options
+/// [extensions]: https://spec.openapis.org/oas/latest.html#specification-extensions
 pub fn into_params(input: TokenStream) -> TokenStream {
     let DeriveInput {
         attrs,
@@ -2710,6 +3048,9 @@ pub fn into_params(input: TokenStream) -> TokenStream {
 ///      ("John" = (summary = "This is John", value = json!({"name": "John"})))
 ///     ```
 ///
+/// * `extensions(...)` List of [specification extensions][extensions] added to the response. See
+///   the [extensions syntax][derive@ToSchema#extensions-syntax].
+///
 /// # Examples
 ///
 /// _**Use reusable response in operation handler.**_
@@ -2781,6 +3122,7 @@ pub fn into_params(input: TokenStream) -> TokenStream {
 /// [primitive]: https://doc.rust-lang.org/std/primitive/index.html
 /// [path]: attr.path.html
 /// [openapi]: derive.OpenApi.html
+/// [extensions]: https://spec.openapis.org/oas/latest.html#specification-extensions
 pub fn to_response(input: TokenStream) -> TokenStream {
     let DeriveInput {
         attrs,
@@ -2873,6 +3215,9 @@ pub fn to_response(input: TokenStream) -> TokenStream {
 ///      ("John" = (summary = "This is John", value = json!({"name": "John"})))
 ///     ```
 ///
+/// * `extensions(...)` List of [specification extensions][extensions] added to the response. See
+///   the [extensions syntax][derive@ToSchema#extensions-syntax].
+///
 /// # Examples
 ///
 /// _**Use `IntoResponses` to define [`fastapi::path`][path] responses.**_
@@ -2966,6 +3311,7 @@ pub fn to_response(input: TokenStream) -> TokenStream {
 /// [path_into_responses]: attr.path.html#responses-from-intoresponses
 /// [primitive]: https://doc.rust-lang.org/std/primitive/index.html
 /// [path]: macro@crate::path
+/// [extensions]: https://spec.openapis.org/oas/latest.html#specification-extensions
 pub fn into_responses(input: TokenStream) -> TokenStream {
     let DeriveInput {
         attrs,
@@ -3096,6 +3442,38 @@ pub fn schema(input: TokenStream) -> TokenStream {
     .into()
 }
 
+/// Resolve the type generated by [`macro@path`] for given handler path.
+///
+/// `crate::pets::get_pet` resolves to `crate::pets::__path_get_pet`. Type arguments of generic
+/// handlers such as `list::<Pet>` are resolved through `fastapi::__dev::HandlerPath` which only
+/// keeps the arguments used by the documentation. This is used by the framework bindings to
+/// document handlers given as any Rust path.
+#[doc(hidden)]
+#[proc_macro]
+pub fn __path_type(input: TokenStream) -> TokenStream {
+    let mut handler = syn::parse_macro_input!(input as syn::Path);
+
+    let Some(segment) = handler.segments.last_mut() else {
+        return handler.to_token_stream().into();
+    };
+    let ident = path::format_path_ident(Cow::Borrowed(&segment.ident)).into_owned();
+    segment.ident = Ident::new(&ident.to_string(), segment.ident.span());
+
+    match mem::replace(&mut segment.arguments, syn::PathArguments::None) {
+        syn::PathArguments::AngleBracketed(arguments) => {
+            let types = arguments.args.iter().filter_map(|argument| match argument {
+                syn::GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            });
+            quote! {
+                <#handler as fastapi::__dev::HandlerPath<( #( #types, )* )>>::Path
+            }
+            .into()
+        }
+        _ => handler.to_token_stream().into(),
+    }
+}
+
 /// Tokenizes slice or Vec of tokenizable items as array either with reference (`&[...]`)
 /// or without correctly to OpenAPI JSON.
 #[cfg_attr(feature = "debug", derive(Debug))]
@@ -3269,6 +3647,62 @@ impl ToTokens for ExternalDocs {
     }
 }
 
+/// OpenAPI specification extensions parsed from `extensions(("x-name" = json!(...)), ...)`.
+#[derive(Default, Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+struct Extensions(Vec<(LitStr, AnyValue)>);
+
+impl Extensions {
+    /// Parse parenthesized `extensions(...)` list following the attribute name.
+    fn parse_parenthesized(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        syn::parenthesized!(content in input);
+        content.parse()
+    }
+}
+
+impl Parse for Extensions {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut extensions = Vec::new();
+
+        while !input.is_empty() {
+            let extension;
+            syn::parenthesized!(extension in input);
+
+            let name = extension.parse::<LitStr>()?;
+            if !name.value().starts_with("x-") {
+                return Err(syn::Error::new(
+                    name.span(),
+                    "extension name must start with `x-`, e.g. `\"x-internal\"`",
+                ));
+            }
+            extension.parse::<Token![=]>()?;
+            extensions.push((name, AnyValue::parse_json(&extension)?));
+
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        Ok(Self(extensions))
+    }
+}
+
+impl ToTokens for Extensions {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let extensions = self
+            .0
+            .iter()
+            .map(|(name, value)| quote! { .add(#name, #value) });
+
+        tokens.extend(quote! {
+            fastapi::openapi::extensions::ExtensionsBuilder::new()
+                #( #extensions )*
+                .build()
+        })
+    }
+}
+
 /// Represents OpenAPI Any value used in example and default fields.
 #[derive(Clone)]
 #[cfg_attr(feature = "debug", derive(Debug))]
@@ -17,13 +17,15 @@ use crate::{
     component::{features::Feature, ComponentSchema, Container, TypeTree},
     parse_utils,
     security_requirement::SecurityRequirementsAttr,
-    Array, Diagnostics, ExternalDocs, ToTokensDiagnostics,
+    Array, Diagnostics, Extensions, ExternalDocs, ToTokensDiagnostics,
 };
 use crate::{path, OptionExt};
 
 use self::info::Info;
+use self::security_scheme::SecuritySchemeAttr;
 
 mod info;
+mod security_scheme;
 
 #[derive(Default)]
 #[cfg_attr(feature = "debug", derive(Debug))]
@@ -53,6 +55,9 @@ impl<'o> OpenApiAttr<'o> {
         if !other.components.responses.is_empty() {
             self.components.responses = other.components.responses;
         }
+        if !other.components.security_schemes.is_empty() {
+            self.components.security_schemes = other.components.security_schemes;
+        }
         if other.security.is_some() {
             self.security = other.security;
         }
@@ -79,6 +84,64 @@ pub fn parse_openapi_attrs(attrs: &[Attribute]) -> Result<Option<OpenApiAttr>, E
         .map(|attrs| attrs.into_iter().reduce(|acc, item| acc.merge(item)))
 }
 
+impl OpenApiAttr<'_> {
+    /// Assert that security requirements of the OpenApi and its paths only reference security
+    /// schemes declared in `components(security_schemes(...))`. Paths are asserted with
+    /// compile time evaluated constants since their security requirements are only known by
+    /// the `#[fastapi::path]` macro.
+    fn security_scheme_assertions(
+        &self,
+        handlers: &[(ExprPath, String, Ident)],
+    ) -> Result<TokenStream, Diagnostics> {
+        if self.components.security_schemes.is_empty() {
+            return Ok(TokenStream::new());
+        }
+
+        let defined = self
+            .components
+            .security_schemes
+            .iter()
+            .map(|security_scheme| &security_scheme.name)
+            .collect::<Vec<_>>();
+
+        if let Some(undefined) = self
+            .security
+            .iter()
+            .chain(self.nested.iter().flat_map(|nested| nested.security.iter()))
+            .flat_map(|security| security.iter())
+            .flat_map(SecurityRequirementsAttr::names)
+            .find(|name| {
+                !defined
+                    .iter()
+                    .any(|defined| defined.value() == name.value())
+            })
+        {
+            return Err(Diagnostics::with_span(
+                undefined.span(),
+                format!(
+                    "undefined security scheme `{}` in `security(...)`",
+                    undefined.value()
+                ),
+            )
+            .help("security schemes must be declared in `components(security_schemes(...))`")
+            .help("schemes added at runtime e.g. with `Modify` can be declared as `(\"name\" = External)`"));
+        }
+
+        Ok(handlers
+            .iter()
+            .zip(&self.paths)
+            .map(|((usage, ..), handler)| {
+                quote_spanned! {handler.span()=>
+                    const _: () = fastapi::__dev::assert_security_schemes_defined(
+                        &[#( #defined ),*],
+                        <#usage as fastapi::__dev::SecuritySchemeNames>::SECURITY_SCHEME_NAMES,
+                    );
+                }
+            })
+            .collect())
+    }
+}
+
 impl Parse for OpenApiAttr<'_> {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         const EXPECTED_ATTRIBUTE: &str =
@@ -213,12 +276,13 @@ struct Tag {
     name: parse_utils::LitStrOrExpr,
     description: Option<parse_utils::LitStrOrExpr>,
     external_docs: Option<ExternalDocs>,
+    extensions: Option<Extensions>,
 }
 
 impl Parse for Tag {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         const EXPECTED_ATTRIBUTE: &str =
-            "unexpected token, expected any of: name, description, external_docs";
+            "unexpected token, expected any of: name, description, external_docs, extensions";
 
         let mut tag = Tag::default();
 
@@ -238,6 +302,7 @@ impl Parse for Tag {
                     parenthesized!(content in input);
                     tag.external_docs = Some(content.parse::<ExternalDocs>()?);
                 }
+                "extensions" => tag.extensions = Some(Extensions::parse_parenthesized(input)?),
                 _ => return Err(syn::Error::new(ident.span(), EXPECTED_ATTRIBUTE)),
             }
 
@@ -269,6 +334,12 @@ impl ToTokens for Tag {
             });
         }
 
+        if let Some(ref extensions) = self.extensions {
+            tokens.extend(quote! {
+                .extensions(Some(#extensions))
+            });
+        }
+
         tokens.extend(quote! { .build() })
     }
 }
@@ -418,8 +489,10 @@ impl Parse for ServerVariable {
 pub(crate) struct OpenApi<'o>(pub Option<OpenApiAttr<'o>>, pub Ident);
 
 impl OpenApi<'_> {
-    fn nested_tokens(&self) -> Option<TokenStream> {
-        let nested = self.0.as_ref().map(|openapi| &openapi.nested)?;
+    fn nested_tokens(&self) -> Result<Option<TokenStream>, Diagnostics> {
+        let Some(nested) = self.0.as_ref().map(|openapi| &openapi.nested) else {
+            return Ok(None);
+        };
         let nest_tokens = nested.iter()
                 .map(|item| {
                     let path = &item.path;
@@ -443,30 +516,39 @@ impl OpenApi<'_> {
                         .map(|segment| segment.ident.to_string())
                         .collect::<Vec<_>>()
                         .join("::");
-                    let tags = &item.tags.iter().collect::<Array<_>>();
+                    let options = item.options_tokens()?;
+                    let schemas = item.schemas_tokens()?;
+                    let schemas = (!schemas.is_empty()).then(|| quote! {
+                        let mut api = api;
+                        let mut nest_schemas = Vec::<(String, fastapi::openapi::RefOr<fastapi::openapi::schema::Schema>)>::new();
+                        let schemas = &mut nest_schemas;
+                        #schemas
+                        api.components.get_or_insert(fastapi::openapi::Components::new()).schemas.extend(nest_schemas);
+                    });
 
                     let span = nest_api.span();
-                    quote_spanned! {span=>
+                    Ok(quote_spanned! {span=>
                         .nest(#path, {
                             #[allow(non_camel_case_types)]
                             struct #nest_api_config;
                             impl fastapi::__dev::NestedApiConfig for #nest_api_config {
-                                fn config() -> (fastapi::openapi::OpenApi, Vec<&'static str>, &'static str) {
+                                fn config() -> (fastapi::openapi::OpenApi, fastapi::openapi::NestOptions, &'static str) {
                                     let api = <#nest_api as fastapi::OpenApi>::openapi();
+                                    #schemas
 
-                                    (api, #tags.into(), #module_path)
+                                    (api, #options, #module_path)
                                 }
                             }
                             <#nest_api_config as fastapi::OpenApi>::openapi()
                         })
-                    }
+                    })
                 })
-                .collect::<TokenStream>();
+                .collect::<Result<TokenStream, Diagnostics>>()?;
 
         if nest_tokens.is_empty() {
-            None
+            Ok(None)
         } else {
-            Some(nest_tokens)
+            Ok(Some(nest_tokens))
         }
     }
 }
@@ -495,6 +577,10 @@ impl ToTokensDiagnostics for OpenApi<'_> {
         let Paths(path_items, handlers) =
             impl_paths(attributes.as_ref().map(|attributes| &attributes.paths));
 
+        let security_scheme_assertions = attributes
+            .as_ref()
+            .map_try(|attributes| attributes.security_scheme_assertions(&handlers))?;
+
         let handler_schemas = handlers.iter().fold(
             quote! {
                     let components = openapi.components.get_or_insert(fastapi::openapi::Components::new());
@@ -555,9 +641,11 @@ impl ToTokensDiagnostics for OpenApi<'_> {
             });
 
         let nested_tokens = self
-            .nested_tokens()
+            .nested_tokens()?
             .map(|tokens| quote! {openapi = openapi #tokens;});
         tokens.extend(quote! {
+            #security_scheme_assertions
+
             impl fastapi::OpenApi for #ident {
                 fn openapi() -> fastapi::openapi::OpenApi {
                     use fastapi::{ToSchema, Path};
@@ -592,6 +680,7 @@ impl ToTokensDiagnostics for OpenApi<'_> {
 struct Components {
     schemas: Vec<Schema>,
     responses: Vec<Response>,
+    security_schemes: Vec<SecuritySchemeAttr>,
 }
 
 impl Parse for Components {
@@ -599,10 +688,11 @@ impl Parse for Components {
         let content;
         parenthesized!(content in input);
         const EXPECTED_ATTRIBUTE: &str =
-            "unexpected attribute. expected one of: schemas, responses";
+            "unexpected attribute. expected one of: schemas, responses, security_schemes";
 
         let mut schemas: Vec<Schema> = Vec::new();
         let mut responses: Vec<Response> = Vec::new();
+        let mut security_schemes: Vec<SecuritySchemeAttr> = Vec::new();
 
         while !content.is_empty() {
             let ident = content.parse::<Ident>().map_err(|error| {
@@ -621,6 +711,13 @@ impl Parse for Components {
                         .into_iter()
                         .collect(),
                 ),
+                "security_schemes" => {
+                    let security_schemes_stream;
+                    parenthesized!(security_schemes_stream in content);
+                    security_schemes.append(&mut parse_utils::parse_groups_collect(
+                        &security_schemes_stream,
+                    )?)
+                }
                 _ => return Err(syn::Error::new(ident.span(), EXPECTED_ATTRIBUTE)),
             }
 
@@ -629,13 +726,23 @@ impl Parse for Components {
             }
         }
 
-        Ok(Self { schemas, responses })
+        Ok(Self {
+            schemas,
+            responses,
+            security_schemes,
+        })
     }
 }
 
 impl crate::ToTokensDiagnostics for Components {
     fn to_tokens(&self, tokens: &mut TokenStream) -> Result<(), Diagnostics> {
-        if self.schemas.is_empty() && self.responses.is_empty() {
+        if self.schemas.is_empty()
+            && self.responses.is_empty()
+            && self
+                .security_schemes
+                .iter()
+                .all(SecuritySchemeAttr::is_external)
+        {
             return Ok(());
         }
 
@@ -676,8 +783,9 @@ impl crate::ToTokensDiagnostics for Components {
                     });
                     builder_tokens
                 });
+        let security_schemes = &self.security_schemes;
 
-        tokens.extend(quote! { #builder_tokens.build() });
+        tokens.extend(quote! { #builder_tokens #( #security_schemes )* .build() });
 
         Ok(())
     }
@@ -772,18 +880,76 @@ fn impl_paths(handler_paths: Option<&Punctuated<ExprPath, Comma>>) -> Paths {
     Paths(tokens, handlers)
 }
 
-/// (path = "/nest/path", api = NestApi, tags = ["tag1", "tag2"])
+/// (path = "/nest/path", api = NestApi, tags = ["tag1", "tag2"], security(...), params(...),
+/// responses(...), servers(...))
 #[cfg_attr(feature = "debug", derive(Debug))]
 #[derive(Default)]
 struct NestOpenApi {
     path: parse_utils::LitStrOrExpr,
     open_api: Option<TypePath>,
     tags: Punctuated<parse_utils::LitStrOrExpr, Comma>,
+    security: Option<Array<'static, SecurityRequirementsAttr>>,
+    params: Vec<path::parameter::Parameter<'static>>,
+    responses: Vec<path::response::Response<'static>>,
+    servers: Punctuated<Server, Comma>,
+}
+
+impl NestOpenApi {
+    /// Tokens of `fastapi::openapi::NestOptions` applied to the nested api.
+    fn options_tokens(&self) -> Result<TokenStream, Diagnostics> {
+        let mut tokens = quote! { fastapi::openapi::NestOptions::new() };
+
+        if !self.tags.is_empty() {
+            let tags = self.tags.iter().collect::<Array<_>>();
+            tokens.extend(quote! { .tags(Some(#tags)) });
+        }
+        if let Some(security) = &self.security {
+            tokens.extend(quote! { .securities(Some(#security)) });
+        }
+        for parameter in &self.params {
+            parameter.to_tokens(&mut tokens)?;
+        }
+        if !self.responses.is_empty() {
+            let responses = path::response::Responses(&self.responses);
+            let responses = crate::as_tokens_or_diagnostics!(&responses);
+            tokens.extend(quote! { .responses(#responses) });
+        }
+        if !self.servers.is_empty() {
+            let servers = self.servers.iter().collect::<Array<&Server>>();
+            tokens.extend(quote! { .servers(Some(#servers)) });
+        }
+
+        Ok(tokens)
+    }
+
+    /// Tokens pushing schemas referenced by the nest `params(...)` and `responses(...)` to
+    /// `schemas` the same way as [`macro@crate::path`] collects them.
+    fn schemas_tokens(&self) -> Result<TokenStream, Diagnostics> {
+        let parameter_schemas = self
+            .params
+            .iter()
+            .map(|parameter| parameter.get_component_schema())
+            .collect::<Result<Vec<_>, Diagnostics>>()?
+            .into_iter()
+            .flatten();
+        let response_schemas = self
+            .responses
+            .iter()
+            .map(|response| response.get_component_schemas())
+            .collect::<Result<Vec<_>, Diagnostics>>()?
+            .into_iter()
+            .flatten();
+
+        Ok(parameter_schemas
+            .chain(response_schemas)
+            .fold(TokenStream::new(), path::to_schema_references))
+    }
 }
 
 impl Parse for NestOpenApi {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        const ERROR_MESSAGE: &str = "unexpected identifier, expected any of: path, api, tags";
+        const ERROR_MESSAGE: &str =
+            "unexpected identifier, expected any of: path, api, tags, security, params, responses, servers";
         let mut nest = NestOpenApi::default();
 
         while !input.is_empty() {
@@ -801,6 +967,24 @@ impl Parse for NestOpenApi {
                         Punctuated::parse_terminated(&tags)
                     })?;
                 }
+                "security" => {
+                    let security;
+                    parenthesized!(security in input);
+                    nest.security = Some(parse_utils::parse_groups_collect(&security)?)
+                }
+                "params" => {
+                    nest.params = parse_utils::parse_comma_separated_within_parenthesis(input)?
+                        .into_iter()
+                        .collect();
+                }
+                "responses" => {
+                    nest.responses = parse_utils::parse_comma_separated_within_parenthesis(input)?
+                        .into_iter()
+                        .collect();
+                }
+                "servers" => {
+                    nest.servers = parse_utils::parse_comma_separated_within_parenthesis(input)?;
+                }
                 _ => return Err(syn::Error::new(ident.span(), ERROR_MESSAGE)),
             }
 
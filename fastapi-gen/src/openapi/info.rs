@@ -8,6 +8,7 @@ use syn::token::Comma;
 use syn::{parenthesized, Error, LitStr};
 
 use crate::parse_utils::{self, LitStrOrExpr};
+use crate::Extensions;
 
 #[derive(Default, Clone)]
 #[cfg_attr(feature = "debug", derive(Debug))]
@@ -18,6 +19,7 @@ pub(super) struct Info<'i> {
     terms_of_service: Option<LitStrOrExpr>,
     license: Option<License<'i>>,
     contact: Option<Contact<'i>>,
+    extensions: Option<Extensions>,
 }
 
 impl Info<'_> {
@@ -80,6 +82,10 @@ impl Info<'_> {
             if info.version.is_some() {
                 from_env.version = info.version;
             }
+
+            if info.extensions.is_some() {
+                from_env.extensions = info.extensions;
+            }
         }
 
         from_env
@@ -125,8 +131,9 @@ impl Parse for Info<'_> {
                     parenthesized!(contact_stream in input);
                     info.contact = Some(contact_stream.parse()?)
                 }
+                "extensions" => info.extensions = Some(Extensions::parse_parenthesized(input)?),
                 _ => {
-                    return Err(Error::new(ident.span(), format!("unexpected attribute: {attribute_name}, expected one of: title, terms_of_service, version, description, license, contact")));
+                    return Err(Error::new(ident.span(), format!("unexpected attribute: {attribute_name}, expected one of: title, terms_of_service, version, description, license, contact, extensions")));
                 }
             }
             if !input.is_empty() {
@@ -161,6 +168,10 @@ impl ToTokens for Info<'_> {
             .contact
             .as_ref()
             .map(|contact| quote! { .contact(Some(#contact)) });
+        let extensions = self
+            .extensions
+            .as_ref()
+            .map(|extensions| quote! { .extensions(Some(#extensions)) });
 
         tokens.extend(quote! {
             fastapi::openapi::InfoBuilder::new()
@@ -170,6 +181,7 @@ impl ToTokens for Info<'_> {
                 #description
                 #license
                 #contact
+                #extensions
         })
     }
 }
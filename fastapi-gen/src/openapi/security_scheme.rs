@@ -0,0 +1,561 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::{quote, ToTokens};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::token::Comma;
+use syn::{parenthesized, Error, LitStr, Token};
+
+use crate::parse_utils;
+
+/// Declared security scheme within `components(security_schemes(...))`.
+///
+/// ("bearer" = Http(scheme = Bearer, bearer_format = "JWT"))
+///
+/// Schemes declared as `External` are added to the `OpenApi` elsewhere, e.g. with `Modify`, and
+/// are only used to assert the security requirements.
+///
+/// ("api_key" = External)
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub(super) struct SecuritySchemeAttr {
+    pub(super) name: LitStr,
+    scheme: Scheme,
+}
+
+impl Parse for SecuritySchemeAttr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse::<LitStr>()?;
+        input.parse::<Token![=]>()?;
+        let scheme = input.parse::<Scheme>()?;
+
+        Ok(Self { name, scheme })
+    }
+}
+
+impl SecuritySchemeAttr {
+    pub(super) fn is_external(&self) -> bool {
+        matches!(self.scheme, Scheme::External)
+    }
+}
+
+impl ToTokens for SecuritySchemeAttr {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let name = &self.name;
+        let scheme = &self.scheme;
+
+        if !self.is_external() {
+            tokens.extend(quote! { .security_scheme(#name, #scheme) })
+        }
+    }
+}
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+enum Scheme {
+    Http(Http),
+    ApiKey(ApiKey),
+    OAuth2(OAuth2),
+    OpenIdConnect(OpenIdConnect),
+    MutualTls(Option<LitStr>),
+    External,
+}
+
+impl Parse for Scheme {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        const EXPECTED_SCHEME: &str =
+            "unexpected security scheme, expected one of: Http, ApiKey, OAuth2, OpenIdConnect, MutualTls, External";
+        let ident = input
+            .parse::<Ident>()
+            .map_err(|error| Error::new(error.span(), format!("{EXPECTED_SCHEME}, {error}")))?;
+
+        match &*ident.to_string() {
+            "Http" => Ok(Self::Http(parse_parenthesized(input)?)),
+            "ApiKey" => Ok(Self::ApiKey(parse_parenthesized(input)?)),
+            "OAuth2" => Ok(Self::OAuth2(parse_parenthesized(input)?)),
+            "OpenIdConnect" => Ok(Self::OpenIdConnect(parse_parenthesized(input)?)),
+            "MutualTls" => {
+                if input.is_empty() {
+                    return Ok(Self::MutualTls(None));
+                }
+                let content;
+                parenthesized!(content in input);
+                let mut description = None;
+                while !content.is_empty() {
+                    let ident = content.parse::<Ident>()?;
+                    match &*ident.to_string() {
+                        "description" => {
+                            description = Some(parse_utils::parse_next(&content, || {
+                                content.parse::<LitStr>()
+                            })?)
+                        }
+                        attribute => {
+                            return Err(Error::new(
+                                ident.span(),
+                                format!("unexpected attribute: {attribute}, expected: description"),
+                            ))
+                        }
+                    }
+                    if !content.is_empty() {
+                        content.parse::<Comma>()?;
+                    }
+                }
+                Ok(Self::MutualTls(description))
+            }
+            "External" => Ok(Self::External),
+            _ => Err(Error::new(ident.span(), EXPECTED_SCHEME)),
+        }
+    }
+}
+
+impl ToTokens for Scheme {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let scheme = match self {
+            Self::Http(http) => quote! { fastapi::openapi::security::SecurityScheme::Http(#http) },
+            Self::ApiKey(api_key) => {
+                quote! { fastapi::openapi::security::SecurityScheme::ApiKey(#api_key) }
+            }
+            Self::OAuth2(oauth2) => {
+                quote! { fastapi::openapi::security::SecurityScheme::OAuth2(#oauth2) }
+            }
+            Self::OpenIdConnect(open_id_connect) => {
+                quote! { fastapi::openapi::security::SecurityScheme::OpenIdConnect(#open_id_connect) }
+            }
+            Self::MutualTls(description) => {
+                let description = description
+                    .as_ref()
+                    .map(|description| quote! { Some(String::from(#description)) })
+                    .unwrap_or_else(|| quote! { None });
+                quote! {
+                    fastapi::openapi::security::SecurityScheme::MutualTls {
+                        description: #description,
+                        extensions: None,
+                    }
+                }
+            }
+            Self::External => return,
+        };
+
+        tokens.extend(scheme)
+    }
+}
+
+fn parse_parenthesized<T: Parse>(input: ParseStream) -> syn::Result<T> {
+    let content;
+    parenthesized!(content in input);
+    content.parse()
+}
+
+/// Http(scheme = Bearer, bearer_format = "JWT", description = "...")
+#[cfg_attr(feature = "debug", derive(Debug))]
+struct Http {
+    scheme: Ident,
+    bearer_format: Option<LitStr>,
+    description: Option<LitStr>,
+}
+
+impl Parse for Http {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        const HTTP_AUTH_SCHEMES: [&str; 10] = [
+            "Basic",
+            "Bearer",
+            "Digest",
+            "Hoba",
+            "Mutual",
+            "Negotiate",
+            "OAuth",
+            "ScramSha1",
+            "ScramSha256",
+            "Vapid",
+        ];
+        let mut scheme: Option<Ident> = None;
+        let mut bearer_format = None;
+        let mut description = None;
+
+        while !input.is_empty() {
+            let ident = input.parse::<Ident>()?;
+            match &*ident.to_string() {
+                "scheme" => {
+                    let value = parse_utils::parse_next(input, || input.parse::<Ident>())?;
+                    if !HTTP_AUTH_SCHEMES.contains(&&*value.to_string()) {
+                        return Err(Error::new(
+                            value.span(),
+                            format!(
+                                "unexpected http auth scheme: {value}, expected one of: {}",
+                                HTTP_AUTH_SCHEMES.join(", ")
+                            ),
+                        ));
+                    }
+                    scheme = Some(value);
+                }
+                "bearer_format" => {
+                    bearer_format =
+                        Some(parse_utils::parse_next(input, || input.parse::<LitStr>())?)
+                }
+                "description" => {
+                    description = Some(parse_utils::parse_next(input, || input.parse::<LitStr>())?)
+                }
+                attribute => {
+                    return Err(Error::new(
+                        ident.span(),
+                        format!("unexpected attribute: {attribute}, expected one of: scheme, bearer_format, description"),
+                    ))
+                }
+            }
+            if !input.is_empty() {
+                input.parse::<Comma>()?;
+            }
+        }
+
+        let scheme = scheme.ok_or_else(|| {
+            Error::new(
+                input.span(),
+                "missing expected attribute: scheme, e.g. `Http(scheme = Bearer)`",
+            )
+        })?;
+
+        Ok(Self {
+            scheme,
+            bearer_format,
+            description,
+        })
+    }
+}
+
+impl ToTokens for Http {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let scheme = &self.scheme;
+        let bearer_format = self
+            .bearer_format
+            .as_ref()
+            .map(|bearer_format| quote! { .bearer_format(#bearer_format) });
+        let description = self
+            .description
+            .as_ref()
+            .map(|description| quote! { .description(Some(#description)) });
+
+        tokens.extend(quote! {
+            fastapi::openapi::security::HttpBuilder::new()
+                .scheme(fastapi::openapi::security::HttpAuthScheme::#scheme)
+                #bearer_format
+                #description
+                .build()
+        })
+    }
+}
+
+/// ApiKey(Header("x-api-key"), description = "...")
+#[cfg_attr(feature = "debug", derive(Debug))]
+struct ApiKey {
+    location: Ident,
+    name: LitStr,
+    description: Option<LitStr>,
+}
+
+impl Parse for ApiKey {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        const EXPECTED_LOCATION: &str =
+            "unexpected api key location, expected one of: Header, Query, Cookie";
+        let location = input
+            .parse::<Ident>()
+            .map_err(|error| Error::new(error.span(), format!("{EXPECTED_LOCATION}, {error}")))?;
+        if !matches!(&*location.to_string(), "Header" | "Query" | "Cookie") {
+            return Err(Error::new(location.span(), EXPECTED_LOCATION));
+        }
+        let name = parse_parenthesized::<LitStr>(input)?;
+
+        let mut description = None;
+        while !input.is_empty() {
+            input.parse::<Comma>()?;
+            if input.is_empty() {
+                break;
+            }
+            let ident = input.parse::<Ident>()?;
+            match &*ident.to_string() {
+                "description" => {
+                    description = Some(parse_utils::parse_next(input, || input.parse::<LitStr>())?)
+                }
+                attribute => {
+                    return Err(Error::new(
+                        ident.span(),
+                        format!("unexpected attribute: {attribute}, expected: description"),
+                    ))
+                }
+            }
+        }
+
+        Ok(Self {
+            location,
+            name,
+            description,
+        })
+    }
+}
+
+impl ToTokens for ApiKey {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let location = &self.location;
+        let name = &self.name;
+        let value = match &self.description {
+            Some(description) => quote! {
+                fastapi::openapi::security::ApiKeyValue::with_description(#name, #description)
+            },
+            None => quote! { fastapi::openapi::security::ApiKeyValue::new(#name) },
+        };
+
+        tokens.extend(quote! { fastapi::openapi::security::ApiKey::#location(#value) })
+    }
+}
+
+/// OpenIdConnect(url = "https://localhost/.well-known/openid-configuration", description = "...")
+#[cfg_attr(feature = "debug", derive(Debug))]
+struct OpenIdConnect {
+    url: LitStr,
+    description: Option<LitStr>,
+}
+
+impl Parse for OpenIdConnect {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut url = None;
+        let mut description = None;
+
+        while !input.is_empty() {
+            let ident = input.parse::<Ident>()?;
+            match &*ident.to_string() {
+                "url" => url = Some(parse_utils::parse_next(input, || input.parse::<LitStr>())?),
+                "description" => {
+                    description = Some(parse_utils::parse_next(input, || input.parse::<LitStr>())?)
+                }
+                attribute => {
+                    return Err(Error::new(
+                        ident.span(),
+                        format!(
+                            "unexpected attribute: {attribute}, expected one of: url, description"
+                        ),
+                    ))
+                }
+            }
+            if !input.is_empty() {
+                input.parse::<Comma>()?;
+            }
+        }
+
+        let url = url.ok_or_else(|| {
+            Error::new(
+                input.span(),
+                "missing expected attribute: url, e.g. `OpenIdConnect(url = \"https://localhost/.well-known/openid-configuration\")`",
+            )
+        })?;
+
+        Ok(Self { url, description })
+    }
+}
+
+impl ToTokens for OpenIdConnect {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let url = &self.url;
+        tokens.extend(match &self.description {
+            Some(description) => quote! {
+                fastapi::openapi::security::OpenIdConnect::with_description(#url, #description)
+            },
+            None => quote! { fastapi::openapi::security::OpenIdConnect::new(#url) },
+        })
+    }
+}
+
+/// OAuth2(flows(Implicit(authorization_url = "...", scopes("read" = "read items"))), description = "...")
+#[cfg_attr(feature = "debug", derive(Debug))]
+struct OAuth2 {
+    flows: Punctuated<Flow, Comma>,
+    description: Option<LitStr>,
+}
+
+impl Parse for OAuth2 {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut flows = Punctuated::new();
+        let mut description = None;
+
+        while !input.is_empty() {
+            let ident = input.parse::<Ident>()?;
+            match &*ident.to_string() {
+                "flows" => flows = parse_utils::parse_comma_separated_within_parenthesis(input)?,
+                "description" => {
+                    description = Some(parse_utils::parse_next(input, || input.parse::<LitStr>())?)
+                }
+                attribute => return Err(Error::new(
+                    ident.span(),
+                    format!(
+                        "unexpected attribute: {attribute}, expected one of: flows, description"
+                    ),
+                )),
+            }
+            if !input.is_empty() {
+                input.parse::<Comma>()?;
+            }
+        }
+
+        Ok(Self { flows, description })
+    }
+}
+
+impl ToTokens for OAuth2 {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let flows = self.flows.iter();
+        let flows = quote! { [#( #flows ),*] };
+        tokens.extend(match &self.description {
+            Some(description) => quote! {
+                fastapi::openapi::security::OAuth2::with_description(#flows, #description)
+            },
+            None => quote! { fastapi::openapi::security::OAuth2::new(#flows) },
+        })
+    }
+}
+
+/// Implicit(authorization_url = "...", refresh_url = "...", scopes("read" = "read items"))
+#[cfg_attr(feature = "debug", derive(Debug))]
+struct Flow {
+    kind: Ident,
+    authorization_url: Option<LitStr>,
+    token_url: Option<LitStr>,
+    refresh_url: Option<LitStr>,
+    scopes: Punctuated<Scope, Comma>,
+}
+
+impl Parse for Flow {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        const EXPECTED_FLOW: &str =
+            "unexpected flow, expected one of: Implicit, Password, ClientCredentials, AuthorizationCode";
+        let kind = input
+            .parse::<Ident>()
+            .map_err(|error| Error::new(error.span(), format!("{EXPECTED_FLOW}, {error}")))?;
+        let (needs_authorization_url, needs_token_url) = match &*kind.to_string() {
+            "Implicit" => (true, false),
+            "Password" | "ClientCredentials" => (false, true),
+            "AuthorizationCode" => (true, true),
+            _ => return Err(Error::new(kind.span(), EXPECTED_FLOW)),
+        };
+
+        let content;
+        parenthesized!(content in input);
+
+        let mut authorization_url = None;
+        let mut token_url = None;
+        let mut refresh_url = None;
+        let mut scopes = Punctuated::new();
+
+        while !content.is_empty() {
+            let ident = content.parse::<Ident>()?;
+            match &*ident.to_string() {
+                "authorization_url" if needs_authorization_url => {
+                    authorization_url = Some(parse_utils::parse_next(&content, || {
+                        content.parse::<LitStr>()
+                    })?)
+                }
+                "token_url" if needs_token_url => {
+                    token_url = Some(parse_utils::parse_next(&content, || {
+                        content.parse::<LitStr>()
+                    })?)
+                }
+                "refresh_url" => {
+                    refresh_url = Some(parse_utils::parse_next(&content, || {
+                        content.parse::<LitStr>()
+                    })?)
+                }
+                "scopes" => {
+                    scopes = parse_utils::parse_comma_separated_within_parenthesis(&content)?
+                }
+                attribute => {
+                    let expected = [
+                        needs_authorization_url.then_some("authorization_url"),
+                        needs_token_url.then_some("token_url"),
+                        Some("refresh_url"),
+                        Some("scopes"),
+                    ]
+                    .into_iter()
+                    .flatten()
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                    return Err(Error::new(
+                        ident.span(),
+                        format!("unexpected attribute: {attribute}, expected one of: {expected}"),
+                    ));
+                }
+            }
+            if !content.is_empty() {
+                content.parse::<Comma>()?;
+            }
+        }
+
+        if needs_authorization_url && authorization_url.is_none() {
+            return Err(Error::new(
+                kind.span(),
+                format!("missing expected attribute: authorization_url for {kind} flow"),
+            ));
+        }
+        if needs_token_url && token_url.is_none() {
+            return Err(Error::new(
+                kind.span(),
+                format!("missing expected attribute: token_url for {kind} flow"),
+            ));
+        }
+
+        Ok(Self {
+            kind,
+            authorization_url,
+            token_url,
+            refresh_url,
+            scopes,
+        })
+    }
+}
+
+impl ToTokens for Flow {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let kind = &self.kind;
+        let scopes = if self.scopes.is_empty() {
+            quote! { fastapi::openapi::security::Scopes::new() }
+        } else {
+            let scopes = self.scopes.iter();
+            quote! {
+                fastapi::openapi::security::Scopes::from_iter([#( #scopes ),*])
+            }
+        };
+        let urls = [&self.authorization_url, &self.token_url]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+
+        let flow = match &self.refresh_url {
+            Some(refresh_url) => quote! {
+                fastapi::openapi::security::#kind::with_refresh_url(#( #urls, )* #scopes, #refresh_url)
+            },
+            None => quote! {
+                fastapi::openapi::security::#kind::new(#( #urls, )* #scopes)
+            },
+        };
+
+        tokens.extend(quote! { fastapi::openapi::security::Flow::#kind(#flow) })
+    }
+}
+
+/// "read:items" = "read my items"
+#[cfg_attr(feature = "debug", derive(Debug))]
+struct Scope {
+    name: LitStr,
+    description: LitStr,
+}
+
+impl Parse for Scope {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse::<LitStr>()?;
+        let description = parse_utils::parse_next(input, || input.parse::<LitStr>())?;
+
+        Ok(Self { name, description })
+    }
+}
+
+impl ToTokens for Scope {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let name = &self.name;
+        let description = &self.description;
+
+        tokens.extend(quote! { (#name, #description) })
+    }
+}
@@ -1,4 +1,5 @@
 use std::borrow::Cow;
+use std::collections::HashSet;
 use std::ops::Deref;
 use std::{io::Error, str::FromStr};
 
@@ -12,20 +13,28 @@ use syn::{Expr, ExprLit, Lit, LitStr};
 
 use crate::component::{ComponentSchema, GenericType, TypeTree};
 use crate::{
-    as_tokens_or_diagnostics, parse_utils, Deprecated, Diagnostics, OptionExt, ToTokensDiagnostics,
+    as_tokens_or_diagnostics, parse_utils, Deprecated, Diagnostics, Extensions, OptionExt,
+    ToTokensDiagnostics,
+};
+use crate::{
+    schema_type::{is_binary_path, SchemaType},
+    security_requirement::SecurityRequirementsAttr,
+    Array,
 };
-use crate::{schema_type::SchemaType, security_requirement::SecurityRequirementsAttr, Array};
 
 use self::response::Response;
 use self::{parameter::Parameter, request_body::RequestBodyAttr, response::Responses};
 
+#[cfg(feature = "client")]
+mod client;
+mod encoding;
 pub mod example;
 pub mod handler;
 pub mod media_type;
 pub mod parameter;
 mod request_body;
 pub mod response;
-mod status;
+pub(crate) mod status;
 
 const PATH_STRUCT_PREFIX: &str = "__path_";
 
@@ -51,15 +60,26 @@ pub struct PathAttr<'p> {
     security: Option<Array<'p, SecurityRequirementsAttr>>,
     context_path: Option<parse_utils::LitStrOrExpr>,
     impl_for: Option<Ident>,
+    #[cfg(feature = "client")]
+    client_name: Option<Ident>,
     description: Option<parse_utils::LitStrOrExpr>,
     summary: Option<parse_utils::LitStrOrExpr>,
+    extensions: Option<Extensions>,
 }
 
 impl<'p> PathAttr<'p> {
-    #[cfg(feature = "auto_into_responses")]
-    pub fn responses_from_into_responses(&mut self, ty: &'p syn::TypePath) {
-        self.responses
-            .push(Response::IntoResponses(Cow::Borrowed(ty)))
+    /// Add responses inferred from the return type of the handler function before explicitly
+    /// defined responses so that explicitly defined response with same status will override it.
+    #[cfg(feature = "infer_responses")]
+    pub fn responses_from_return_type(&mut self, responses: Vec<Response<'p>>) {
+        self.responses.splice(0..0, responses);
+    }
+
+    /// Add resolved Server-Sent Events response as first response so that explicitly defined
+    /// response with same status will override it.
+    #[cfg(all(feature = "infer_responses", feature = "axum_extras"))]
+    pub fn responses_from_server_sent_events(&mut self, response: Response<'p>) {
+        self.responses.insert(0, response)
     }
 
     #[cfg(any(
@@ -71,7 +91,9 @@ impl<'p> PathAttr<'p> {
         use self::media_type::Schema;
         if self.request_body.is_none() {
             if let Some(schema) = schema {
-                self.request_body = Some(RequestBodyAttr::from_schema(Schema::Ext(schema)));
+                self.request_body = Some(RequestBodyAttr::from_schemas(
+                    schema.into_variants().into_iter().map(Schema::Ext),
+                ));
             }
         }
     }
@@ -107,7 +129,7 @@ impl<'p> PathAttr<'p> {
 
 impl Parse for PathAttr<'_> {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
-        const EXPECTED_ATTRIBUTE_MESSAGE: &str = "unexpected identifier, expected any of: method, get, post, put, delete, options, head, patch, trace, operation_id, path, request_body, responses, params, tag, security, context_path, description, summary";
+        const EXPECTED_ATTRIBUTE_MESSAGE: &str = "unexpected identifier, expected any of: method, get, post, put, delete, options, head, patch, trace, operation_id, path, request_body, responses, params, tag, security, context_path, client_name, description, summary, extensions";
         let mut path_attr = PathAttr::default();
 
         while !input.is_empty() {
@@ -175,6 +197,13 @@ impl Parse for PathAttr<'_> {
                     path_attr.impl_for =
                         Some(parse_utils::parse_next(input, || input.parse::<Ident>())?);
                 }
+                "client_name" => {
+                    let _client_name = parse_utils::parse_next(input, || input.parse::<Ident>())?;
+                    #[cfg(feature = "client")]
+                    {
+                        path_attr.client_name = Some(_client_name);
+                    }
+                }
                 "description" => {
                     path_attr.description =
                         Some(parse_utils::parse_next_literal_str_or_expr(input)?)
@@ -182,6 +211,9 @@ impl Parse for PathAttr<'_> {
                 "summary" => {
                     path_attr.summary = Some(parse_utils::parse_next_literal_str_or_expr(input)?)
                 }
+                "extensions" => {
+                    path_attr.extensions = Some(Extensions::parse_parenthesized(input)?);
+                }
                 _ => {
                     if let Some(path_operation) =
                         attribute_name.parse::<HttpMethod>().into_iter().next()
@@ -293,6 +325,10 @@ pub struct Path<'p> {
     path: Option<String>,
     doc_comments: Option<Vec<String>>,
     deprecated: bool,
+    generics: syn::Generics,
+    extractors: Vec<syn::Type>,
+    #[cfg(feature = "client")]
+    vis: Option<&'p syn::Visibility>,
 }
 
 impl<'p> Path<'p> {
@@ -304,6 +340,10 @@ impl<'p> Path<'p> {
             path: None,
             doc_comments: None,
             deprecated: false,
+            generics: syn::Generics::default(),
+            extractors: Vec::new(),
+            #[cfg(feature = "client")]
+            vis: None,
         }
     }
 
@@ -330,6 +370,55 @@ impl<'p> Path<'p> {
 
         self
     }
+
+    /// Set generics of the handler function. Only the type parameters used by the documentation
+    /// and their bounds are carried over to the generated path type so that a concrete
+    /// instantiation such as `__path_list::<User>` substitutes the schemas of the given types.
+    /// Type parameters only used by the handler itself, e.g. `State<S>`, are left out.
+    pub fn generics(mut self, generics: &syn::Generics) -> Self {
+        let params = generics
+            .type_params()
+            .cloned()
+            .map(syn::GenericParam::Type)
+            .collect::<Punctuated<_, Comma>>();
+        let where_clause = generics.where_clause.as_ref().map(|where_clause| {
+            let predicates = where_clause
+                .predicates
+                .iter()
+                .filter(|predicate| matches!(predicate, syn::WherePredicate::Type(_)))
+                .cloned()
+                .collect::<Punctuated<_, Comma>>();
+            syn::WhereClause {
+                where_token: where_clause.where_token,
+                predicates,
+            }
+        });
+
+        self.generics = syn::Generics {
+            lt_token: (!params.is_empty()).then(Default::default),
+            gt_token: (!params.is_empty()).then(Default::default),
+            params,
+            where_clause,
+        };
+
+        self
+    }
+
+    /// Set handler function argument types which are documented with `fastapi::FastapiExtractor`
+    /// if they implement it.
+    #[cfg(feature = "axum_extras")]
+    pub fn extractors(mut self, extractors: Vec<syn::Type>) -> Self {
+        self.extractors = extractors;
+
+        self
+    }
+
+    #[cfg(feature = "client")]
+    pub fn vis(mut self, vis: &'p syn::Visibility) -> Self {
+        self.vis = Some(vis);
+
+        self
+    }
 }
 
 impl<'p> ToTokensDiagnostics for Path<'p> {
@@ -467,38 +556,10 @@ impl<'p> ToTokensDiagnostics for Path<'p> {
             request_body: self.path_attr.request_body.as_ref(),
             responses: self.path_attr.responses.as_ref(),
             security: self.path_attr.security.as_ref(),
+            extensions: self.path_attr.extensions.as_ref(),
         };
         let operation = as_tokens_or_diagnostics!(&operation);
 
-        fn to_schema_references(
-            mut schemas: TokenStream2,
-            (is_inline, component_schema): (bool, ComponentSchema),
-        ) -> TokenStream2 {
-            for reference in component_schema.schema_references {
-                let name = &reference.name;
-                let tokens = &reference.tokens;
-                let references = &reference.references;
-
-                #[cfg(feature = "config")]
-                let should_collect_schema = (matches!(
-                    crate::CONFIG.schema_collect,
-                    fastapi_config::SchemaCollect::NonInlined
-                ) && !is_inline)
-                    || matches!(
-                        crate::CONFIG.schema_collect,
-                        fastapi_config::SchemaCollect::All
-                    );
-                #[cfg(not(feature = "config"))]
-                let should_collect_schema = !is_inline;
-                if should_collect_schema {
-                    schemas.extend(quote!( schemas.push((#name, #tokens)); ));
-                }
-                schemas.extend(quote!( #references; ));
-            }
-
-            schemas
-        }
-
         let response_schemas = self
             .path_attr
             .responses
@@ -525,23 +586,78 @@ impl<'p> ToTokensDiagnostics for Path<'p> {
         }
         let tags_list = tags.into_iter().collect::<Array<_>>();
 
+        let security_scheme_names = self
+            .path_attr
+            .security
+            .iter()
+            .flat_map(|security| security.iter())
+            .flat_map(SecurityRequirementsAttr::names)
+            .collect::<Vec<_>>();
+
+        let generics = used_generics(
+            &self.generics,
+            &[
+                &operation,
+                &schemas,
+                &response_schemas,
+                &path_with_context_path,
+                &tags_list.to_token_stream(),
+            ],
+        );
+
         let impl_for = if let Some(impl_for) = &self.path_attr.impl_for {
             Cow::Borrowed(impl_for)
         } else {
             let path_struct = format_path_ident(Cow::Borrowed(self.fn_ident));
 
-            tokens.extend(quote! {
-                #[allow(non_camel_case_types)]
-                #[doc(hidden)]
-                #[derive(Clone)]
-                pub struct #path_struct;
-            });
+            if generics.params.is_empty() {
+                tokens.extend(quote! {
+                    #[allow(non_camel_case_types)]
+                    #[doc(hidden)]
+                    #[derive(Clone)]
+                    pub struct #path_struct;
+                });
+            } else {
+                // parameters default to `()` without bounds so that the type can be named without
+                // arguments when resolving it through `fastapi::__dev::HandlerPath`
+                let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+                let type_params = generics
+                    .type_params()
+                    .map(|param| &param.ident)
+                    .collect::<Vec<_>>();
+                tokens.extend(quote! {
+                    #[allow(non_camel_case_types)]
+                    #[doc(hidden)]
+                    pub struct #path_struct < #( #type_params = () ),* > (core::marker::PhantomData<fn() -> ( #( #type_params, )* )>);
+
+                    impl #impl_generics Clone for #path_struct #ty_generics #where_clause {
+                        fn clone(&self) -> Self {
+                            Self(core::marker::PhantomData)
+                        }
+                    }
+                });
+            }
+
+            if !self.generics.params.is_empty() {
+                let (handler_impl_generics, _, handler_where_clause) =
+                    self.generics.split_for_impl();
+                let handler_params = self.generics.type_params().map(|param| &param.ident);
+                let (_, ty_generics, _) = generics.split_for_impl();
+                tokens.extend(quote! {
+                    impl #handler_impl_generics fastapi::__dev::HandlerPath<( #( #handler_params, )* )> for #path_struct #handler_where_clause {
+                        type Path = #path_struct #ty_generics;
+                    }
+                });
+            }
 
             #[cfg(feature = "actix_extras")]
             {
                 // Add supporting passthrough implementations only if actix-web service config
                 // is implemented and no impl_for has been defined
-                if self.path_attr.impl_for.is_none() && !self.ext_methods.is_empty() {
+                if self.path_attr.impl_for.is_none()
+                    && !self.ext_methods.is_empty()
+                    && generics.params.is_empty()
+                {
                     let fn_ident = self.fn_ident;
                     tokens.extend(quote! {
                         impl ::actix_web::dev::HttpServiceFactory for #path_struct {
@@ -573,6 +689,11 @@ impl<'p> ToTokensDiagnostics for Path<'p> {
                                 <#path_struct as fastapi::__dev::SchemaReferences>::schemas(schemas);
                             }
                         }
+
+                        impl fastapi::__dev::SecuritySchemeNames for #fn_ident {
+                            const SECURITY_SCHEME_NAMES: &'static [&'static str] =
+                                <#path_struct as fastapi::__dev::SecuritySchemeNames>::SECURITY_SCHEME_NAMES;
+                        }
                     })
                 }
             }
@@ -580,13 +701,61 @@ impl<'p> ToTokensDiagnostics for Path<'p> {
             path_struct
         };
 
+        // generics only apply to the generated path type, custom `impl_for` types are used as is
+        let generics = if self.path_attr.impl_for.is_none() {
+            generics
+        } else {
+            syn::Generics::default()
+        };
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+        // extractors referring to type parameters not carried over cannot be named in the impls
+        let unused_params = self
+            .generics
+            .type_params()
+            .filter(|param| !generics.type_params().any(|used| used.ident == param.ident))
+            .map(|param| param.ident.to_string())
+            .collect::<HashSet<_>>();
+        let extractors = self
+            .extractors
+            .iter()
+            .filter(|extractor| {
+                let mut idents = HashSet::new();
+                collect_idents(extractor.to_token_stream(), &mut idents);
+                idents.is_disjoint(&unused_params)
+            })
+            .collect::<Vec<_>>();
+        let (operation, extractor_schemas) = if extractors.is_empty() {
+            (quote! { #operation.into() }, None)
+        } else {
+            let use_extractors = quote! {
+                use fastapi::__dev::{DocumentExtractor as _, SkipExtractor as _};
+            };
+            (
+                quote! {
+                    #use_extractors
+                    let mut operation: fastapi::openapi::path::Operation = #operation.into();
+                    #( (&fastapi::__dev::Extractor::<#extractors>(core::marker::PhantomData)).document_operation(&mut operation); )*
+                    operation
+                },
+                Some(quote! {
+                    #use_extractors
+                    #( (&fastapi::__dev::Extractor::<#extractors>(core::marker::PhantomData)).extractor_schemas(schemas); )*
+                }),
+            )
+        };
+
+        let mut tags_generics = generics.clone();
+        tags_generics.params.insert(0, syn::parse_quote!('t));
+        let (tags_impl_generics, _, _) = tags_generics.split_for_impl();
+
         tokens.extend(quote! {
-            impl<'t> fastapi::__dev::Tags<'t> for #impl_for {
+            impl #tags_impl_generics fastapi::__dev::Tags<'t> for #impl_for #ty_generics #where_clause {
                 fn tags() -> Vec<&'t str> {
                     #tags_list.into()
                 }
             }
-            impl fastapi::Path for #impl_for {
+            impl #impl_generics fastapi::Path for #impl_for #ty_generics #where_clause {
                 fn path() -> String {
                     #path_with_context_path
                 }
@@ -598,23 +767,156 @@ impl<'p> ToTokensDiagnostics for Path<'p> {
                 fn operation() -> fastapi::openapi::path::Operation {
                     use fastapi::openapi::ToArray;
                     use std::iter::FromIterator;
-                    #operation.into()
+                    #operation
                 }
             }
 
-            impl fastapi::__dev::SchemaReferences for #impl_for {
+            impl #impl_generics fastapi::__dev::SchemaReferences for #impl_for #ty_generics #where_clause {
                 fn schemas(schemas: &mut Vec<(String, fastapi::openapi::RefOr<fastapi::openapi::schema::Schema>)>) {
                     #schemas
                     #response_schemas
+                    #extractor_schemas
                 }
             }
 
+            impl #impl_generics fastapi::__dev::SecuritySchemeNames for #impl_for #ty_generics #where_clause {
+                const SECURITY_SCHEME_NAMES: &'static [&'static str] = &[#( #security_scheme_names ),*];
+            }
+
         });
 
+        #[cfg(feature = "client")]
+        if generics.params.is_empty() {
+            let inherited = syn::Visibility::Inherited;
+            let client = client::Client {
+                path: self,
+                impl_for: &impl_for,
+                methods,
+                vis: self.vis.unwrap_or(&inherited),
+            };
+            tokens.extend(client.try_to_token_stream()?);
+        }
+
         Ok(())
     }
 }
 
+/// Extend `schemas` with tokens pushing the referenced schemas of the `component_schema` to the
+/// `schemas` vector of the generated code.
+pub(crate) fn to_schema_references(
+    mut schemas: TokenStream2,
+    (is_inline, component_schema): (bool, ComponentSchema),
+) -> TokenStream2 {
+    for reference in component_schema.schema_references {
+        let name = &reference.name;
+        let tokens = &reference.tokens;
+        let references = &reference.references;
+
+        #[cfg(feature = "config")]
+        let should_collect_schema = (matches!(
+            crate::CONFIG.schema_collect,
+            fastapi_config::SchemaCollect::NonInlined
+        ) && !is_inline)
+            || matches!(
+                crate::CONFIG.schema_collect,
+                fastapi_config::SchemaCollect::All
+            );
+        #[cfg(not(feature = "config"))]
+        let should_collect_schema = !is_inline;
+        if should_collect_schema {
+            schemas.extend(quote!( schemas.push((#name, #tokens)); ));
+        }
+        schemas.extend(quote!( #references; ));
+    }
+
+    schemas
+}
+
+/// Keep the type parameters of `generics` referenced by the `documentation` tokens along with
+/// the type parameters their bounds and where predicates depend on.
+fn used_generics(generics: &syn::Generics, documentation: &[&TokenStream2]) -> syn::Generics {
+    let mut idents = HashSet::new();
+    documentation
+        .iter()
+        .for_each(|tokens| collect_idents((*tokens).clone(), &mut idents));
+
+    let predicates = generics
+        .where_clause
+        .as_ref()
+        .map(|where_clause| where_clause.predicates.iter().collect::<Vec<_>>())
+        .unwrap_or_default();
+    let is_used = |ident: &Ident, idents: &HashSet<String>| idents.contains(&ident.to_string());
+
+    loop {
+        let count = idents.len();
+        for param in generics.type_params() {
+            if is_used(&param.ident, &idents) {
+                collect_idents(param.bounds.to_token_stream(), &mut idents);
+            }
+        }
+        for predicate in &predicates {
+            if let syn::WherePredicate::Type(predicate_type) = predicate {
+                let mut bounded = HashSet::new();
+                collect_idents(predicate_type.bounded_ty.to_token_stream(), &mut bounded);
+                if generics
+                    .type_params()
+                    .any(|param| is_used(&param.ident, &idents) && is_used(&param.ident, &bounded))
+                {
+                    collect_idents(predicate.to_token_stream(), &mut idents);
+                }
+            }
+        }
+        if count == idents.len() {
+            break;
+        }
+    }
+
+    let params = generics
+        .params
+        .iter()
+        .filter(|param| matches!(param, syn::GenericParam::Type(param) if is_used(&param.ident, &idents)))
+        .cloned()
+        .collect::<Punctuated<_, Comma>>();
+    let where_clause = generics.where_clause.as_ref().map(|where_clause| {
+        let predicates = predicates
+            .iter()
+            .filter(|predicate| {
+                let mut predicate_idents = HashSet::new();
+                collect_idents(predicate.to_token_stream(), &mut predicate_idents);
+                let mut mentioned = generics
+                    .type_params()
+                    .filter(|param| is_used(&param.ident, &predicate_idents))
+                    .peekable();
+                mentioned.peek().is_some() && mentioned.all(|param| is_used(&param.ident, &idents))
+            })
+            .map(|predicate| (*predicate).clone())
+            .collect::<Punctuated<_, Comma>>();
+        syn::WhereClause {
+            where_token: where_clause.where_token,
+            predicates,
+        }
+    });
+
+    syn::Generics {
+        lt_token: (!params.is_empty()).then(Default::default),
+        gt_token: (!params.is_empty()).then(Default::default),
+        params,
+        where_clause,
+    }
+}
+
+fn collect_idents(tokens: TokenStream2, idents: &mut HashSet<String>) {
+    for token in tokens {
+        match token {
+            proc_macro2::TokenTree::Ident(ident) => {
+                idents.insert(ident.to_string());
+            }
+            proc_macro2::TokenTree::Group(group) => collect_idents(group.stream(), idents),
+            _ => (),
+        }
+    }
+}
+
 #[cfg_attr(feature = "debug", derive(Debug))]
 struct Operation<'a> {
     operation_id: Expr,
@@ -625,6 +927,7 @@ struct Operation<'a> {
     request_body: Option<&'a RequestBodyAttr<'a>>,
     responses: &'a Vec<Response<'a>>,
     security: Option<&'a Array<'a, SecurityRequirementsAttr>>,
+    extensions: Option<&'a Extensions>,
 }
 
 impl ToTokensDiagnostics for Operation<'_> {
@@ -670,6 +973,10 @@ impl ToTokensDiagnostics for Operation<'_> {
             parameter.to_tokens(tokens)?;
         }
 
+        if let Some(extensions) = self.extensions {
+            tokens.extend(quote! { .extensions(Some(#extensions)) })
+        }
+
         Ok(())
     }
 }
@@ -727,6 +1034,12 @@ pub trait PathTypeTree {
     fn is_array(&self) -> bool;
 }
 
+/// Check whether the type or any of its generic arguments is known binary type.
+fn is_binary(type_tree: &TypeTree) -> bool {
+    type_tree.path.as_deref().is_some_and(is_binary_path)
+        || type_tree.children.iter().flatten().any(is_binary)
+}
+
 impl<'p> PathTypeTree for TypeTree<'p> {
     /// Resolve default content type based on current [`Type`].
     fn get_default_content_type(&self) -> Cow<'static, str> {
@@ -747,6 +1060,7 @@ impl<'p> PathTypeTree for TypeTree<'p> {
                         })
                 })
                 .unwrap_or(false)
+            || is_binary(self)
         {
             Cow::Borrowed("application/octet-stream")
         } else if self
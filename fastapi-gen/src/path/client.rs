@@ -0,0 +1,405 @@
+//! Generate typed client for `#[fastapi::path(...)]` operation with `client` feature.
+
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote, ToTokens};
+use syn::{parse_quote, GenericArgument, PathArguments, Type, Visibility};
+
+use crate::component::{GenericType, TypeTree, ValueType};
+use crate::{Diagnostics, ToTokensDiagnostics};
+
+use super::media_type::{DefaultSchema, MediaTypeAttr, Schema};
+use super::parameter::Parameter;
+use super::response::Response;
+use super::status::STATUS_CODES;
+use super::HttpMethod;
+
+/// Lifetime of the argument types. Argument types are wrapped in `fn(T)` pointer where the
+/// anonymous lifetime is allowed.
+pub const ARGUMENT_LIFETIME: &str = "'_";
+
+/// Client of single path operation. Generates `{FnName}Response` enum, or enum named with
+/// `client_name = ...`, with variant per declared response and `send` function to call the
+/// operation over `fastapi::client::Transport`. Operations with multiple methods get
+/// `send_{method}` function per method instead.
+pub struct Client<'c> {
+    pub path: &'c super::Path<'c>,
+    pub impl_for: &'c Ident,
+    pub methods: &'c [HttpMethod],
+    pub vis: &'c Visibility,
+}
+
+impl ToTokensDiagnostics for Client<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) -> Result<(), Diagnostics> {
+        let fn_ident = self.path.fn_ident;
+        let vis = self.vis;
+        let impl_for = self.impl_for;
+        let enum_ident =
+            self.path.path_attr.client_name.clone().unwrap_or_else(|| {
+                format_ident!("{}Response", to_pascal_case(&fn_ident.to_string()))
+            });
+
+        let mut args = Vec::<TokenStream>::new();
+        let mut request = TokenStream::new();
+        // Response bounds are higher-ranked so that they are only checked when the function is
+        // called. This way types of operations that are never called do not need to implement
+        // serde. Arguments are deferred similarly with `fastapi::client::Argument`.
+        let mut bounds = Vec::<TokenStream>::new();
+        for parameter in &self.path.path_attr.params {
+            match parameter {
+                Parameter::Value(value) => {
+                    if value.name.is_empty() {
+                        continue;
+                    }
+                    let arg = to_arg_ident(&value.name);
+                    let name = value.name_tokens();
+                    let ty = value.client_type().unwrap_or_else(|| parse_quote!(String));
+                    let parameter_in = value.parameter_in();
+
+                    args.push(quote! { #arg: impl fastapi::client::Argument<fn(#ty)> });
+                    request.extend(quote! {
+                        __request.param(#parameter_in, #name, &#arg)?;
+                    });
+                }
+                Parameter::IntoParamsIdent(into_params) => {
+                    let path = &into_params.path;
+                    let last_ident = &path
+                        .segments
+                        .last()
+                        .expect("IntoParams path must have at least one segment")
+                        .ident;
+                    let arg = to_arg_ident(&last_ident.to_string());
+                    let default_parameter_in_provider = &quote! { || None };
+                    let parameter_in_provider = into_params
+                        .parameter_in_provider()
+                        .unwrap_or(default_parameter_in_provider);
+
+                    args.push(quote! { #arg: impl fastapi::client::Argument<fn(#path)> });
+                    request.extend(quote! {
+                        __request.params(
+                            &#arg,
+                            <#path as fastapi::IntoParams>::into_params(#parameter_in_provider)
+                        )?;
+                    });
+                }
+            }
+        }
+
+        if let Some(media_type) = self
+            .path
+            .path_attr
+            .request_body
+            .as_ref()
+            .and_then(|request_body| request_body.content().first())
+        {
+            if let Some(ty) = body_type(media_type, ARGUMENT_LIFETIME) {
+                let content_type = match &media_type.content_type {
+                    Some(content_type) => content_type.to_token_stream(),
+                    None => media_type
+                        .schema
+                        .get_default_content_type()?
+                        .to_token_stream(),
+                };
+
+                args.push(quote! { body: impl fastapi::client::Argument<fn(#ty)> });
+                request.extend(quote! {
+                    __request.body(#content_type, &body)?;
+                });
+            }
+        }
+
+        let mut variants = Vec::<Ident>::new();
+        let mut variant_tokens = TokenStream::new();
+        let mut arms = Vec::<(u8, TokenStream)>::new();
+        for response in &self.path.path_attr.responses {
+            let Response::Tuple(tuple) = response else {
+                continue;
+            };
+            let status = tuple.status().to_string();
+            let status = status.trim_matches('"');
+            let (variant, pattern, order) = status_variant(status);
+            if variants.contains(&variant) {
+                continue;
+            }
+
+            let doc = format!(" Response with status `{status}`.");
+            let body = tuple
+                .content()
+                .and_then(|media_type| body_type(media_type, "'static"));
+            let arm = if let Some(ty) = body {
+                bounds.push(quote! { #ty: fastapi::client::DeserializeOwned });
+                variant_tokens.extend(quote! {
+                    #[doc = #doc]
+                    #variant(#ty),
+                });
+                quote! { #pattern => Ok(Self::#variant(__response.decode()?)), }
+            } else {
+                variant_tokens.extend(quote! {
+                    #[doc = #doc]
+                    #variant,
+                });
+                quote! { #pattern => Ok(Self::#variant), }
+            };
+
+            variants.push(variant);
+            arms.push((order, arm));
+        }
+        arms.sort_by_key(|(order, _)| *order);
+        let arms = arms.into_iter().map(|(_, arm)| arm).collect::<Vec<_>>();
+        let unexpected = if variants.iter().any(|variant| variant == "Default") {
+            None
+        } else {
+            Some(quote! { _ => Err(fastapi::client::Error::UnexpectedStatus(__response)), })
+        };
+
+        let enum_doc = format!(" Responses of `{fn_ident}` operation.");
+        let send_fns = self.methods.iter().map(|method| {
+            let (send_ident, send_doc) = match self.methods {
+                [_] => (
+                    format_ident!("send"),
+                    format!(
+                        " Send request of `{fn_ident}` operation with given [`fastapi::client::Transport`]."
+                    ),
+                ),
+                _ => {
+                    let method_name = method_name(method);
+                    (
+                        format_ident!("send_{method_name}"),
+                        format!(
+                            " Send `{}` request of `{fn_ident}` operation with given [`fastapi::client::Transport`].",
+                            method_name.to_uppercase()
+                        ),
+                    )
+                }
+            };
+
+            quote! {
+                #[doc = #send_doc]
+                #[allow(clippy::too_many_arguments)]
+                #vis async fn #send_ident<T: fastapi::client::Transport>(
+                    transport: &T,
+                    #( #args ),*
+                ) -> Result<Self, fastapi::client::Error>
+                where
+                    #( for<'__client> #bounds ),*
+                {
+                    let mut __request = fastapi::client::Request::new(
+                        #method,
+                        <#impl_for as fastapi::Path>::path()
+                    );
+                    #request
+                    let __response = fastapi::client::Transport::send(transport, __request).await?;
+
+                    match __response.status {
+                        #( #arms )*
+                        #unexpected
+                    }
+                }
+            }
+        });
+
+        tokens.extend(quote! {
+            #[doc = #enum_doc]
+            #vis enum #enum_ident {
+                #variant_tokens
+            }
+
+            impl #enum_ident {
+                #( #send_fns )*
+            }
+        });
+
+        Ok(())
+    }
+}
+
+/// Resolve the owned Rust type a client uses for the content of [`MediaTypeAttr`]. Schema
+/// references do not have a Rust type and will not be resolved.
+fn body_type(media_type: &MediaTypeAttr, lifetime: &str) -> Option<Type> {
+    match &media_type.schema {
+        Schema::Default(DefaultSchema::TypePath(parsed)) => Some(owned_type(&parsed.ty, lifetime)),
+        Schema::Default(DefaultSchema::Raw { ty, .. }) => Some(owned_type(ty, lifetime)),
+        // streams are not decoded as single value
+        Schema::Default(DefaultSchema::Ref(_) | DefaultSchema::None | DefaultSchema::Stream(_)) => {
+            None
+        }
+        Schema::Ext(ext) => {
+            // multipart body is sent as already encoded bytes
+            let is_binary = ext.get_default_content_type().is_ok_and(|content_type| {
+                content_type == "application/octet-stream" || content_type == "multipart/form-data"
+            });
+            if is_binary {
+                Some(parse_quote!(Vec<u8>))
+            } else {
+                type_tree_to_type(&ext.get_actual_body_type_tree(), lifetime)
+            }
+        }
+    }
+}
+
+/// Convert type used in the attribute to owned type that can be used as function argument
+/// and deserialized. E.g. `[Pet]` becomes `Vec<Pet>`, `&str` becomes `String` and lifetimes
+/// are replaced with given `lifetime`.
+pub fn owned_type(ty: &Type, lifetime: &str) -> Type {
+    match ty {
+        Type::Slice(slice) => {
+            let elem = owned_type(&slice.elem, lifetime);
+            parse_quote!(Vec<#elem>)
+        }
+        Type::Array(array) => {
+            let elem = owned_type(&array.elem, lifetime);
+            parse_quote!(Vec<#elem>)
+        }
+        Type::Reference(reference) => owned_type(&reference.elem, lifetime),
+        Type::Group(group) => owned_type(&group.elem, lifetime),
+        Type::Paren(paren) => owned_type(&paren.elem, lifetime),
+        Type::Tuple(tuple) => {
+            let mut tuple = tuple.clone();
+            tuple
+                .elems
+                .iter_mut()
+                .for_each(|elem| *elem = owned_type(elem, lifetime));
+            Type::Tuple(tuple)
+        }
+        Type::Path(type_path) if type_path.qself.is_none() && type_path.path.is_ident("str") => {
+            parse_quote!(String)
+        }
+        Type::Path(type_path) => {
+            let mut type_path = type_path.clone();
+            for segment in &mut type_path.path.segments {
+                if let PathArguments::AngleBracketed(arguments) = &mut segment.arguments {
+                    for argument in &mut arguments.args {
+                        match argument {
+                            GenericArgument::Type(ty) => *ty = owned_type(ty, lifetime),
+                            GenericArgument::Lifetime(argument_lifetime) => {
+                                *argument_lifetime =
+                                    syn::Lifetime::new(lifetime, argument_lifetime.span())
+                            }
+                            _ => (),
+                        }
+                    }
+                }
+            }
+            Type::Path(type_path)
+        }
+        ty => ty.clone(),
+    }
+}
+
+/// Convert [`TypeTree`] resolved from handler function arguments back to owned Rust type.
+pub fn type_tree_to_type(type_tree: &TypeTree, lifetime: &str) -> Option<Type> {
+    let children = type_tree.children.as_deref().unwrap_or_default();
+    match (
+        &type_tree.generic_type,
+        &type_tree.value_type,
+        &type_tree.path,
+    ) {
+        (Some(GenericType::Option), _, _) => {
+            let child = type_tree_to_type(children.first()?, lifetime)?;
+            Some(parse_quote!(Option<#child>))
+        }
+        (Some(GenericType::Vec), _, Some(path)) if path.is_ident("Array") => {
+            let child = type_tree_to_type(children.first()?, lifetime)?;
+            Some(parse_quote!(Vec<#child>))
+        }
+        (_, ValueType::Tuple, None) => {
+            let children = children
+                .iter()
+                .map(|child| type_tree_to_type(child, lifetime))
+                .collect::<Option<Vec<_>>>()?;
+            Some(parse_quote!(( #( #children ),* )))
+        }
+        (_, _, Some(path)) => Some(owned_type(
+            &Type::Path(syn::TypePath {
+                qself: None,
+                path: (**path).clone(),
+            }),
+            lifetime,
+        )),
+        _ => None,
+    }
+}
+
+fn method_name(method: &HttpMethod) -> &'static str {
+    match method {
+        HttpMethod::Get => "get",
+        HttpMethod::Post => "post",
+        HttpMethod::Put => "put",
+        HttpMethod::Delete => "delete",
+        HttpMethod::Options => "options",
+        HttpMethod::Head => "head",
+        HttpMethod::Patch => "patch",
+        HttpMethod::Trace => "trace",
+    }
+}
+
+/// Get variant name, match pattern and sort order of the match arm for response status.
+fn status_variant(status: &str) -> (Ident, TokenStream, u8) {
+    let range = |name: &str, start: u16| {
+        let end = start + 99;
+        (
+            Ident::new(name, Span::call_site()),
+            quote! { #start..=#end },
+            1,
+        )
+    };
+
+    match status {
+        "default" => (Ident::new("Default", Span::call_site()), quote! { _ }, 2),
+        "1XX" => range("Informational", 100),
+        "2XX" => range("Success", 200),
+        "3XX" => range("Redirection", 300),
+        "4XX" => range("ClientError", 400),
+        "5XX" => range("ServerError", 500),
+        code => {
+            let variant = STATUS_CODES
+                .iter()
+                .find(|(status_code, _)| status_code.to_string() == code)
+                .map(|(_, name)| to_pascal_case(&name.to_lowercase()))
+                .unwrap_or_else(|| format!("Status{code}"));
+            let code = code.parse::<u16>().unwrap_or_default();
+
+            (Ident::new(&variant, Span::call_site()), quote! { #code }, 0)
+        }
+    }
+}
+
+fn to_pascal_case(name: &str) -> String {
+    name.split('_')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+/// Convert parameter name to snake case argument identifier e.g. `X-Request-Id` becomes
+/// `x_request_id` and `PetQuery` becomes `pet_query`. Keywords are used as raw identifiers
+/// except the ones that cannot be raw, e.g. `self`, which are suffixed with `_`.
+fn to_arg_ident(name: &str) -> Ident {
+    let mut arg = String::with_capacity(name.len());
+    for (index, char) in name.chars().enumerate() {
+        if char.is_ascii_uppercase() {
+            if index > 0 && !arg.ends_with('_') {
+                arg.push('_');
+            }
+            arg.push(char.to_ascii_lowercase());
+        } else if char.is_ascii_alphanumeric() {
+            arg.push(char);
+        } else if !arg.ends_with('_') {
+            arg.push('_');
+        }
+    }
+    if arg.starts_with(|char: char| char.is_ascii_digit()) {
+        arg.insert(0, '_');
+    }
+
+    match &*arg {
+        "self" | "super" | "crate" | "_" => format_ident!("{arg}_"),
+        _ => syn::parse_str::<Ident>(&arg)
+            .unwrap_or_else(|_| Ident::new_raw(&arg, Span::call_site())),
+    }
+}
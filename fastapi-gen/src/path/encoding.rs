@@ -0,0 +1,119 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::token::Comma;
+use syn::{parenthesized, Error, LitStr};
+
+use crate::{parse_utils, Diagnostics, ToTokensDiagnostics};
+
+use super::parameter::ParameterStyle;
+use super::response::header::{self, Header};
+
+// encoding(part = "...", content_type = "...", headers(...), style = ..., explode, allow_reserved)
+#[derive(Default)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct Encoding {
+    pub(super) part: String,
+    pub(super) content_type: Option<parse_utils::LitStrOrExpr>,
+    pub(super) headers: Vec<Header>,
+    pub(super) style: Option<ParameterStyle>,
+    pub(super) explode: Option<bool>,
+    pub(super) allow_reserved: Option<bool>,
+}
+
+impl Parse for Encoding {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        const EXPECTED_ATTRIBUTE: &str =
+            "unexpected attribute, expected any of: part, content_type, headers, style, explode, allow_reserved";
+        let content;
+        parenthesized!(content in input);
+
+        let mut encoding = Encoding::default();
+        let mut part = None::<LitStr>;
+
+        while !content.is_empty() {
+            let ident = content
+                .parse::<Ident>()
+                .map_err(|error| Error::new(error.span(), EXPECTED_ATTRIBUTE))?;
+            let attribute_name = &*ident.to_string();
+            match attribute_name {
+                "part" => {
+                    part = Some(parse_utils::parse_next(&content, || {
+                        content.parse::<LitStr>()
+                    })?)
+                }
+                "content_type" => {
+                    encoding.content_type =
+                        Some(parse_utils::parse_next_literal_str_or_expr(&content)?)
+                }
+                "headers" => encoding.headers = header::headers(&content)?,
+                "style" => {
+                    encoding.style = Some(parse_utils::parse_next(&content, || {
+                        content.parse::<ParameterStyle>()
+                    })?)
+                }
+                "explode" => encoding.explode = Some(parse_utils::parse_bool_or_true(&content)?),
+                "allow_reserved" => {
+                    encoding.allow_reserved = Some(parse_utils::parse_bool_or_true(&content)?)
+                }
+                _ => return Err(Error::new(ident.span(), EXPECTED_ATTRIBUTE)),
+            }
+
+            if !content.is_empty() {
+                content.parse::<Comma>()?;
+            }
+        }
+
+        encoding.part = part
+            .ok_or_else(|| {
+                Error::new(
+                    content.span(),
+                    r#"missing `part` of encoding, expected e.g. `part = "metadata"`"#,
+                )
+            })?
+            .value();
+
+        Ok(encoding)
+    }
+}
+
+impl ToTokensDiagnostics for Encoding {
+    fn to_tokens(&self, tokens: &mut TokenStream) -> Result<(), Diagnostics> {
+        let content_type = self
+            .content_type
+            .as_ref()
+            .map(|content_type| quote!( .content_type(Some(#content_type)) ));
+        let headers = self
+            .headers
+            .iter()
+            .map(|header| {
+                let name = &header.name;
+                let header_tokens = header.try_to_token_stream()?;
+
+                Ok(quote!( .header(#name, #header_tokens) ))
+            })
+            .collect::<Result<Vec<_>, Diagnostics>>()?;
+        let style = self
+            .style
+            .as_ref()
+            .map(|style| quote!( .style(Some(#style)) ));
+        let explode = self
+            .explode
+            .map(|explode| quote!( .explode(Some(#explode)) ));
+        let allow_reserved = self
+            .allow_reserved
+            .map(|allow_reserved| quote!( .allow_reserved(Some(#allow_reserved)) ));
+
+        tokens.extend(quote! {
+            fastapi::openapi::encoding::EncodingBuilder::new()
+                #content_type
+                #( #headers )*
+                #style
+                #explode
+                #allow_reserved
+                .build()
+        });
+
+        Ok(())
+    }
+}
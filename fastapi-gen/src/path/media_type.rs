@@ -5,7 +5,7 @@ use quote::{quote, ToTokens};
 use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
 use syn::token::{Comma, Paren};
-use syn::{Error, Generics, Ident, Token, Type};
+use syn::{Error, Generics, Ident, LitStr, Token, Type};
 
 use crate::component::features::attributes::Inline;
 use crate::component::features::Feature;
@@ -13,6 +13,7 @@ use crate::component::{ComponentSchema, ComponentSchemaProps, Container, TypeTre
 use crate::ext::ExtSchema;
 use crate::{parse_utils, AnyValue, Array, Diagnostics, ToTokensDiagnostics};
 
+use super::encoding::Encoding;
 use super::example::Example;
 use super::PathTypeTree;
 
@@ -28,7 +29,7 @@ pub struct MediaTypeAttr<'m> {
     pub schema: Schema<'m>,
     pub example: Option<AnyValue>,
     pub examples: Punctuated<Example, Comma>,
-    // econding: String, // TODO parse encoding
+    pub encoding: Vec<Encoding>,
 }
 
 impl Parse for MediaTypeAttr<'_> {
@@ -105,13 +106,12 @@ impl<'m> MediaTypeAttr<'m> {
             "examples" => {
                 media_type.examples = parse_utils::parse_comma_separated_within_parenthesis(input)?
             }
-            // // TODO implement encoding support
-            // "encoding" => (),
+            "encoding" => media_type.encoding.push(input.parse::<Encoding>()?),
             unexpected => {
                 return Err(syn::Error::new(
                     attribute.span(),
                     format!(
-                        "unexpected attribute: {unexpected}, expected any of: example, examples"
+                        "unexpected attribute: {unexpected}, expected any of: example, examples, encoding"
                     ),
                 ))
             }
@@ -127,11 +127,16 @@ impl<'m> MediaTypeAttr<'m> {
 
 impl ToTokensDiagnostics for MediaTypeAttr<'_> {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) -> Result<(), Diagnostics> {
-        let schema = &self.schema.try_to_token_stream()?;
-        let schema_tokens = if schema.is_empty() {
-            None
+        let schema_tokens = if let Schema::Default(DefaultSchema::Stream(stream)) = &self.schema {
+            let item_schema = stream.item_schema_tokens(self.is_event_stream())?;
+            Some(quote! { .item_schema(Some(#item_schema)) })
         } else {
-            Some(quote! { .schema(Some(#schema)) })
+            let schema = &self.schema.try_to_token_stream()?;
+            if schema.is_empty() {
+                None
+            } else {
+                Some(quote! { .schema(Some(#schema)) })
+            }
         };
         let example = self
             .example
@@ -151,12 +156,23 @@ impl ToTokensDiagnostics for MediaTypeAttr<'_> {
         } else {
             None
         };
+        let encoding = self
+            .encoding
+            .iter()
+            .map(|encoding| {
+                let part = &encoding.part;
+                let encoding_tokens = encoding.try_to_token_stream()?;
+
+                Ok(quote!( .encoding(#part, #encoding_tokens) ))
+            })
+            .collect::<Result<Vec<_>, Diagnostics>>()?;
 
         tokens.extend(quote! {
             fastapi::openapi::content::ContentBuilder::new()
                 #schema_tokens
                 #example
                 #examples
+                #( #encoding )*
                 .into()
         });
 
@@ -164,6 +180,19 @@ impl ToTokensDiagnostics for MediaTypeAttr<'_> {
     }
 }
 
+impl MediaTypeAttr<'_> {
+    /// Check whether the content is `text/event-stream`. Content type defined with an expression
+    /// is considered to be an event stream as the actual value is only known at runtime.
+    fn is_event_stream(&self) -> bool {
+        match &self.content_type {
+            Some(parse_utils::LitStrOrExpr::LitStr(content_type)) => {
+                content_type.value().starts_with(EVENT_STREAM)
+            }
+            Some(parse_utils::LitStrOrExpr::Expr(_)) | None => true,
+        }
+    }
+}
+
 pub trait MediaTypePathExt<'a> {
     fn get_component_schema(&self) -> Result<Option<ComponentSchema>, Diagnostics>;
 }
@@ -203,6 +232,29 @@ impl Schema<'_> {
         }
     }
 
+    /// Get all component schemas of the schema along with the inline flag. This is same as
+    /// [`Schema::get_component_schema`] but also supports streams with multiple item schemas.
+    pub fn get_component_schemas(&self) -> Result<Vec<(bool, ComponentSchema)>, Diagnostics> {
+        match self {
+            Self::Default(DefaultSchema::Stream(stream)) => stream
+                .items
+                .iter()
+                .map(|item| {
+                    let type_tree = item.ty.to_type_tree()?;
+                    Ok(type_tree
+                        .get_component_schema()?
+                        .map(|component_schema| (item.ty.is_inline, component_schema)))
+                })
+                .filter_map(Result::transpose)
+                .collect(),
+            _ => Ok(self
+                .get_component_schema()?
+                .map(|component_schema| (self.is_inline(), component_schema))
+                .into_iter()
+                .collect()),
+        }
+    }
+
     pub fn is_inline(&self) -> bool {
         match self {
             Self::Default(def) => match def {
@@ -239,6 +291,8 @@ pub enum DefaultSchema<'d> {
         tokens: TokenStream,
         ty: Cow<'d, Type>,
     },
+    /// Streamed content defined with `stream(...)`.
+    Stream(StreamSchema<'d>),
 }
 
 impl ToTokensDiagnostics for DefaultSchema<'_> {
@@ -268,8 +322,8 @@ impl ToTokensDiagnostics for DefaultSchema<'_> {
             } => {
                 raw_tokens.to_tokens(tokens);
             }
-            // nada
-            Self::None => (),
+            // stream does not have schema, it is resolved as item schema of the media type
+            Self::None | Self::Stream(_) => (),
         }
 
         Ok(())
@@ -304,6 +358,7 @@ impl DefaultSchema<'_> {
                 Ok(type_tree.get_default_content_type())
             }
             Self::Ref(_) => Ok(Cow::Borrowed("application/json")),
+            Self::Stream(_) => Ok(Cow::Borrowed(EVENT_STREAM)),
             Self::Raw { ty, .. } => {
                 let type_tree = TypeTree::from_type(ty.as_ref())?;
                 Ok(type_tree.get_default_content_type())
@@ -343,12 +398,25 @@ impl Parse for DefaultSchema<'_> {
             false
         };
 
+        let fork = input.fork();
+        let is_stream = if let Some(ident) = fork.parse::<Option<syn::Ident>>()? {
+            ident == "stream" && fork.peek(Paren)
+        } else {
+            false
+        };
+
         if is_ref {
             input.parse::<Token![ref]>()?;
             let ref_stream;
             syn::parenthesized!(ref_stream in input);
 
             ref_stream.parse().map(Self::Ref)
+        } else if is_stream {
+            input.parse::<syn::Ident>()?;
+            let stream;
+            syn::parenthesized!(stream in input);
+
+            stream.parse().map(Self::Stream)
         } else {
             input.parse().map(Self::TypePath)
         }
@@ -361,6 +429,102 @@ impl<'r> From<ParsedType<'r>> for Schema<'r> {
     }
 }
 
+const EVENT_STREAM: &str = "text/event-stream";
+
+/// Parse streamed content of media type.
+/// stream( Schema )
+/// stream( "event" = Schema, "event2" = Schema2 )
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct StreamSchema<'s> {
+    items: Vec<StreamItem<'s>>,
+}
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+struct StreamItem<'s> {
+    event: Option<LitStr>,
+    ty: ParsedType<'s>,
+}
+
+impl Parse for StreamSchema<'_> {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let items = Punctuated::<StreamItem, Comma>::parse_terminated(input)?
+            .into_iter()
+            .collect::<Vec<_>>();
+
+        if items.is_empty() {
+            return Err(Error::new(
+                input.span(),
+                r#"expected at least one stream item e.g. `stream(Pet)` or `stream("created" = Pet)`"#,
+            ));
+        }
+
+        Ok(Self { items })
+    }
+}
+
+impl Parse for StreamItem<'_> {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let event = if input.peek(LitStr) {
+            let event = input.parse::<LitStr>()?;
+            input.parse::<Token![=]>()?;
+            Some(event)
+        } else {
+            None
+        };
+
+        Ok(Self {
+            event,
+            ty: input.parse()?,
+        })
+    }
+}
+
+impl StreamSchema<'_> {
+    /// Get tokens of the item schema. For event stream each item is wrapped in Server-Sent
+    /// Event schema, otherwise the item is used as is.
+    fn item_schema_tokens(&self, is_event_stream: bool) -> Result<TokenStream, Diagnostics> {
+        let items = self
+            .items
+            .iter()
+            .map(|item| {
+                let type_tree = item.ty.to_type_tree()?;
+                let data = ComponentSchema::new(ComponentSchemaProps {
+                    type_tree: &type_tree,
+                    features: vec![Inline::from(item.ty.is_inline).into()],
+                    description: None,
+                    container: &Container {
+                        generics: &Generics::default(),
+                    },
+                })?
+                .to_token_stream();
+
+                match (is_event_stream, &item.event) {
+                    (true, Some(event)) => Ok(quote! {
+                        fastapi::openapi::content::server_sent_event(Some(#event), #data)
+                    }),
+                    (true, None) => Ok(quote! {
+                        fastapi::openapi::content::server_sent_event(None::<String>, #data)
+                    }),
+                    (false, Some(event)) => Err(Diagnostics::with_span(
+                        event.span(),
+                        "event names are only supported with `text/event-stream` content type",
+                    )),
+                    (false, None) => Ok(data),
+                }
+            })
+            .collect::<Result<Vec<_>, Diagnostics>>()?;
+
+        if let [item] = items.as_slice() {
+            Ok(item.clone())
+        } else {
+            Ok(quote! {
+                fastapi::openapi::schema::OneOfBuilder::new()
+                    #( .item(#items) )*
+            })
+        }
+    }
+}
+
 // inline(syn::TypePath) | syn::TypePath
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub struct ParsedType<'i> {
@@ -370,7 +534,7 @@ pub struct ParsedType<'i> {
 
 impl ParsedType<'_> {
     /// Get's the underlying [`syn::Type`] as [`TypeTree`].
-    fn to_type_tree(&self) -> Result<TypeTree, Diagnostics> {
+    pub fn to_type_tree(&self) -> Result<TypeTree, Diagnostics> {
         TypeTree::from_type(&self.ty)
     }
 }
@@ -29,7 +29,7 @@ use crate::{
     parse_utils, Diagnostics, Required, ToTokensDiagnostics,
 };
 
-use super::media_type::ParsedType;
+use super::media_type::{MediaTypePathExt, ParsedType};
 
 /// Parameter of request such as in path, header, query or cookie
 ///
@@ -77,6 +77,27 @@ impl<'p> Parameter<'p> {
     }
 }
 
+impl Parameter<'_> {
+    /// Get [`ComponentSchema`] of the parameter type defined in `("name" = Type, ...)` along with
+    /// the inline flag.
+    pub fn get_component_schema(&self) -> Result<Option<(bool, ComponentSchema)>, Diagnostics> {
+        match self {
+            Self::Value(ValueParameter {
+                parameter_schema:
+                    Some(ParameterSchema {
+                        parameter_type: ParameterType::Parsed(parsed),
+                        ..
+                    }),
+                ..
+            }) => Ok(parsed
+                .to_type_tree()?
+                .get_component_schema()?
+                .map(|component_schema| (parsed.is_inline, component_schema))),
+            _ => Ok(None),
+        }
+    }
+}
+
 impl Parse for Parameter<'_> {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         if input.fork().parse::<TypePath>().is_ok() {
@@ -126,18 +147,67 @@ impl ToTokensDiagnostics for Parameter<'_> {
 ))]
 impl<'a> From<crate::ext::ValueArgument<'a>> for Parameter<'a> {
     fn from(argument: crate::ext::ValueArgument<'a>) -> Self {
+        use crate::component::features::attributes::{Explode, Style};
+        use crate::ext::ArgumentIn;
+
+        let (parameter_in, header_trait) = match argument.argument_in {
+            ArgumentIn::Path => (ParameterIn::Path, None),
+            #[cfg(any(feature = "rocket_extras", feature = "axum_extras"))]
+            ArgumentIn::Query => (ParameterIn::Query, None),
+            #[cfg(any(feature = "actix_extras", feature = "axum_extras"))]
+            ArgumentIn::Header(header_trait) => (ParameterIn::Header, Some(header_trait)),
+        };
+
+        // map of query parameters is an object where each entry is a query parameter of its own
+        let mut parameter_features = Vec::new();
+        if parameter_in == ParameterIn::Query
+            && argument
+                .type_tree
+                .as_ref()
+                .is_some_and(|type_tree| type_tree.is_map())
+        {
+            parameter_features.push(Style::from(ParameterStyle::Form).into());
+            parameter_features.push(Explode::from(true).into());
+        }
+
+        let typed_header = header_trait.and_then(|header_trait| {
+            let type_tree = argument.type_tree.as_ref()?;
+            let wrapper = if type_tree.is_option() {
+                type_tree.children.as_ref()?.first()?
+            } else {
+                type_tree
+            };
+            let header = wrapper.children.as_ref()?.first()?.path.clone()?;
+
+            Some((header, header_trait))
+        });
+
+        let parameter_type = argument.type_tree.map(|type_tree| {
+            if parameter_in == ParameterIn::Header {
+                // typed headers are parsed from their string representation
+                let ty: syn::Type = if type_tree.is_option() {
+                    syn::parse_quote!(Option<String>)
+                } else {
+                    syn::parse_quote!(String)
+                };
+                ParameterType::Parsed(ParsedType {
+                    ty: Cow::Owned(ty),
+                    is_inline: false,
+                })
+            } else {
+                ParameterType::External(type_tree)
+            }
+        });
+
         Self::Value(ValueParameter {
             name: argument.name.unwrap_or_else(|| Cow::Owned(String::new())),
-            parameter_in: if argument.argument_in == crate::ext::ArgumentIn::Path {
-                ParameterIn::Path
-            } else {
-                ParameterIn::Query
-            },
-            parameter_schema: argument.type_tree.map(|type_tree| ParameterSchema {
-                parameter_type: ParameterType::External(type_tree),
+            typed_header,
+            parameter_in,
+            parameter_schema: parameter_type.map(|parameter_type| ParameterSchema {
+                parameter_type,
                 features: Vec::new(),
             }),
-            ..Default::default()
+            features: (Vec::new(), parameter_features),
         })
     }
 }
@@ -175,7 +245,8 @@ impl ToTokensDiagnostics for ParameterSchema<'_> {
                 feature = "axum_extras"
             ))]
             ParameterType::External(type_tree) => {
-                let required: Required = (!type_tree.is_option()).into();
+                // maps may always be empty so they are never required
+                let required: Required = (!type_tree.is_option() && !type_tree.is_map()).into();
 
                 to_tokens(
                     ComponentSchema::new(component::ComponentSchemaProps {
@@ -231,11 +302,40 @@ enum ParameterType<'p> {
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub struct ValueParameter<'a> {
     pub name: Cow<'a, str>,
+    /// Type of typed header argument and the header trait defining the name of the header.
+    typed_header: Option<(Cow<'a, syn::Path>, syn::Path)>,
     parameter_in: ParameterIn,
     parameter_schema: Option<ParameterSchema<'a>>,
     features: (Vec<Feature>, Vec<Feature>),
 }
 
+#[cfg(feature = "client")]
+impl ValueParameter<'_> {
+    pub fn parameter_in(&self) -> ParameterIn {
+        self.parameter_in
+    }
+
+    /// Get owned Rust type of the parameter if one is defined.
+    pub fn client_type(&self) -> Option<syn::Type> {
+        self.parameter_schema
+            .as_ref()
+            .and_then(|schema| match &schema.parameter_type {
+                #[cfg(any(
+                    feature = "actix_extras",
+                    feature = "rocket_extras",
+                    feature = "axum_extras"
+                ))]
+                ParameterType::External(type_tree) => {
+                    super::client::type_tree_to_type(type_tree, super::client::ARGUMENT_LIFETIME)
+                }
+                ParameterType::Parsed(parsed) => Some(super::client::owned_type(
+                    &parsed.ty,
+                    super::client::ARGUMENT_LIFETIME,
+                )),
+            })
+    }
+}
+
 impl PartialEq for ValueParameter<'_> {
     fn eq(&self, other: &Self) -> bool {
         self.name == other.name && self.parameter_in == other.parameter_in
@@ -369,9 +469,23 @@ impl ParameterFeatures {
 
 impl_into_inner!(ParameterFeatures);
 
+impl ValueParameter<'_> {
+    /// Get expression of the parameter name. Name of typed header is taken from the header trait
+    /// implementation of the header type.
+    pub fn name_tokens(&self) -> TokenStream {
+        match &self.typed_header {
+            Some((header, header_trait)) => quote! { <#header as #header_trait>::name().as_str() },
+            None => {
+                let name = &*self.name;
+                quote! { #name }
+            }
+        }
+    }
+}
+
 impl ToTokensDiagnostics for ValueParameter<'_> {
     fn to_tokens(&self, tokens: &mut TokenStream) -> Result<(), Diagnostics> {
-        let name = &*self.name;
+        let name = self.name_tokens();
         tokens.extend(quote! {
             fastapi::openapi::path::ParameterBuilder::from(fastapi::openapi::path::Parameter::new(#name))
         });
@@ -404,6 +518,13 @@ pub struct IntoParamsIdentParameter<'i> {
     parameter_in_fn: Option<TokenStream>,
 }
 
+#[cfg(feature = "client")]
+impl IntoParamsIdentParameter<'_> {
+    pub fn parameter_in_provider(&self) -> Option<&TokenStream> {
+        self.parameter_in_fn.as_ref()
+    }
+}
+
 // Compare paths loosely only by segment idents ignoring possible generics
 impl PartialEq for IntoParamsIdentParameter<'_> {
     fn eq(&self, other: &Self) -> bool {
@@ -77,30 +77,31 @@ impl<'r> RequestBodyAttr<'r> {
         feature = "rocket_extras",
         feature = "axum_extras"
     ))]
-    pub fn from_schema(schema: Schema<'r>) -> RequestBodyAttr<'r> {
+    pub fn from_schemas<I: IntoIterator<Item = Schema<'r>>>(schemas: I) -> RequestBodyAttr<'r> {
         Self {
-            content: vec![MediaTypeAttr {
-                schema,
-                ..Default::default()
-            }],
+            content: schemas
+                .into_iter()
+                .map(|schema| MediaTypeAttr {
+                    schema,
+                    ..Default::default()
+                })
+                .collect(),
             ..Self::new()
         }
     }
 
+    #[cfg(feature = "client")]
+    pub fn content(&self) -> &[MediaTypeAttr<'r>] {
+        &self.content
+    }
+
     pub fn get_component_schemas(
         &self,
     ) -> Result<impl Iterator<Item = (bool, ComponentSchema)>, Diagnostics> {
         Ok(self
             .content
             .iter()
-            .map(
-                |media_type| match media_type.schema.get_component_schema() {
-                    Ok(component_schema) => {
-                        Ok(Some(media_type.schema.is_inline()).zip(component_schema))
-                    }
-                    Err(error) => Err(error),
-                },
-            )
+            .map(|media_type| media_type.schema.get_component_schemas())
             .collect::<Result<Vec<_>, Diagnostics>>()?
             .into_iter()
             .flatten())
@@ -110,7 +111,7 @@ impl<'r> RequestBodyAttr<'r> {
 impl Parse for RequestBodyAttr<'_> {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         const EXPECTED_ATTRIBUTE_MESSAGE: &str =
-            "unexpected attribute, expected any of: content, content_type, description, examples, example";
+            "unexpected attribute, expected any of: content, content_type, description, examples, example, encoding";
         let lookahead = input.lookahead1();
 
         if lookahead.peek(Paren) {
@@ -182,6 +183,8 @@ impl Parse for RequestBodyAttr<'_> {
                             &group,
                             &ident,
                         )?;
+                        // named attributes parse the trailing comma
+                        continue;
                     }
                 }
 
@@ -199,6 +202,7 @@ impl Parse for RequestBodyAttr<'_> {
                 content_type: None,
                 example: None,
                 examples: Punctuated::default(),
+                encoding: Vec::new(),
             };
 
             Ok(RequestBodyAttr {
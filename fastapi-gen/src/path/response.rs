@@ -2,6 +2,7 @@ use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
 use quote::{quote, quote_spanned, ToTokens};
 use std::borrow::Cow;
 use syn::{
+    ext::IdentExt,
     parenthesized,
     parse::{Parse, ParseStream},
     punctuated::Punctuated,
@@ -12,7 +13,7 @@ use syn::{
 
 use crate::{
     component::ComponentSchema, parse_utils, path::media_type::Schema, AnyValue, Diagnostics,
-    ToTokensDiagnostics,
+    Extensions, ToTokensDiagnostics,
 };
 
 use self::{header::Header, link::LinkTuple};
@@ -25,7 +26,7 @@ use super::{
 };
 
 pub mod derive;
-mod header;
+pub mod header;
 pub mod link;
 
 #[cfg_attr(feature = "debug", derive(Debug))]
@@ -34,11 +35,23 @@ pub enum Response<'r> {
     IntoResponses(Cow<'r, TypePath>),
     /// The tuple definition of a response.
     Tuple(ResponseTuple<'r>),
+    /// Return type of the handler function which may implement `fastapi::IntoResponses`. Types
+    /// not implementing it will not produce any responses.
+    #[cfg(feature = "infer_responses")]
+    ReturnType(syn::Type),
 }
 
 impl Parse for Response<'_> {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        if input.fork().parse::<ExprPath>().is_ok() {
+        let fork = input.fork();
+        if fork.parse::<Ident>().is_ok_and(|ident| ident == "problem")
+            && fork.peek(syn::token::Paren)
+        {
+            let ident = input.parse::<Ident>()?;
+            let problem;
+            parenthesized!(problem in input);
+            Ok(Self::Tuple(parse_problem(&problem, &ident)?))
+        } else if input.fork().parse::<ExprPath>().is_ok() {
             Ok(Self::IntoResponses(Cow::Owned(input.parse::<TypePath>()?)))
         } else {
             let response;
@@ -48,6 +61,73 @@ impl Parse for Response<'_> {
     }
 }
 
+/// Parse `problem(status = ..., type = "...", title = "...", description = "...")` shorthand
+/// of _`application/problem+json`_ response with `fastapi::problem::Problem` body.
+fn parse_problem<'r>(input: ParseStream, problem: &Ident) -> syn::Result<ResponseTuple<'r>> {
+    const EXPECTED_ATTRIBUTES: &str = "status, type, title, description";
+
+    let mut status: Option<ResponseStatus> = None;
+    let mut problem_type: Option<LitStr> = None;
+    let mut title: Option<LitStr> = None;
+    let mut description: Option<parse_utils::LitStrOrExpr> = None;
+
+    while !input.is_empty() {
+        let ident = input.call(Ident::parse_any).map_err(|error| {
+            Error::new(
+                error.span(),
+                format!("unexpected attribute, expected any of: {EXPECTED_ATTRIBUTES}, {error}"),
+            )
+        })?;
+        match &*ident.to_string() {
+            "status" => status = Some(parse_utils::parse_next(input, || input.parse())?),
+            "type" => problem_type = Some(parse_utils::parse_next(input, || input.parse())?),
+            "title" => title = Some(parse_utils::parse_next(input, || input.parse())?),
+            "description" => description = Some(parse::description(input)?),
+            _ => {
+                return Err(Error::new(
+                    ident.span(),
+                    format!(
+                        "unexpected attribute: {ident}, expected any of: {EXPECTED_ATTRIBUTES}"
+                    ),
+                ))
+            }
+        }
+
+        if !input.is_empty() {
+            input.parse::<Token![,]>()?;
+        }
+    }
+
+    let status = status.ok_or_else(|| {
+        Error::new(
+            problem.span(),
+            "missing expected `status` attribute for `problem(...)`",
+        )
+    })?;
+    let status_code = status.code().map(proc_macro2::Literal::u16_unsuffixed);
+
+    let description = description
+        .or_else(|| title.clone().map(parse_utils::LitStrOrExpr::LitStr))
+        .unwrap_or_default();
+    let members = problem_type
+        .map(|problem_type| quote!("type": #problem_type))
+        .into_iter()
+        .chain(title.map(|title| quote!("title": #title)))
+        .chain(status_code.map(|status_code| quote!("status": #status_code)))
+        .collect::<Vec<_>>();
+    let example = (!members.is_empty()).then(|| quote!(, example = json!({ #(#members),* })));
+
+    let mut response = syn::parse2::<ResponseTuple>(quote! {
+        description = #description,
+        body = fastapi::problem::Problem,
+        content_type = "application/problem+json"
+        #example
+    })?;
+    response.status_code = status;
+
+    Ok(response)
+}
+
 impl Response<'_> {
     pub fn get_component_schemas(
         &self,
@@ -60,15 +140,7 @@ impl Response<'_> {
                         value
                             .content
                             .iter()
-                            .map(
-                                |media_type| match media_type.schema.get_component_schema() {
-                                    Ok(component_schema) => {
-                                        Ok(Some(media_type.schema.is_inline())
-                                            .zip(component_schema))
-                                    }
-                                    Err(error) => Err(error),
-                                },
-                            )
+                            .map(|media_type| media_type.schema.get_component_schemas())
                             .collect::<Result<Vec<_>, Diagnostics>>()?
                             .into_iter()
                             .flatten(),
@@ -77,6 +149,8 @@ impl Response<'_> {
                 _ => Ok(ResponseComponentSchemaIter::Empty),
             },
             Self::IntoResponses(_) => Ok(ResponseComponentSchemaIter::Empty),
+            #[cfg(feature = "infer_responses")]
+            Self::ReturnType(_) => Ok(ResponseComponentSchemaIter::Empty),
         }
     }
 }
@@ -157,6 +231,21 @@ impl<'r> ResponseTuple<'r> {
     }
 }
 
+#[cfg(feature = "client")]
+impl<'r> ResponseTuple<'r> {
+    pub fn status(&self) -> &TokenStream2 {
+        &self.status_code.0
+    }
+
+    /// Get first content of the response if the response is not a reference to `ToResponse` type.
+    pub fn content(&self) -> Option<&MediaTypeAttr<'r>> {
+        match &self.inner {
+            Some(ResponseTupleInner::Value(value)) => value.content.first(),
+            _ => None,
+        }
+    }
+}
+
 #[cfg_attr(feature = "debug", derive(Debug))]
 enum ResponseTupleInner<'r> {
     Value(ResponseValue<'r>),
@@ -166,7 +255,7 @@ enum ResponseTupleInner<'r> {
 impl Parse for ResponseTuple<'_> {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         const EXPECTED_ATTRIBUTES: &str =
-            "status, description, body, content_type, headers, example, examples, response";
+            "status, description, body, content_type, headers, example, examples, response, extensions";
 
         let mut response = ResponseTuple::default();
 
@@ -233,6 +322,7 @@ pub struct ResponseValue<'r> {
     links: Punctuated<LinkTuple, Comma>,
     content: Vec<MediaTypeAttr<'r>>,
     is_content_group: bool,
+    extensions: Option<Extensions>,
 }
 
 impl Parse for ResponseValue<'_> {
@@ -262,7 +352,7 @@ impl Parse for ResponseValue<'_> {
 
 impl<'r> ResponseValue<'r> {
     const EXPECTED_ATTRIBUTES: &'static str =
-        "description, body, content_type, headers, example, examples";
+        "description, body, content_type, headers, example, examples, extensions";
 
     fn parse_named_attributes(&mut self, input: ParseStream, attribute: &Ident) -> syn::Result<()> {
         let attribute_name = &*attribute.to_string();
@@ -322,6 +412,9 @@ impl<'r> ResponseValue<'r> {
             "links" => {
                 self.links = parse_utils::parse_comma_separated_within_parenthesis(input)?;
             }
+            "extensions" => {
+                self.extensions = Some(Extensions::parse_parenthesized(input)?);
+            }
             _ => {
                 MediaTypeAttr::parse_named_attributes(
                     self.content.get_mut(0).expect(
@@ -361,6 +454,7 @@ impl<'r> ResponseValue<'r> {
                 .examples
                 .map(|(examples, _)| examples)
                 .unwrap_or_default(),
+            encoding: Vec::new(),
         };
 
         Self {
@@ -373,6 +467,7 @@ impl<'r> ResponseValue<'r> {
             },
             headers: derive_value.headers,
             content: vec![media_type],
+            extensions: derive_value.extensions,
             ..Default::default()
         }
     }
@@ -390,6 +485,7 @@ impl<'r> ResponseValue<'r> {
                 .examples
                 .map(|(examples, _)| examples)
                 .unwrap_or_default(),
+            encoding: Vec::new(),
         };
 
         ResponseValue {
@@ -402,6 +498,7 @@ impl<'r> ResponseValue<'r> {
             },
             headers: response_value.headers,
             content: vec![media_type],
+            extensions: response_value.extensions,
             ..Default::default()
         }
     }
@@ -460,6 +557,12 @@ impl ToTokensDiagnostics for ResponseTuple<'_> {
                     })
                 }
 
+                if let Some(extensions) = &value.extensions {
+                    tokens.extend(quote! {
+                        .extensions(Some(#extensions))
+                    })
+                }
+
                 tokens.extend(quote! { .build() });
             }
             None => tokens.extend(quote! {
@@ -493,6 +596,7 @@ struct DeriveToResponseValue {
     description: parse_utils::LitStrOrExpr,
     example: Option<(AnyValue, Ident)>,
     examples: Option<(Punctuated<Example, Comma>, Ident)>,
+    extensions: Option<Extensions>,
 }
 
 impl DeriveResponseValue for DeriveToResponseValue {
@@ -512,6 +616,9 @@ impl DeriveResponseValue for DeriveToResponseValue {
         if other.examples.is_some() {
             self.examples = other.examples;
         }
+        if other.extensions.is_some() {
+            self.extensions = other.extensions;
+        }
 
         self
     }
@@ -542,10 +649,13 @@ impl Parse for DeriveToResponseValue {
                 "examples" => {
                     response.examples = Some((parse::examples(input)?, ident));
                 }
+                "extensions" => {
+                    response.extensions = Some(Extensions::parse_parenthesized(input)?);
+                }
                 _ => {
                     return Err(Error::new(
                         ident.span(),
-                        format!("unexpected attribute: {attribute_name}, expected any of: inline, description, content_type, headers, example"),
+                        format!("unexpected attribute: {attribute_name}, expected any of: inline, description, content_type, headers, example, examples, extensions"),
                     ));
                 }
             }
@@ -567,6 +677,7 @@ struct DeriveIntoResponsesValue {
     description: parse_utils::LitStrOrExpr,
     example: Option<(AnyValue, Ident)>,
     examples: Option<(Punctuated<Example, Comma>, Ident)>,
+    extensions: Option<Extensions>,
 }
 
 impl DeriveResponseValue for DeriveIntoResponsesValue {
@@ -588,6 +699,9 @@ impl DeriveResponseValue for DeriveIntoResponsesValue {
         if other.examples.is_some() {
             self.examples = other.examples;
         }
+        if other.extensions.is_some() {
+            self.extensions = other.extensions;
+        }
 
         self
     }
@@ -638,10 +752,13 @@ impl Parse for DeriveIntoResponsesValue {
                 "examples" => {
                     response.examples = Some((parse::examples(input)?, ident));
                 }
+                "extensions" => {
+                    response.extensions = Some(Extensions::parse_parenthesized(input)?);
+                }
                 _ => {
                     return Err(Error::new(
                         ident.span(),
-                        format!("unexpected attribute: {attribute_name}, expected any of: description, content_type, headers, example, examples"),
+                        format!("unexpected attribute: {attribute_name}, expected any of: description, content_type, headers, example, examples, extensions"),
                     ));
                 }
             }
@@ -659,6 +776,17 @@ impl Parse for DeriveIntoResponsesValue {
 #[cfg_attr(feature = "debug", derive(Debug))]
 struct ResponseStatus(TokenStream2);
 
+impl ResponseStatus {
+    /// Get the numeric status code of the response. Status given as integer or as
+    /// `http::StatusCode` constant is resolved to the code while parsing, ranges such as `"4XX"`
+    /// and `"default"` have no code.
+    fn code(&self) -> Option<u16> {
+        syn::parse2::<LitStr>(self.0.clone())
+            .ok()
+            .and_then(|status| status.value().parse::<u16>().ok())
+    }
+}
+
 impl Parse for ResponseStatus {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         fn parse_lit_int(input: ParseStream) -> syn::Result<Cow<'_, str>> {
@@ -748,6 +876,15 @@ impl ToTokensDiagnostics for Responses<'_> {
                             .responses_from_into_responses::<#path>()
                         })
                     }
+                    #[cfg(feature = "infer_responses")]
+                    Response::ReturnType(ty) => Ok(quote! {
+                        .responses_from_iter({
+                            #[allow(unused_imports)]
+                            use fastapi::__dev::{DocumentReturnType as _, SkipReturnType as _};
+                            (&fastapi::__dev::ReturnType::<#ty>(core::marker::PhantomData))
+                                .return_type_responses()
+                        })
+                    }),
                     Response::Tuple(response) => {
                         let code = &response.status_code;
                         let response = crate::as_tokens_or_diagnostics!(response);
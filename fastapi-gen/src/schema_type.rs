@@ -64,6 +64,9 @@ impl SchemaType<'_> {
     /// Check whether type is known to be primitive in which case returns true.
     pub fn is_primitive(&self) -> bool {
         let SchemaType { path, .. } = self;
+        if is_binary_path(path) {
+            return true;
+        }
         let last_segment = match path.segments.last() {
             Some(segment) => segment,
             None => return false,
@@ -205,6 +208,51 @@ fn is_primitive(name: &str) -> bool {
     )
 }
 
+/// Check whether the path is a known binary type which is documented as _`string`_ with
+/// _`binary`_ format. Only qualified paths such as `fastapi::UploadFile` and `bytes::Bytes` are
+/// matched so that user defined types of the same name keep their own schema.
+pub fn is_binary_path(path: &Path) -> bool {
+    let segments = path
+        .segments
+        .iter()
+        .map(|segment| segment.ident.to_string())
+        .collect::<Vec<_>>();
+
+    matches!(
+        segments
+            .iter()
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+            .as_slice(),
+        [.., "fastapi", "UploadFile"]
+            | [.., "bytes", "Bytes"]
+            | [.., "axum", "body", "Bytes"]
+            | [.., "actix_web", "web", "Bytes"]
+    )
+}
+
+/// Check whether the path is a bare known binary type name, `UploadFile` or `Bytes`, which cannot
+/// be told apart from user defined type of the same name. Returns error asking to qualify the
+/// path in that case.
+pub fn check_unqualified_binary_path(path: &Path) -> Result<(), Diagnostics> {
+    let [segment] = path.segments.iter().collect::<Vec<_>>()[..] else {
+        return Ok(());
+    };
+
+    let qualified = match &*segment.ident.to_string() {
+        "UploadFile" => "`fastapi::UploadFile`",
+        "Bytes" => "`bytes::Bytes`, `axum::body::Bytes` or `actix_web::web::Bytes`",
+        _ => return Ok(()),
+    };
+
+    Err(Diagnostics::with_span(
+        path.span(),
+        format!("ambiguous type `{}`, binary types must be used with qualified path", segment.ident),
+    )
+    .help("use qualified path of user defined type of the same name e.g. `crate::UploadFile` to reference its schema")
+    .help(format!("use the qualified path {qualified} to document the type as binary string")))
+}
+
 #[inline]
 #[cfg(feature = "chrono")]
 fn is_primitive_chrono(name: &str) -> bool {
@@ -263,6 +311,10 @@ impl ToTokensDiagnostics for SchemaType<'_> {
             }
             "f32" | "f64" => schema_type_tokens(tokens, SchemaTypeInner::Number, self.nullable),
 
+            "UploadFile" | "Bytes" if is_binary_path(&self.path) => {
+                schema_type_tokens(tokens, SchemaTypeInner::String, self.nullable)
+            }
+
             #[cfg(feature = "chrono")]
             "DateTime" | "NaiveDateTime" | "NaiveDate" | "NaiveTime" => {
                 schema_type_tokens(tokens, SchemaTypeInner::String, self.nullable)
@@ -392,6 +444,7 @@ impl KnownFormat {
             "i64" => Self::Int64,
             "f32" => Self::Float,
             "f64" => Self::Double,
+            "UploadFile" | "Bytes" if is_binary_path(path) => Self::Binary,
 
             #[cfg(feature = "chrono")]
             "NaiveDate" => Self::Date,
@@ -689,6 +742,8 @@ impl PrimitiveType {
             | "u128" | "usize" => syn::parse_quote!(#path),
             "f32" | "f64" => syn::parse_quote!(#path),
 
+            "UploadFile" | "Bytes" if is_binary_path(path) => syn::parse_quote!(String),
+
             #[cfg(feature = "chrono")]
             "DateTime" | "NaiveDateTime" | "NaiveDate" | "NaiveTime" => {
                 syn::parse_quote!(String)
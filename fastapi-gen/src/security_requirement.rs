@@ -13,7 +13,7 @@ use crate::Array;
 #[derive(Default)]
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub struct SecurityRequirementsAttrItem {
-    pub name: Option<String>,
+    pub name: Option<LitStr>,
     pub scopes: Option<Vec<String>>,
 }
 
@@ -21,6 +21,13 @@ pub struct SecurityRequirementsAttrItem {
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub struct SecurityRequirementsAttr(Punctuated<SecurityRequirementsAttrItem, Comma>);
 
+impl SecurityRequirementsAttr {
+    /// Names of the security schemes referenced by this requirement.
+    pub fn names(&self) -> impl Iterator<Item = &LitStr> {
+        self.0.iter().filter_map(|item| item.name.as_ref())
+    }
+}
+
 impl Parse for SecurityRequirementsAttr {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         Punctuated::<SecurityRequirementsAttrItem, Comma>::parse_terminated(input)
@@ -30,7 +37,7 @@ impl Parse for SecurityRequirementsAttr {
 
 impl Parse for SecurityRequirementsAttrItem {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        let name = input.parse::<LitStr>()?.value();
+        let name = input.parse::<LitStr>()?;
 
         input.parse::<Token![=]>()?;
 
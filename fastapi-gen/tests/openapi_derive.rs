@@ -114,6 +114,203 @@ fn derive_openapi_tags_with_const_name() {
     }
 }
 
+#[test]
+fn derive_openapi_with_security_schemes() {
+    #[fastapi::path(
+        get,
+        path = "/items",
+        responses((status = 200, description = "Items")),
+        security((), ("bearer" = []), ("oauth" = ["read:items"]))
+    )]
+    #[allow(unused)]
+    fn get_items() {}
+
+    #[derive(OpenApi)]
+    #[openapi(
+        paths(get_items),
+        components(security_schemes(
+            ("bearer" = Http(scheme = Bearer, bearer_format = "JWT")),
+            ("basic" = Http(scheme = Basic, description = "basic auth")),
+            ("api_key" = ApiKey(Header("x-api-key"))),
+            ("query_key" = ApiKey(Query("api_key"), description = "key in query")),
+            ("cookie_key" = ApiKey(Cookie("session"))),
+            ("oidc" = OpenIdConnect(url = "https://localhost/.well-known/openid-configuration")),
+            ("mtls" = MutualTls(description = "client certificate")),
+            ("oauth" = OAuth2(
+                flows(
+                    Implicit(
+                        authorization_url = "https://localhost/authorize",
+                        scopes("read:items" = "read items", "edit:items" = "edit items")
+                    ),
+                    Password(token_url = "https://localhost/token", refresh_url = "https://localhost/refresh"),
+                    ClientCredentials(token_url = "https://localhost/token"),
+                    AuthorizationCode(
+                        authorization_url = "https://localhost/authorize",
+                        token_url = "https://localhost/token",
+                        scopes("read:items" = "read items")
+                    ),
+                ),
+                description = "oauth flows"
+            )),
+        )),
+        security(("api_key" = []))
+    )]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+
+    assert_json_eq!(
+        doc.pointer("/components/securitySchemes"),
+        json!({
+            "bearer": {
+                "type": "http",
+                "scheme": "bearer",
+                "bearerFormat": "JWT"
+            },
+            "basic": {
+                "type": "http",
+                "scheme": "basic",
+                "description": "basic auth"
+            },
+            "api_key": {
+                "type": "apiKey",
+                "in": "header",
+                "name": "x-api-key"
+            },
+            "query_key": {
+                "type": "apiKey",
+                "in": "query",
+                "name": "api_key",
+                "description": "key in query"
+            },
+            "cookie_key": {
+                "type": "apiKey",
+                "in": "cookie",
+                "name": "session"
+            },
+            "oidc": {
+                "type": "openIdConnect",
+                "openIdConnectUrl": "https://localhost/.well-known/openid-configuration"
+            },
+            "mtls": {
+                "type": "mutualTLS",
+                "description": "client certificate"
+            },
+            "oauth": {
+                "type": "oauth2",
+                "description": "oauth flows",
+                "flows": {
+                    "implicit": {
+                        "authorizationUrl": "https://localhost/authorize",
+                        "scopes": {
+                            "read:items": "read items",
+                            "edit:items": "edit items"
+                        }
+                    },
+                    "password": {
+                        "tokenUrl": "https://localhost/token",
+                        "refreshUrl": "https://localhost/refresh",
+                        "scopes": {}
+                    },
+                    "clientCredentials": {
+                        "tokenUrl": "https://localhost/token",
+                        "scopes": {}
+                    },
+                    "authorizationCode": {
+                        "authorizationUrl": "https://localhost/authorize",
+                        "tokenUrl": "https://localhost/token",
+                        "scopes": {
+                            "read:items": "read items"
+                        }
+                    }
+                }
+            }
+        })
+    );
+    assert_json_eq!(doc.pointer("/security"), json!([{"api_key": []}]));
+}
+
+#[test]
+fn derive_openapi_with_all_http_auth_schemes() {
+    #[derive(OpenApi)]
+    #[openapi(components(security_schemes(
+        ("basic" = Http(scheme = Basic)),
+        ("bearer" = Http(scheme = Bearer)),
+        ("digest" = Http(scheme = Digest)),
+        ("hoba" = Http(scheme = Hoba)),
+        ("mutual" = Http(scheme = Mutual)),
+        ("negotiate" = Http(scheme = Negotiate)),
+        ("oauth" = Http(scheme = OAuth)),
+        ("scram_sha1" = Http(scheme = ScramSha1)),
+        ("scram_sha256" = Http(scheme = ScramSha256)),
+        ("vapid" = Http(scheme = Vapid)),
+    )))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let schemes = doc
+        .pointer("/components/securitySchemes")
+        .and_then(Value::as_object)
+        .expect("should have security schemes")
+        .iter()
+        .map(|(name, scheme)| (name.as_str(), scheme.pointer("/scheme").unwrap().clone()))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        schemes,
+        vec![
+            ("basic", json!("basic")),
+            ("bearer", json!("bearer")),
+            ("digest", json!("digest")),
+            ("hoba", json!("hoba")),
+            ("mutual", json!("mutual")),
+            ("negotiate", json!("negotiate")),
+            ("oauth", json!("oauth")),
+            ("scram_sha1", json!("scram-sha-1")),
+            ("scram_sha256", json!("scram-sha-256")),
+            ("vapid", json!("vapid")),
+        ]
+    );
+}
+
+#[test]
+fn derive_openapi_tags_with_extensions() {
+    #[derive(OpenApi)]
+    #[openapi(tags(
+        (name = "pets::api", extensions(("x-display-name" = json!("Pets")), ("x-traits" = json!(["paged"])))),
+    ))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+
+    assert_json_eq!(
+        doc.pointer("/tags/0"),
+        json!({
+            "name": "pets::api",
+            "x-display-name": "Pets",
+            "x-traits": ["paged"]
+        })
+    );
+}
+
+#[test]
+fn derive_openapi_info_with_extensions() {
+    #[derive(OpenApi)]
+    #[openapi(info(
+        title = "title override",
+        extensions(("x-logo" = json!({"url": "https://localhost/logo.png"})))
+    ))]
+    struct ApiDoc;
+
+    let value = serde_json::to_value(ApiDoc::openapi()).unwrap();
+
+    assert_json_eq!(
+        value.pointer("/info/x-logo"),
+        json!({"url": "https://localhost/logo.png"})
+    );
+    assert_json_eq!(value.pointer("/info/title"), json!("title override"));
+}
+
 #[test]
 fn derive_openapi_with_external_docs() {
     #[derive(OpenApi)]
@@ -553,6 +750,136 @@ fn derive_nest_openapi_with_tags() {
     )
 }
 
+#[test]
+fn derive_nest_openapi_with_options() {
+    mod admin_api {
+        #[fastapi::path(get, path = "/users")]
+        #[allow(dead_code)]
+        fn list_users() {}
+
+        #[fastapi::path(delete, path = "/users", security(()), responses(
+            (status = 403, description = "Cannot delete users")
+        ))]
+        #[allow(dead_code)]
+        fn delete_users() {}
+
+        #[derive(super::OpenApi)]
+        #[openapi(paths(list_users, delete_users))]
+        pub(super) struct AdminApi;
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(nest(
+        (
+            path = "/admin",
+            api = admin_api::AdminApi,
+            tags = ["admin"],
+            security(("admin_auth" = [])),
+            params(("X-Tenant" = String, Header, description = "Tenant of the request")),
+            responses((status = 403, description = "Forbidden")),
+            servers((url = "/admin-server"))
+        )
+    ))]
+    struct ApiDoc;
+
+    let api = serde_json::to_value(ApiDoc::openapi()).expect("should serialize to value");
+    let paths = api.pointer("/paths");
+
+    assert_json_eq!(
+        paths,
+        json!({
+            "/admin/users": {
+                "servers": [{ "url": "/admin-server" }],
+                "parameters": [{
+                    "name": "X-Tenant",
+                    "in": "header",
+                    "required": true,
+                    "description": "Tenant of the request",
+                    "schema": {
+                        "type": "string"
+                    }
+                }],
+                "get": {
+                    "operationId": "list_users",
+                    "responses": {
+                        "403": { "description": "Forbidden" }
+                    },
+                    "security": [{ "admin_auth": [] }],
+                    "tags": [ "admin" ]
+                },
+                "delete": {
+                    "operationId": "delete_users",
+                    "responses": {
+                        "403": { "description": "Cannot delete users" }
+                    },
+                    "security": [{}],
+                    "tags": [ "admin" ]
+                }
+            }
+        })
+    )
+}
+
+#[test]
+fn derive_nest_openapi_with_options_collects_schemas() {
+    #![allow(dead_code)]
+    use fastapi::ToSchema;
+
+    #[derive(ToSchema)]
+    struct AdminError {
+        message: String,
+    }
+
+    #[derive(ToSchema)]
+    enum Tenant {
+        Internal,
+        External,
+    }
+
+    mod admin_api {
+        #[fastapi::path(get, path = "/users")]
+        fn list_users() {}
+
+        #[derive(super::OpenApi)]
+        #[openapi(paths(list_users))]
+        pub(super) struct AdminApi;
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(nest(
+        (
+            path = "/admin",
+            api = admin_api::AdminApi,
+            params(("X-Tenant" = Tenant, Header)),
+            responses((status = 403, body = AdminError))
+        )
+    ))]
+    struct ApiDoc;
+
+    let api = serde_json::to_value(ApiDoc::openapi()).expect("should serialize to value");
+
+    assert_json_eq!(
+        api.pointer("/paths/~1admin~1users/get/responses/403/content/application~1json/schema"),
+        json!({ "$ref": "#/components/schemas/AdminError" })
+    );
+    assert_json_eq!(
+        api.pointer("/components/schemas"),
+        json!({
+            "AdminError": {
+                "type": "object",
+                "required": ["message"],
+                "properties": {
+                    "message": { "type": "string" }
+                }
+            },
+            "Tenant": {
+                "type": "string",
+                "enum": ["Internal", "External"]
+            }
+        })
+    )
+}
+
 #[test]
 fn openapi_schemas_resolve_generic_enum_schema() {
     #![allow(dead_code)]
@@ -769,3 +1096,55 @@ fn openapi_resolvle_recursive_references() {
         })
     )
 }
+
+#[test]
+fn derive_openapi_with_external_security_scheme_added_by_modify() {
+    use fastapi::openapi::security::{ApiKey, ApiKeyValue, SecurityScheme};
+    use fastapi::Modify;
+
+    struct ApiKeyModifier;
+
+    impl Modify for ApiKeyModifier {
+        fn modify(&self, openapi: &mut fastapi::openapi::OpenApi) {
+            openapi
+                .components
+                .get_or_insert_with(Default::default)
+                .add_security_scheme(
+                    "api_key",
+                    SecurityScheme::ApiKey(ApiKey::Header(ApiKeyValue::new("x-api-key"))),
+                );
+        }
+    }
+
+    #[fastapi::path(get, path = "/items", security(("api_key" = []), ("bearer" = [])))]
+    #[allow(unused)]
+    fn get_items() {}
+
+    #[derive(OpenApi)]
+    #[openapi(
+        paths(get_items),
+        components(security_schemes(
+            ("bearer" = Http(scheme = Bearer)),
+            ("api_key" = External)
+        )),
+        modifiers(&ApiKeyModifier)
+    )]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+
+    assert_json_eq!(
+        doc.pointer("/components/securitySchemes").unwrap(),
+        json!({
+            "api_key": {
+                "type": "apiKey",
+                "in": "header",
+                "name": "x-api-key"
+            },
+            "bearer": {
+                "type": "http",
+                "scheme": "bearer"
+            }
+        })
+    );
+}
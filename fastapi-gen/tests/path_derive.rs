@@ -287,6 +287,39 @@ fn derive_path_with_security_requirements() {
     }
 }
 
+#[test]
+fn derive_path_with_extensions() {
+    #[fastapi::path(
+        post,
+        path = "/items",
+        request_body = String,
+        responses(
+            (status = 200, description = "success response", extensions(("x-cache" = json!(true))))
+        ),
+        extensions(
+            ("x-codegen-request-body-name" = json!("item")),
+            ("x-amazon-apigateway-integration" = json!({"type": "http_proxy", "httpMethod": "POST"}))
+        )
+    )]
+    #[allow(unused)]
+    fn post_item() {}
+    let operation = test_api_fn_doc! {
+        post_item,
+        operation: post,
+        path: "/items"
+    };
+
+    assert_json_eq!(
+        operation.pointer("/x-codegen-request-body-name"),
+        json!("item")
+    );
+    assert_json_eq!(
+        operation.pointer("/x-amazon-apigateway-integration"),
+        json!({"type": "http_proxy", "httpMethod": "POST"})
+    );
+    assert_json_eq!(operation.pointer("/responses/200/x-cache"), json!(true));
+}
+
 #[test]
 fn derive_path_with_datetime_format_query_parameter() {
     #[derive(serde::Deserialize, fastapi::ToSchema)]
@@ -1791,6 +1824,43 @@ fn derive_into_params_required() {
     )
 }
 
+#[test]
+fn derive_into_params_with_extensions() {
+    #[derive(IntoParams)]
+    #[into_params(parameter_in = Query)]
+    #[allow(unused)]
+    struct Params {
+        #[param(extensions(("x-example-source" = json!("catalog"))))]
+        name: String,
+    }
+
+    #[fastapi::path(get, path = "/params", params(Params))]
+    #[allow(unused)]
+    fn get_params() {}
+    let operation = test_api_fn_doc! {
+        get_params,
+        operation: get,
+        path: "/params"
+    };
+
+    let value = operation.pointer("/parameters");
+
+    assert_json_eq!(
+        value,
+        json!([
+          {
+              "in": "query",
+              "name": "name",
+              "required": true,
+              "schema": {
+                  "type": "string",
+              },
+              "x-example-source": "catalog",
+          },
+        ])
+    )
+}
+
 #[test]
 fn derive_into_params_with_serde_skip() {
     #[derive(IntoParams, Serialize)]
@@ -3167,3 +3237,44 @@ fn derive_lifetime_generic_request_body_compiles() {
     let _ = serde_json::to_value(__path_test_const_generic::operation())
         .expect("Operation is JSON serializable");
 }
+
+#[test]
+fn derive_path_for_generic_handler_substitutes_type() {
+    #![allow(unused)]
+
+    #[derive(ToSchema)]
+    struct User {
+        id: i32,
+    }
+
+    #[fastapi::path(
+        get,
+        path = "/items",
+        responses((status = 200, body = Vec<T>))
+    )]
+    async fn list<T: ToSchema + 'static>() -> String {
+        T::name().into_owned()
+    }
+
+    let operation = serde_json::to_value(__path_list::<User>::operation())
+        .expect("Operation is JSON serializable");
+    let mut schemas = Vec::new();
+    <__path_list<User> as fastapi::__dev::SchemaReferences>::schemas(&mut schemas);
+
+    assert_json_eq!(
+        operation.pointer("/responses/200/content/application~1json/schema"),
+        json!({
+            "type": "array",
+            "items": {
+                "$ref": "#/components/schemas/User"
+            }
+        })
+    );
+    assert_eq!(
+        schemas
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .collect::<Vec<_>>(),
+        ["User"]
+    );
+}
@@ -1159,3 +1159,79 @@ fn derive_path_with_multiple_methods_skip_connect() {
         __path_multiple_methods::methods()
     )
 }
+
+#[test]
+fn derive_path_with_typed_header_and_either_request_body() {
+    use actix_web::http::header::{ContentLanguage, IfMatch};
+    use actix_web::web::{Either, Form, Header};
+
+    #[derive(Deserialize, ToSchema)]
+    struct Item {
+        #[allow(unused)]
+        name: String,
+    }
+
+    #[fastapi::path(responses((status = 200, description = "success response")))]
+    #[post("/items/{id}")]
+    #[allow(unused)]
+    async fn create_item(
+        id: Path<i32>,
+        language: Header<ContentLanguage>,
+        if_match: Option<Header<IfMatch>>,
+        item: Either<Json<Item>, Form<Item>>,
+    ) -> impl Responder {
+        String::new()
+    }
+
+    let operation =
+        serde_json::to_value(<__path_create_item as fastapi::Path>::operation()).unwrap();
+
+    assert_json_eq!(
+        operation.pointer("/parameters"),
+        json!([
+            {
+                "in": "path",
+                "name": "id",
+                "required": true,
+                "schema": {
+                    "format": "int32",
+                    "type": "integer"
+                }
+            },
+            {
+                "in": "header",
+                "name": "content-language",
+                "required": true,
+                "schema": {
+                    "type": "string"
+                }
+            },
+            {
+                "in": "header",
+                "name": "if-match",
+                "required": false,
+                "schema": {
+                    "type": ["string", "null"]
+                }
+            }
+        ])
+    );
+    assert_json_eq!(
+        operation.pointer("/requestBody"),
+        json!({
+            "content": {
+                "application/json": {
+                    "schema": {
+                        "$ref": "#/components/schemas/Item"
+                    }
+                },
+                "application/x-www-form-urlencoded": {
+                    "schema": {
+                        "$ref": "#/components/schemas/Item"
+                    }
+                }
+            },
+            "required": true
+        })
+    )
+}
@@ -1,4 +1,4 @@
-#![cfg(feature = "auto_into_responses")]
+#![cfg(feature = "infer_responses")]
 
 use assert_json_diff::assert_json_eq;
 use fastapi::OpenApi;
@@ -1,4 +1,4 @@
-#![cfg(all(feature = "auto_into_responses", feature = "actix_extras"))]
+#![cfg(all(feature = "infer_responses", feature = "actix_extras"))]
 
 use actix_web::web::{Form, Json};
 use fastapi::OpenApi;
@@ -172,7 +172,6 @@ fn path_operation_auto_types_fn_parameters() {
                     }
                 }
             },
-            "description": "",
             "required": true,
         })
     )
@@ -255,17 +254,10 @@ fn path_operation_optional_json_body() {
             "content": {
                 "application/json": {
                     "schema": {
-                        "allOf": [
-                            {
-                                "$ref": "#/components/schemas/ItemBody"
-                            }
-                        ],
-                        "nullable": true,
+                        "$ref": "#/components/schemas/ItemBody"
                     }
                 }
             },
-            "description": "",
-            "required": false,
         })
     )
 }
@@ -324,21 +316,25 @@ fn path_operation_auto_types_tuple() {
             "content": {
                 "application/json": {
                     "schema": {
-                        "items": {
-                            "allOf": [
-                                {
-                                    "$ref": "#/components/schemas/ItemBody"
+                        "items": false,
+                        "prefixItems": [
+                            {
+                                "properties": {
+                                    "value": {
+                                        "type": "string"
+                                    }
                                 },
-                                {
-                                    "type": "string"
-                                }
-                            ]
-                        },
+                                "required": ["value"],
+                                "type": "object"
+                            },
+                            {
+                                "type": "string"
+                            }
+                        ],
                         "type": "array",
                     }
                 }
             },
-            "description": "",
             "required": true,
         })
     )
@@ -403,7 +399,6 @@ fn path_operation_request_body_bytes() {
                     }
                 }
             },
-            "description": "",
             "required": true,
         })
     )
@@ -467,8 +462,69 @@ fn path_operation_request_body_form() {
                     }
                 }
             },
-            "description": "",
             "required": true,
         })
     )
 }
+
+#[test]
+fn path_operation_inferred_responses() {
+    use actix_web::http::StatusCode;
+    use actix_web::web::Path;
+
+    #[derive(serde::Serialize, serde::Deserialize, fastapi::ToSchema)]
+    struct Pet {
+        id: u64,
+    }
+
+    #[fastapi::path]
+    #[get("/pets/{id}")]
+    #[allow(unused)]
+    async fn get_pet(id: Path<u64>) -> Option<Json<Pet>> {
+        Some(Json(Pet {
+            id: id.into_inner(),
+        }))
+    }
+
+    #[fastapi::path]
+    #[post("/pets")]
+    #[allow(unused)]
+    async fn create_pet(pet: Json<Pet>) -> Result<(Json<Pet>, StatusCode), actix_web::Error> {
+        Ok((pet, StatusCode::CREATED))
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(paths(get_pet, create_pet))]
+    struct ApiDoc;
+
+    let doc = ApiDoc::openapi();
+    let value = serde_json::to_value(&doc).unwrap();
+    let pet = |description: &str| {
+        serde_json::json!({
+            "content": {
+                "application/json": {
+                    "schema": {
+                        "$ref": "#/components/schemas/Pet"
+                    }
+                }
+            },
+            "description": description
+        })
+    };
+
+    assert_json_eq!(
+        value.pointer("/paths/~1pets~1{id}/get/responses").unwrap(),
+        serde_json::json!({
+            "200": pet("OK"),
+            "404": {
+                "description": "Not Found"
+            }
+        })
+    );
+    assert_json_eq!(
+        value.pointer("/paths/~1pets/post/responses").unwrap(),
+        serde_json::json!({
+            "201": pet("Created")
+        })
+    );
+}
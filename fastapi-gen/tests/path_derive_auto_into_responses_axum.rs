@@ -1,4 +1,4 @@
-#![cfg(all(feature = "auto_into_responses", feature = "axum_extras"))]
+#![cfg(all(feature = "infer_responses", feature = "axum_extras"))]
 
 use assert_json_diff::assert_json_eq;
 use fastapi::OpenApi;
@@ -55,3 +55,283 @@ fn path_operation_auto_types_responses() {
         })
     )
 }
+
+#[test]
+fn path_operation_auto_types_server_sent_events() {
+    use std::convert::Infallible;
+
+    use axum::response::sse::{Event, Sse};
+    use futures_util::stream::{self, Stream};
+
+    #[fastapi::path(get, path = "/events")]
+    #[allow(unused)]
+    async fn events() -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+        Sse::new(stream::empty())
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(paths(events))]
+    struct ApiDoc;
+
+    let doc = ApiDoc::openapi();
+    let value = serde_json::to_value(&doc).unwrap();
+    let path = value.pointer("/paths/~1events/get").unwrap();
+
+    assert_json_eq!(
+        &path.pointer("/responses").unwrap(),
+        serde_json::json!({
+            "200": {
+                "content": {
+                    "text/event-stream": {
+                        "itemSchema": {
+                            "properties": {
+                                "data": {
+                                    "type": "string"
+                                },
+                                "event": {
+                                    "type": "string"
+                                },
+                                "id": {
+                                    "type": "string"
+                                },
+                                "retry": {
+                                    "minimum": 0,
+                                    "type": "integer"
+                                }
+                            },
+                            "required": ["data"],
+                            "type": "object"
+                        }
+                    }
+                },
+                "description": "OK"
+            }
+        })
+    )
+}
+
+#[test]
+fn path_operation_inferred_json_and_option_responses() {
+    use axum::extract::Path;
+    use axum::Json;
+
+    #[derive(serde::Serialize, fastapi::ToSchema)]
+    struct Pet {
+        id: u64,
+    }
+
+    #[fastapi::path(get, path = "/pets")]
+    #[allow(unused)]
+    async fn list_pets() -> Json<Vec<Pet>> {
+        Json(Vec::new())
+    }
+
+    #[fastapi::path(get, path = "/pets/{id}")]
+    #[allow(unused)]
+    async fn get_pet(Path(id): Path<u64>) -> Option<Json<Pet>> {
+        Some(Json(Pet { id }))
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(paths(list_pets, get_pet))]
+    struct ApiDoc;
+
+    let doc = ApiDoc::openapi();
+    let value = serde_json::to_value(&doc).unwrap();
+
+    assert_json_eq!(
+        value.pointer("/paths/~1pets/get/responses").unwrap(),
+        serde_json::json!({
+            "200": {
+                "content": {
+                    "application/json": {
+                        "schema": {
+                            "items": {
+                                "$ref": "#/components/schemas/Pet"
+                            },
+                            "type": "array"
+                        }
+                    }
+                },
+                "description": "OK"
+            }
+        })
+    );
+    assert_json_eq!(
+        value.pointer("/paths/~1pets~1{id}/get/responses").unwrap(),
+        serde_json::json!({
+            "200": {
+                "content": {
+                    "application/json": {
+                        "schema": {
+                            "$ref": "#/components/schemas/Pet"
+                        }
+                    }
+                },
+                "description": "OK"
+            },
+            "404": {
+                "description": "Not Found"
+            }
+        })
+    );
+}
+
+#[test]
+fn path_operation_inferred_status_code_tuple_responses() {
+    use axum::http::StatusCode;
+    use axum::Json;
+
+    #[derive(serde::Serialize, serde::Deserialize, fastapi::ToSchema)]
+    struct Pet {
+        name: String,
+    }
+
+    /// Pet store error
+    #[derive(fastapi::IntoResponses)]
+    #[allow(unused)]
+    enum PetError {
+        /// Pet already exists
+        #[response(status = CONFLICT)]
+        Conflict(String),
+    }
+
+    impl axum::response::IntoResponse for PetError {
+        fn into_response(self) -> axum::response::Response {
+            StatusCode::CONFLICT.into_response()
+        }
+    }
+
+    #[fastapi::path(post, path = "/pets")]
+    #[allow(unused)]
+    async fn create_pet(Json(pet): Json<Pet>) -> Result<(StatusCode, Json<Pet>), PetError> {
+        if pet.name.is_empty() {
+            return Err(PetError::Conflict(pet.name));
+        }
+        Ok((StatusCode::CREATED, Json(pet)))
+    }
+
+    #[fastapi::path(
+        put,
+        path = "/pets",
+        responses(
+            (status = 400, description = "Invalid pet name")
+        )
+    )]
+    #[allow(unused)]
+    async fn update_pet(Json(pet): Json<Pet>) -> Result<Json<Pet>, (StatusCode, String)> {
+        let name = pet
+            .name
+            .parse::<u8>()
+            .map_err(|error| (StatusCode::BAD_REQUEST, error.to_string()))?;
+        if name == 0 {
+            return Err((StatusCode::NOT_FOUND, "no pet".to_string()));
+        }
+        Ok(Json(pet))
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(paths(create_pet, update_pet))]
+    struct ApiDoc;
+
+    let doc = ApiDoc::openapi();
+    let value = serde_json::to_value(&doc).unwrap();
+
+    assert_json_eq!(
+        value.pointer("/paths/~1pets/post/responses").unwrap(),
+        serde_json::json!({
+            "201": {
+                "content": {
+                    "application/json": {
+                        "schema": {
+                            "$ref": "#/components/schemas/Pet"
+                        }
+                    }
+                },
+                "description": "Created"
+            },
+            "409": {
+                "content": {
+                    "text/plain": {
+                        "schema": {
+                            "type": "string"
+                        }
+                    }
+                },
+                "description": "Pet already exists"
+            }
+        })
+    );
+    assert_json_eq!(
+        value.pointer("/paths/~1pets/put/responses").unwrap(),
+        serde_json::json!({
+            "200": {
+                "content": {
+                    "application/json": {
+                        "schema": {
+                            "$ref": "#/components/schemas/Pet"
+                        }
+                    }
+                },
+                "description": "OK"
+            },
+            "400": {
+                "description": "Invalid pet name"
+            },
+            "404": {
+                "content": {
+                    "text/plain": {
+                        "schema": {
+                            "type": "string"
+                        }
+                    }
+                },
+                "description": "Not Found"
+            }
+        })
+    );
+}
+
+#[test]
+fn path_operation_inferred_status_codes_only_from_returned_expressions() {
+    use axum::http::StatusCode;
+    use axum::Json;
+
+    #[derive(serde::Serialize, serde::Deserialize, fastapi::ToSchema)]
+    struct Pet {
+        name: String,
+    }
+
+    #[fastapi::path(post, path = "/pets")]
+    #[allow(unused)]
+    async fn create_pet(Json(pet): Json<Pet>) -> (StatusCode, Json<Pet>) {
+        fn conflict() -> (StatusCode, &'static str) {
+            (StatusCode::CONFLICT, "conflict")
+        }
+        let fallback = (StatusCode::IM_A_TEAPOT, "teapot");
+        let retry = || (StatusCode::SERVICE_UNAVAILABLE, "retry later");
+
+        if pet.name.is_empty() {
+            return (StatusCode::ACCEPTED, Json(pet));
+        }
+        (StatusCode::CREATED, Json(pet))
+    }
+
+    #[derive(OpenApi)]
+    #[openapi(paths(create_pet))]
+    struct ApiDoc;
+
+    let doc = ApiDoc::openapi();
+    let value = serde_json::to_value(&doc).unwrap();
+    let mut statuses = value
+        .pointer("/paths/~1pets/post/responses")
+        .unwrap()
+        .as_object()
+        .unwrap()
+        .keys()
+        .map(String::as_str)
+        .collect::<Vec<_>>();
+    statuses.sort_unstable();
+
+    assert_eq!(statuses, ["201", "202"]);
+}
@@ -459,6 +459,65 @@ fn path_with_path_query_body_resolved() {
     )
 }
 
+#[test]
+fn path_with_multipart_request_body_resolved() {
+    #[fastapi::path(path = "/item/{id}", post)]
+    #[allow(unused)]
+    async fn upload_item(_path: Path<i32>, _multipart: axum::extract::Multipart) {}
+
+    #[derive(fastapi::OpenApi)]
+    #[openapi(paths(upload_item))]
+    struct Doc;
+
+    let doc = serde_json::to_value(Doc::openapi()).unwrap();
+    let operation = doc.pointer("/paths/~1item~1{id}/post").unwrap();
+
+    assert_json_eq!(
+        &operation.pointer("/requestBody"),
+        json!({
+            "content": {
+                "multipart/form-data": {
+                    "schema": {
+                        "type": "object"
+                    }
+                }
+            },
+            "required": true,
+        })
+    )
+}
+
+#[test]
+fn path_with_bytes_request_body_resolved() {
+    use axum::body::Bytes;
+
+    #[fastapi::path(path = "/item/{id}", post)]
+    #[allow(unused)]
+    async fn upload_item(_path: Path<i32>, _body: Bytes) {}
+
+    #[derive(fastapi::OpenApi)]
+    #[openapi(paths(upload_item))]
+    struct Doc;
+
+    let doc = serde_json::to_value(Doc::openapi()).unwrap();
+    let operation = doc.pointer("/paths/~1item~1{id}/post").unwrap();
+
+    assert_json_eq!(
+        &operation.pointer("/requestBody"),
+        json!({
+            "content": {
+                "application/octet-stream": {
+                    "schema": {
+                        "type": "string",
+                        "format": "binary"
+                    }
+                }
+            },
+            "required": true,
+        })
+    )
+}
+
 #[test]
 fn test_into_params_for_option_query_type() {
     #[fastapi::path(
@@ -807,3 +866,262 @@ fn path_derive_inline_with_tuple() {
         })
     )
 }
+
+#[test]
+fn path_with_typed_headers_and_query_map() {
+    use std::collections::HashMap;
+
+    use axum_extra::headers::{IfMatch, UserAgent};
+    use axum_extra::TypedHeader;
+
+    #[fastapi::path(get, path = "/items/{id}", responses((status = 200)))]
+    #[allow(unused)]
+    async fn get_item(
+        id: Path<u32>,
+        TypedHeader(user_agent): TypedHeader<UserAgent>,
+        if_match: Option<TypedHeader<IfMatch>>,
+        Query(filters): Query<HashMap<String, String>>,
+    ) {
+    }
+
+    let operation = serde_json::to_value(<__path_get_item as fastapi::Path>::operation()).unwrap();
+
+    assert_json_eq!(
+        operation.pointer("/parameters"),
+        json!([
+            {
+                "in": "path",
+                "name": "id",
+                "required": true,
+                "schema": {
+                    "format": "int32",
+                    "type": "integer",
+                    "minimum": 0
+                }
+            },
+            {
+                "in": "header",
+                "name": "user-agent",
+                "required": true,
+                "schema": {
+                    "type": "string"
+                }
+            },
+            {
+                "in": "header",
+                "name": "if-match",
+                "required": false,
+                "schema": {
+                    "type": ["string", "null"]
+                }
+            },
+            {
+                "in": "query",
+                "name": "filters",
+                "required": false,
+                "style": "form",
+                "explode": true,
+                "schema": {
+                    "type": "object",
+                    "additionalProperties": {
+                        "type": "string"
+                    },
+                    "propertyNames": {
+                        "type": "string"
+                    }
+                }
+            }
+        ])
+    )
+}
+
+#[test]
+fn path_with_typed_header_named_by_header_trait() {
+    use axum::http::{HeaderName, HeaderValue};
+    use axum_extra::headers::{self, Header};
+    use axum_extra::TypedHeader;
+
+    struct RequestId(String);
+
+    impl Header for RequestId {
+        fn name() -> &'static HeaderName {
+            static NAME: HeaderName = HeaderName::from_static("x-request-id");
+            &NAME
+        }
+
+        fn decode<'i, I: Iterator<Item = &'i HeaderValue>>(
+            values: &mut I,
+        ) -> Result<Self, headers::Error> {
+            values
+                .next()
+                .and_then(|value| value.to_str().ok())
+                .map(|value| RequestId(value.to_string()))
+                .ok_or_else(headers::Error::invalid)
+        }
+
+        fn encode<E: Extend<HeaderValue>>(&self, values: &mut E) {
+            values.extend(HeaderValue::from_str(&self.0).ok());
+        }
+    }
+
+    #[fastapi::path(get, path = "/items", responses((status = 200)))]
+    #[allow(unused)]
+    async fn get_items(request_id: TypedHeader<RequestId>) {}
+
+    let operation = serde_json::to_value(<__path_get_items as fastapi::Path>::operation()).unwrap();
+
+    assert_json_eq!(
+        operation.pointer("/parameters"),
+        json!([
+            {
+                "in": "header",
+                "name": "x-request-id",
+                "required": true,
+                "schema": {
+                    "type": "string"
+                }
+            }
+        ])
+    )
+}
+
+#[test]
+fn path_with_custom_extractor() {
+    use fastapi::openapi::path::{Parameter, ParameterBuilder, ParameterIn};
+    use fastapi::openapi::security::SecurityRequirement;
+    use fastapi::openapi::{ObjectBuilder, Required, Type};
+
+    #[allow(unused)]
+    struct ApiKey(String);
+
+    impl fastapi::FastapiExtractor for ApiKey {
+        fn parameters() -> Vec<Parameter> {
+            vec![ParameterBuilder::new()
+                .name("x-api-key")
+                .parameter_in(ParameterIn::Header)
+                .required(Required::True)
+                .schema(Some(ObjectBuilder::new().schema_type(Type::String)))
+                .build()]
+        }
+
+        fn security() -> Vec<SecurityRequirement> {
+            vec![SecurityRequirement::new::<_, _, &str>("api_key", [])]
+        }
+    }
+
+    #[fastapi::path(get, path = "/items", responses((status = 200)))]
+    #[allow(unused)]
+    async fn get_items(_: ApiKey, Extension(state): Extension<Arc<Mutex<String>>>) {}
+
+    let operation = serde_json::to_value(<__path_get_items as fastapi::Path>::operation()).unwrap();
+
+    assert_json_eq!(
+        operation,
+        json!({
+            "operationId": "get_items",
+            "parameters": [
+                {
+                    "in": "header",
+                    "name": "x-api-key",
+                    "required": true,
+                    "schema": {
+                        "type": "string"
+                    }
+                }
+            ],
+            "responses": {
+                "200": {
+                    "description": ""
+                }
+            },
+            "security": [
+                {
+                    "api_key": []
+                }
+            ]
+        })
+    )
+}
+
+#[test]
+fn path_with_custom_extractor_security_required_with_operation_security() {
+    use fastapi::openapi::security::SecurityRequirement;
+
+    #[allow(unused)]
+    struct ApiKey(String);
+
+    impl fastapi::FastapiExtractor for ApiKey {
+        fn security() -> Vec<SecurityRequirement> {
+            vec![SecurityRequirement::new::<_, _, &str>("api_key", [])]
+        }
+    }
+
+    #[fastapi::path(
+        get,
+        path = "/items",
+        responses((status = 200)),
+        security(("oauth" = ["read:items"]), ("basic" = []))
+    )]
+    #[allow(unused)]
+    async fn get_items(_: ApiKey) {}
+
+    let operation = serde_json::to_value(<__path_get_items as fastapi::Path>::operation()).unwrap();
+
+    assert_json_eq!(
+        operation.pointer("/security"),
+        json!([
+            {
+                "api_key": [],
+                "oauth": ["read:items"]
+            },
+            {
+                "api_key": [],
+                "basic": []
+            }
+        ])
+    )
+}
+
+#[test]
+fn path_with_handler_generic_over_state() {
+    use axum::extract::State;
+    use fastapi::ToSchema;
+
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    struct Item {
+        id: i32,
+    }
+
+    #[fastapi::path(get, path = "/items", responses((status = 200, body = Vec<T>)))]
+    #[allow(unused)]
+    async fn list_items<S, T>(State(_state): State<S>) -> String
+    where
+        S: Clone + Send + Sync + 'static,
+        T: ToSchema + 'static,
+    {
+        T::name().into_owned()
+    }
+
+    #[fastapi::path(get, path = "/state", responses((status = 200)))]
+    #[allow(unused)]
+    async fn get_state<S: Clone + Send + Sync + 'static>(State(_state): State<S>) {}
+
+    #[derive(OpenApi)]
+    #[openapi(paths(get_state))]
+    struct ApiDoc;
+
+    // type parameters not used by the documentation are not part of the path type
+    let openapi = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    type Items = <__path_list_items as fastapi::__dev::HandlerPath<(String, Item)>>::Path;
+    let items = serde_json::to_value(<Items as fastapi::Path>::operation()).unwrap();
+
+    assert_json_eq!(
+        openapi.pointer("/paths/~1state/get/operationId"),
+        json!("get_state")
+    );
+    assert_json_eq!(
+        items.pointer("/responses/200/content/application~1json/schema/items"),
+        json!({ "$ref": "#/components/schemas/Item" })
+    );
+}
@@ -0,0 +1,302 @@
+#![cfg(feature = "client")]
+
+use std::future::Future;
+
+use axum::body::Body;
+use axum::extract::{Path, Query};
+use axum::http::{HeaderMap, StatusCode};
+use axum::routing::get;
+use axum::{Json, Router};
+use fastapi::client::{Error, Request, Response, Transport};
+use fastapi::{IntoParams, ToSchema};
+use http_body_util::BodyExt;
+use serde::{Deserialize, Serialize};
+use tower::ServiceExt;
+
+/// Transport calling the axum [`Router`] in process.
+struct RouterTransport(Router);
+
+impl Transport for RouterTransport {
+    fn send(&self, request: Request) -> impl Future<Output = Result<Response, Error>> + Send {
+        let router = self.0.clone();
+        async move {
+            let mut builder = axum::http::Request::builder()
+                .method(request.method_str())
+                .uri(request.uri());
+            for (name, value) in &request.headers {
+                builder = builder.header(name, value);
+            }
+            let http_request = builder
+                .body(Body::from(request.body.unwrap_or_default()))
+                .map_err(|error| Error::Transport(error.into()))?;
+
+            let http_response = router
+                .oneshot(http_request)
+                .await
+                .map_err(|error| Error::Transport(error.into()))?;
+            let status = http_response.status().as_u16();
+            let headers = http_response
+                .headers()
+                .iter()
+                .map(|(name, value)| {
+                    (
+                        name.to_string(),
+                        value.to_str().unwrap_or_default().to_string(),
+                    )
+                })
+                .collect::<Vec<_>>();
+            let body = http_response
+                .into_body()
+                .collect()
+                .await
+                .map_err(|error| Error::Transport(error.into()))?
+                .to_bytes()
+                .to_vec();
+
+            Ok(headers
+                .into_iter()
+                .fold(Response::new(status, body), |response, (name, value)| {
+                    response.header(name, value)
+                }))
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, ToSchema, Clone, PartialEq, Debug)]
+struct Pet {
+    id: u64,
+    name: String,
+}
+
+#[derive(Serialize, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+struct PetQuery {
+    name: Option<String>,
+    limit: Option<usize>,
+}
+
+fn pets() -> Vec<Pet> {
+    vec![
+        Pet {
+            id: 1,
+            name: "Lemon".to_string(),
+        },
+        Pet {
+            id: 2,
+            name: "Lemon Tree".to_string(),
+        },
+    ]
+}
+
+#[fastapi::path(
+    get,
+    path = "/pets/{id}",
+    params(("id" = u64, Path, description = "Pet database id")),
+    responses(
+        (status = 200, description = "Pet found", body = Pet),
+        (status = 404, description = "Pet not found")
+    )
+)]
+async fn get_pet(Path(id): Path<u64>) -> Result<Json<Pet>, StatusCode> {
+    match id {
+        0 => Err(StatusCode::INTERNAL_SERVER_ERROR),
+        id => pets()
+            .into_iter()
+            .find(|pet| pet.id == id)
+            .map(Json)
+            .ok_or(StatusCode::NOT_FOUND),
+    }
+}
+
+#[fastapi::path(
+    get,
+    path = "/pets",
+    params(PetQuery, ("x-tenant" = String, Header, description = "Tenant of the pets")),
+    responses(
+        (status = 200, description = "Pets found", body = [Pet]),
+        (status = "4XX", description = "Invalid request", body = String, content_type = "text/plain")
+    )
+)]
+async fn list_pets(
+    Query(query): Query<PetQuery>,
+    headers: HeaderMap,
+) -> Result<Json<Vec<Pet>>, (StatusCode, String)> {
+    if headers.get("x-tenant").is_none() {
+        return Err((StatusCode::BAD_REQUEST, "missing tenant".to_string()));
+    }
+
+    Ok(Json(
+        pets()
+            .into_iter()
+            .filter(|pet| {
+                query
+                    .name
+                    .as_ref()
+                    .map(|name| pet.name.starts_with(name))
+                    .unwrap_or(true)
+            })
+            .take(query.limit.unwrap_or(usize::MAX))
+            .collect(),
+    ))
+}
+
+#[fastapi::path(
+    post,
+    path = "/pets",
+    request_body = Pet,
+    responses(
+        (status = 201, description = "Pet created", body = Pet),
+        (status = "default", description = "Unexpected error")
+    )
+)]
+async fn create_pet(Json(pet): Json<Pet>) -> (StatusCode, Json<Pet>) {
+    match pet.id {
+        0 => (StatusCode::SERVICE_UNAVAILABLE, Json(pet)),
+        _ => (StatusCode::CREATED, Json(pet)),
+    }
+}
+
+fn transport() -> RouterTransport {
+    RouterTransport(
+        Router::new()
+            .route("/pets", get(list_pets).post(create_pet))
+            .route("/pets/:id", get(get_pet)),
+    )
+}
+
+#[tokio::test]
+async fn client_get_by_path_parameter() {
+    let transport = transport();
+
+    let response = GetPetResponse::send(&transport, 1).await.unwrap();
+    assert!(matches!(response, GetPetResponse::Ok(pet) if pet == pets()[0]));
+
+    let response = GetPetResponse::send(&transport, 3).await.unwrap();
+    assert!(matches!(response, GetPetResponse::NotFound));
+
+    let error = GetPetResponse::send(&transport, 0).await.err().unwrap();
+    assert!(matches!(error, Error::UnexpectedStatus(response) if response.status == 500));
+}
+
+#[tokio::test]
+async fn client_list_with_into_params_and_header() {
+    let transport = transport();
+
+    let response = ListPetsResponse::send(
+        &transport,
+        PetQuery {
+            name: Some("Lemon Tree".to_string()),
+            limit: Some(10),
+        },
+        "tenant".to_string(),
+    )
+    .await
+    .unwrap();
+    assert!(matches!(response, ListPetsResponse::Ok(found) if found == vec![pets()[1].clone()]));
+}
+
+#[tokio::test]
+async fn client_list_client_error_range() {
+    let transport = RouterTransport(Router::new().route(
+        "/pets",
+        get(|| async { (StatusCode::BAD_REQUEST, "missing tenant") }),
+    ));
+
+    let response = ListPetsResponse::send(
+        &transport,
+        PetQuery {
+            name: None,
+            limit: None,
+        },
+        String::new(),
+    )
+    .await
+    .unwrap();
+    assert!(
+        matches!(response, ListPetsResponse::ClientError(message) if message == "missing tenant")
+    );
+}
+
+#[tokio::test]
+async fn client_post_request_body() {
+    let transport = transport();
+    let pet = Pet {
+        id: 3,
+        name: "Lime".to_string(),
+    };
+
+    let response = CreatePetResponse::send(&transport, pet.clone())
+        .await
+        .unwrap();
+    assert!(matches!(response, CreatePetResponse::Created(created) if created == pet));
+
+    let response = CreatePetResponse::send(
+        &transport,
+        Pet {
+            id: 0,
+            name: "Lime".to_string(),
+        },
+    )
+    .await
+    .unwrap();
+    assert!(matches!(response, CreatePetResponse::Default));
+}
+
+#[derive(Serialize, ToSchema)]
+struct PetName<'a> {
+    name: &'a str,
+}
+
+#[fastapi::path(
+    method(get, head),
+    path = "/pets/{id}/exists",
+    client_name = PetExists,
+    params(("id" = u64, Path), ("self" = Option<bool>, Query)),
+    responses(
+        (status = 200, description = "Pet exists"),
+        (status = 404, description = "Pet does not exist")
+    )
+)]
+async fn pet_exists(Path(id): Path<u64>) -> StatusCode {
+    match pets().iter().any(|pet| pet.id == id) {
+        true => StatusCode::OK,
+        false => StatusCode::NOT_FOUND,
+    }
+}
+
+#[fastapi::path(
+    put,
+    path = "/pets/{id}/name",
+    params(("id" = u64, Path)),
+    request_body = PetName,
+    responses(
+        (status = 200, description = "Pet renamed", body = String, content_type = "text/plain")
+    )
+)]
+async fn rename_pet(Json(name): Json<serde_json::Value>) -> String {
+    name["name"].as_str().unwrap_or_default().to_string()
+}
+
+#[tokio::test]
+async fn client_multiple_methods_with_client_name() {
+    let transport = RouterTransport(Router::new().route("/pets/:id/exists", get(pet_exists)));
+
+    let response = PetExists::send_get(&transport, 1, Some(true))
+        .await
+        .unwrap();
+    assert!(matches!(response, PetExists::Ok));
+
+    let response = PetExists::send_head(&transport, 3, None).await.unwrap();
+    assert!(matches!(response, PetExists::NotFound));
+}
+
+#[tokio::test]
+async fn client_request_body_with_elided_lifetime() {
+    let transport =
+        RouterTransport(Router::new().route("/pets/:id/name", axum::routing::put(rename_pet)));
+
+    let response = RenamePetResponse::send(&transport, 1, PetName { name: "Lime" })
+        .await
+        .unwrap();
+    assert!(matches!(response, RenamePetResponse::Ok(name) if name == "Lime"));
+}
@@ -715,3 +715,208 @@ fn path_response_with_no_schema() {
         })
     )
 }
+
+#[test]
+fn path_response_with_ndjson_stream() {
+    #[derive(serde::Serialize, fastapi::ToSchema)]
+    #[allow(unused)]
+    struct Pet {
+        name: String,
+    }
+
+    #[fastapi::path(
+        get,
+        path = "/pets",
+        responses(
+            (status = 200, body = stream(Pet), content_type = "application/x-ndjson")
+        )
+    )]
+    #[allow(unused)]
+    fn list_pets() {}
+
+    #[derive(fastapi::OpenApi)]
+    #[openapi(paths(list_pets))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let responses = doc.pointer("/paths/~1pets/get/responses").unwrap();
+
+    assert_json_eq!(
+        responses,
+        json!({
+            "200": {
+                "content": {
+                    "application/x-ndjson": {
+                        "itemSchema": {
+                            "$ref": "#/components/schemas/Pet"
+                        },
+                    },
+                },
+                "description": "",
+            },
+        })
+    );
+    assert!(
+        doc.pointer("/components/schemas/Pet").is_some(),
+        "stream item schema should be collected"
+    );
+}
+
+#[test]
+fn path_response_with_server_sent_events() {
+    #[derive(serde::Serialize, fastapi::ToSchema)]
+    #[allow(unused)]
+    struct Pet {
+        name: String,
+    }
+
+    #[fastapi::path(
+        get,
+        path = "/pets/events",
+        responses(
+            (status = 200, body = stream("created" = Pet, "deleted" = inline(u64)))
+        )
+    )]
+    #[allow(unused)]
+    fn pet_events() {}
+
+    #[derive(fastapi::OpenApi)]
+    #[openapi(paths(pet_events))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let responses = doc.pointer("/paths/~1pets~1events/get/responses").unwrap();
+
+    let event = |name: &str, data: Value| {
+        json!({
+            "properties": {
+                "data": data,
+                "event": {
+                    "enum": [name],
+                    "type": "string"
+                },
+                "id": {
+                    "type": "string"
+                },
+                "retry": {
+                    "minimum": 0,
+                    "type": "integer"
+                }
+            },
+            "required": ["data", "event"],
+            "type": "object"
+        })
+    };
+    assert_json_eq!(
+        responses,
+        json!({
+            "200": {
+                "content": {
+                    "text/event-stream": {
+                        "itemSchema": {
+                            "oneOf": [
+                                event("created", json!({ "$ref": "#/components/schemas/Pet" })),
+                                event("deleted", json!({ "format": "int64", "minimum": 0, "type": "integer" }))
+                            ]
+                        },
+                    },
+                },
+                "description": "",
+            },
+        })
+    );
+    assert!(
+        doc.pointer("/components/schemas/Pet").is_some(),
+        "stream item schema should be collected"
+    );
+}
+
+#[test]
+fn path_response_problem_shorthand() {
+    #[fastapi::path(
+        get,
+        path = "/pets/{id}",
+        responses(
+            (status = 200, description = "Pet found"),
+            problem(status = NOT_FOUND, type = "https://example.com/probs/pet-not-found", title = "Pet not found"),
+            problem(status = http::status::StatusCode::CONFLICT, title = "Pet already exists"),
+            problem(status = 422, title = "Invalid pet"),
+            problem(status = "5XX", description = "Unexpected error")
+        )
+    )]
+    #[allow(unused)]
+    fn get_pet() {}
+
+    #[derive(fastapi::OpenApi)]
+    #[openapi(paths(get_pet))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+    let responses = doc.pointer("/paths/~1pets~1{id}/get/responses").unwrap();
+
+    assert_json_eq!(
+        responses,
+        json!({
+            "200": {
+                "description": "Pet found"
+            },
+            "404": {
+                "content": {
+                    "application/problem+json": {
+                        "example": {
+                            "status": 404,
+                            "title": "Pet not found",
+                            "type": "https://example.com/probs/pet-not-found"
+                        },
+                        "schema": {
+                            "$ref": "#/components/schemas/Problem"
+                        }
+                    }
+                },
+                "description": "Pet not found"
+            },
+            "409": {
+                "content": {
+                    "application/problem+json": {
+                        "example": {
+                            "status": 409,
+                            "title": "Pet already exists"
+                        },
+                        "schema": {
+                            "$ref": "#/components/schemas/Problem"
+                        }
+                    }
+                },
+                "description": "Pet already exists"
+            },
+            "422": {
+                "content": {
+                    "application/problem+json": {
+                        "example": {
+                            "status": 422,
+                            "title": "Invalid pet"
+                        },
+                        "schema": {
+                            "$ref": "#/components/schemas/Problem"
+                        }
+                    }
+                },
+                "description": "Invalid pet"
+            },
+            "5XX": {
+                "content": {
+                    "application/problem+json": {
+                        "schema": {
+                            "$ref": "#/components/schemas/Problem"
+                        }
+                    }
+                },
+                "description": "Unexpected error"
+            }
+        })
+    );
+    assert!(
+        doc.pointer("/components/schemas/Problem").is_some(),
+        "problem schema should be collected"
+    );
+}
@@ -864,6 +864,170 @@ fn request_body_with_binary() {
     )
 }
 
+#[test]
+fn request_body_multipart_with_encoding() {
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    struct Metadata {
+        name: String,
+    }
+
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    struct Upload {
+        metadata: Metadata,
+        file: fastapi::UploadFile,
+        #[schema(format = Byte)]
+        thumbnail: Option<fastapi::UploadFile>,
+    }
+
+    #[fastapi::path(
+        post,
+        path = "/upload",
+        request_body(
+            content = Upload,
+            content_type = "multipart/form-data",
+            encoding(part = "metadata", content_type = "application/json"),
+            encoding(
+                part = "file",
+                content_type = "image/png, image/jpeg",
+                headers(("x-checksum" = String, description = "Checksum of the file"))
+            )
+        )
+    )]
+    #[allow(dead_code)]
+    fn upload() {}
+
+    #[derive(OpenApi)]
+    #[openapi(components(schemas(Upload, Metadata)), paths(upload))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+
+    let content = doc
+        .pointer("/paths/~1upload/post/requestBody/content")
+        .unwrap();
+    assert_json_eq!(
+        content,
+        json!({
+            "multipart/form-data": {
+                "encoding": {
+                    "file": {
+                        "contentType": "image/png, image/jpeg",
+                        "headers": {
+                            "x-checksum": {
+                                "description": "Checksum of the file",
+                                "schema": {
+                                    "type": "string"
+                                }
+                            }
+                        }
+                    },
+                    "metadata": {
+                        "contentType": "application/json"
+                    }
+                },
+                "schema": {
+                    "$ref": "#/components/schemas/Upload"
+                }
+            }
+        })
+    );
+
+    let upload = doc.pointer("/components/schemas/Upload").unwrap();
+    assert_json_eq!(
+        upload,
+        json!({
+            "properties": {
+                "file": {
+                    "format": "binary",
+                    "type": "string"
+                },
+                "metadata": {
+                    "$ref": "#/components/schemas/Metadata"
+                },
+                "thumbnail": {
+                    "format": "byte",
+                    "type": ["string", "null"]
+                }
+            },
+            "required": ["metadata", "file"],
+            "type": "object"
+        })
+    )
+}
+
+#[test]
+fn request_body_form_with_encoding_style() {
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    struct Filter {
+        tags: Vec<String>,
+    }
+
+    #[fastapi::path(
+        post,
+        path = "/filter",
+        request_body(
+            content = Filter,
+            content_type = "application/x-www-form-urlencoded",
+            encoding(part = "tags", style = SpaceDelimited, explode = false, allow_reserved)
+        )
+    )]
+    #[allow(dead_code)]
+    fn filter() {}
+
+    #[derive(OpenApi)]
+    #[openapi(paths(filter))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+
+    let encoding = doc
+        .pointer(
+            "/paths/~1filter/post/requestBody/content/application~1x-www-form-urlencoded/encoding",
+        )
+        .unwrap();
+    assert_json_eq!(
+        encoding,
+        json!({
+            "tags": {
+                "allowReserved": true,
+                "explode": false,
+                "style": "spaceDelimited"
+            }
+        })
+    )
+}
+
+#[test]
+fn request_body_with_upload_file() {
+    #[fastapi::path(post, path = "/upload", request_body = fastapi::UploadFile)]
+    #[allow(dead_code)]
+    fn upload() {}
+
+    #[derive(OpenApi)]
+    #[openapi(paths(upload))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+
+    let content = doc
+        .pointer("/paths/~1upload/post/requestBody/content")
+        .unwrap();
+    assert_json_eq!(
+        content,
+        json!({
+            "application/octet-stream": {
+                "schema": {
+                    "format": "binary",
+                    "type": "string"
+                }
+            }
+        })
+    )
+}
+
 #[test]
 fn request_body_with_external_ref() {
     #[fastapi::path(get, path = "/item", request_body(content = ref("./MyUser.json")))]
@@ -890,3 +1054,93 @@ fn request_body_with_external_ref() {
         })
     )
 }
+
+#[test]
+fn request_body_with_user_defined_upload_file() {
+    mod upload {
+        #[derive(fastapi::ToSchema)]
+        #[allow(dead_code)]
+        pub struct UploadFile {
+            name: String,
+        }
+    }
+
+    #[fastapi::path(post, path = "/upload", request_body = upload::UploadFile)]
+    #[allow(dead_code)]
+    fn upload() {}
+
+    #[derive(OpenApi)]
+    #[openapi(paths(upload))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+
+    let content = doc
+        .pointer("/paths/~1upload/post/requestBody/content")
+        .unwrap();
+    assert_json_eq!(
+        content,
+        json!({
+            "application/json": {
+                "schema": {
+                    "$ref": "#/components/schemas/UploadFile"
+                }
+            }
+        })
+    );
+    assert_json_eq!(
+        doc.pointer("/components/schemas/UploadFile").unwrap(),
+        json!({
+            "type": "object",
+            "required": ["name"],
+            "properties": {
+                "name": {
+                    "type": "string"
+                }
+            }
+        })
+    )
+}
+
+#[test]
+fn request_body_with_bytes_by_path() {
+    mod chunks {
+        #[derive(fastapi::ToSchema)]
+        #[allow(dead_code)]
+        pub struct Bytes(Vec<String>);
+    }
+
+    #[fastapi::path(post, path = "/raw", request_body = axum::body::Bytes)]
+    #[allow(dead_code)]
+    fn raw() {}
+
+    #[fastapi::path(post, path = "/chunks", request_body = chunks::Bytes)]
+    #[allow(dead_code)]
+    fn chunks() {}
+
+    #[derive(OpenApi)]
+    #[openapi(paths(raw, chunks))]
+    struct ApiDoc;
+
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+
+    assert_json_eq!(
+        doc.pointer("/paths/~1raw/post/requestBody/content")
+            .unwrap(),
+        json!({
+            "application/octet-stream": {
+                "schema": {
+                    "format": "binary",
+                    "type": "string"
+                }
+            }
+        })
+    );
+    assert_json_eq!(
+        doc.pointer("/paths/~1chunks/post/requestBody/content/application~1json/schema")
+            .unwrap(),
+        json!({
+            "$ref": "#/components/schemas/Bytes"
+        })
+    )
+}
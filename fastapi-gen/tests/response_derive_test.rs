@@ -204,6 +204,38 @@ fn derive_response_with_attributes() {
     )
 }
 
+#[test]
+fn derive_response_with_extensions() {
+    #[derive(ToSchema, ToResponse)]
+    #[response(description = "Person", extensions(("x-internal" = json!(true))))]
+    #[allow(unused)]
+    struct Person {
+        name: String,
+    }
+    let (_, v) = <Person as fastapi::ToResponse>::response();
+    let value = serde_json::to_value(v).unwrap();
+
+    assert_json_eq!(value.pointer("/x-internal"), json!(true));
+}
+
+#[test]
+fn derive_into_responses_with_extensions() {
+    #[derive(fastapi::IntoResponses)]
+    #[allow(unused)]
+    enum PersonResponses {
+        #[response(status = 200, extensions(("x-cache-ttl" = json!(60))))]
+        Success { name: String },
+
+        #[response(status = 404)]
+        NotFound,
+    }
+    let responses = <PersonResponses as fastapi::IntoResponses>::responses();
+    let value = serde_json::to_value(responses).unwrap();
+
+    assert_json_eq!(value.pointer("/200/x-cache-ttl"), json!(60));
+    assert_json_eq!(value.pointer("/404/x-cache-ttl"), json!(null));
+}
+
 #[test]
 fn derive_response_multiple_examples() {
     #[derive(ToSchema, ToResponse)]
@@ -2028,6 +2028,105 @@ fn derive_struct_with_title() {
     )
 }
 
+#[test]
+fn derive_struct_with_extensions() {
+    #[derive(ToSchema)]
+    #[allow(unused)]
+    struct Owner {
+        name: String,
+    }
+
+    let value: Value = api_doc! {
+        #[schema(extensions(("x-internal" = json!(true))))]
+        struct Post {
+            #[schema(extensions(("x-order" = json!(1))))]
+            id: i64,
+            #[schema(extensions(("x-deprecated-since" = json!("1.2"))))]
+            owner: Owner,
+            #[schema(extensions(("x-max-shown" = json!(3))))]
+            tags: Vec<String>,
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "properties": {
+                "id": {
+                    "type": "integer",
+                    "format": "int64",
+                    "x-order": 1,
+                },
+                "owner": {
+                    "oneOf": [
+                        {
+                            "$ref": "#/components/schemas/Owner"
+                        }
+                    ],
+                    "x-deprecated-since": "1.2",
+                },
+                "tags": {
+                    "type": "array",
+                    "items": {
+                        "type": "string"
+                    },
+                    "x-max-shown": 3,
+                }
+            },
+            "required": ["id", "owner", "tags"],
+            "type": "object",
+            "x-internal": true,
+        })
+    )
+}
+
+#[test]
+fn derive_enum_with_extensions() {
+    let value: Value = api_doc! {
+        #[schema(extensions(("x-enum-varnames" = json!(["Admin", "User"]))))]
+        enum UserType {
+            Admin,
+            User,
+        }
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "enum": ["Admin", "User"],
+            "type": "string",
+            "x-enum-varnames": ["Admin", "User"],
+        })
+    );
+
+    let value: Value = api_doc! {
+        #[schema(extensions(("x-internal" = json!(true))))]
+        enum Shape {
+            Circle { radius: f64 },
+            Square(f64),
+        }
+    };
+
+    assert_json_eq!(value.pointer("/x-internal"), json!(true));
+    assert!(value.pointer("/oneOf").is_some());
+}
+
+#[test]
+fn derive_unnamed_struct_with_extensions() {
+    let value: Value = api_doc! {
+        #[schema(extensions(("x-go-type" = json!("uuid.UUID"))))]
+        struct Id(String);
+    };
+
+    assert_json_eq!(
+        value,
+        json!({
+            "type": "string",
+            "x-go-type": "uuid.UUID",
+        })
+    )
+}
+
 #[test]
 fn derive_enum_with_title() {
     let value: Value = api_doc! {
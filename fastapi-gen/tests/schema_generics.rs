@@ -468,6 +468,130 @@ fn slice_generic_args() {
     // assert_eq!(expected.trim(), api_json.trim());
 }
 
+#[test]
+fn generic_type_json_schema() {
+    #![allow(unused)]
+
+    #[derive(ToSchema)]
+    struct Owner {
+        name: String,
+        pets: Vec<Pet>,
+    }
+
+    #[derive(ToSchema)]
+    struct Pet {
+        name: String,
+        #[schema(no_recursion)]
+        parent: Option<Box<Pet>>,
+    }
+
+    #[derive(ToSchema)]
+    struct Page<T> {
+        total: usize,
+        items: Vec<T>,
+    }
+
+    #[derive(ToSchema)]
+    struct OwnerPage {
+        page: Page<Owner>,
+    }
+
+    let schema = fastapi::json_schema::<OwnerPage>();
+
+    assert_json_eq!(
+        schema,
+        json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "object",
+            "properties": {
+                "page": {
+                    "$ref": "#/$defs/Page_Owner"
+                }
+            },
+            "required": ["page"],
+            "$defs": {
+                "Page_Owner": {
+                    "type": "object",
+                    "properties": {
+                        "total": {
+                            "type": "integer",
+                            "minimum": 0
+                        },
+                        "items": {
+                            "type": "array",
+                            "items": {
+                                "type": "object",
+                                "properties": {
+                                    "name": {
+                                        "type": "string"
+                                    },
+                                    "pets": {
+                                        "type": "array",
+                                        "items": {
+                                            "$ref": "#/$defs/Pet"
+                                        }
+                                    }
+                                },
+                                "required": ["name", "pets"]
+                            }
+                        }
+                    },
+                    "required": ["total", "items"]
+                },
+                "Pet": {
+                    "type": "object",
+                    "properties": {
+                        "name": {
+                            "type": "string"
+                        },
+                        "parent": {
+                            "oneOf": [
+                                {
+                                    "type": "null"
+                                },
+                                {
+                                    "$ref": "#/$defs/Pet"
+                                }
+                            ]
+                        }
+                    },
+                    "required": ["name"]
+                }
+            }
+        })
+    );
+}
+
+#[test]
+fn recursive_type_json_schema_references_root() {
+    #![allow(unused)]
+
+    #[derive(ToSchema)]
+    #[schema(no_recursion)]
+    struct Node {
+        children: Vec<Node>,
+    }
+
+    let schema = fastapi::json_schema::<Node>();
+
+    assert_json_eq!(
+        schema,
+        json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "type": "object",
+            "properties": {
+                "children": {
+                    "type": "array",
+                    "items": {
+                        "$ref": "#"
+                    }
+                }
+            },
+            "required": ["children"]
+        })
+    );
+}
+
 #[test]
 #[ignore = "For debugging only"]
 fn schema_macro_run() {
@@ -14,6 +14,8 @@
 //! * **actix-web** Allows serving [`RapiDoc`] via _**`actix-web`**_.
 //! * **rocket** Allows serving [`RapiDoc`] via _**`rocket`**_.
 //! * **axum** Allows serving [`RapiDoc`] via _**`axum`**_.
+//! * **tower** Allows serving [`RapiDoc`] via framework agnostic [`tower::RapiDocService`] with any
+//!   `tower` compatible server.
 //!
 //! # Install
 //!
@@ -66,6 +68,21 @@
 //! OpenAPI spec url provided with [`RapiDoc::new`] function when creating a new [`RapiDoc`]
 //! instance. Variable will be replaced during [`RapiDoc::to_html`] function execution.
 //!
+//! The template may also contain _**`$config`**_ variable inside the `<rapi-doc>` element which
+//! will be replaced with the attributes of typed [`Config`][config::Config] given with
+//! [`RapiDoc::config`]. Both variables are escaped for HTML attribute values.
+//!
+//! _**Configure RapiDoc with typed [`Config`][config::Config].**_
+//! ```rust
+//! # use fastapi_rapidoc::RapiDoc;
+//! # use fastapi_rapidoc::config::{Config, SchemaStyle, Theme};
+//! RapiDoc::new("/api-docs/openapi.json").config(
+//!     Config::new()
+//!         .theme(Theme::Dark)
+//!         .schema_style(SchemaStyle::Table),
+//! );
+//! ```
+//!
 //! _**Overriding the HTML template with a custom one.**_
 //! ```rust
 //! # use fastapi_rapidoc::RapiDoc;
@@ -140,6 +157,28 @@
 
 use std::borrow::Cow;
 
+#[cfg(any(
+    feature = "actix-web",
+    feature = "rocket",
+    feature = "axum",
+    feature = "tower"
+))]
+use std::sync::Arc;
+
+use fastapi_ui::html::{escape_html, render};
+#[cfg(any(
+    feature = "actix-web",
+    feature = "rocket",
+    feature = "axum",
+    feature = "tower"
+))]
+use fastapi_ui::{
+    guard::Guard,
+    shared::{SharedOpenApi, SpecProvider},
+};
+
+use crate::config::Config;
+
 const DEFAULT_HTML: &str = include_str!("../res/rapidoc.html");
 
 /// Is [RapiDoc][rapidoc] UI.
@@ -156,8 +195,21 @@ pub struct RapiDoc {
     path: Cow<'static, str>,
     spec_url: Cow<'static, str>,
     html: Cow<'static, str>,
-    #[cfg(any(feature = "actix-web", feature = "rocket", feature = "axum"))]
-    openapi: Option<fastapi::openapi::OpenApi>,
+    config: Config,
+    #[cfg(any(
+        feature = "actix-web",
+        feature = "rocket",
+        feature = "axum",
+        feature = "tower"
+    ))]
+    openapi: Option<Arc<dyn SpecProvider>>,
+    #[cfg(any(
+        feature = "actix-web",
+        feature = "rocket",
+        feature = "axum",
+        feature = "tower"
+    ))]
+    guard: Option<Guard>,
 }
 
 impl RapiDoc {
@@ -177,8 +229,21 @@ impl RapiDoc {
             path: Cow::Borrowed(""),
             spec_url: spec_url.into(),
             html: Cow::Borrowed(DEFAULT_HTML),
-            #[cfg(any(feature = "actix-web", feature = "rocket", feature = "axum"))]
+            config: Config::default(),
+            #[cfg(any(
+                feature = "actix-web",
+                feature = "rocket",
+                feature = "axum",
+                feature = "tower"
+            ))]
             openapi: None,
+            #[cfg(any(
+                feature = "actix-web",
+                feature = "rocket",
+                feature = "axum",
+                feature = "tower"
+            ))]
+            guard: None,
         }
     }
 
@@ -203,10 +268,20 @@ impl RapiDoc {
     ///     ApiDoc::openapi()
     /// );
     /// ```
-    #[cfg(any(feature = "actix-web", feature = "rocket", feature = "axum"))]
+    #[cfg(any(
+        feature = "actix-web",
+        feature = "rocket",
+        feature = "axum",
+        feature = "tower"
+    ))]
     #[cfg_attr(
         doc_cfg,
-        doc(cfg(any(feature = "actix-web", feature = "rocket", feature = "axum")))
+        doc(cfg(any(
+            feature = "actix-web",
+            feature = "rocket",
+            feature = "axum",
+            feature = "tower"
+        )))
     )]
     pub fn with_openapi<U: Into<Cow<'static, str>>>(
         spec_url: U,
@@ -216,7 +291,9 @@ impl RapiDoc {
             path: Cow::Borrowed(""),
             spec_url: spec_url.into(),
             html: Cow::Borrowed(DEFAULT_HTML),
-            openapi: Some(openapi),
+            config: Config::default(),
+            openapi: Some(Arc::new(SharedOpenApi::new(openapi))),
+            guard: None,
         }
     }
 
@@ -243,10 +320,20 @@ impl RapiDoc {
     ///     ApiDoc::openapi()
     /// );
     /// ```
-    #[cfg(any(feature = "actix-web", feature = "rocket", feature = "axum"))]
+    #[cfg(any(
+        feature = "actix-web",
+        feature = "rocket",
+        feature = "axum",
+        feature = "tower"
+    ))]
     #[cfg_attr(
         doc_cfg,
-        doc(cfg(any(feature = "actix-web", feature = "rocket", feature = "axum")))
+        doc(cfg(any(
+            feature = "actix-web",
+            feature = "rocket",
+            feature = "axum",
+            feature = "tower"
+        )))
     )]
     pub fn with_url<U: Into<Cow<'static, str>>, S: Into<Cow<'static, str>>>(
         url: U,
@@ -257,7 +344,59 @@ impl RapiDoc {
             path: url.into(),
             spec_url: spec_url.into(),
             html: Cow::Borrowed(DEFAULT_HTML),
-            openapi: Some(openapi),
+            config: Config::default(),
+            openapi: Some(Arc::new(SharedOpenApi::new(openapi))),
+            guard: None,
+        }
+    }
+
+    /// Construct a new [`RapiDoc`] with given `spec_url` and [`SpecProvider`]. The spec url must
+    /// point to the location where the OpenAPI of the `provider` will be served.
+    ///
+    /// Unlike [`RapiDoc::with_openapi`] the OpenAPI is not captured when the [`RapiDoc`] is
+    /// registered to the application. The `provider` is read on each request of the spec url
+    /// instead, so updates through [`SharedOpenApi`] handle are visible immediately.
+    ///
+    /// # Examples
+    ///
+    /// _**Create new [`RapiDoc`] serving OpenAPI that can be updated at runtime.**_
+    ///
+    /// ```
+    /// # use fastapi_rapidoc::RapiDoc;
+    /// # use fastapi::openapi::OpenApiBuilder;
+    /// # use fastapi_ui::shared::SharedOpenApi;
+    /// let shared = SharedOpenApi::new(OpenApiBuilder::new().build());
+    /// RapiDoc::with_spec_provider("/api-docs/openapi.json", shared.clone()).path("/rapidoc");
+    ///
+    /// // later on
+    /// shared.update(|openapi| openapi.info.version = "2.0.0".to_string());
+    /// ```
+    #[cfg(any(
+        feature = "actix-web",
+        feature = "rocket",
+        feature = "axum",
+        feature = "tower"
+    ))]
+    #[cfg_attr(
+        doc_cfg,
+        doc(cfg(any(
+            feature = "actix-web",
+            feature = "rocket",
+            feature = "axum",
+            feature = "tower"
+        )))
+    )]
+    pub fn with_spec_provider<U: Into<Cow<'static, str>>, P: SpecProvider + 'static>(
+        spec_url: U,
+        provider: P,
+    ) -> Self {
+        Self {
+            path: Cow::Borrowed(""),
+            spec_url: spec_url.into(),
+            html: Cow::Borrowed(DEFAULT_HTML),
+            config: Config::default(),
+            openapi: Some(Arc::new(provider)),
+            guard: None,
         }
     }
 
@@ -283,7 +422,12 @@ impl RapiDoc {
     /// RapiDoc::new("https://petstore3.swagger.io/api/v3/openapi.json")
     ///     .path("/rapidoc");
     /// ```
-    #[cfg(any(feature = "actix-web", feature = "rocket", feature = "axum"))]
+    #[cfg(any(
+        feature = "actix-web",
+        feature = "rocket",
+        feature = "axum",
+        feature = "tower"
+    ))]
     pub fn path<U: Into<Cow<'static, str>>>(mut self, path: U) -> Self {
         self.path = path.into();
 
@@ -302,17 +446,240 @@ impl RapiDoc {
     /// [rapidoc_quickstart]: <https://rapidocweb.com/quickstart.html>
     /// [customization]: index.html#customization
     pub fn to_html(&self) -> String {
-        self.html.replace("$specUrl", self.spec_url.as_ref())
+        render(
+            &self.html,
+            &[
+                ("$specUrl", &escape_html(&self.spec_url)),
+                ("$config", &self.config.to_attributes()),
+            ],
+        )
+    }
+
+    /// Add typed [`Config`] for the [`RapiDoc`]. The config will be rendered as HTML attributes
+    /// of the `<rapi-doc>` element in place of _**`$config`**_ variable.
+    ///
+    /// # Examples
+    ///
+    /// _**Use dark theme and focused render style.**_
+    /// ```
+    /// # use fastapi_rapidoc::RapiDoc;
+    /// # use fastapi_rapidoc::config::{Config, RenderStyle, Theme};
+    /// RapiDoc::new("/api-docs/openapi.json").config(
+    ///     Config::new()
+    ///         .theme(Theme::Dark)
+    ///         .render_style(RenderStyle::Focused)
+    ///         .allow_try(false),
+    /// );
+    /// ```
+    pub fn config(mut self, config: Config) -> Self {
+        self.config = config;
+
+        self
+    }
+
+    /// Restrict access to the [`RapiDoc`] and the OpenAPI spec served by it with [`Guard`].
+    ///
+    /// Rejected requests get `401 Unauthorized` or `403 Forbidden` response depending on the
+    /// [`Rejection`][rejection] of the guard. With `axum` the application must be served with
+    /// `into_make_service_with_connect_info::<SocketAddr>()` for IP allow-lists to work.
+    ///
+    /// # Examples
+    ///
+    /// _**Allow access only with valid bearer token.**_
+    /// ```
+    /// # use fastapi_rapidoc::RapiDoc;
+    /// # use fastapi_ui::guard::Guard;
+    /// # use fastapi::OpenApi;
+    /// # #[derive(OpenApi)]
+    /// # #[openapi()]
+    /// # struct ApiDoc;
+    /// RapiDoc::with_openapi("/api-docs/openapi.json", ApiDoc::openapi())
+    ///     .path("/rapidoc")
+    ///     .guard(Guard::bearer(|token| token == "staff-token"));
+    /// ```
+    ///
+    /// [rejection]: fastapi_ui::guard::Rejection
+    #[cfg(any(
+        feature = "actix-web",
+        feature = "rocket",
+        feature = "axum",
+        feature = "tower"
+    ))]
+    #[cfg_attr(
+        doc_cfg,
+        doc(cfg(any(
+            feature = "actix-web",
+            feature = "rocket",
+            feature = "axum",
+            feature = "tower"
+        )))
+    )]
+    pub fn guard(mut self, guard: Guard) -> Self {
+        self.guard = Some(guard);
+
+        self
+    }
+}
+
+/// Implements typed [RapiDoc configuration][rapidoc_api] for [`RapiDoc`].
+///
+/// [`Config`][config::Config] is serialized to HTML attributes of the `<rapi-doc>` element in
+/// place of _**`$config`**_ variable of the HTML template. Only options that are defined are
+/// rendered and RapiDoc defaults are used for the rest.
+///
+/// [rapidoc_api]: <https://rapidocweb.com/api.html>
+pub mod config {
+    use fastapi_ui::html::escape_html;
+    use serde::Serialize;
+
+    /// Typed [RapiDoc configuration][rapidoc_api] rendered as attributes of the `<rapi-doc>`
+    /// element.
+    ///
+    /// # Examples
+    ///
+    /// _**Create new [`Config`] with table schema style and server selection.**_
+    /// ```
+    /// # use fastapi_rapidoc::config::{Config, SchemaStyle};
+    /// let config = Config::new()
+    ///     .schema_style(SchemaStyle::Table)
+    ///     .allow_server_selection(true);
+    /// ```
+    ///
+    /// [rapidoc_api]: <https://rapidocweb.com/api.html>
+    #[non_exhaustive]
+    #[derive(Serialize, Clone, Default, Debug, PartialEq, Eq)]
+    #[serde(rename_all = "kebab-case")]
+    pub struct Config {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        theme: Option<Theme>,
+
+        #[serde(skip_serializing_if = "Option::is_none")]
+        render_style: Option<RenderStyle>,
+
+        #[serde(skip_serializing_if = "Option::is_none")]
+        schema_style: Option<SchemaStyle>,
+
+        #[serde(skip_serializing_if = "Option::is_none")]
+        allow_try: Option<bool>,
+
+        #[serde(skip_serializing_if = "Option::is_none")]
+        allow_server_selection: Option<bool>,
+    }
+
+    impl Config {
+        /// Construct a new empty [`Config`] using RapiDoc defaults.
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Set the [`Theme`] of the RapiDoc.
+        pub fn theme(mut self, theme: Theme) -> Self {
+            self.theme = Some(theme);
+
+            self
+        }
+
+        /// Set the [`RenderStyle`] of the RapiDoc.
+        pub fn render_style(mut self, render_style: RenderStyle) -> Self {
+            self.render_style = Some(render_style);
+
+            self
+        }
+
+        /// Set the [`SchemaStyle`] used to render the schemas.
+        pub fn schema_style(mut self, schema_style: SchemaStyle) -> Self {
+            self.schema_style = Some(schema_style);
+
+            self
+        }
+
+        /// Define whether the _TRY_ feature for making API calls from the RapiDoc is enabled.
+        /// Default is `true`.
+        pub fn allow_try(mut self, allow_try: bool) -> Self {
+            self.allow_try = Some(allow_try);
+
+            self
+        }
+
+        /// Define whether the API server can be selected from the servers of the OpenAPI spec.
+        /// Default is `true`.
+        pub fn allow_server_selection(mut self, allow_server_selection: bool) -> Self {
+            self.allow_server_selection = Some(allow_server_selection);
+
+            self
+        }
+
+        /// Render this [`Config`] as space separated HTML attributes with escaped values.
+        pub(crate) fn to_attributes(&self) -> String {
+            let serde_json::Value::Object(options) =
+                serde_json::to_value(self).expect("RapiDoc config must be serializable")
+            else {
+                return String::new();
+            };
+
+            options
+                .into_iter()
+                .map(|(name, value)| {
+                    let value = match value {
+                        serde_json::Value::String(value) => value,
+                        value => value.to_string(),
+                    };
+                    format!("{name}=\"{}\"", escape_html(&value))
+                })
+                .collect::<Vec<_>>()
+                .join(" ")
+        }
+    }
+
+    /// Color theme of the RapiDoc.
+    #[non_exhaustive]
+    #[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+    #[serde(rename_all = "lowercase")]
+    pub enum Theme {
+        /// Light theme.
+        Light,
+        /// Dark theme.
+        Dark,
+    }
+
+    /// Render style of the RapiDoc.
+    #[non_exhaustive]
+    #[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+    #[serde(rename_all = "lowercase")]
+    pub enum RenderStyle {
+        /// Operations are rendered in a single scrollable page.
+        Read,
+        /// Operations are rendered as expandable list.
+        View,
+        /// Only the selected operation is rendered.
+        Focused,
+    }
+
+    /// Style used to render the schemas.
+    #[non_exhaustive]
+    #[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+    #[serde(rename_all = "lowercase")]
+    pub enum SchemaStyle {
+        /// Schemas are rendered as expandable tree.
+        Tree,
+        /// Schemas are rendered as table.
+        Table,
     }
 }
 
 mod actix {
     #![cfg(feature = "actix-web")]
 
-    use actix_web::dev::HttpServiceFactory;
+    use std::fmt::{self, Display};
+    use std::sync::Arc;
+
+    use actix_web::dev::{HttpServiceFactory, Service, ServiceRequest};
     use actix_web::guard::Get;
+    use actix_web::http::{header, StatusCode};
     use actix_web::web::Data;
-    use actix_web::{HttpResponse, Resource, Responder};
+    use actix_web::{HttpResponse, Resource, Responder, ResponseError};
+    use fastapi_ui::guard::{Guard, GuardRequest, Rejection};
+    use fastapi_ui::shared::SpecProvider;
 
     use crate::RapiDoc;
 
@@ -326,60 +693,263 @@ mod actix {
                     .body(rapidoc.to_string())
             }
 
+            let guard = self.guard.clone();
             Resource::new(self.path.as_ref())
                 .guard(Get())
                 .app_data(Data::new(html))
                 .to(serve_rapidoc)
+                .wrap_fn(move |request, service| {
+                    let response = check(guard.as_ref(), &request).map(|()| service.call(request));
+                    async move { response?.await }
+                })
                 .register(config);
 
             if let Some(openapi) = self.openapi {
-                async fn serve_openapi(openapi: Data<String>) -> impl Responder {
-                    HttpResponse::Ok()
-                        .content_type("application/json")
-                        .body(openapi.into_inner().to_string())
+                async fn serve_openapi(openapi: Data<Arc<dyn SpecProvider>>) -> impl Responder {
+                    match openapi.to_json() {
+                        Ok(json) => HttpResponse::Ok()
+                            .content_type("application/json")
+                            .body(json.to_string()),
+                        Err(error) => HttpResponse::InternalServerError().body(error.to_string()),
+                    }
                 }
 
+                let guard = self.guard;
                 Resource::new(self.spec_url.as_ref())
                     .guard(Get())
-                    .app_data(Data::new(
-                        openapi.to_json().expect("Should serialize to JSON"),
-                    ))
+                    .app_data(Data::new(openapi))
                     .to(serve_openapi)
+                    .wrap_fn(move |request, service| {
+                        let response =
+                            check(guard.as_ref(), &request).map(|()| service.call(request));
+                        async move { response?.await }
+                    })
                     .register(config);
             }
         }
     }
+
+    /// Check the `request` against the `guard` if any.
+    fn check(guard: Option<&Guard>, request: &ServiceRequest) -> Result<(), actix_web::Error> {
+        let Some(guard) = guard else {
+            return Ok(());
+        };
+        let authorization = request
+            .headers()
+            .get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok());
+
+        guard
+            .check(&GuardRequest::new(
+                authorization,
+                request.peer_addr().map(|addr| addr.ip()),
+            ))
+            .map_err(|rejection| GuardRejection(rejection).into())
+    }
+
+    /// [`Rejection`] of the [`Guard`] as `actix-web` error.
+    #[derive(Debug)]
+    struct GuardRejection(Rejection);
+
+    impl Display for GuardRejection {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            self.0.fmt(f)
+        }
+    }
+
+    impl ResponseError for GuardRejection {
+        fn status_code(&self) -> StatusCode {
+            StatusCode::from_u16(self.0.status_code()).unwrap_or(StatusCode::FORBIDDEN)
+        }
+
+        fn error_response(&self) -> HttpResponse {
+            let mut response = HttpResponse::build(self.status_code());
+            if let Some(challenge) = self.0.www_authenticate() {
+                response.insert_header((header::WWW_AUTHENTICATE, challenge));
+            }
+
+            response.finish()
+        }
+    }
 }
 
 mod axum {
     #![cfg(feature = "axum")]
 
-    use axum::response::Html;
-    use axum::{routing, Json, Router};
+    use axum::Router;
 
-    use crate::RapiDoc;
+    use crate::{tower::RapiDocService, RapiDoc};
 
     impl<R> From<RapiDoc> for Router<R>
     where
         R: Clone + Send + Sync + 'static,
     {
         fn from(value: RapiDoc) -> Self {
-            let html = value.to_html();
-            let openapi = value.openapi;
+            let service = RapiDocService::from(value);
 
-            let path = value.path.as_ref();
-            let path = if path.is_empty() { "/" } else { path };
-            let mut router =
-                Router::<R>::new().route(path, routing::get(move || async { Html(html) }));
+            service.routes().fold(Router::<R>::new(), |router, route| {
+                router.route_service(route, service.clone())
+            })
+        }
+    }
+}
 
-            if let Some(openapi) = openapi {
-                router = router.route(
-                    value.spec_url.as_ref(),
-                    routing::get(move || async { Json(openapi) }),
+/// Implements framework agnostic [`tower_service::Service`] for serving the [`RapiDoc`].
+///
+/// [`RapiDocService`][tower::RapiDocService] can be used with any server that accepts a
+/// `tower::Service` such as plain `hyper` or `poem`. The `axum` integration is built on top of it
+/// as well.
+#[cfg(feature = "tower")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "tower")))]
+pub mod tower {
+    use std::{
+        collections::HashMap,
+        convert::Infallible,
+        future::{ready, Ready},
+        sync::Arc,
+        task::{Context, Poll},
+    };
+
+    use bytes::Bytes;
+    use fastapi_ui::guard::Guard;
+    use fastapi_ui::shared::SpecProvider;
+    use fastapi_ui::tower::{check_guard, rejected};
+    use http::{header, Method, Request, Response, StatusCode};
+    use http_body_util::Full;
+    use tower_service::Service;
+
+    use crate::RapiDoc;
+
+    /// Framework agnostic [`tower_service::Service`] serving the [`RapiDoc`] HTML from the path
+    /// of the [`RapiDoc`] and the OpenAPI spec from the spec url if [`RapiDoc`] was created with
+    /// an OpenAPI.
+    ///
+    /// The service is created from [`RapiDoc`] with [`From`] implementation. The HTML is rendered
+    /// once when the service is created and the OpenAPI JSON is read from the [`SpecProvider`] of
+    /// the [`RapiDoc`] on each request. Only `GET` and `HEAD`
+    /// requests are served, other methods will get `405 Method Not Allowed` and unknown paths
+    /// `404 Not Found` response.
+    ///
+    /// All requests are checked against the [`Guard`] of the [`RapiDoc`] if any. The peer address
+    /// for IP allow-lists is read with [`peer_addr`][fastapi_ui::tower::peer_addr].
+    ///
+    /// # Examples
+    ///
+    /// _**Create [`RapiDocService`] serving [`RapiDoc`] from `/rapidoc`.**_
+    /// ```rust
+    /// # use fastapi::OpenApi;
+    /// use fastapi_rapidoc::{RapiDoc, tower::RapiDocService};
+    /// # #[derive(OpenApi)]
+    /// # #[openapi()]
+    /// # struct ApiDoc;
+    ///
+    /// let service = RapiDocService::from(
+    ///     RapiDoc::with_url("/rapidoc", "/api-docs/openapi.json", ApiDoc::openapi()),
+    /// );
+    /// ```
+    #[derive(Clone)]
+    pub struct RapiDocService {
+        files: Arc<HashMap<String, (&'static str, File)>>,
+        guard: Option<Guard>,
+    }
+
+    /// File served by the [`RapiDocService`].
+    enum File {
+        Static(Bytes),
+        Spec(Arc<dyn SpecProvider>),
+    }
+
+    impl RapiDocService {
+        /// Get paths served by this [`RapiDocService`].
+        #[cfg(feature = "axum")]
+        pub(crate) fn routes(&self) -> impl Iterator<Item = &str> {
+            self.files.keys().map(String::as_str)
+        }
+    }
+
+    impl From<RapiDoc> for RapiDocService {
+        fn from(rapidoc: RapiDoc) -> Self {
+            let path = if rapidoc.path.is_empty() {
+                "/"
+            } else {
+                rapidoc.path.as_ref()
+            };
+            let mut files = HashMap::from([(
+                path.to_string(),
+                (
+                    "text/html; charset=utf-8",
+                    File::Static(Bytes::from(rapidoc.to_html())),
+                ),
+            )]);
+
+            if let Some(openapi) = &rapidoc.openapi {
+                files.insert(
+                    rapidoc.spec_url.to_string(),
+                    ("application/json", File::Spec(openapi.clone())),
                 );
             }
 
-            router
+            Self {
+                files: Arc::new(files),
+                guard: rapidoc.guard,
+            }
+        }
+    }
+
+    impl<B> Service<Request<B>> for RapiDocService {
+        type Response = Response<Full<Bytes>>;
+        type Error = Infallible;
+        type Future = Ready<Result<Self::Response, Self::Error>>;
+
+        fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, request: Request<B>) -> Self::Future {
+            let method = request.method();
+            let file = self.files.get(request.uri().path());
+            if let Some(Err(rejection)) = self
+                .guard
+                .as_ref()
+                .map(|guard| check_guard(guard, &request))
+            {
+                return ready(Ok(rejected(&rejection, file.is_some())));
+            }
+
+            let response = if method != Method::GET && method != Method::HEAD {
+                Response::builder()
+                    .status(StatusCode::METHOD_NOT_ALLOWED)
+                    .header(header::ALLOW, "GET, HEAD")
+                    .body(Full::default())
+            } else if let Some((content_type, file)) = file {
+                let body = match file {
+                    File::Static(body) => Ok(body.clone()),
+                    File::Spec(provider) => provider
+                        .to_json()
+                        .map(|json| Bytes::copy_from_slice(json.as_bytes())),
+                };
+
+                match body {
+                    Ok(body) => Response::builder()
+                        .status(StatusCode::OK)
+                        .header(header::CONTENT_TYPE, *content_type)
+                        .body(if method == Method::HEAD {
+                            Full::default()
+                        } else {
+                            Full::new(body)
+                        }),
+                    Err(error) => Response::builder()
+                        .status(StatusCode::INTERNAL_SERVER_ERROR)
+                        .header(header::CONTENT_TYPE, "text/plain; charset=utf-8")
+                        .body(Full::new(Bytes::from(error.to_string()))),
+                }
+            } else {
+                Response::builder()
+                    .status(StatusCode::NOT_FOUND)
+                    .body(Full::default())
+            };
+
+            ready(Ok(response.expect("RapiDoc response must be valid")))
         }
     }
 }
@@ -387,11 +957,15 @@ mod axum {
 mod rocket {
     #![cfg(feature = "rocket")]
 
-    use rocket::http::Method;
+    use std::sync::Arc;
+
+    use fastapi_ui::guard::{Guard, GuardRequest, Rejection};
+    use fastapi_ui::shared::SpecProvider;
+    use rocket::http::{ContentType, Method, Status};
     use rocket::response::content::RawHtml;
+    use rocket::response::Responder;
     use rocket::route::{Handler, Outcome};
-    use rocket::serde::json::Json;
-    use rocket::{Data, Request, Route};
+    use rocket::{Data, Request, Response, Route};
 
     use crate::RapiDoc;
 
@@ -400,14 +974,14 @@ mod rocket {
             let mut routes = vec![Route::new(
                 Method::Get,
                 value.path.as_ref(),
-                RapiDocHandler(value.to_html()),
+                Guarded(value.guard.clone(), RapiDocHandler(value.to_html())),
             )];
 
             if let Some(openapi) = value.openapi {
                 routes.push(Route::new(
                     Method::Get,
                     value.spec_url.as_ref(),
-                    OpenApiHandler(openapi),
+                    Guarded(value.guard.clone(), OpenApiHandler(openapi)),
                 ));
             }
 
@@ -415,6 +989,40 @@ mod rocket {
         }
     }
 
+    /// Checks the request against the [`Guard`] before passing it to the inner [`Handler`].
+    #[derive(Clone)]
+    struct Guarded<H>(Option<Guard>, H);
+
+    #[rocket::async_trait]
+    impl<H: Handler + Clone> Handler for Guarded<H> {
+        async fn handle<'r>(&self, request: &'r Request<'_>, data: Data<'r>) -> Outcome<'r> {
+            if let Some(guard) = &self.0 {
+                if let Err(rejection) = guard.check(&GuardRequest::new(
+                    request.headers().get_one("Authorization"),
+                    request.remote().map(|addr| addr.ip()),
+                )) {
+                    return Outcome::from(request, GuardRejection(rejection));
+                }
+            }
+
+            self.1.handle(request, data).await
+        }
+    }
+
+    struct GuardRejection(Rejection);
+
+    impl<'r, 'o: 'r> Responder<'r, 'o> for GuardRejection {
+        fn respond_to(self, _: &'r Request<'_>) -> rocket::response::Result<'o> {
+            let mut response = Response::build();
+            response.status(Status::new(self.0.status_code()));
+            if let Some(challenge) = self.0.www_authenticate() {
+                response.raw_header("WWW-Authenticate", challenge.to_string());
+            }
+
+            response.ok()
+        }
+    }
+
     #[derive(Clone)]
     struct RapiDocHandler(String);
 
@@ -426,18 +1034,58 @@ mod rocket {
     }
 
     #[derive(Clone)]
-    struct OpenApiHandler(fastapi::openapi::OpenApi);
+    struct OpenApiHandler(Arc<dyn SpecProvider>);
 
     #[rocket::async_trait]
     impl Handler for OpenApiHandler {
         async fn handle<'r>(&self, request: &'r Request<'_>, _: Data<'r>) -> Outcome<'r> {
-            Outcome::from(request, Json(self.0.clone()))
+            match self.0.to_json() {
+                Ok(json) => Outcome::from(request, (ContentType::JSON, json.to_string())),
+                Err(error) => Outcome::from(
+                    request,
+                    rocket::response::status::Custom(
+                        Status::InternalServerError,
+                        error.to_string(),
+                    ),
+                ),
+            }
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
+    #[test]
+    fn test_to_html_renders_config_attributes() {
+        use super::{
+            config::{Config, RenderStyle, Theme},
+            RapiDoc,
+        };
+
+        let html = RapiDoc::new("/api-docs/openapi.json?a=\"$config\"")
+            .custom_html(r#"<rapi-doc spec-url="$specUrl" $config></rapi-doc>"#)
+            .config(
+                Config::new()
+                    .theme(Theme::Dark)
+                    .render_style(RenderStyle::Focused)
+                    .allow_try(false),
+            )
+            .to_html();
+
+        assert!(html
+            .starts_with(r#"<rapi-doc spec-url="/api-docs/openapi.json?a=&quot;$config&quot;" "#));
+        for attribute in [
+            r#"theme="dark""#,
+            r#"render-style="focused""#,
+            r#"allow-try="false""#,
+        ] {
+            assert!(
+                html.contains(attribute),
+                "{html} should contain {attribute}"
+            );
+        }
+    }
+
     #[test]
     #[cfg(feature = "axum")]
     fn test_axum_with_empty_path() {
@@ -452,4 +1100,128 @@ mod tests {
 
         let _: Router = Router::new().merge(RapiDoc::with_openapi("/rapidoc", ApiDoc::openapi()));
     }
+
+    #[tokio::test]
+    #[cfg(feature = "tower")]
+    async fn test_tower_service_serves_html_and_openapi() {
+        use fastapi::OpenApi;
+        use http::{Method, Request, StatusCode};
+        use http_body_util::BodyExt;
+        use tower::ServiceExt;
+
+        use super::{tower::RapiDocService, RapiDoc};
+
+        #[derive(fastapi::OpenApi)]
+        #[openapi()]
+        struct ApiDoc;
+
+        let service = RapiDocService::from(RapiDoc::with_url(
+            "/rapidoc",
+            "/api-docs/openapi.json",
+            ApiDoc::openapi(),
+        ));
+        let request = |method, uri| Request::builder().method(method).uri(uri).body(()).unwrap();
+
+        let response = service
+            .clone()
+            .oneshot(request(Method::GET, "/rapidoc"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let html = response.into_body().collect().await.unwrap().to_bytes();
+        assert!(std::str::from_utf8(&html)
+            .unwrap()
+            .contains("/api-docs/openapi.json"));
+
+        let response = service
+            .clone()
+            .oneshot(request(Method::GET, "/api-docs/openapi.json"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()["content-type"], "application/json");
+
+        let response = service
+            .clone()
+            .oneshot(request(Method::GET, "/other"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        let response = service
+            .oneshot(request(Method::DELETE, "/rapidoc"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::METHOD_NOT_ALLOWED);
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "tower")]
+    async fn test_tower_service_serves_updated_shared_openapi() {
+        use fastapi::openapi::OpenApiBuilder;
+        use fastapi_ui::shared::SharedOpenApi;
+        use http::Request;
+        use http_body_util::BodyExt;
+        use tower::ServiceExt;
+
+        use super::{tower::RapiDocService, RapiDoc};
+
+        let shared = SharedOpenApi::new(OpenApiBuilder::new().build());
+        let service = RapiDocService::from(
+            RapiDoc::with_spec_provider("/api-docs/openapi.json", shared.clone()).path("/rapidoc"),
+        );
+
+        for title in ["first", "second"] {
+            shared.update(|openapi| openapi.info.title = title.to_string());
+
+            let response = service
+                .clone()
+                .oneshot(Request::get("/api-docs/openapi.json").body(()).unwrap())
+                .await
+                .unwrap();
+            let body = response.into_body().collect().await.unwrap().to_bytes();
+            assert!(std::str::from_utf8(&body)
+                .unwrap()
+                .contains(&format!(r#""title":"{title}""#)));
+        }
+    }
+
+    #[tokio::test]
+    #[cfg(feature = "tower")]
+    async fn test_tower_service_guard_protects_rapidoc_and_openapi() {
+        use fastapi::openapi::OpenApiBuilder;
+        use fastapi_ui::guard::Guard;
+        use http::{header, Request, StatusCode};
+        use tower::ServiceExt;
+
+        use super::{tower::RapiDocService, RapiDoc};
+
+        let service = RapiDocService::from(
+            RapiDoc::with_openapi("/api-docs/openapi.json", OpenApiBuilder::new().build())
+                .path("/rapidoc")
+                .guard(Guard::bearer(|token| token == "token")),
+        );
+
+        for uri in ["/rapidoc", "/api-docs/openapi.json"] {
+            let response = service
+                .clone()
+                .oneshot(Request::get(uri).body(()).unwrap())
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+            assert_eq!(response.headers()[header::WWW_AUTHENTICATE], "Bearer");
+
+            let response = service
+                .clone()
+                .oneshot(
+                    Request::get(uri)
+                        .header(header::AUTHORIZATION, "Bearer token")
+                        .body(())
+                        .unwrap(),
+                )
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+    }
 }
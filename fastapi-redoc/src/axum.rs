@@ -1,19 +1,15 @@
 #![cfg(feature = "axum")]
 
-use axum::response::Html;
-use axum::{routing, Router};
+use axum::Router;
 
-use crate::{Redoc, Spec};
+use crate::{tower::RedocService, Redoc, Spec};
 
 impl<S: Spec, R> From<Redoc<S>> for Router<R>
 where
     R: Clone + Send + Sync + 'static,
 {
     fn from(value: Redoc<S>) -> Self {
-        let html = value.to_html();
-        Router::<R>::new().route(
-            value.url.as_ref(),
-            routing::get(move || async { Html(html) }),
-        )
+        let service = RedocService::from(value);
+        Router::<R>::new().route_service(service.url(), service.clone())
     }
 }
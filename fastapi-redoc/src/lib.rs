@@ -14,6 +14,8 @@
 //! * **actix-web** Allows serving [`Redoc`] via _**`actix-web`**_.
 //! * **rocket** Allows serving [`Redoc`] via _**`rocket`**_.
 //! * **axum** Allows serving [`Redoc`] via _**`axum`**_.
+//! * **tower** Allows serving [`Redoc`] via framework agnostic [`tower::RedocService`] with any
+//!   `tower` compatible server.
 //!
 //! # Install
 //!
@@ -182,29 +184,51 @@
 //! [examples]: <https://github.com/nxpkg/fastapi/tree/master/examples>
 
 use std::fs::OpenOptions;
+use std::sync::Arc;
 use std::{borrow::Cow, env};
 
 use fastapi::openapi::OpenApi;
+#[cfg(any(
+    feature = "actix-web",
+    feature = "rocket",
+    feature = "axum",
+    feature = "tower"
+))]
+use fastapi_ui::guard::Guard;
+use fastapi_ui::shared::{SharedOpenApi, SpecProvider};
 use serde::Serialize;
 use serde_json::{json, Value};
 
 mod actix;
 mod axum;
 mod rocket;
+#[cfg(feature = "tower")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "tower")))]
+pub mod tower;
 
 const DEFAULT_HTML: &str = include_str!("../res/redoc.html");
 
 /// Trait makes [`Redoc`] to accept an _`URL`_ the [Redoc][redoc] will be served via predefined web
 /// server.
 ///
-/// This is used **only** with **`actix-web`**, **`rocket`** or **`axum`** since they have implicit
-/// implementation for serving the [`Redoc`] via the _`URL`_.
+/// This is used **only** with **`actix-web`**, **`rocket`**, **`axum`** or **`tower`** since they
+/// have implicit implementation for serving the [`Redoc`] via the _`URL`_.
 ///
 /// [redoc]: <https://redocly.com/>
-#[cfg(any(feature = "actix-web", feature = "rocket", feature = "axum"))]
+#[cfg(any(
+    feature = "actix-web",
+    feature = "rocket",
+    feature = "axum",
+    feature = "tower"
+))]
 #[cfg_attr(
     doc_cfg,
-    doc(cfg(any(feature = "actix-web", feature = "rocket", feature = "axum")))
+    doc(cfg(any(
+        feature = "actix-web",
+        feature = "rocket",
+        feature = "axum",
+        feature = "tower"
+    )))
 )]
 pub trait Servable<S>
 where
@@ -228,7 +252,12 @@ where
     ) -> Self;
 }
 
-#[cfg(any(feature = "actix-web", feature = "rocket", feature = "axum"))]
+#[cfg(any(
+    feature = "actix-web",
+    feature = "rocket",
+    feature = "axum",
+    feature = "tower"
+))]
 impl<S: Spec> Servable<S> for Redoc<S> {
     fn with_url<U: Into<Cow<'static, str>>>(url: U, openapi: S) -> Self {
         Self::with_url_and_config(url, openapi, EmptyConfig)
@@ -244,6 +273,13 @@ impl<S: Spec> Servable<S> for Redoc<S> {
             html: Cow::Borrowed(DEFAULT_HTML),
             openapi,
             config: config.load(),
+            #[cfg(any(
+                feature = "actix-web",
+                feature = "rocket",
+                feature = "axum",
+                feature = "tower"
+            ))]
+            guard: None,
         }
     }
 }
@@ -263,6 +299,13 @@ pub struct Redoc<S: Spec> {
     html: Cow<'static, str>,
     openapi: S,
     config: Value,
+    #[cfg(any(
+        feature = "actix-web",
+        feature = "rocket",
+        feature = "axum",
+        feature = "tower"
+    ))]
+    guard: Option<Guard>,
 }
 
 impl<S: Spec> Redoc<S> {
@@ -298,6 +341,13 @@ impl<S: Spec> Redoc<S> {
             url: Cow::Borrowed(""),
             openapi,
             config: config.load(),
+            #[cfg(any(
+                feature = "actix-web",
+                feature = "rocket",
+                feature = "axum",
+                feature = "tower"
+            ))]
+            guard: None,
         }
     }
 
@@ -312,6 +362,51 @@ impl<S: Spec> Redoc<S> {
         self
     }
 
+    /// Restrict access to the [`Redoc`] with [`Guard`].
+    ///
+    /// Rejected requests get `401 Unauthorized` or `403 Forbidden` response depending on the
+    /// [`Rejection`][rejection] of the guard. With `axum` the application must be served with
+    /// `into_make_service_with_connect_info::<SocketAddr>()` for IP allow-lists to work.
+    ///
+    /// # Examples
+    ///
+    /// _**Allow access only from loopback addresses.**_
+    /// ```rust
+    /// # use std::net::{Ipv4Addr, Ipv6Addr};
+    /// # use fastapi_redoc::{Redoc, Servable};
+    /// # use fastapi_ui::guard::{Guard, IpNetwork};
+    /// # use fastapi::OpenApi;
+    /// # #[derive(OpenApi)]
+    /// # #[openapi()]
+    /// # struct ApiDoc;
+    /// Redoc::with_url("/redoc", ApiDoc::openapi()).guard(Guard::ip_allow_list([
+    ///     IpNetwork::from(Ipv4Addr::LOCALHOST),
+    ///     IpNetwork::from(Ipv6Addr::LOCALHOST),
+    /// ]));
+    /// ```
+    ///
+    /// [rejection]: fastapi_ui::guard::Rejection
+    #[cfg(any(
+        feature = "actix-web",
+        feature = "rocket",
+        feature = "axum",
+        feature = "tower"
+    ))]
+    #[cfg_attr(
+        doc_cfg,
+        doc(cfg(any(
+            feature = "actix-web",
+            feature = "rocket",
+            feature = "axum",
+            feature = "tower"
+        )))
+    )]
+    pub fn guard(mut self, guard: Guard) -> Self {
+        self.guard = Some(guard);
+
+        self
+    }
+
     /// Converts this [`Redoc`] instance to servable HTML file.
     ///
     /// This will replace _**`$config`**_ variable placeholder with [`Config`] of this instance and
@@ -324,24 +419,108 @@ impl<S: Spec> Redoc<S> {
     /// [redoc_html_quickstart]: <https://redocly.com/docs/redoc/quickstart/>
     /// [customization]: index.html#customization
     pub fn to_html(&self) -> String {
+        self.render(
+            &serde_json::to_string(&self.openapi).expect(
+                "Invalid OpenAPI spec, expected OpenApi, String, &str or serde_json::Value",
+            ),
+        )
+    }
+
+    fn render(&self, spec: &str) -> String {
         self.html
             .replace("$config", &self.config.to_string())
-            .replace(
-                "$spec",
-                &serde_json::to_string(&self.openapi).expect(
-                    "Invalid OpenAPI spec, expected OpenApi, String, &str or serde_json::Value",
-                ),
-            )
+            .replace("$spec", spec)
+    }
+}
+
+/// HTML of the [`Redoc`] served by the framework integrations.
+///
+/// HTML of [`Spec`] without [`SpecProvider`] is rendered once. Otherwise the HTML is rendered
+/// again when the JSON of the provider changes.
+#[cfg(any(
+    feature = "actix-web",
+    feature = "rocket",
+    feature = "axum",
+    feature = "tower"
+))]
+pub(crate) enum RedocHtml {
+    Static(Arc<str>),
+    Shared {
+        template: Redoc<&'static str>,
+        provider: Arc<dyn SpecProvider>,
+        cache: std::sync::Mutex<Option<(Arc<str>, Arc<str>)>>,
+    },
+}
+
+#[cfg(any(
+    feature = "actix-web",
+    feature = "rocket",
+    feature = "axum",
+    feature = "tower"
+))]
+impl RedocHtml {
+    pub(crate) fn html(&self) -> Result<Arc<str>, serde_json::Error> {
+        match self {
+            Self::Static(html) => Ok(html.clone()),
+            Self::Shared {
+                template,
+                provider,
+                cache,
+            } => {
+                let json = provider.to_json()?;
+                let mut cache = cache
+                    .lock()
+                    .unwrap_or_else(std::sync::PoisonError::into_inner);
+
+                match &*cache {
+                    Some((cached_json, html)) if Arc::ptr_eq(cached_json, &json) => {
+                        Ok(html.clone())
+                    }
+                    _ => {
+                        let html = Arc::<str>::from(template.render(&json));
+                        *cache = Some((json, html.clone()));
+                        Ok(html)
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(any(
+    feature = "actix-web",
+    feature = "rocket",
+    feature = "axum",
+    feature = "tower"
+))]
+impl<S: Spec> From<&Redoc<S>> for RedocHtml {
+    fn from(redoc: &Redoc<S>) -> Self {
+        match redoc.openapi.spec_provider() {
+            Some(provider) => Self::Shared {
+                template: Redoc {
+                    url: redoc.url.clone(),
+                    html: redoc.html.clone(),
+                    openapi: "",
+                    config: redoc.config.clone(),
+                    guard: None,
+                },
+                provider,
+                cache: std::sync::Mutex::new(None),
+            },
+            None => Self::Static(Arc::from(redoc.to_html())),
+        }
     }
 }
 
 /// Trait defines OpenAPI spec resource types supported by [`Redoc`].
 ///
 /// By default this trait is implemented for [`fastapi::openapi::OpenApi`], [`String`], [`&str`] and
-/// [`serde_json::Value`].
+/// [`serde_json::Value`] and [`SharedOpenApi`].
 ///
 /// * **OpenApi** implementation allows using fastapi's OpenApi struct as a OpenAPI spec resource
 ///   for the [`Redoc`].
+/// * **SharedOpenApi** implementation allows serving OpenAPI spec that can be updated at runtime.
+///   The HTML served by the framework integrations is rendered again after the spec has changed.
 /// * **String** and **&str** implementations allows defining HTTP URL for [`Redoc`] to load the
 ///   OpenAPI spec from.
 /// * **Value** implementation enables the use of arbitrary JSON values with serde's `json!()`
@@ -370,10 +549,36 @@ impl<S: Spec> Redoc<S> {
 /// # use serde_json::json;
 /// Redoc::new(json!({"openapi": "3.1.0"}));
 /// ```
-pub trait Spec: Serialize {}
+///
+/// _**Use [`Redoc`] to serve OpenAPI spec that can be updated at runtime.**_
+/// ```rust
+/// # use fastapi_redoc::Redoc;
+/// # use fastapi::openapi::OpenApiBuilder;
+/// # use fastapi_ui::shared::SharedOpenApi;
+/// let shared = SharedOpenApi::new(OpenApiBuilder::new().build());
+/// Redoc::new(shared.clone());
+///
+/// // later on
+/// shared.update(|openapi| openapi.info.version = "2.0.0".to_string());
+/// ```
+pub trait Spec: Serialize {
+    /// Get [`SpecProvider`] of this spec if the spec may change at runtime. Framework
+    /// integrations read the spec from the provider on each request when it is defined.
+    ///
+    /// By default the spec is static and `None` is returned.
+    fn spec_provider(&self) -> Option<Arc<dyn SpecProvider>> {
+        None
+    }
+}
 
 impl Spec for OpenApi {}
 
+impl Spec for SharedOpenApi {
+    fn spec_provider(&self) -> Option<Arc<dyn SpecProvider>> {
+        Some(Arc::new(self.clone()))
+    }
+}
+
 impl Spec for String {}
 
 impl Spec for &str {}
@@ -1,26 +1,80 @@
 #![cfg(feature = "actix-web")]
 
-use actix_web::dev::HttpServiceFactory;
+use std::fmt::{self, Display};
+
+use actix_web::dev::{HttpServiceFactory, Service, ServiceRequest};
 use actix_web::guard::Get;
+use actix_web::http::{header, StatusCode};
 use actix_web::web::Data;
-use actix_web::{HttpResponse, Resource, Responder};
+use actix_web::{HttpResponse, Resource, Responder, ResponseError};
+use fastapi_ui::guard::{Guard, GuardRequest, Rejection};
 
-use crate::{Scalar, Spec};
+use crate::{Scalar, ScalarHtml, Spec};
 
 impl<S: Spec> HttpServiceFactory for Scalar<S> {
     fn register(self, config: &mut actix_web::dev::AppService) {
-        let html = self.to_html();
+        let html = ScalarHtml::from(&self);
 
-        async fn serve_scalar(scalar: Data<String>) -> impl Responder {
-            HttpResponse::Ok()
-                .content_type("text/html")
-                .body(scalar.to_string())
+        async fn serve_scalar(scalar: Data<ScalarHtml>) -> impl Responder {
+            match scalar.html() {
+                Ok(html) => HttpResponse::Ok()
+                    .content_type("text/html")
+                    .body(html.to_string()),
+                Err(error) => HttpResponse::InternalServerError().body(error.to_string()),
+            }
         }
 
         Resource::new(self.url.as_ref())
             .guard(Get())
             .app_data(Data::new(html))
             .to(serve_scalar)
+            .wrap_fn(move |request, service| {
+                let response = check(self.guard.as_ref(), &request).map(|()| service.call(request));
+                async move { response?.await }
+            })
             .register(config);
     }
 }
+
+/// Check the `request` against the `guard` if any.
+fn check(guard: Option<&Guard>, request: &ServiceRequest) -> Result<(), actix_web::Error> {
+    let Some(guard) = guard else {
+        return Ok(());
+    };
+    let authorization = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok());
+
+    guard
+        .check(&GuardRequest::new(
+            authorization,
+            request.peer_addr().map(|addr| addr.ip()),
+        ))
+        .map_err(|rejection| GuardRejection(rejection).into())
+}
+
+/// [`Rejection`] of the [`Guard`] as `actix-web` error.
+#[derive(Debug)]
+struct GuardRejection(Rejection);
+
+impl Display for GuardRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl ResponseError for GuardRejection {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::from_u16(self.0.status_code()).unwrap_or(StatusCode::FORBIDDEN)
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        let mut response = HttpResponse::build(self.status_code());
+        if let Some(challenge) = self.0.www_authenticate() {
+            response.insert_header((header::WWW_AUTHENTICATE, challenge));
+        }
+
+        response.finish()
+    }
+}
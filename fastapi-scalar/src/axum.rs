@@ -1,19 +1,15 @@
 #![cfg(feature = "axum")]
 
-use axum::response::Html;
-use axum::{routing, Router};
+use axum::Router;
 
-use crate::{Scalar, Spec};
+use crate::{tower::ScalarService, Scalar, Spec};
 
 impl<S: Spec, R> From<Scalar<S>> for Router<R>
 where
     R: Clone + Send + Sync + 'static,
 {
     fn from(value: Scalar<S>) -> Self {
-        let html = value.to_html();
-        Router::<R>::new().route(
-            value.url.as_ref(),
-            routing::get(move || async { Html(html) }),
-        )
+        let service = ScalarService::from(value);
+        Router::<R>::new().route_service(service.url(), service.clone())
     }
 }
@@ -0,0 +1,410 @@
+//! Implements typed [Scalar configuration][configuration] for [`Scalar`][crate::Scalar].
+//!
+//! [`Config`] is serialized to JSON and rendered into the `data-configuration` attribute of the
+//! default HTML template. Custom HTML templates can use the **`$config`** variable to place the
+//! configuration. See more details at [`Scalar::config`][crate::Scalar::config].
+//!
+//! [configuration]: <https://github.com/scalar/scalar/blob/main/documentation/configuration.md>
+
+use std::collections::BTreeMap;
+
+use serde::{Serialize, Serializer};
+
+/// Typed [Scalar configuration][configuration].
+///
+/// All options are optional and Scalar defaults are used for options that are not defined.
+///
+/// # Examples
+///
+/// _**Create new [`Config`] with purple theme and classic layout.**_
+/// ```rust
+/// # use fastapi_scalar::config::{Config, Layout, Theme};
+/// let config = Config::new()
+///     .theme(Theme::Purple)
+///     .layout(Layout::Classic)
+///     .search_hot_key('s');
+/// ```
+///
+/// [configuration]: <https://github.com/scalar/scalar/blob/main/documentation/configuration.md>
+#[non_exhaustive]
+#[derive(Serialize, Clone, Default, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Config {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    theme: Option<Theme>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    layout: Option<Layout>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hidden_clients: Option<HiddenClients>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_http_client: Option<HttpClient>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    authentication: Option<Authentication>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    proxy_url: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    search_hot_key: Option<char>,
+}
+
+impl Config {
+    /// Construct a new empty [`Config`] using Scalar defaults.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the [`Theme`] of the Scalar.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fastapi_scalar::config::{Config, Theme};
+    /// let config = Config::new().theme(Theme::DeepSpace);
+    /// ```
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.theme = Some(theme);
+
+        self
+    }
+
+    /// Set the [`Layout`] of the Scalar.
+    pub fn layout(mut self, layout: Layout) -> Self {
+        self.layout = Some(layout);
+
+        self
+    }
+
+    /// Define [`HiddenClients`] that will not be shown in the client code examples.
+    ///
+    /// # Examples
+    ///
+    /// _**Hide `fetch` and `axios` clients.**_
+    /// ```rust
+    /// # use fastapi_scalar::config::{Config, HiddenClients};
+    /// let config = Config::new().hidden_clients(HiddenClients::clients(["fetch", "axios"]));
+    /// ```
+    pub fn hidden_clients(mut self, hidden_clients: HiddenClients) -> Self {
+        self.hidden_clients = Some(hidden_clients);
+
+        self
+    }
+
+    /// Set the [`HttpClient`] selected by default in the client code examples.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fastapi_scalar::config::{Config, HttpClient};
+    /// let config = Config::new().default_http_client(HttpClient::new("shell", "curl"));
+    /// ```
+    pub fn default_http_client(mut self, default_http_client: HttpClient) -> Self {
+        self.default_http_client = Some(default_http_client);
+
+        self
+    }
+
+    /// Set [`Authentication`] presets used to pre-fill the authentication of the API client.
+    pub fn authentication(mut self, authentication: Authentication) -> Self {
+        self.authentication = Some(authentication);
+
+        self
+    }
+
+    /// Set the url of the proxy used to avoid CORS issues when sending requests from the
+    /// API client e.g. `https://proxy.scalar.com`.
+    pub fn proxy_url<S: Into<String>>(mut self, proxy_url: S) -> Self {
+        self.proxy_url = Some(proxy_url.into());
+
+        self
+    }
+
+    /// Set the key used together with `CTRL` / `⌘` to open the search dialog. Default is `k`.
+    pub fn search_hot_key(mut self, search_hot_key: char) -> Self {
+        self.search_hot_key = Some(search_hot_key);
+
+        self
+    }
+}
+
+/// Color theme of the Scalar.
+#[non_exhaustive]
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum Theme {
+    /// Alternate theme.
+    Alternate,
+    /// Default theme.
+    Default,
+    /// Moon theme.
+    Moon,
+    /// Purple theme.
+    Purple,
+    /// Solarized theme.
+    Solarized,
+    /// Blue planet theme.
+    BluePlanet,
+    /// Deep space theme.
+    DeepSpace,
+    /// Saturn theme.
+    Saturn,
+    /// Kepler theme.
+    Kepler,
+    /// Mars theme.
+    Mars,
+    /// No theme at all, useful with custom CSS.
+    None,
+}
+
+/// Layout of the Scalar.
+#[non_exhaustive]
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum Layout {
+    /// Modern layout with sidebar and content side by side.
+    Modern,
+    /// Classic layout with content stacked like in Swagger UI.
+    Classic,
+}
+
+/// Defines HTTP clients hidden from the client code examples.
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum HiddenClients {
+    /// Hide all clients.
+    All,
+    /// Hide clients by client key from all targets e.g. `fetch` or `curl`.
+    Clients(Vec<String>),
+    /// Hide clients per target, e.g. target `node` with clients `fetch` and `axios`. Target
+    /// without clients hides the whole target.
+    Targets(BTreeMap<String, Vec<String>>),
+}
+
+impl HiddenClients {
+    /// Construct [`HiddenClients::Clients`] from client keys.
+    pub fn clients<I: IntoIterator<Item = S>, S: Into<String>>(clients: I) -> Self {
+        Self::Clients(clients.into_iter().map(Into::into).collect())
+    }
+}
+
+impl Serialize for HiddenClients {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Self::All => serializer.serialize_bool(true),
+            Self::Clients(clients) => clients.serialize(serializer),
+            Self::Targets(targets) => {
+                use serde::ser::SerializeMap;
+
+                let mut map = serializer.serialize_map(Some(targets.len()))?;
+                for (target, clients) in targets {
+                    if clients.is_empty() {
+                        map.serialize_entry(target, &true)?;
+                    } else {
+                        map.serialize_entry(target, clients)?;
+                    }
+                }
+                map.end()
+            }
+        }
+    }
+}
+
+/// HTTP client of the client code examples identified by target and client key, e.g. target
+/// `shell` and client `curl`.
+#[non_exhaustive]
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct HttpClient {
+    target_key: String,
+    client_key: String,
+}
+
+impl HttpClient {
+    /// Construct a new [`HttpClient`] with `target_key` and `client_key`.
+    pub fn new<T: Into<String>, C: Into<String>>(target_key: T, client_key: C) -> Self {
+        Self {
+            target_key: target_key.into(),
+            client_key: client_key.into(),
+        }
+    }
+}
+
+/// Authentication presets of the Scalar API client.
+///
+/// # Examples
+///
+/// _**Prefer `api_key` security scheme with pre-filled token.**_
+/// ```rust
+/// # use fastapi_scalar::config::Authentication;
+/// let authentication = Authentication::new()
+///     .preferred_security_scheme("api_key")
+///     .api_key("my-token");
+/// ```
+#[non_exhaustive]
+#[derive(Serialize, Clone, Default, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Authentication {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    preferred_security_scheme: Option<String>,
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    api_key: Option<Token>,
+
+    #[serde(skip_serializing_if = "Http::is_empty")]
+    http: Http,
+
+    #[serde(rename = "oAuth2", skip_serializing_if = "Option::is_none")]
+    oauth2: Option<OAuth2>,
+}
+
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+struct Token {
+    token: String,
+}
+
+#[derive(Serialize, Clone, Default, Debug, PartialEq, Eq)]
+struct Http {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    basic: Option<Basic>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bearer: Option<Token>,
+}
+
+impl Http {
+    fn is_empty(&self) -> bool {
+        self.basic.is_none() && self.bearer.is_none()
+    }
+}
+
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+struct Basic {
+    username: String,
+    password: String,
+}
+
+#[derive(Serialize, Clone, Debug, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+struct OAuth2 {
+    client_id: String,
+    scopes: Vec<String>,
+}
+
+impl Authentication {
+    /// Construct a new empty [`Authentication`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set name of the security scheme selected by default in the API client.
+    pub fn preferred_security_scheme<S: Into<String>>(mut self, name: S) -> Self {
+        self.preferred_security_scheme = Some(name.into());
+
+        self
+    }
+
+    /// Pre-fill token of API key security scheme.
+    pub fn api_key<S: Into<String>>(mut self, token: S) -> Self {
+        self.api_key = Some(Token {
+            token: token.into(),
+        });
+
+        self
+    }
+
+    /// Pre-fill username and password of HTTP basic security scheme.
+    pub fn http_basic<U: Into<String>, P: Into<String>>(
+        mut self,
+        username: U,
+        password: P,
+    ) -> Self {
+        self.http.basic = Some(Basic {
+            username: username.into(),
+            password: password.into(),
+        });
+
+        self
+    }
+
+    /// Pre-fill token of HTTP bearer security scheme.
+    pub fn http_bearer<S: Into<String>>(mut self, token: S) -> Self {
+        self.http.bearer = Some(Token {
+            token: token.into(),
+        });
+
+        self
+    }
+
+    /// Pre-fill client id and selected scopes of OAuth2 security scheme.
+    pub fn oauth2<C: Into<String>, I: IntoIterator<Item = S>, S: Into<String>>(
+        mut self,
+        client_id: C,
+        scopes: I,
+    ) -> Self {
+        self.oauth2 = Some(OAuth2 {
+            client_id: client_id.into(),
+            scopes: scopes.into_iter().map(Into::into).collect(),
+        });
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn serialize_config() {
+        let config = Config::new()
+            .theme(Theme::BluePlanet)
+            .layout(Layout::Classic)
+            .hidden_clients(HiddenClients::Targets(BTreeMap::from([
+                ("node".to_string(), vec!["fetch".to_string()]),
+                ("php".to_string(), Vec::new()),
+            ])))
+            .default_http_client(HttpClient::new("shell", "curl"))
+            .authentication(
+                Authentication::new()
+                    .preferred_security_scheme("bearer")
+                    .http_bearer("token")
+                    .oauth2("client", ["read"]),
+            )
+            .proxy_url("https://proxy.scalar.com")
+            .search_hot_key('s');
+
+        assert_eq!(
+            serde_json::to_value(&config).unwrap(),
+            json!({
+                "theme": "bluePlanet",
+                "layout": "classic",
+                "hiddenClients": { "node": ["fetch"], "php": true },
+                "defaultHttpClient": { "targetKey": "shell", "clientKey": "curl" },
+                "authentication": {
+                    "preferredSecurityScheme": "bearer",
+                    "http": { "bearer": { "token": "token" } },
+                    "oAuth2": { "clientId": "client", "scopes": ["read"] }
+                },
+                "proxyUrl": "https://proxy.scalar.com",
+                "searchHotKey": "s"
+            })
+        );
+    }
+
+    #[test]
+    fn serialize_empty_config() {
+        assert_eq!(serde_json::to_value(Config::new()).unwrap(), json!({}));
+        assert_eq!(
+            serde_json::to_value(Config::new().hidden_clients(HiddenClients::All)).unwrap(),
+            json!({ "hiddenClients": true })
+        );
+    }
+}
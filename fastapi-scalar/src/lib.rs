@@ -14,6 +14,8 @@
 //! * **actix-web** Allows serving [`Scalar`] via _**`actix-web`**_.
 //! * **rocket** Allows serving [`Scalar`] via _**`rocket`**_.
 //! * **axum** Allows serving [`Scalar`] via _**`axum`**_.
+//! * **tower** Allows serving [`Scalar`] via framework agnostic [`tower::ScalarService`] with any
+//!   `tower` compatible server.
 //!
 //! # Install
 //!
@@ -69,6 +71,25 @@
 //! [`Scalar::to_html`] execution.
 //!
 //! * **`$spec`** Will be the [`Spec`] that will be rendered via [Scalar][scalar].
+//! * **`$config`** Will be the typed [`Config`][config::Config] serialized to JSON and escaped
+//!   for HTML attribute value. In the default template it is the `data-configuration` attribute.
+//!
+//! _**Configure Scalar with typed [`Config`][config::Config].**_
+//! ```rust
+//! # use fastapi_scalar::Scalar;
+//! # use fastapi_scalar::config::{Authentication, Config, HttpClient, Theme};
+//! # use fastapi::OpenApi;
+//! # #[derive(OpenApi)]
+//! # #[openapi()]
+//! # struct ApiDoc;
+//! #
+//! Scalar::new(ApiDoc::openapi()).config(
+//!     Config::new()
+//!         .theme(Theme::Moon)
+//!         .default_http_client(HttpClient::new("shell", "curl"))
+//!         .authentication(Authentication::new().preferred_security_scheme("api_key")),
+//! );
+//! ```
 //!
 //! _**Overriding the HTML template with a custom one.**_
 //! ```rust
@@ -146,28 +167,54 @@
 //! [html]: <https://github.com/scalar/scalar/blob/main/documentation/integrations/html.md>
 
 use std::borrow::Cow;
+use std::sync::Arc;
 
 use fastapi::openapi::OpenApi;
+#[cfg(any(
+    feature = "actix-web",
+    feature = "rocket",
+    feature = "axum",
+    feature = "tower"
+))]
+use fastapi_ui::guard::Guard;
+use fastapi_ui::html::{escape_html, escape_script_json, render};
+use fastapi_ui::shared::{SharedOpenApi, SpecProvider};
 use serde::Serialize;
 use serde_json::Value;
 
+use crate::config::Config;
+
 mod actix;
 mod axum;
+pub mod config;
 mod rocket;
+#[cfg(feature = "tower")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "tower")))]
+pub mod tower;
 
 const DEFAULT_HTML: &str = include_str!("../res/scalar.html");
 
 /// Trait makes [`Scalar`] to accept an _`URL`_ the [Scalar][scalar] will be served via predefined
 /// web server.
 ///
-/// This is used **only** with **`actix-web`**, **`rocket`** or **`axum`** since they have implicit
-/// implementation for serving the [`Scalar`] via the _`URL`_.
+/// This is used **only** with **`actix-web`**, **`rocket`**, **`axum`** or **`tower`** since they
+/// have implicit implementation for serving the [`Scalar`] via the _`URL`_.
 ///
 /// [scalar]: <https://scalar.com/>
-#[cfg(any(feature = "actix-web", feature = "rocket", feature = "axum"))]
+#[cfg(any(
+    feature = "actix-web",
+    feature = "rocket",
+    feature = "axum",
+    feature = "tower"
+))]
 #[cfg_attr(
     doc_cfg,
-    doc(cfg(any(feature = "actix-web", feature = "rocket", feature = "axum")))
+    doc(cfg(any(
+        feature = "actix-web",
+        feature = "rocket",
+        feature = "axum",
+        feature = "tower"
+    )))
 )]
 pub trait Servable<S>
 where
@@ -180,13 +227,26 @@ where
     fn with_url<U: Into<Cow<'static, str>>>(url: U, openapi: S) -> Self;
 }
 
-#[cfg(any(feature = "actix-web", feature = "rocket", feature = "axum"))]
+#[cfg(any(
+    feature = "actix-web",
+    feature = "rocket",
+    feature = "axum",
+    feature = "tower"
+))]
 impl<S: Spec> Servable<S> for Scalar<S> {
     fn with_url<U: Into<Cow<'static, str>>>(url: U, openapi: S) -> Self {
         Self {
             html: Cow::Borrowed(DEFAULT_HTML),
             url: url.into(),
             openapi,
+            config: Config::default(),
+            #[cfg(any(
+                feature = "actix-web",
+                feature = "rocket",
+                feature = "axum",
+                feature = "tower"
+            ))]
+            guard: None,
         }
     }
 }
@@ -205,6 +265,14 @@ pub struct Scalar<S: Spec> {
     url: Cow<'static, str>,
     html: Cow<'static, str>,
     openapi: S,
+    config: Config,
+    #[cfg(any(
+        feature = "actix-web",
+        feature = "rocket",
+        feature = "axum",
+        feature = "tower"
+    ))]
+    guard: Option<Guard>,
 }
 
 impl<S: Spec> Scalar<S> {
@@ -223,26 +291,65 @@ impl<S: Spec> Scalar<S> {
             html: Cow::Borrowed(DEFAULT_HTML),
             url: Cow::Borrowed("/"),
             openapi,
+            config: Config::default(),
+            #[cfg(any(
+                feature = "actix-web",
+                feature = "rocket",
+                feature = "axum",
+                feature = "tower"
+            ))]
+            guard: None,
         }
     }
 
+    /// Set typed [`Config`] for the [`Scalar`].
+    ///
+    /// The configuration is serialized to JSON and rendered into the **`$config`** variable of
+    /// the HTML template escaped for HTML attribute value.
+    ///
+    /// # Examples
+    ///
+    /// _**Use purple theme with classic layout.**_
+    /// ```
+    /// # use fastapi_scalar::Scalar;
+    /// # use fastapi_scalar::config::{Config, Layout, Theme};
+    /// # use serde_json::json;
+    /// Scalar::new(json!({"openapi": "3.1.0"}))
+    ///     .config(Config::new().theme(Theme::Purple).layout(Layout::Classic));
+    /// ```
+    pub fn config(mut self, config: Config) -> Self {
+        self.config = config;
+
+        self
+    }
+
     /// Converts this [`Scalar`] instance to servable HTML file.
     ///
     /// This will replace _**`$spec`**_ variable placeholder with [`Spec`] of this instance
-    /// provided to this instance serializing it to JSON from the HTML template used with the
-    /// [`Scalar`].
-    ///
-    /// At this point in time, it is not possible to customize the HTML template used by the
-    /// [`Scalar`] instance.
+    /// serialized to JSON and _**`$config`**_ variable placeholder with [`Config`] serialized to
+    /// JSON from the HTML template used with the [`Scalar`]. The spec is escaped so that it can
+    /// be safely placed inside `<script>` element and the config is escaped for HTML attribute
+    /// value.
     pub fn to_html(&self) -> String {
-        self.html.replace(
-            "$spec",
+        self.render_spec(
             &serde_json::to_string(&self.openapi).expect(
                 "Invalid OpenAPI spec, expected OpenApi, String, &str or serde_json::Value",
             ),
         )
     }
 
+    fn render_spec(&self, spec: &str) -> String {
+        let config = serde_json::to_string(&self.config).expect("Config should serialize to JSON");
+
+        render(
+            &self.html,
+            &[
+                ("$spec", &escape_script_json(spec)),
+                ("$config", &escape_html(&config)),
+            ],
+        )
+    }
+
     /// Override the [default HTML template][scalar_html_quickstart] with new one. Refer to
     /// [customization] for more comprehensive guide for customization options.
     ///
@@ -253,14 +360,141 @@ impl<S: Spec> Scalar<S> {
 
         self
     }
+
+    /// Restrict access to the [`Scalar`] with [`Guard`].
+    ///
+    /// Rejected requests get `401 Unauthorized` or `403 Forbidden` response depending on the
+    /// [`Rejection`][rejection] of the guard. With `axum` the application must be served with
+    /// `into_make_service_with_connect_info::<SocketAddr>()` for IP allow-lists to work.
+    ///
+    /// # Examples
+    ///
+    /// _**Allow access only from loopback addresses.**_
+    /// ```rust
+    /// # use std::net::{Ipv4Addr, Ipv6Addr};
+    /// # use fastapi_scalar::{Scalar, Servable};
+    /// # use fastapi_ui::guard::{Guard, IpNetwork};
+    /// # use fastapi::OpenApi;
+    /// # #[derive(OpenApi)]
+    /// # #[openapi()]
+    /// # struct ApiDoc;
+    /// Scalar::with_url("/scalar", ApiDoc::openapi()).guard(Guard::ip_allow_list([
+    ///     IpNetwork::from(Ipv4Addr::LOCALHOST),
+    ///     IpNetwork::from(Ipv6Addr::LOCALHOST),
+    /// ]));
+    /// ```
+    ///
+    /// [rejection]: fastapi_ui::guard::Rejection
+    #[cfg(any(
+        feature = "actix-web",
+        feature = "rocket",
+        feature = "axum",
+        feature = "tower"
+    ))]
+    #[cfg_attr(
+        doc_cfg,
+        doc(cfg(any(
+            feature = "actix-web",
+            feature = "rocket",
+            feature = "axum",
+            feature = "tower"
+        )))
+    )]
+    pub fn guard(mut self, guard: Guard) -> Self {
+        self.guard = Some(guard);
+
+        self
+    }
+}
+
+/// HTML of the [`Scalar`] served by the framework integrations.
+///
+/// HTML of [`Spec`] without [`SpecProvider`] is rendered once. Otherwise the HTML is rendered
+/// again when the JSON of the provider changes.
+#[cfg(any(
+    feature = "actix-web",
+    feature = "rocket",
+    feature = "axum",
+    feature = "tower"
+))]
+pub(crate) enum ScalarHtml {
+    Static(Arc<str>),
+    Shared {
+        template: Box<Scalar<Value>>,
+        provider: Arc<dyn SpecProvider>,
+        cache: std::sync::Mutex<Option<(Arc<str>, Arc<str>)>>,
+    },
+}
+
+#[cfg(any(
+    feature = "actix-web",
+    feature = "rocket",
+    feature = "axum",
+    feature = "tower"
+))]
+impl ScalarHtml {
+    pub(crate) fn html(&self) -> Result<Arc<str>, serde_json::Error> {
+        match self {
+            Self::Static(html) => Ok(html.clone()),
+            Self::Shared {
+                template,
+                provider,
+                cache,
+            } => {
+                let json = provider.to_json()?;
+                let mut cache = cache
+                    .lock()
+                    .unwrap_or_else(std::sync::PoisonError::into_inner);
+
+                match &*cache {
+                    Some((cached_json, html)) if Arc::ptr_eq(cached_json, &json) => {
+                        Ok(html.clone())
+                    }
+                    _ => {
+                        let html = Arc::<str>::from(template.render_spec(&json));
+                        *cache = Some((json, html.clone()));
+                        Ok(html)
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(any(
+    feature = "actix-web",
+    feature = "rocket",
+    feature = "axum",
+    feature = "tower"
+))]
+impl<S: Spec> From<&Scalar<S>> for ScalarHtml {
+    fn from(scalar: &Scalar<S>) -> Self {
+        match scalar.openapi.spec_provider() {
+            Some(provider) => Self::Shared {
+                template: Box::new(Scalar {
+                    url: scalar.url.clone(),
+                    html: scalar.html.clone(),
+                    openapi: Value::Null,
+                    config: scalar.config.clone(),
+                    guard: None,
+                }),
+                provider,
+                cache: std::sync::Mutex::new(None),
+            },
+            None => Self::Static(Arc::from(scalar.to_html())),
+        }
+    }
 }
 
 /// Trait defines OpenAPI spec resource types supported by [`Scalar`].
 ///
-/// By default this trait is implemented for [`fastapi::openapi::OpenApi`] and [`serde_json::Value`].
+/// By default this trait is implemented for [`fastapi::openapi::OpenApi`], [`serde_json::Value`]
+/// and [`SharedOpenApi`].
 ///
 /// * **OpenApi** implementation allows using fastapi's OpenApi struct as a OpenAPI spec resource
 ///   for the [`Scalar`].
+/// * **SharedOpenApi** implementation allows serving OpenAPI spec that can be updated at runtime.
+///   The HTML served by the framework integrations is rendered again after the spec has changed.
 /// * **Value** implementation enables the use of arbitrary JSON values with serde's `json!()`
 ///   macro as a OpenAPI spec for the [`Scalar`].
 ///
@@ -280,8 +514,85 @@ impl<S: Spec> Scalar<S> {
 /// # use serde_json::json;
 /// Scalar::new(json!({"openapi": "3.1.0"}));
 /// ```
-pub trait Spec: Serialize {}
+///
+/// _**Use [`Scalar`] to serve OpenAPI spec that can be updated at runtime.**_
+/// ```rust
+/// # use fastapi_scalar::Scalar;
+/// # use fastapi::openapi::OpenApiBuilder;
+/// # use fastapi_ui::shared::SharedOpenApi;
+/// let shared = SharedOpenApi::new(OpenApiBuilder::new().build());
+/// Scalar::new(shared.clone());
+///
+/// // later on
+/// shared.update(|openapi| openapi.info.version = "2.0.0".to_string());
+/// ```
+pub trait Spec: Serialize {
+    /// Get [`SpecProvider`] of this spec if the spec may change at runtime. Framework
+    /// integrations read the spec from the provider on each request when it is defined.
+    ///
+    /// By default the spec is static and `None` is returned.
+    fn spec_provider(&self) -> Option<Arc<dyn SpecProvider>> {
+        None
+    }
+}
 
 impl Spec for OpenApi {}
 
+impl Spec for SharedOpenApi {
+    fn spec_provider(&self) -> Option<Arc<dyn SpecProvider>> {
+        Some(Arc::new(self.clone()))
+    }
+}
+
 impl Spec for Value {}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::config::Theme;
+
+    #[test]
+    fn to_html_escapes_spec_and_config() {
+        let html = Scalar::new(json!({"info": {"title": "</script><script>alert(1)</script>"}}))
+            .config(
+                Config::new()
+                    .theme(Theme::Purple)
+                    .proxy_url("https://proxy/?a='b'&c=\"d\""),
+            )
+            .custom_html("<script data-configuration='$config'>$spec</script>")
+            .to_html();
+
+        assert_eq!(
+            html,
+            r#"<script data-configuration='{&quot;theme&quot;:&quot;purple&quot;,&quot;proxyUrl&quot;:&quot;https://proxy/?a=&#39;b&#39;&amp;c=\&quot;d\&quot;&quot;}'>{"info":{"title":"\u003c/script\u003e\u003cscript\u003ealert(1)\u003c/script\u003e"}}</script>"#
+        );
+    }
+
+    #[test]
+    fn render_does_not_substitute_variables_in_values() {
+        let html = Scalar::new(json!({"description": "$config"}))
+            .custom_html("$spec|$config")
+            .to_html();
+
+        assert_eq!(html, r#"{"description":"$config"}|{}"#);
+    }
+
+    #[test]
+    #[cfg(feature = "tower")]
+    fn shared_html_is_rendered_again_after_update() {
+        use fastapi::openapi::OpenApiBuilder;
+
+        let shared = SharedOpenApi::new(OpenApiBuilder::new().build());
+        let html = ScalarHtml::from(&Scalar::new(shared.clone()).custom_html("$spec"));
+
+        shared.update(|openapi| openapi.info.title = "first".to_string());
+        let first = html.html().unwrap();
+        assert!(first.contains(r#""title":"first""#));
+        assert!(Arc::ptr_eq(&first, &html.html().unwrap()));
+
+        shared.update(|openapi| openapi.info.title = "second".to_string());
+        assert!(html.html().unwrap().contains(r#""title":"second""#));
+    }
+}
@@ -1,28 +1,76 @@
 #![cfg(feature = "rocket")]
 
-use rocket::http::Method;
+use std::sync::Arc;
+
+use fastapi_ui::guard::{Guard, GuardRequest, Rejection};
+use rocket::http::{Method, Status};
 use rocket::response::content::RawHtml;
+use rocket::response::status::Custom;
+use rocket::response::Responder;
 use rocket::route::{Handler, Outcome};
-use rocket::{Data, Request, Route};
+use rocket::{Data, Request, Response, Route};
 
-use crate::{Scalar, Spec};
+use crate::{Scalar, ScalarHtml, Spec};
 
 impl<S: Spec> From<Scalar<S>> for Vec<Route> {
     fn from(value: Scalar<S>) -> Self {
         vec![Route::new(
             Method::Get,
             value.url.as_ref(),
-            ScalarHandler(value.to_html()),
+            Guarded(
+                value.guard.clone(),
+                ScalarHandler(Arc::new(ScalarHtml::from(&value))),
+            ),
         )]
     }
 }
 
+/// Checks the request against the [`Guard`] before passing it to the inner [`Handler`].
+#[derive(Clone)]
+struct Guarded<H>(Option<Guard>, H);
+
+#[rocket::async_trait]
+impl<H: Handler + Clone> Handler for Guarded<H> {
+    async fn handle<'r>(&self, request: &'r Request<'_>, data: Data<'r>) -> Outcome<'r> {
+        if let Some(guard) = &self.0 {
+            if let Err(rejection) = guard.check(&GuardRequest::new(
+                request.headers().get_one("Authorization"),
+                request.remote().map(|addr| addr.ip()),
+            )) {
+                return Outcome::from(request, GuardRejection(rejection));
+            }
+        }
+
+        self.1.handle(request, data).await
+    }
+}
+
+struct GuardRejection(Rejection);
+
+impl<'r, 'o: 'r> Responder<'r, 'o> for GuardRejection {
+    fn respond_to(self, _: &'r Request<'_>) -> rocket::response::Result<'o> {
+        let mut response = Response::build();
+        response.status(Status::new(self.0.status_code()));
+        if let Some(challenge) = self.0.www_authenticate() {
+            response.raw_header("WWW-Authenticate", challenge.to_string());
+        }
+
+        response.ok()
+    }
+}
+
 #[derive(Clone)]
-struct ScalarHandler(String);
+struct ScalarHandler(Arc<ScalarHtml>);
 
 #[rocket::async_trait]
 impl Handler for ScalarHandler {
     async fn handle<'r>(&self, request: &'r Request<'_>, _: Data<'r>) -> Outcome<'r> {
-        Outcome::from(request, RawHtml(self.0.clone()))
+        match self.0.html() {
+            Ok(html) => Outcome::from(request, RawHtml(html.to_string())),
+            Err(error) => Outcome::from(
+                request,
+                Custom(Status::InternalServerError, error.to_string()),
+            ),
+        }
     }
 }
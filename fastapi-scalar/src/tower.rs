@@ -0,0 +1,123 @@
+//! Implements framework agnostic [`tower_service::Service`] for serving the [`Scalar`].
+//!
+//! [`ScalarService`] can be used with any server that accepts a `tower::Service` such as plain
+//! `hyper` or `poem`. The `axum` integration is built on top of it as well.
+
+use std::{
+    convert::Infallible,
+    future::{ready, Ready},
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+use bytes::Bytes;
+use fastapi_ui::guard::Guard;
+use fastapi_ui::tower::{check_guard, rejected};
+use http::{header, Method, Request, Response, StatusCode};
+use http_body_util::Full;
+use tower_service::Service;
+
+use crate::{Scalar, ScalarHtml, Spec};
+
+/// Framework agnostic [`tower_service::Service`] serving the [`Scalar`] HTML from the url of
+/// the [`Scalar`].
+///
+/// The service is created from [`Scalar`] with [`From`] implementation. The HTML is rendered
+/// once when the service is created unless the [`Spec`] of the [`Scalar`] may change at
+/// runtime, e.g. [`SharedOpenApi`][fastapi_ui::shared::SharedOpenApi]. Only `GET` and `HEAD` requests are served, other methods
+/// will get `405 Method Not Allowed` and unknown paths `404 Not Found` response.
+///
+/// All requests are checked against the [`Guard`] of the [`Scalar`] if any. The peer address
+/// for IP allow-lists is read with [`peer_addr`][fastapi_ui::tower::peer_addr].
+///
+/// # Examples
+///
+/// _**Create [`ScalarService`] serving [`Scalar`] from `/scalar`.**_
+/// ```rust
+/// # use fastapi::OpenApi;
+/// use fastapi_scalar::{Scalar, Servable, tower::ScalarService};
+/// # #[derive(OpenApi)]
+/// # #[openapi()]
+/// # struct ApiDoc;
+///
+/// let service = ScalarService::from(Scalar::with_url("/scalar", ApiDoc::openapi()));
+/// ```
+#[derive(Clone)]
+pub struct ScalarService {
+    url: Arc<str>,
+    html: Arc<ScalarHtml>,
+    guard: Option<Guard>,
+}
+
+impl ScalarService {
+    /// Get the url this [`ScalarService`] is served from.
+    #[cfg(feature = "axum")]
+    pub(crate) fn url(&self) -> &str {
+        &self.url
+    }
+}
+
+impl<S: Spec> From<Scalar<S>> for ScalarService {
+    fn from(scalar: Scalar<S>) -> Self {
+        Self {
+            html: Arc::new(ScalarHtml::from(&scalar)),
+            url: Arc::from(scalar.url.as_ref()),
+            guard: scalar.guard,
+        }
+    }
+}
+
+impl<B> Service<Request<B>> for ScalarService {
+    type Response = Response<Full<Bytes>>;
+    type Error = Infallible;
+    type Future = Ready<Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: Request<B>) -> Self::Future {
+        let method = request.method();
+        let served = request.uri().path() == &*self.url;
+        if let Some(Err(rejection)) = self
+            .guard
+            .as_ref()
+            .map(|guard| check_guard(guard, &request))
+        {
+            return ready(Ok(rejected(&rejection, served)));
+        }
+
+        let response = if method != Method::GET && method != Method::HEAD {
+            Response::builder()
+                .status(StatusCode::METHOD_NOT_ALLOWED)
+                .header(header::ALLOW, "GET, HEAD")
+                .body(Full::default())
+        } else if !served {
+            Response::builder()
+                .status(StatusCode::NOT_FOUND)
+                .body(Full::default())
+        } else {
+            let body = self
+                .html
+                .html()
+                .map(|html| Bytes::copy_from_slice(html.as_bytes()));
+
+            match body {
+                Ok(body) => Response::builder()
+                    .status(StatusCode::OK)
+                    .header(header::CONTENT_TYPE, "text/html; charset=utf-8")
+                    .body(if method == Method::HEAD {
+                        Full::default()
+                    } else {
+                        Full::new(body)
+                    }),
+                Err(error) => Response::builder()
+                    .status(StatusCode::INTERNAL_SERVER_ERROR)
+                    .header(header::CONTENT_TYPE, "text/plain; charset=utf-8")
+                    .body(Full::new(Bytes::from(error.to_string()))),
+            }
+        };
+
+        ready(Ok(response.expect("Scalar response must be valid")))
+    }
+}
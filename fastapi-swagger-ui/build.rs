@@ -72,6 +72,8 @@ fn main() {
     } else {
         println!("{SWAGGER_UI_OVERWRITE_FOLDER} not found: {overwrite_folder:?}");
     }
+
+    compress_dist(&target_dir, &zip_top_level_folder);
 }
 
 enum SwaggerZip {
@@ -364,6 +366,68 @@ fn download_file_curl<T: AsRef<Path>>(url: &str, target_dir: T) -> Result<(), Bo
         .map_err(Box::new)?)
 }
 
+/// Extensions of the files that will be pre-compressed with gzip and brotli.
+const COMPRESSED_EXTENSIONS: [&str; 3] = ["js", "css", "html"];
+
+/// Write pre-compressed `.gz` and `.br` variants of the text files of Swagger UI dist next to
+/// the original files. `swagger-initializer.js` is skipped since it is rendered at runtime with
+/// the user defined config.
+fn compress_dist(target_dir: &str, zip_top_level_folder: &str) {
+    let dist = [target_dir, zip_top_level_folder, "dist"]
+        .iter()
+        .collect::<PathBuf>();
+
+    for entry in fs::read_dir(&dist).unwrap() {
+        let path = entry.unwrap().path();
+        let compress = path.is_file()
+            && path.file_name().and_then(|name| name.to_str()) != Some("swagger-initializer.js")
+            && path
+                .extension()
+                .and_then(|extension| extension.to_str())
+                .map(|extension| COMPRESSED_EXTENSIONS.contains(&extension))
+                .unwrap_or(false);
+        if !compress {
+            continue;
+        }
+
+        let content = fs::read(&path).unwrap();
+        write_compressed(&path, "gz", &content, gzip(&content));
+        write_compressed(&path, "br", &content, brotli(&content));
+    }
+}
+
+/// Write compressed variant of the file only if it is smaller than the original content.
+fn write_compressed(path: &Path, extension: &str, content: &[u8], compressed: Vec<u8>) {
+    let mut compressed_path = path.as_os_str().to_owned();
+    compressed_path.push(".");
+    compressed_path.push(extension);
+
+    if compressed.len() < content.len() {
+        fs::write(&compressed_path, compressed).unwrap();
+    } else if Path::new(&compressed_path).exists() {
+        fs::remove_file(&compressed_path).unwrap();
+    }
+}
+
+fn gzip(content: &[u8]) -> Vec<u8> {
+    use io::Write;
+
+    let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::best());
+    encoder.write_all(content).unwrap();
+    encoder.finish().unwrap()
+}
+
+fn brotli(content: &[u8]) -> Vec<u8> {
+    let mut compressed = Vec::new();
+    let params = brotli::enc::BrotliEncoderParams {
+        quality: 9,
+        lgwin: 22,
+        ..Default::default()
+    };
+    brotli::BrotliCompress(&mut Cursor::new(content), &mut compressed, &params).unwrap();
+    compressed
+}
+
 fn overwrite_target_file(target_dir: &str, swagger_ui_dist_zip: &str, path_in: PathBuf) {
     let filename = path_in.file_name().unwrap().to_str().unwrap();
     println!("overwrite file: {:?}", path_in.file_name().unwrap());
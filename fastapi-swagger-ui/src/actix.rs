@@ -1,11 +1,22 @@
 #![cfg(feature = "actix-web")]
 
+use std::fmt::{self, Display};
+
 use actix_web::{
-    dev::HttpServiceFactory, guard::Get, web, web::Data, HttpResponse, Resource,
-    Responder as ActixResponder,
+    dev::{HttpServiceFactory, Service, ServiceRequest},
+    guard::Get,
+    http::header,
+    http::StatusCode,
+    web,
+    web::Data,
+    HttpRequest, HttpResponse, Resource, Responder as ActixResponder, ResponseError,
 };
+use fastapi_ui::guard::{Guard, GuardRequest, Rejection};
 
-use crate::{ApiDoc, Config, SwaggerUi};
+use crate::{
+    assets::{AssetStatus, SwaggerUiAssets},
+    ApiDoc, Config, SwaggerUi,
+};
 
 impl HttpServiceFactory for SwaggerUi {
     fn register(self, config: &mut actix_web::dev::AppService) {
@@ -13,52 +24,155 @@ impl HttpServiceFactory for SwaggerUi {
             .urls
             .into_iter()
             .map(|(url, openapi)| {
-                register_api_doc_url_resource(url.url.as_ref(), ApiDoc::Fastapi(openapi), config);
+                register_api_doc_url_resource(
+                    url.url.as_ref(),
+                    ApiDoc::Fastapi(openapi),
+                    self.guard.clone(),
+                    config,
+                );
                 url
             })
             .collect::<Vec<_>>();
         let external_api_docs = self.external_urls.into_iter().map(|(url, api_doc)| {
-            register_api_doc_url_resource(url.url.as_ref(), ApiDoc::Value(api_doc), config);
+            register_api_doc_url_resource(
+                url.url.as_ref(),
+                ApiDoc::Value(api_doc),
+                self.guard.clone(),
+                config,
+            );
             url
         });
         urls.extend(external_api_docs);
+        let shared_api_docs = self.shared_urls.into_iter().map(|(url, provider)| {
+            register_api_doc_url_resource(
+                url.url.as_ref(),
+                ApiDoc::Shared(provider),
+                self.guard.clone(),
+                config,
+            );
+            url
+        });
+        urls.extend(shared_api_docs);
 
         let swagger_resource = Resource::new(self.path.as_ref())
             .guard(Get())
-            .app_data(Data::new(if let Some(config) = self.config {
-                if config.url.is_some() || !config.urls.is_empty() {
-                    config
+            .app_data(Data::new(SwaggerUiAssets::new(
+                &if let Some(config) = self.config {
+                    if config.url.is_some() || !config.urls.is_empty() {
+                        config
+                    } else {
+                        config.configure_defaults(urls)
+                    }
                 } else {
-                    config.configure_defaults(urls)
-                }
-            } else {
-                Config::new(urls)
-            }))
-            .to(serve_swagger_ui);
+                    Config::new(urls)
+                },
+            )))
+            .to(serve_swagger_ui)
+            .wrap_fn(move |request, service| {
+                let response = check(self.guard.as_ref(), &request).map(|()| service.call(request));
+                async move { response?.await }
+            });
 
         HttpServiceFactory::register(swagger_resource, config);
     }
 }
 
-fn register_api_doc_url_resource(url: &str, api: ApiDoc, config: &mut actix_web::dev::AppService) {
+fn register_api_doc_url_resource(
+    url: &str,
+    api: ApiDoc,
+    guard: Option<Guard>,
+    config: &mut actix_web::dev::AppService,
+) {
     async fn get_api_doc(api_doc: web::Data<ApiDoc>) -> impl ActixResponder {
-        HttpResponse::Ok().json(api_doc.as_ref())
+        match api_doc.to_json() {
+            Ok(json) => HttpResponse::Ok()
+                .content_type("application/json")
+                .body(json.to_string()),
+            Err(error) => HttpResponse::InternalServerError().body(error.to_string()),
+        }
     }
 
     let url_resource = Resource::new(url)
         .guard(Get())
         .app_data(Data::new(api))
-        .to(get_api_doc);
+        .to(get_api_doc)
+        .wrap_fn(move |request, service| {
+            let response = check(guard.as_ref(), &request).map(|()| service.call(request));
+            async move { response?.await }
+        });
     HttpServiceFactory::register(url_resource, config);
 }
 
-async fn serve_swagger_ui(path: web::Path<String>, data: web::Data<Config<'_>>) -> HttpResponse {
-    match super::serve(&path.into_inner(), data.into_inner()) {
+/// Check the `request` against the `guard` if any.
+fn check(guard: Option<&Guard>, request: &ServiceRequest) -> Result<(), actix_web::Error> {
+    let Some(guard) = guard else {
+        return Ok(());
+    };
+    let authorization = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok());
+
+    guard
+        .check(&GuardRequest::new(
+            authorization,
+            request.peer_addr().map(|addr| addr.ip()),
+        ))
+        .map_err(|rejection| GuardRejection(rejection).into())
+}
+
+/// [`Rejection`] of the [`Guard`] as `actix-web` error.
+#[derive(Debug)]
+struct GuardRejection(Rejection);
+
+impl Display for GuardRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl ResponseError for GuardRejection {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::from_u16(self.0.status_code()).unwrap_or(StatusCode::FORBIDDEN)
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        let mut response = HttpResponse::build(self.status_code());
+        if let Some(challenge) = self.0.www_authenticate() {
+            response.insert_header((header::WWW_AUTHENTICATE, challenge));
+        }
+
+        response.finish()
+    }
+}
+
+async fn serve_swagger_ui(
+    path: web::Path<String>,
+    request: HttpRequest,
+    assets: web::Data<SwaggerUiAssets>,
+) -> HttpResponse {
+    let header_value = |name| {
+        request
+            .headers()
+            .get(name)
+            .and_then(|value: &header::HeaderValue| value.to_str().ok())
+    };
+
+    match assets.serve(
+        &path.into_inner(),
+        header_value(header::ACCEPT_ENCODING),
+        header_value(header::IF_NONE_MATCH),
+    ) {
         Ok(swagger_file) => swagger_file
             .map(|file| {
-                HttpResponse::Ok()
-                    .content_type(file.content_type)
-                    .body(file.bytes.to_vec())
+                let mut response = HttpResponse::build(match file.status {
+                    AssetStatus::Ok => StatusCode::OK,
+                    AssetStatus::NotModified => StatusCode::NOT_MODIFIED,
+                });
+                for (name, value) in file.headers {
+                    response.insert_header((name, value.into_owned()));
+                }
+                response.body(file.body.into_owned())
             })
             .unwrap_or_else(|| HttpResponse::NotFound().finish()),
         Err(error) => HttpResponse::InternalServerError().body(error.to_string()),
@@ -0,0 +1,342 @@
+//! Implements serving of pre-rendered Swagger UI files with HTTP caching headers shared by the
+//! framework integrations.
+//!
+//! The `swagger-initializer.js` is rendered once with the user defined [`Config`] when
+//! [`SwaggerUiAssets`] is created. Files are served with strong `ETag`s and `Cache-Control:
+//! no-cache` and the `If-None-Match` request header is answered with `304 Not Modified`. The file
+//! names of the Swagger UI distribution are not fingerprinted, so caches must revalidate every
+//! file to pick up a new Swagger UI version. Pre-compressed brotli and gzip variants created at
+//! build time are served according to `Accept-Encoding`.
+use std::{borrow::Cow, collections::HashMap};
+
+use crate::{render_swagger_initializer, Config, SwaggerUiDist};
+
+/// `Cache-Control` of all files. Files may be stored but must be revalidated with the `ETag`.
+const CACHE_CONTROL: &str = "no-cache";
+
+/// Supported pre-compressed encodings in the order of preference and their file extensions.
+const ENCODINGS: [(&str, &str); 2] = [("br", "br"), ("gzip", "gz")];
+
+/// Swagger UI files ready to be served.
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub(crate) struct SwaggerUiAssets {
+    files: HashMap<String, Result<Asset, String>>,
+}
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+struct Asset {
+    content_type: String,
+    identity: Representation,
+    encoded: Vec<Representation>,
+}
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+struct Representation {
+    encoding: Option<&'static str>,
+    etag: String,
+    bytes: Cow<'static, [u8]>,
+}
+
+impl Representation {
+    fn new(encoding: Option<&'static str>, bytes: Cow<'static, [u8]>) -> Self {
+        Self {
+            encoding,
+            etag: etag(&bytes),
+            bytes,
+        }
+    }
+}
+
+/// Status of the [`AssetResponse`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum AssetStatus {
+    /// `200 OK` with the file as body.
+    Ok,
+    /// `304 Not Modified` without body.
+    NotModified,
+}
+
+/// Framework independent response of served Swagger UI file.
+pub(crate) struct AssetResponse<'a> {
+    pub(crate) status: AssetStatus,
+    pub(crate) headers: Vec<(&'static str, Cow<'a, str>)>,
+    pub(crate) body: Cow<'static, [u8]>,
+}
+
+impl SwaggerUiAssets {
+    /// Create new [`SwaggerUiAssets`] rendering the `swagger-initializer.js` with given `config`.
+    pub(crate) fn new(config: &Config) -> Self {
+        let files = SwaggerUiDist::iter()
+            .filter(|path| {
+                !ENCODINGS
+                    .iter()
+                    .any(|(_, extension)| path.ends_with(&format!(".{extension}")))
+            })
+            .filter_map(|path| {
+                let file = SwaggerUiDist::get(&path)?;
+                let asset = if path == "swagger-initializer.js" {
+                    render_swagger_initializer(config, file.data)
+                        .map(|initializer| {
+                            Asset::new(&path, Cow::Owned(initializer.into_bytes()), Vec::new())
+                        })
+                        .map_err(|error| error.to_string())
+                } else {
+                    let encoded = ENCODINGS
+                        .iter()
+                        .filter_map(|(encoding, extension)| {
+                            SwaggerUiDist::get(&format!("{path}.{extension}"))
+                                .map(|file| Representation::new(Some(encoding), file.data))
+                        })
+                        .collect();
+                    Ok(Asset::new(&path, file.data, encoded))
+                };
+
+                Some((path.into_owned(), asset))
+            })
+            .collect();
+
+        Self { files }
+    }
+
+    /// Check whether file exists in given `path` relative to the root of the Swagger UI.
+    pub(crate) fn contains(&self, path: &str) -> bool {
+        self.files.contains_key(file_path(path))
+    }
+
+    /// Serve file from given `path` relative to the root of the Swagger UI.
+    ///
+    /// * `accept_encoding` is the value of `Accept-Encoding` request header.
+    /// * `if_none_match` is the value of `If-None-Match` request header.
+    ///
+    /// Returns `Ok(None)` if file is not found and `Err` if the file could not be rendered.
+    pub(crate) fn serve(
+        &self,
+        path: &str,
+        accept_encoding: Option<&str>,
+        if_none_match: Option<&str>,
+    ) -> Result<Option<AssetResponse<'_>>, &str> {
+        let asset = match self.files.get(file_path(path)) {
+            Some(Ok(asset)) => asset,
+            Some(Err(error)) => return Err(error),
+            None => return Ok(None),
+        };
+
+        let representation = asset
+            .encoded
+            .iter()
+            .find(|representation| {
+                representation
+                    .encoding
+                    .map(|encoding| accepts_encoding(accept_encoding, encoding))
+                    .unwrap_or(false)
+            })
+            .unwrap_or(&asset.identity);
+
+        let mut headers = vec![
+            ("ETag", Cow::Borrowed(representation.etag.as_str())),
+            ("Cache-Control", Cow::Borrowed(CACHE_CONTROL)),
+        ];
+        if !asset.encoded.is_empty() {
+            headers.push(("Vary", Cow::Borrowed("Accept-Encoding")));
+        }
+
+        if matches_etag(if_none_match, &representation.etag) {
+            return Ok(Some(AssetResponse {
+                status: AssetStatus::NotModified,
+                headers,
+                body: Cow::Borrowed(&[]),
+            }));
+        }
+
+        headers.push(("Content-Type", Cow::Borrowed(asset.content_type.as_str())));
+        if let Some(encoding) = representation.encoding {
+            headers.push(("Content-Encoding", Cow::Borrowed(encoding)));
+        }
+
+        Ok(Some(AssetResponse {
+            status: AssetStatus::Ok,
+            headers,
+            body: representation.bytes.clone(),
+        }))
+    }
+}
+
+impl Asset {
+    fn new(path: &str, bytes: Cow<'static, [u8]>, encoded: Vec<Representation>) -> Self {
+        Self {
+            content_type: mime_guess::from_path(path)
+                .first_or_octet_stream()
+                .to_string(),
+            identity: Representation::new(None, bytes),
+            encoded,
+        }
+    }
+}
+
+/// Get path of the file relative to the root of the Swagger UI. Root serves the `index.html`.
+fn file_path(path: &str) -> &str {
+    let path = path.trim_start_matches('/');
+    if path.is_empty() {
+        "index.html"
+    } else {
+        path
+    }
+}
+
+/// Create strong `ETag` from 64 bit FNV-1a hash of the content. The hash is stable across builds
+/// and processes so that multiple instances behind a CDN produce same `ETag`s.
+fn etag(bytes: &[u8]) -> String {
+    let hash = bytes.iter().fold(0xcbf29ce484222325_u64, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x100000001b3)
+    });
+
+    format!("\"{hash:016x}-{:x}\"", bytes.len())
+}
+
+/// Check whether `If-None-Match` header value matches to the given `etag`. Comparison is weak as
+/// required by RFC 9110 for `If-None-Match`.
+fn matches_etag(if_none_match: Option<&str>, etag: &str) -> bool {
+    if_none_match
+        .map(|if_none_match| {
+            if_none_match
+                .split(',')
+                .map(str::trim)
+                .any(|candidate| candidate == "*" || candidate.trim_start_matches("W/") == etag)
+        })
+        .unwrap_or(false)
+}
+
+/// Check whether `Accept-Encoding` header value accepts the given `encoding` with non zero quality.
+fn accepts_encoding(accept_encoding: Option<&str>, encoding: &str) -> bool {
+    let Some(accept_encoding) = accept_encoding else {
+        return false;
+    };
+
+    let quality = |name: &str| {
+        accept_encoding.split(',').find_map(|coding| {
+            let mut parts = coding.split(';').map(str::trim);
+            let coding_name = parts.next()?;
+            if !coding_name.eq_ignore_ascii_case(name) {
+                return None;
+            }
+            let quality = parts
+                .filter_map(|param| param.split_once('='))
+                .find(|(key, _)| key.trim().eq_ignore_ascii_case("q"))
+                .and_then(|(_, value)| value.trim().parse::<f32>().ok())
+                .unwrap_or(1.0);
+            Some(quality)
+        })
+    };
+
+    quality(encoding).or_else(|| quality("*")).unwrap_or(0.0) > 0.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn header<'a>(response: &'a AssetResponse, name: &str) -> Option<&'a str> {
+        response
+            .headers
+            .iter()
+            .find(|(header, _)| *header == name)
+            .map(|(_, value)| value.as_ref())
+    }
+
+    #[test]
+    fn serve_index_with_caching_headers() {
+        let assets = SwaggerUiAssets::new(&Config::new(["/api-docs/openapi.json"]));
+        let response = assets.serve("/", None, None).unwrap().unwrap();
+
+        assert_eq!(response.status, AssetStatus::Ok);
+        assert_eq!(header(&response, "Content-Type"), Some("text/html"));
+        assert_eq!(header(&response, "Cache-Control"), Some("no-cache"));
+        assert!(header(&response, "ETag").is_some());
+    }
+
+    #[test]
+    fn serve_rendered_swagger_initializer() {
+        let assets = SwaggerUiAssets::new(&Config::new(["/api-docs/openapi.json"]));
+        let response = assets
+            .serve("swagger-initializer.js", None, None)
+            .unwrap()
+            .unwrap();
+
+        let initializer = std::str::from_utf8(&response.body).unwrap();
+        assert!(initializer.contains(r#""url": "/api-docs/openapi.json""#));
+        assert!(!initializer.contains("{{config}}"));
+    }
+
+    #[test]
+    fn serve_not_modified_when_etag_matches() {
+        let assets = SwaggerUiAssets::new(&Config::new(["/api-docs/openapi.json"]));
+        let etag = assets
+            .serve("swagger-ui.css", None, None)
+            .unwrap()
+            .map(|response| header(&response, "ETag").unwrap().to_string())
+            .unwrap();
+
+        let response = assets
+            .serve(
+                "swagger-ui.css",
+                None,
+                Some(&format!("\"other\", W/{etag}")),
+            )
+            .unwrap()
+            .unwrap();
+        assert_eq!(response.status, AssetStatus::NotModified);
+        assert!(response.body.is_empty());
+        assert_eq!(header(&response, "ETag"), Some(etag.as_str()));
+
+        let response = assets
+            .serve("swagger-ui.css", None, Some("\"other\""))
+            .unwrap()
+            .unwrap();
+        assert_eq!(response.status, AssetStatus::Ok);
+    }
+
+    #[test]
+    fn serve_not_found() {
+        let assets = SwaggerUiAssets::new(&Config::new(["/api-docs/openapi.json"]));
+
+        assert!(assets.serve("not-found.js", None, None).unwrap().is_none());
+        assert!(!assets.contains("not-found.js"));
+        assert!(assets.contains("/"));
+    }
+
+    #[test]
+    fn accepts_encoding_with_quality() {
+        assert!(accepts_encoding(Some("gzip, deflate, br"), "br"));
+        assert!(accepts_encoding(Some("gzip;q=0.5"), "gzip"));
+        assert!(!accepts_encoding(Some("gzip;q=0, br"), "gzip"));
+        assert!(accepts_encoding(Some("*"), "gzip"));
+        assert!(!accepts_encoding(Some("*, br;q=0"), "br"));
+        assert!(!accepts_encoding(Some("identity"), "br"));
+        assert!(!accepts_encoding(None, "gzip"));
+    }
+
+    #[test]
+    fn serve_pre_compressed_variant() {
+        let assets = SwaggerUiAssets::new(&Config::new(["/api-docs/openapi.json"]));
+        let Some(Ok(asset)) = assets.files.get("swagger-ui-bundle.js") else {
+            panic!("swagger-ui-bundle.js should exist");
+        };
+        let identity = assets
+            .serve("swagger-ui-bundle.js", None, None)
+            .unwrap()
+            .unwrap();
+        assert_eq!(header(&identity, "Content-Encoding"), None);
+
+        for representation in &asset.encoded {
+            let encoding = representation.encoding.unwrap();
+            let response = assets
+                .serve("swagger-ui-bundle.js", Some(encoding), None)
+                .unwrap()
+                .unwrap();
+
+            assert_eq!(header(&response, "Content-Encoding"), Some(encoding));
+            assert_eq!(header(&response, "Vary"), Some("Accept-Encoding"));
+            assert_ne!(header(&response, "ETag"), header(&identity, "ETag"));
+        }
+    }
+}
@@ -17,6 +17,13 @@
 //!
 //! [examples]: <https://github.com/nxpkg/fastapi/tree/master/examples>
 //!
+//! The framework integrations render the `swagger-initializer.js` with the [`Config`] once when
+//! [`SwaggerUi`] is converted to a service. Files are served with strong `ETag` and `Cache-Control:
+//! no-cache` headers and conditional requests with matching `If-None-Match` are answered with
+//! `304 Not Modified`.
+//! Gzip and brotli compressed variants of the Swagger UI files are created at build time and served
+//! according to the `Accept-Encoding` request header, making the Swagger UI friendly to CDNs and proxies.
+//!
 //! # Crate Features
 //!
 //! * **`actix-web`** Enables `actix-web` integration with pre-configured SwaggerUI service factory allowing
@@ -25,6 +32,8 @@
 //!   and api doc without a hassle.
 //! * **`axum`** Enables `axum` integration with pre-configured Router serving Swagger UI and OpenAPI specs
 //!   hassle free.
+//! * **`tower`** Enables framework agnostic [`tower::SwaggerUiService`] implementing
+//!   `tower::Service` serving the Swagger UI and OpenAPI specs with any `tower` compatible server.
 //! * **`debug-embed`** Enables `debug-embed` feature on `rust_embed` crate to allow embedding files in debug
 //!   builds as well.
 //! * **`reqwest`** Use `reqwest` for downloading Swagger UI according to the `SWAGGER_UI_DOWNLOAD_URL` environment
@@ -144,12 +153,28 @@
 use std::{borrow::Cow, error::Error, mem, sync::Arc};
 
 mod actix;
+#[cfg(any(
+    feature = "actix-web",
+    feature = "rocket",
+    feature = "axum",
+    feature = "tower"
+))]
+mod assets;
 mod axum;
 pub mod oauth;
 mod rocket;
-
-#[cfg(any(feature = "actix-web", feature = "rocket", feature = "axum"))]
+#[cfg(feature = "tower")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "tower")))]
+pub mod tower;
+
+#[cfg(any(
+    feature = "actix-web",
+    feature = "rocket",
+    feature = "axum",
+    feature = "tower"
+))]
 use fastapi::openapi::OpenApi;
+use fastapi_ui::{guard::Guard, shared::SpecProvider};
 use rust_embed::RustEmbed;
 use serde::Serialize;
 
@@ -187,22 +212,44 @@ include!(concat!(env!("OUT_DIR"), "/embed.rs"));
 #[non_exhaustive]
 #[derive(Clone)]
 #[cfg_attr(feature = "debug", derive(Debug))]
-#[cfg(any(feature = "actix-web", feature = "rocket", feature = "axum"))]
+#[cfg(any(
+    feature = "actix-web",
+    feature = "rocket",
+    feature = "axum",
+    feature = "tower"
+))]
 #[cfg_attr(
     doc_cfg,
-    doc(cfg(any(feature = "actix-web", feature = "rocket", feature = "axum")))
+    doc(cfg(any(
+        feature = "actix-web",
+        feature = "rocket",
+        feature = "axum",
+        feature = "tower"
+    )))
 )]
 pub struct SwaggerUi {
     path: Cow<'static, str>,
     urls: Vec<(Url<'static>, OpenApi)>,
     config: Option<Config<'static>>,
     external_urls: Vec<(Url<'static>, serde_json::Value)>,
+    shared_urls: Vec<(Url<'static>, Arc<dyn SpecProvider>)>,
+    guard: Option<Guard>,
 }
 
-#[cfg(any(feature = "actix-web", feature = "rocket", feature = "axum"))]
+#[cfg(any(
+    feature = "actix-web",
+    feature = "rocket",
+    feature = "axum",
+    feature = "tower"
+))]
 #[cfg_attr(
     doc_cfg,
-    doc(cfg(any(feature = "actix-web", feature = "rocket", feature = "axum")))
+    doc(cfg(any(
+        feature = "actix-web",
+        feature = "rocket",
+        feature = "axum",
+        feature = "tower"
+    )))
 )]
 impl SwaggerUi {
     /// Create a new [`SwaggerUi`] for given path.
@@ -224,6 +271,8 @@ impl SwaggerUi {
             urls: Vec::new(),
             config: None,
             external_urls: Vec::new(),
+            shared_urls: Vec::new(),
+            guard: None,
         }
     }
 
@@ -368,6 +417,38 @@ impl SwaggerUi {
         self
     }
 
+    /// Add api doc [`Url`] served from [`SpecProvider`] into [`SwaggerUi`].
+    ///
+    /// Unlike [`SwaggerUi::url`] the API doc is not captured when the Swagger UI is registered to
+    /// the application. Instead the [`SpecProvider`] is read on each request of the API doc, so
+    /// updates through [`SharedOpenApi`][shared] handle are visible immediately.
+    ///
+    /// # Examples
+    ///
+    /// _**Expose API doc that can be updated at runtime.**_
+    /// ```rust
+    /// # use fastapi_swagger_ui::SwaggerUi;
+    /// # use fastapi::openapi::OpenApiBuilder;
+    /// # use fastapi_ui::shared::SharedOpenApi;
+    /// let shared = SharedOpenApi::new(OpenApiBuilder::new().build());
+    /// let swagger = SwaggerUi::new("/swagger-ui/{_:.*}")
+    ///     .shared_url("/api-docs/openapi.json", shared.clone());
+    ///
+    /// // later on
+    /// shared.update(|openapi| openapi.info.version = "2.0.0".to_string());
+    /// ```
+    ///
+    /// [shared]: fastapi_ui::shared::SharedOpenApi
+    pub fn shared_url<U: Into<Url<'static>>, P: SpecProvider + 'static>(
+        mut self,
+        url: U,
+        provider: P,
+    ) -> Self {
+        self.shared_urls.push((url.into(), Arc::new(provider)));
+
+        self
+    }
+
     /// Add oauth [`oauth::Config`] into [`SwaggerUi`].
     ///
     /// Method takes one argument which exposes the [`oauth::Config`] to the user.
@@ -420,6 +501,39 @@ impl SwaggerUi {
 
         self
     }
+
+    /// Restrict access to the Swagger UI, its files and the served api docs with [`Guard`].
+    ///
+    /// Rejected requests get `401 Unauthorized` or `403 Forbidden` response depending on the
+    /// [`Rejection`][rejection] of the guard. IP allow-lists need the peer address of the request
+    /// which is available in `actix-web` and `rocket` by default. With `axum` the application must
+    /// be served with `into_make_service_with_connect_info::<SocketAddr>()` and with plain `tower`
+    /// the [`SocketAddr`][socket_addr] of the peer must be inserted to the request extensions.
+    ///
+    /// # Examples
+    ///
+    /// _**Allow access to Swagger UI only for staff with HTTP Basic authentication.**_
+    /// ```rust
+    /// # use fastapi_swagger_ui::SwaggerUi;
+    /// # use fastapi_ui::guard::Guard;
+    /// # use fastapi::OpenApi;
+    /// # #[derive(OpenApi)]
+    /// # #[openapi()]
+    /// # struct ApiDoc;
+    /// let swagger = SwaggerUi::new("/swagger-ui/{_:.*}")
+    ///     .url("/api-docs/openapi.json", ApiDoc::openapi())
+    ///     .guard(Guard::basic("docs", |username, password| {
+    ///         username == "staff" && password == "secret"
+    ///     }));
+    /// ```
+    ///
+    /// [rejection]: fastapi_ui::guard::Rejection
+    /// [socket_addr]: std::net::SocketAddr
+    pub fn guard(mut self, guard: Guard) -> Self {
+        self.guard = Some(guard);
+
+        self
+    }
 }
 
 /// Rust type for Swagger UI url configuration object.
@@ -778,10 +892,20 @@ impl<'a> Config<'a> {
     /// is called on.
     ///
     /// Current config will be returned with configured default values.
-    #[cfg(any(feature = "actix-web", feature = "rocket", feature = "axum"))]
+    #[cfg(any(
+        feature = "actix-web",
+        feature = "rocket",
+        feature = "axum",
+        feature = "tower"
+    ))]
     #[cfg_attr(
         doc_cfg,
-        doc(cfg(any(feature = "actix-web", feature = "rocket", feature = "axum")))
+        doc(cfg(any(
+            feature = "actix-web",
+            feature = "rocket",
+            feature = "axum",
+            feature = "tower"
+        )))
     )]
     fn configure_defaults<I: IntoIterator<Item = U>, U: Into<Url<'a>>>(mut self, urls: I) -> Self {
         let Config {
@@ -1433,21 +1557,7 @@ pub fn serve<'a>(
         let mut bytes = file.data;
 
         if file_path == "swagger-initializer.js" {
-            let mut file = match String::from_utf8(bytes.to_vec()) {
-                Ok(file) => file,
-                Err(error) => return Err(Box::new(error)),
-            };
-
-            file = format_config(config.as_ref(), file)?;
-
-            if let Some(oauth) = &config.oauth {
-                match oauth::format_swagger_config(oauth, file) {
-                    Ok(oauth_file) => file = oauth_file,
-                    Err(error) => return Err(Box::new(error)),
-                }
-            }
-
-            bytes = Cow::Owned(file.as_bytes().to_vec())
+            bytes = Cow::Owned(render_swagger_initializer(config.as_ref(), bytes)?.into_bytes())
         };
 
         Ok(Some(SwaggerFile {
@@ -1461,6 +1571,28 @@ pub fn serve<'a>(
     }
 }
 
+/// Render the `swagger-initializer.js` template with given [`Config`] and its [`oauth::Config`].
+fn render_swagger_initializer(
+    config: &Config,
+    template: Cow<[u8]>,
+) -> Result<String, Box<dyn Error>> {
+    let mut file = match String::from_utf8(template.into_owned()) {
+        Ok(file) => file,
+        Err(error) => return Err(Box::new(error)),
+    };
+
+    file = format_config(config, file)?;
+
+    if let Some(oauth) = &config.oauth {
+        match oauth::format_swagger_config(oauth, file) {
+            Ok(oauth_file) => file = oauth_file,
+            Err(error) => return Err(Box::new(error)),
+        }
+    }
+
+    Ok(file)
+}
+
 #[inline]
 fn format_config(config: &Config, file: String) -> Result<String, Box<dyn Error>> {
     let config_json = match serde_json::to_string_pretty(&config) {
@@ -1473,15 +1605,39 @@ fn format_config(config: &Config, file: String) -> Result<String, Box<dyn Error>
 }
 
 /// Is used to provide general way to deliver multiple types of OpenAPI docs via `fastapi-swagger-ui`.
-#[cfg(any(feature = "actix-web", feature = "rocket", feature = "axum"))]
+#[cfg(any(
+    feature = "actix-web",
+    feature = "rocket",
+    feature = "axum",
+    feature = "tower"
+))]
 #[derive(Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
 enum ApiDoc {
     Fastapi(fastapi::openapi::OpenApi),
     Value(serde_json::Value),
+    Shared(Arc<dyn SpecProvider>),
+}
+
+#[cfg(any(feature = "actix-web", feature = "rocket"))]
+impl ApiDoc {
+    /// Serialize the API doc to JSON. Shared API docs are read on each call and use the JSON
+    /// cached by the [`SpecProvider`].
+    fn to_json(&self) -> Result<Arc<str>, serde_json::Error> {
+        match self {
+            Self::Shared(provider) => provider.to_json(),
+            api_doc => serde_json::to_string(api_doc).map(Arc::from),
+        }
+    }
 }
 
 // Delegate serde's `Serialize` to the variant itself.
-#[cfg(any(feature = "actix-web", feature = "rocket", feature = "axum"))]
+#[cfg(any(
+    feature = "actix-web",
+    feature = "rocket",
+    feature = "axum",
+    feature = "tower"
+))]
 impl Serialize for ApiDoc {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -1490,6 +1646,7 @@ impl Serialize for ApiDoc {
         match self {
             Self::Value(value) => value.serialize(serializer),
             Self::Fastapi(fastapi) => fastapi.serialize(serializer),
+            Self::Shared(provider) => provider.openapi().serialize(serializer),
         }
     }
 }
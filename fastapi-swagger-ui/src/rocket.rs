@@ -2,15 +2,18 @@
 
 use std::{borrow::Cow, io::Cursor, sync::Arc};
 
+use fastapi_ui::guard::{Guard, GuardRequest, Rejection};
 use rocket::{
-    http::{Header, Status},
+    http::{ContentType, Header, Status},
     response::{status::NotFound, Responder as RocketResponder},
     route::{Handler, Outcome},
-    serde::json::Json,
     Data as RocketData, Request, Response, Route,
 };
 
-use crate::{ApiDoc, Config, SwaggerFile, SwaggerUi};
+use crate::{
+    assets::{AssetResponse, AssetStatus, SwaggerUiAssets},
+    ApiDoc, Config, SwaggerFile, SwaggerUi,
+};
 
 impl From<SwaggerUi> for Vec<Route> {
     fn from(swagger_ui: SwaggerUi) -> Self {
@@ -29,11 +32,17 @@ impl From<SwaggerUi> for Vec<Route> {
                     .into_iter()
                     .map(|(url, api_doc)| (url, ApiDoc::Value(api_doc))),
             )
+            .chain(
+                swagger_ui
+                    .shared_urls
+                    .into_iter()
+                    .map(|(url, provider)| (url, ApiDoc::Shared(provider))),
+            )
             .map(|(url, openapi)| {
                 api_docs.push(Route::new(
                     rocket::http::Method::Get,
                     &url.url,
-                    ServeApiDoc(openapi),
+                    ServeApiDoc(openapi, swagger_ui.guard.clone()),
                 ));
                 url
             });
@@ -43,15 +52,18 @@ impl From<SwaggerUi> for Vec<Route> {
             swagger_ui.path.as_ref(),
             ServeSwagger(
                 swagger_ui.path.clone(),
-                Arc::new(if let Some(config) = swagger_ui.config {
-                    if config.url.is_some() || !config.urls.is_empty() {
-                        config
+                swagger_ui.guard.clone(),
+                Arc::new(SwaggerUiAssets::new(
+                    &if let Some(config) = swagger_ui.config {
+                        if config.url.is_some() || !config.urls.is_empty() {
+                            config
+                        } else {
+                            config.configure_defaults(urls)
+                        }
                     } else {
-                        config.configure_defaults(urls)
-                    }
-                } else {
-                    Config::new(urls)
-                }),
+                        Config::new(urls)
+                    },
+                )),
             ),
         ));
         routes.extend(api_docs);
@@ -61,21 +73,35 @@ impl From<SwaggerUi> for Vec<Route> {
 }
 
 #[derive(Clone)]
-struct ServeApiDoc(ApiDoc);
+struct ServeApiDoc(ApiDoc, Option<Guard>);
 
 #[rocket::async_trait]
 impl Handler for ServeApiDoc {
     async fn handle<'r>(&self, request: &'r Request<'_>, _: RocketData<'r>) -> Outcome<'r> {
-        Outcome::from(request, Json(self.0.clone()))
+        if let Err(rejection) = check(self.1.as_ref(), request) {
+            return Outcome::from(request, rejection);
+        }
+
+        match self.0.to_json() {
+            Ok(json) => Outcome::from(request, (ContentType::JSON, json.to_string())),
+            Err(error) => Outcome::from(
+                request,
+                rocket::response::status::Custom(Status::InternalServerError, error.to_string()),
+            ),
+        }
     }
 }
 
 #[derive(Clone)]
-struct ServeSwagger(Cow<'static, str>, Arc<Config<'static>>);
+struct ServeSwagger(Cow<'static, str>, Option<Guard>, Arc<SwaggerUiAssets>);
 
 #[rocket::async_trait]
 impl Handler for ServeSwagger {
     async fn handle<'r>(&self, request: &'r Request<'_>, _: RocketData<'r>) -> Outcome<'r> {
+        if let Err(rejection) = check(self.1.as_ref(), request) {
+            return Outcome::from(request, rejection);
+        }
+
         let mut base_path = self.0.as_ref();
         if let Some(index) = self.0.find('<') {
             base_path = &base_path[..index];
@@ -86,7 +112,12 @@ impl Handler for ServeSwagger {
             Some(stripped) => stripped,
             None => return Outcome::from(request, RedirectResponder(base_path.into())),
         };
-        match super::serve(request_path, self.1.clone()) {
+        let headers = request.headers();
+        match self.2.serve(
+            request_path,
+            headers.get_one("Accept-Encoding"),
+            headers.get_one("If-None-Match"),
+        ) {
             Ok(swagger_file) => swagger_file
                 .map(|file| Outcome::from(request, file))
                 .unwrap_or_else(|| Outcome::from(request, NotFound("Swagger UI file not found"))),
@@ -108,6 +139,49 @@ impl<'r, 'o: 'r> RocketResponder<'r, 'o> for SwaggerFile<'o> {
     }
 }
 
+impl<'r, 'o: 'r> RocketResponder<'r, 'o> for AssetResponse<'_> {
+    fn respond_to(self, _: &'r Request<'_>) -> rocket::response::Result<'o> {
+        let mut response = Response::build();
+        for (name, value) in self.headers {
+            response.header(Header::new(name, value.into_owned()));
+        }
+        response
+            .sized_body(self.body.len(), Cursor::new(self.body.into_owned()))
+            .status(match self.status {
+                AssetStatus::Ok => Status::Ok,
+                AssetStatus::NotModified => Status::NotModified,
+            })
+            .ok()
+    }
+}
+
+/// Check the `request` against the `guard` if any.
+fn check(guard: Option<&Guard>, request: &Request<'_>) -> Result<(), GuardRejection> {
+    let Some(guard) = guard else {
+        return Ok(());
+    };
+
+    guard
+        .check(&GuardRequest::new(
+            request.headers().get_one("Authorization"),
+            request.remote().map(|addr| addr.ip()),
+        ))
+        .map_err(GuardRejection)
+}
+
+struct GuardRejection(Rejection);
+impl<'r, 'a: 'r> RocketResponder<'r, 'a> for GuardRejection {
+    fn respond_to(self, _request: &'r Request<'_>) -> rocket::response::Result<'a> {
+        let mut response = Response::build();
+        response.status(Status::new(self.0.status_code()));
+        if let Some(challenge) = self.0.www_authenticate() {
+            response.raw_header("WWW-Authenticate", challenge.to_string());
+        }
+
+        response.ok()
+    }
+}
+
 struct RedirectResponder(String);
 impl<'r, 'a: 'r> RocketResponder<'r, 'a> for RedirectResponder {
     fn respond_to(self, _request: &'r Request<'_>) -> rocket::response::Result<'a> {
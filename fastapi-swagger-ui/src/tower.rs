@@ -0,0 +1,533 @@
+//! Implements framework agnostic [`tower_service::Service`] for serving the Swagger UI.
+//!
+//! [`SwaggerUiService`] can be used with any server that accepts a `tower::Service` such as
+//! plain `hyper` or `poem`. The `axum` integration is built on top of it as well.
+
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    convert::Infallible,
+    future::{ready, Ready},
+    sync::{Arc, Mutex, PoisonError},
+    task::{Context, Poll},
+};
+
+use bytes::Bytes;
+use fastapi_ui::guard::Guard;
+use fastapi_ui::shared::SpecProvider;
+use fastapi_ui::tower::{check_guard, rejected};
+use http::{header, HeaderValue, Method, Request, Response, StatusCode};
+use http_body_util::Full;
+use tower_service::Service;
+
+use crate::{
+    assets::{AssetStatus, SwaggerUiAssets},
+    ApiDoc, Config, SwaggerUi,
+};
+
+/// Framework agnostic [`tower_service::Service`] serving the Swagger UI, its files and the
+/// OpenAPI specs registered to the [`SwaggerUi`].
+///
+/// The service is created from [`SwaggerUi`] with [`From`] implementation. Path of the
+/// [`SwaggerUi`] is the prefix the Swagger UI is served from e.g. `/swagger-ui`. The service
+/// will redirect requests from `/swagger-ui` to `/swagger-ui/` and serve Swagger UI files under
+/// it. OpenAPI specs are serialized once and served from their own urls. Specs that may change at
+/// runtime, e.g. [`SharedOpenApi`][fastapi_ui::shared::SharedOpenApi], are re-read only when the
+/// JSON of the [`SpecProvider`] changes.
+///
+/// All requests are checked against the [`Guard`] of the [`SwaggerUi`] if any. The peer address
+/// for IP allow-lists is read with [`peer_addr`][fastapi_ui::tower::peer_addr].
+///
+/// Only `GET` and `HEAD` requests are served, other methods will get `405 Method Not Allowed`
+/// and unknown paths `404 Not Found` response.
+///
+/// # Examples
+///
+/// _**Serve Swagger UI with `hyper` compatible `tower::Service`.**_
+/// ```rust
+/// # use fastapi::OpenApi;
+/// # use fastapi_swagger_ui::{SwaggerUi, tower::SwaggerUiService};
+/// # #[derive(OpenApi)]
+/// # #[openapi()]
+/// # struct ApiDoc;
+/// let service = SwaggerUiService::from(
+///     SwaggerUi::new("/swagger-ui").url("/api-docs/openapi.json", ApiDoc::openapi()),
+/// );
+/// ```
+#[derive(Clone)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct SwaggerUiService {
+    inner: Arc<Inner>,
+}
+
+#[cfg_attr(feature = "debug", derive(Debug))]
+struct Inner {
+    path: String,
+    api_docs: HashMap<String, ApiDocJson>,
+    assets: SwaggerUiAssets,
+    guard: Option<Guard>,
+}
+
+/// API doc served as JSON. Static API docs are serialized once and shared API docs are read from
+/// the [`SpecProvider`] on each request. Body of shared API doc is cached until the JSON of the
+/// [`SpecProvider`] changes.
+#[cfg_attr(feature = "debug", derive(Debug))]
+enum ApiDocJson {
+    Serialized(Result<Bytes, String>),
+    Shared {
+        provider: Arc<dyn SpecProvider>,
+        cache: Mutex<Option<(Arc<str>, Bytes)>>,
+    },
+}
+
+impl ApiDocJson {
+    fn json(&self) -> Result<Bytes, String> {
+        match self {
+            Self::Serialized(json) => json.clone(),
+            Self::Shared { provider, cache } => {
+                let json = provider.to_json().map_err(|error| error.to_string())?;
+                let mut cache = cache.lock().unwrap_or_else(PoisonError::into_inner);
+
+                match &*cache {
+                    Some((cached_json, body)) if Arc::ptr_eq(cached_json, &json) => {
+                        Ok(body.clone())
+                    }
+                    _ => {
+                        let body = Bytes::copy_from_slice(json.as_bytes());
+                        *cache = Some((json, body.clone()));
+                        Ok(body)
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl SwaggerUiService {
+    /// Get paths served by this [`SwaggerUiService`] in `axum` route syntax.
+    #[cfg(feature = "axum")]
+    pub(crate) fn routes(&self) -> Vec<String> {
+        let path = &self.inner.path;
+        let ui_routes = if path.is_empty() {
+            vec!["/".to_string(), "/*rest".to_string()]
+        } else {
+            vec![path.clone(), format!("{path}/"), format!("{path}/*rest")]
+        };
+
+        self.inner
+            .api_docs
+            .keys()
+            .cloned()
+            .chain(ui_routes)
+            .collect()
+    }
+}
+
+impl From<SwaggerUi> for SwaggerUiService {
+    fn from(swagger_ui: SwaggerUi) -> Self {
+        let api_docs = swagger_ui
+            .urls
+            .into_iter()
+            .map(|(url, openapi)| (url, ApiDoc::Fastapi(openapi)))
+            .chain(
+                swagger_ui
+                    .external_urls
+                    .into_iter()
+                    .map(|(url, api_doc)| (url, ApiDoc::Value(api_doc))),
+            )
+            .chain(
+                swagger_ui
+                    .shared_urls
+                    .into_iter()
+                    .map(|(url, provider)| (url, ApiDoc::Shared(provider))),
+            )
+            .collect::<Vec<_>>();
+
+        let mut urls = Vec::with_capacity(api_docs.len());
+        let api_docs = api_docs
+            .into_iter()
+            .map(|(url, api_doc)| {
+                let path = url.url.to_string();
+                urls.push(url);
+                let api_doc = match api_doc {
+                    ApiDoc::Shared(provider) => ApiDocJson::Shared {
+                        provider,
+                        cache: Mutex::new(None),
+                    },
+                    api_doc => ApiDocJson::Serialized(
+                        serde_json::to_vec(&api_doc)
+                            .map(Bytes::from)
+                            .map_err(|error| error.to_string()),
+                    ),
+                };
+                (path, api_doc)
+            })
+            .collect();
+
+        let config = if let Some(config) = swagger_ui.config {
+            if config.url.is_some() || !config.urls.is_empty() {
+                config
+            } else {
+                config.configure_defaults(urls)
+            }
+        } else {
+            Config::new(urls)
+        };
+
+        let path = swagger_ui.path.trim_end_matches('/').to_string();
+
+        Self {
+            inner: Arc::new(Inner {
+                path,
+                api_docs,
+                assets: SwaggerUiAssets::new(&config),
+                guard: swagger_ui.guard,
+            }),
+        }
+    }
+}
+
+impl<B> Service<Request<B>> for SwaggerUiService {
+    type Response = Response<Full<Bytes>>;
+    type Error = Infallible;
+    type Future = Ready<Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, request: Request<B>) -> Self::Future {
+        ready(Ok(self.inner.serve(&request)))
+    }
+}
+
+impl Inner {
+    fn serve<B>(&self, request: &Request<B>) -> Response<Full<Bytes>> {
+        let path = request.uri().path();
+        if let Some(Err(rejection)) = self.guard.as_ref().map(|guard| check_guard(guard, request)) {
+            return rejected(&rejection, self.is_served(path));
+        }
+
+        let method = request.method();
+        if method != Method::GET && method != Method::HEAD {
+            return response(StatusCode::METHOD_NOT_ALLOWED)
+                .header(header::ALLOW, "GET, HEAD")
+                .body(Full::default())
+                .expect("Method not allowed response must be valid");
+        }
+
+        if let Some(api_doc) = self.api_docs.get(path) {
+            return match api_doc.json() {
+                Ok(json) => with_body(
+                    method,
+                    response(StatusCode::OK).header(header::CONTENT_TYPE, "application/json"),
+                    json,
+                ),
+                Err(error) => internal_server_error(&error),
+            };
+        }
+
+        if !self.path.is_empty() && path == self.path {
+            return response(StatusCode::SEE_OTHER)
+                .header(header::LOCATION, format!("{path}/"))
+                .body(Full::default())
+                .expect("Redirect response must be valid");
+        }
+
+        let Some(tail) = self.asset_path(path) else {
+            return not_found();
+        };
+
+        let header_value = |name| {
+            request
+                .headers()
+                .get(name)
+                .and_then(|value: &HeaderValue| value.to_str().ok())
+        };
+        match self.assets.serve(
+            tail,
+            header_value(header::ACCEPT_ENCODING),
+            header_value(header::IF_NONE_MATCH),
+        ) {
+            Ok(Some(file)) => {
+                let builder = file.headers.iter().fold(
+                    response(match file.status {
+                        AssetStatus::Ok => StatusCode::OK,
+                        AssetStatus::NotModified => StatusCode::NOT_MODIFIED,
+                    }),
+                    |builder, (name, value)| builder.header(*name, value.as_ref()),
+                );
+                let body = match file.body {
+                    Cow::Borrowed(bytes) => Bytes::from_static(bytes),
+                    Cow::Owned(bytes) => Bytes::from(bytes),
+                };
+
+                with_body(method, builder, body)
+            }
+            Ok(None) => not_found(),
+            Err(error) => internal_server_error(error),
+        }
+    }
+
+    /// Check whether the `path` is one of the served OpenAPI documents, Swagger UI path or files.
+    fn is_served(&self, path: &str) -> bool {
+        self.api_docs.contains_key(path)
+            || (!self.path.is_empty() && path == self.path)
+            || self
+                .asset_path(path)
+                .is_some_and(|tail| self.assets.contains(tail))
+    }
+
+    /// Get path of the Swagger UI file relative to the Swagger UI path.
+    fn asset_path<'p>(&self, path: &'p str) -> Option<&'p str> {
+        path.strip_prefix(self.path.as_str())
+            .and_then(|tail| tail.strip_prefix('/'))
+    }
+}
+
+fn response(status: StatusCode) -> http::response::Builder {
+    Response::builder().status(status)
+}
+
+/// Finalize response with `body`. `HEAD` requests get the headers without the body.
+fn with_body(
+    method: &Method,
+    builder: http::response::Builder,
+    body: Bytes,
+) -> Response<Full<Bytes>> {
+    let body = if method == Method::HEAD {
+        Full::default()
+    } else {
+        Full::new(body)
+    };
+
+    builder
+        .body(body)
+        .expect("Swagger UI response headers must be valid")
+}
+
+fn not_found() -> Response<Full<Bytes>> {
+    response(StatusCode::NOT_FOUND)
+        .body(Full::default())
+        .expect("Not found response must be valid")
+}
+
+fn internal_server_error(error: &str) -> Response<Full<Bytes>> {
+    response(StatusCode::INTERNAL_SERVER_ERROR)
+        .header(header::CONTENT_TYPE, "text/plain; charset=utf-8")
+        .body(Full::new(Bytes::from(error.to_string())))
+        .expect("Internal server error response must be valid")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::SocketAddr;
+
+    use fastapi::openapi::OpenApiBuilder;
+    use fastapi_ui::guard::UnknownPath;
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    use super::*;
+
+    fn request(method: Method, uri: &str) -> Request<()> {
+        Request::builder().method(method).uri(uri).body(()).unwrap()
+    }
+
+    fn service() -> SwaggerUiService {
+        SwaggerUiService::from(
+            SwaggerUi::new("/swagger-ui/")
+                .url("/api-docs/openapi.json", OpenApiBuilder::new().build()),
+        )
+    }
+
+    #[tokio::test]
+    async fn serve_openapi_json() {
+        let response = service()
+            .oneshot(request(Method::GET, "/api-docs/openapi.json"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[header::CONTENT_TYPE], "application/json");
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let openapi: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(openapi["openapi"], "3.1.0");
+    }
+
+    #[tokio::test]
+    async fn serve_swagger_ui_files() {
+        let response = service()
+            .oneshot(request(Method::GET, "/swagger-ui"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::SEE_OTHER);
+        assert_eq!(response.headers()[header::LOCATION], "/swagger-ui/");
+
+        let response = service()
+            .oneshot(request(Method::GET, "/swagger-ui/"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[header::CONTENT_TYPE], "text/html");
+
+        let response = service()
+            .oneshot(request(Method::GET, "/swagger-ui/swagger-initializer.js"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        assert!(std::str::from_utf8(&body)
+            .unwrap()
+            .contains("/api-docs/openapi.json"));
+    }
+
+    #[tokio::test]
+    async fn serve_head_without_body() {
+        let response = service()
+            .oneshot(request(Method::HEAD, "/swagger-ui/swagger-ui.css"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(response.headers().contains_key(header::ETAG));
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        assert!(body.is_empty());
+    }
+
+    #[tokio::test]
+    async fn serve_shared_openapi_json_after_update() {
+        let shared = fastapi_ui::shared::SharedOpenApi::new(OpenApiBuilder::new().build());
+        let service = SwaggerUiService::from(
+            SwaggerUi::new("/swagger-ui").shared_url("/api-docs/openapi.json", shared.clone()),
+        );
+
+        shared.update(|openapi| openapi.info.title = "updated".to_string());
+
+        let response = service
+            .oneshot(request(Method::GET, "/api-docs/openapi.json"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let openapi: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(openapi["info"]["title"], "updated");
+    }
+
+    #[test]
+    fn shared_openapi_json_is_cached_until_update() {
+        let shared = fastapi_ui::shared::SharedOpenApi::new(OpenApiBuilder::new().build());
+        let api_doc = ApiDocJson::Shared {
+            provider: Arc::new(shared.clone()),
+            cache: Mutex::new(None),
+        };
+
+        let body = api_doc.json().unwrap();
+        assert_eq!(body.as_ptr(), api_doc.json().unwrap().as_ptr());
+
+        shared.update(|openapi| openapi.info.title = "updated".to_string());
+        let updated = api_doc.json().unwrap();
+        assert_ne!(body.as_ptr(), updated.as_ptr());
+        assert_eq!(updated.as_ptr(), api_doc.json().unwrap().as_ptr());
+    }
+
+    #[tokio::test]
+    async fn guard_protects_swagger_ui_and_openapi_json() {
+        let service = SwaggerUiService::from(
+            SwaggerUi::new("/swagger-ui")
+                .url("/api-docs/openapi.json", OpenApiBuilder::new().build())
+                .guard(Guard::basic("docs", |username, password| {
+                    username == "staff" && password == "secret"
+                })),
+        );
+
+        for uri in ["/swagger-ui/", "/api-docs/openapi.json"] {
+            let response = service
+                .clone()
+                .oneshot(request(Method::GET, uri))
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+            assert_eq!(
+                response.headers()[header::WWW_AUTHENTICATE],
+                r#"Basic realm="docs", charset="UTF-8""#
+            );
+
+            let mut authorized = request(Method::GET, uri);
+            authorized.headers_mut().insert(
+                header::AUTHORIZATION,
+                HeaderValue::from_static("Basic c3RhZmY6c2VjcmV0"),
+            );
+            let response = service.clone().oneshot(authorized).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+    }
+
+    #[tokio::test]
+    async fn guard_rejects_unknown_paths_marked_as_unknown() {
+        let service = SwaggerUiService::from(
+            SwaggerUi::new("/swagger-ui")
+                .url("/api-docs/openapi.json", OpenApiBuilder::new().build())
+                .guard(Guard::bearer(|token| token == "secret")),
+        );
+
+        let response = service
+            .clone()
+            .oneshot(request(Method::GET, "/swagger-ui/"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        assert!(response.extensions().get::<UnknownPath>().is_none());
+
+        let response = service
+            .oneshot(request(Method::GET, "/swagger-ui/not-found.js"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        assert!(response.extensions().get::<UnknownPath>().is_some());
+    }
+
+    #[tokio::test]
+    async fn ip_allow_list_guard_reads_peer_addr_from_extensions() {
+        let service = SwaggerUiService::from(
+            SwaggerUi::new("/swagger-ui")
+                .guard(Guard::ip_allow_list(["10.0.0.0/8".parse().unwrap()])),
+        );
+
+        let response = service
+            .clone()
+            .oneshot(request(Method::GET, "/swagger-ui/"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+        let mut allowed = request(Method::GET, "/swagger-ui/");
+        allowed
+            .extensions_mut()
+            .insert(SocketAddr::from(([10, 0, 0, 1], 40000)));
+        let response = service.oneshot(allowed).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn serve_unknown_and_not_allowed() {
+        let response = service()
+            .oneshot(request(Method::GET, "/other"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        let response = service()
+            .oneshot(request(Method::GET, "/swagger-ui/not-found.js"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        let response = service()
+            .oneshot(request(Method::POST, "/swagger-ui/"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::METHOD_NOT_ALLOWED);
+        assert_eq!(response.headers()[header::ALLOW], "GET, HEAD");
+    }
+}
@@ -0,0 +1,501 @@
+//! This crate implements necessary bindings for automatically collecting `paths` and `schemas` from
+//! [`tide`] endpoints. It provides natural API reducing duplication while generating OpenAPI
+//! specification without the need to declare `paths` and `schemas` to `#[openapi(...)]` attribute
+//! of `OpenApi` derive.
+//!
+//! Endpoints annotated with `#[fastapi::path]` are registered to [`OpenApiServer`] with
+//! [`route!`] macro. The path and the HTTP methods of the route are taken from the
+//! `#[fastapi::path]` attribute, so the route is always served from the path it is documented
+//! with. Other endpoints can be registered with [`OpenApiServer::at`] as with [`tide::Server`].
+//!
+//! Documentation UIs can be served with [`serve_docs`] middleware from any of the UI crates
+//! implementing `tower::Service` via their `tower` feature.
+//!
+//! ## Install
+//!
+//! Add dependency declaration to `Cargo.toml`.
+//!
+//! ```toml
+//! [dependencies]
+//! fastapi-tide = "0.1.1"
+//! ```
+//!
+//! ## Examples
+//!
+//! _**Collect endpoints annotated with `#[fastapi::path]` to compose OpenAPI spec.**_
+//!
+//! ```rust
+//! use fastapi_tide::{route, OpenApiServer};
+//!
+//! #[derive(fastapi::ToSchema)]
+//! struct User {
+//!     id: i32,
+//! }
+//!
+//! #[fastapi::path(get, path = "/user/{id}", responses((status = OK, body = User)))]
+//! async fn get_user(request: tide::Request<()>) -> tide::Result {
+//!     Ok(format!("user {}", request.param("id")?).into())
+//! }
+//!
+//! let mut users = OpenApiServer::new();
+//! users.route(route!(get_user));
+//!
+//! let mut app = OpenApiServer::new();
+//! app.nest("/api/v1", users);
+//!
+//! let (server, api) = app.split_for_parts();
+//! ```
+
+#![cfg_attr(doc_cfg, feature(doc_cfg))]
+#![warn(missing_docs)]
+#![warn(rustdoc::broken_intra_doc_links)]
+
+use std::convert::Infallible;
+use std::net::SocketAddr;
+
+use bytes::Bytes;
+use fastapi::openapi::path::HttpMethod;
+use fastapi::openapi::OpenApi;
+use fastapi::routing::path_for_nested_route;
+use fastapi_ui::guard::UnknownPath;
+use http_body_util::{BodyExt, Full};
+use tide::http::Method;
+use tide::{Endpoint, Middleware, Next, Request};
+use tower_service::Service;
+
+/// Wrapper type for [`tide::Server`] and [`fastapi::openapi::OpenApi`].
+///
+/// [`OpenApiServer`] behaves same way as [`tide::Server`] but allows automatic _`schema`_ and
+/// _`path`_ collection from endpoints registered with [`OpenApiServer::route`].
+///
+/// # Examples
+///
+/// _**Register documented endpoints with state.**_
+/// ```rust
+/// use fastapi_tide::{route, OpenApiServer};
+///
+/// #[derive(Clone, Default)]
+/// struct Store;
+///
+/// #[fastapi::path(get, path = "/todo")]
+/// async fn list_todos(_: tide::Request<Store>) -> tide::Result {
+///     Ok("[]".into())
+/// }
+///
+/// #[fastapi::path(delete, path = "/todo/{id}")]
+/// async fn delete_todo(_: tide::Request<Store>) -> tide::Result {
+///     Ok(tide::StatusCode::NoContent.into())
+/// }
+///
+/// let mut todos = OpenApiServer::with_state(Store);
+/// todos.route(route!(list_todos)).route(route!(delete_todo));
+///
+/// let api = todos.get_openapi();
+/// assert!(api.paths.paths.contains_key("/todo/{id}"));
+/// ```
+pub struct OpenApiServer<State>(tide::Server<State>, OpenApi);
+
+impl OpenApiServer<()> {
+    /// Construct new [`OpenApiServer`] wrapping [`tide::new`] server without state.
+    pub fn new() -> Self {
+        Self::from(tide::new())
+    }
+}
+
+impl Default for OpenApiServer<()> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<State> From<tide::Server<State>> for OpenApiServer<State>
+where
+    State: Clone + Send + Sync + 'static,
+{
+    fn from(value: tide::Server<State>) -> Self {
+        #[derive(fastapi::OpenApi)]
+        struct Api;
+        Self(value, <Api as fastapi::OpenApi>::openapi())
+    }
+}
+
+impl<State> OpenApiServer<State>
+where
+    State: Clone + Send + Sync + 'static,
+{
+    /// Construct new [`OpenApiServer`] wrapping [`tide::with_state`] server with given _`state`_.
+    pub fn with_state(state: State) -> Self {
+        Self::from(tide::with_state(state))
+    }
+
+    /// Use given _`openapi`_ as the base [`OpenApi`] of this [`OpenApiServer`].
+    ///
+    /// Paths and schemas already collected to this [`OpenApiServer`] are merged to the
+    /// _`openapi`_. This is useful to provide info, servers and other content that cannot be
+    /// collected from the endpoints.
+    pub fn openapi(&mut self, openapi: OpenApi) -> &mut Self {
+        let collected = std::mem::take(&mut self.1);
+        self.1 = openapi.merge_from(collected);
+
+        self
+    }
+
+    /// Register documented endpoint created with [`route!`] macro.
+    ///
+    /// The endpoint is registered to the path and for the HTTP methods defined in the
+    /// `#[fastapi::path]` attribute and the OpenAPI path and schemas of the endpoint are added
+    /// to the [`OpenApi`] of this [`OpenApiServer`].
+    pub fn route<E>(&mut self, route: DocumentedRoute<E>) -> &mut Self
+    where
+        E: Endpoint<State> + Clone,
+    {
+        let mut tide_route = self.0.at(&colonized_params(&route.path));
+        for method in &route.methods {
+            tide_route.method(to_tide_method(method), route.endpoint.clone());
+        }
+        self.1.merge(route.openapi);
+
+        self
+    }
+
+    /// Passthrough implementation for [`tide::Server::at`].
+    ///
+    /// Endpoints registered with the returned [`tide::Route`] are not documented.
+    pub fn at<'a>(&'a mut self, path: &str) -> tide::Route<'a, State> {
+        self.0.at(path)
+    }
+
+    /// Passthrough implementation for [`tide::Server::with`].
+    pub fn with<M>(&mut self, middleware: M) -> &mut Self
+    where
+        M: Middleware<State>,
+    {
+        self.0.with(middleware);
+
+        self
+    }
+
+    /// Nest _`server`_ under given _`path`_ like [`tide::Route::nest`] and nest the [`OpenApi`]
+    /// of the _`server`_ under the _`path`_.
+    ///
+    /// The _`path`_ must not contain path parameters.
+    pub fn nest<InnerState>(&mut self, path: &str, server: OpenApiServer<InnerState>) -> &mut Self
+    where
+        InnerState: Clone + Send + Sync + 'static,
+    {
+        self.0.at(path).nest(server.0);
+
+        let prefix = format!("/{}", path.trim_matches('/'));
+        let mut openapi = server.1;
+        openapi.paths.paths = std::mem::take(&mut openapi.paths.paths)
+            .into_iter()
+            .map(|(path, item)| (path_for_nested_route(&prefix, &path), item))
+            .collect();
+        self.1.merge(openapi);
+
+        self
+    }
+
+    /// Get reference to the [`OpenApi`] of this [`OpenApiServer`].
+    pub fn get_openapi(&self) -> &OpenApi {
+        &self.1
+    }
+
+    /// Split this [`OpenApiServer`] to the wrapped [`tide::Server`] and the [`OpenApi`].
+    ///
+    /// This is convenient to serve the [`OpenApi`] with one of the UIs via [`serve_docs`] and
+    /// then start listening the [`tide::Server`].
+    pub fn split_for_parts(self) -> (tide::Server<State>, OpenApi) {
+        (self.0, self.1)
+    }
+}
+
+#[inline]
+fn colonized_params(path: &str) -> String {
+    path.replace('}', "").replace('{', ":")
+}
+
+#[inline]
+fn to_tide_method(method: &HttpMethod) -> Method {
+    match method {
+        HttpMethod::Get => Method::Get,
+        HttpMethod::Post => Method::Post,
+        HttpMethod::Put => Method::Put,
+        HttpMethod::Delete => Method::Delete,
+        HttpMethod::Options => Method::Options,
+        HttpMethod::Head => Method::Head,
+        HttpMethod::Patch => Method::Patch,
+        HttpMethod::Trace => Method::Trace,
+    }
+}
+
+/// Endpoint along with its OpenAPI path and schemas created with [`route!`] macro.
+///
+/// This is used with [`OpenApiServer::route`] to register the endpoint and document it.
+pub struct DocumentedRoute<E> {
+    path: String,
+    methods: Vec<HttpMethod>,
+    openapi: OpenApi,
+    endpoint: E,
+}
+
+impl<E> DocumentedRoute<E> {
+    #[doc(hidden)]
+    pub fn __new<'t, P>(endpoint: E) -> Self
+    where
+        P: fastapi::Path + fastapi::__dev::SchemaReferences + fastapi::__dev::Tags<'t>,
+    {
+        let mut openapi = OpenApi::default();
+        fastapi::routing::__document::<P>(&mut openapi);
+
+        Self {
+            path: P::path(),
+            methods: P::methods(),
+            openapi,
+            endpoint,
+        }
+    }
+}
+
+/// Create [`DocumentedRoute`] from endpoint annotated with `#[fastapi::path]`.
+///
+/// The endpoint may be prefixed with the module path e.g. `route!(todo::list_todos)`. Tags of
+/// the endpoint are added to the operation same way as the `#[openapi(paths(...))]` attribute of
+/// `OpenApi` derive does.
+///
+/// See usage from [`OpenApiServer`].
+#[macro_export]
+macro_rules! route {
+    ( $handler:path $(,)? ) => {
+        $crate::DocumentedRoute::__new::<fastapi::__path_type!($handler)>($handler)
+    };
+}
+
+/// Create [`tide::Middleware`] serving documentation UI from given _`service`_.
+///
+/// The _`service`_ is any `tower::Service` of the UI crates, e.g.
+/// `fastapi_swagger_ui::tower::SwaggerUiService` or `fastapi_redoc::tower::RedocService` enabled
+/// with their `tower` feature. The service serves the UI and the OpenAPI documents registered to
+/// the UI from their own paths.
+///
+/// Only `GET` and `HEAD` requests are forwarded to the _`service`_ and requests the _`service`_
+/// does not know are passed to the next middleware and the endpoint of the request. Peer address
+/// of the request is passed to the _`service`_ for guards using IP allow-lists. Requests rejected
+/// by the guard of the UI are passed on as well if the _`service`_ does not know the path, see
+/// [`UnknownPath`][fastapi_ui::guard::UnknownPath].
+///
+/// # Examples
+///
+/// _**Serve Redoc along with the documented endpoints.**_
+/// ```rust
+/// use fastapi_redoc::{tower::RedocService, Redoc, Servable};
+/// use fastapi_tide::{route, serve_docs, OpenApiServer};
+///
+/// #[fastapi::path(get, path = "/health")]
+/// async fn health(_: tide::Request<()>) -> tide::Result {
+///     Ok("OK".into())
+/// }
+///
+/// let mut app = OpenApiServer::new();
+/// app.route(route!(health));
+///
+/// let (mut server, api) = app.split_for_parts();
+/// server.with(serve_docs(RedocService::from(Redoc::with_url("/redoc", api))));
+/// ```
+pub fn serve_docs<S>(service: S) -> DocsMiddleware<S>
+where
+    S: Service<http::Request<()>, Response = http::Response<Full<Bytes>>, Error = Infallible>
+        + Clone
+        + Send
+        + Sync
+        + 'static,
+    S::Future: Send,
+{
+    DocsMiddleware(service)
+}
+
+/// [`tide::Middleware`] serving documentation UI created with [`serve_docs`].
+#[derive(Clone)]
+pub struct DocsMiddleware<S>(S);
+
+#[tide::utils::async_trait]
+impl<State, S> Middleware<State> for DocsMiddleware<S>
+where
+    State: Clone + Send + Sync + 'static,
+    S: Service<http::Request<()>, Response = http::Response<Full<Bytes>>, Error = Infallible>
+        + Clone
+        + Send
+        + Sync
+        + 'static,
+    S::Future: Send,
+{
+    async fn handle(&self, request: Request<State>, next: Next<'_, State>) -> tide::Result {
+        let method = request.method();
+        if method != Method::Get && method != Method::Head {
+            return Ok(next.run(request).await);
+        }
+
+        let mut docs_request = request
+            .iter()
+            .flat_map(|(name, values)| values.iter().map(move |value| (name, value)))
+            .fold(
+                http::Request::builder()
+                    .method(method.as_ref())
+                    .uri(request.url().path()),
+                |docs_request, (name, value)| docs_request.header(name.as_str(), value.as_str()),
+            );
+        if let Some(remote) = request
+            .peer_addr()
+            .and_then(|addr| addr.parse::<SocketAddr>().ok())
+        {
+            docs_request = docs_request.extension(remote);
+        }
+        let Ok(docs_request) = docs_request.body(()) else {
+            return Ok(next.run(request).await);
+        };
+
+        let response = match self.0.clone().call(docs_request).await {
+            Ok(response) => response,
+            Err(never) => match never {},
+        };
+        if response.status() == http::StatusCode::NOT_FOUND
+            || response.extensions().get::<UnknownPath>().is_some()
+        {
+            return Ok(next.run(request).await);
+        }
+
+        let (parts, body) = response.into_parts();
+        let body = match body.collect().await {
+            Ok(body) => body.to_bytes(),
+            Err(never) => match never {},
+        };
+
+        let mut response = tide::Response::new(parts.status.as_u16());
+        response.set_body(body.to_vec());
+        response.remove_header(tide::http::headers::CONTENT_TYPE);
+        for (name, value) in &parts.headers {
+            if let Ok(value) = value.to_str() {
+                response.append_header(name.as_str(), value);
+            }
+        }
+
+        Ok(response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fastapi_redoc::{tower::RedocService, Redoc, Servable};
+    use tide::http::{Response, Url};
+    use tide::StatusCode;
+
+    use super::*;
+
+    #[derive(fastapi::ToSchema)]
+    #[allow(unused)]
+    struct Todo {
+        id: i64,
+    }
+
+    #[derive(Clone, Default)]
+    struct Store;
+
+    #[fastapi::path(get, path = "/todo", tag = "todo", responses((status = OK, body = [Todo])))]
+    async fn list_todos(_: Request<Store>) -> tide::Result {
+        Ok("[]".into())
+    }
+
+    #[fastapi::path(delete, path = "/todo/{id}")]
+    async fn delete_todo(request: Request<Store>) -> tide::Result {
+        let id: i64 = request.param("id")?.parse()?;
+        Ok(tide::Response::builder(StatusCode::NoContent)
+            .header("x-deleted", id.to_string())
+            .build())
+    }
+
+    mod health {
+        #[fastapi::path(get, path = "/")]
+        pub async fn root(_: tide::Request<()>) -> tide::Result {
+            Ok("OK".into())
+        }
+    }
+
+    fn app() -> OpenApiServer<()> {
+        let mut todos = OpenApiServer::with_state(Store);
+        todos.route(route!(list_todos)).route(route!(delete_todo));
+
+        let mut app = OpenApiServer::new();
+        app.route(route!(health::root)).nest("/api/v1/", todos);
+
+        app
+    }
+
+    async fn request(server: &tide::Server<()>, method: Method, path: &str) -> Response {
+        let url = Url::parse("http://localhost").unwrap().join(path).unwrap();
+        server
+            .respond(tide::http::Request::new(method, url))
+            .await
+            .unwrap()
+    }
+
+    #[test]
+    fn openapi_server_collects_nested_paths() {
+        let (_, api) = app().split_for_parts();
+
+        assert_eq!(
+            api.paths.paths.keys().collect::<Vec<_>>(),
+            ["/", "/api/v1/todo", "/api/v1/todo/{id}"]
+        );
+        let operation = api.paths.paths["/api/v1/todo"].get.as_ref().unwrap();
+        assert_eq!(operation.tags, Some(vec!["todo".to_string()]));
+        assert!(api.components.unwrap().schemas.contains_key("Todo"));
+    }
+
+    #[async_std::test]
+    async fn openapi_server_routes_documented_endpoints() {
+        let (server, _) = app().split_for_parts();
+
+        let response = request(&server, Method::Get, "/api/v1/todo").await;
+        assert_eq!(response.status(), StatusCode::Ok);
+
+        let response = request(&server, Method::Delete, "/api/v1/todo/1").await;
+        assert_eq!(response.status(), StatusCode::NoContent);
+        assert_eq!(response["x-deleted"], "1");
+
+        let response = request(&server, Method::Post, "/api/v1/todo").await;
+        assert_eq!(response.status(), StatusCode::MethodNotAllowed);
+    }
+
+    #[async_std::test]
+    async fn serve_docs_passes_unknown_paths_to_next() {
+        let (mut server, api) = app().split_for_parts();
+        server.with(serve_docs(RedocService::from(Redoc::with_url(
+            "/redoc", api,
+        ))));
+
+        let response = request(&server, Method::Get, "/redoc").await;
+        assert_eq!(response.status(), StatusCode::Ok);
+        assert_eq!(response["content-type"], "text/html; charset=utf-8");
+
+        let response = request(&server, Method::Get, "/api/v1/todo").await;
+        assert_eq!(response.status(), StatusCode::Ok);
+
+        let response = request(&server, Method::Delete, "/api/v1/todo/1").await;
+        assert_eq!(response.status(), StatusCode::NoContent);
+    }
+
+    #[async_std::test]
+    async fn serve_docs_with_guard_passes_unknown_paths_to_next() {
+        use fastapi_ui::guard::Guard;
+
+        let (mut server, api) = app().split_for_parts();
+        let redoc = Redoc::with_url("/redoc", api).guard(Guard::bearer(|token| token == "secret"));
+        server.with(serve_docs(RedocService::from(redoc)));
+
+        let response = request(&server, Method::Get, "/redoc").await;
+        assert_eq!(response.status(), StatusCode::Unauthorized);
+
+        let response = request(&server, Method::Get, "/api/v1/todo").await;
+        assert_eq!(response.status(), StatusCode::Ok);
+    }
+}
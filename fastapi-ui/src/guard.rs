@@ -0,0 +1,497 @@
+//! Implements access guards for the served OpenAPI documentation.
+//!
+//! The UI integrations serve the documentation publicly by default. [`Guard`] can be given to the
+//! UI integrations to restrict the access to the UI, its assets and the served OpenAPI documents
+//! alike. Guards are framework agnostic and the UI integrations translate the [`Rejection`] to
+//! response of the framework in use.
+//!
+//! There are built-in guards for HTTP Basic authentication ([`Guard::basic`]), bearer token
+//! authentication ([`Guard::bearer`]) and IP allow-lists ([`Guard::ip_allow_list`]). Guards can be
+//! combined with [`Guard::and`] and custom guards can be created with [`Guard::from_fn`].
+use std::fmt::{self, Display};
+use std::net::IpAddr;
+use std::str::FromStr;
+use std::sync::Arc;
+
+/// Request information available for the [`Guard`].
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct GuardRequest<'a> {
+    /// Value of the `Authorization` header of the request if any.
+    pub authorization: Option<&'a str>,
+
+    /// IP address of the peer of the connection if known by the framework integration.
+    pub peer_addr: Option<IpAddr>,
+}
+
+impl<'a> GuardRequest<'a> {
+    /// Construct new [`GuardRequest`] with `authorization` header value and `peer_addr`.
+    pub fn new(authorization: Option<&'a str>, peer_addr: Option<IpAddr>) -> Self {
+        Self {
+            authorization,
+            peer_addr,
+        }
+    }
+}
+
+/// Rejection of request returned by [`Guard`].
+#[non_exhaustive]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Rejection {
+    /// Request is missing valid credentials. Translated to `401 Unauthorized` response with
+    /// `WWW-Authenticate` header containing the challenge.
+    Unauthorized(String),
+
+    /// Request is not allowed regardless of the credentials. Translated to `403 Forbidden`
+    /// response.
+    Forbidden,
+}
+
+impl Rejection {
+    /// Get HTTP status code of the [`Rejection`].
+    pub fn status_code(&self) -> u16 {
+        match self {
+            Self::Unauthorized(_) => 401,
+            Self::Forbidden => 403,
+        }
+    }
+
+    /// Get value of `WWW-Authenticate` header of the [`Rejection`] if any.
+    pub fn www_authenticate(&self) -> Option<&str> {
+        match self {
+            Self::Unauthorized(challenge) => Some(challenge),
+            Self::Forbidden => None,
+        }
+    }
+}
+
+impl Display for Rejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unauthorized(_) => f.write_str("Unauthorized"),
+            Self::Forbidden => f.write_str("Forbidden"),
+        }
+    }
+}
+
+impl std::error::Error for Rejection {}
+
+/// Response extension marking [`Rejection`] of a request to a path the UI integration does not
+/// serve.
+///
+/// UI integrations check the [`Guard`] before the path of the request so that the rejection does
+/// not reveal which paths exist. The extension is not sent to the client but it allows framework
+/// integrations serving the UI along with other routes to pass such requests on to the other
+/// routes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UnknownPath;
+
+type GuardFn = dyn Fn(&GuardRequest<'_>) -> Result<(), Rejection> + Send + Sync;
+
+/// Access guard of the served documentation.
+///
+/// [`Guard`] is cheap to clone and the same guard can be given to multiple UI integrations.
+///
+/// # Examples
+///
+/// _**Allow access only from local network with valid staff credentials.**_
+/// ```rust
+/// # use fastapi_ui::guard::Guard;
+/// let guard = Guard::ip_allow_list(["10.0.0.0/8".parse().unwrap()])
+///     .and(Guard::basic("docs", |username, password| {
+///         username == "staff" && password == "secret"
+///     }));
+/// ```
+#[derive(Clone)]
+pub struct Guard(Arc<GuardFn>);
+
+impl Guard {
+    /// Construct new [`Guard`] from function deciding whether the request is allowed.
+    ///
+    /// # Examples
+    ///
+    /// _**Allow only requests with given `Authorization` header value.**_
+    /// ```rust
+    /// # use fastapi_ui::guard::{Guard, Rejection};
+    /// let guard = Guard::from_fn(|request| match request.authorization {
+    ///     Some("ApiKey secret") => Ok(()),
+    ///     _ => Err(Rejection::Unauthorized("ApiKey".to_string())),
+    /// });
+    /// ```
+    pub fn from_fn<F>(guard: F) -> Self
+    where
+        F: Fn(&GuardRequest<'_>) -> Result<(), Rejection> + Send + Sync + 'static,
+    {
+        Self(Arc::new(guard))
+    }
+
+    /// Construct new HTTP Basic authentication [`Guard`] with `realm` and credential `verify`
+    /// function called with username and password of the request.
+    ///
+    /// Requests without valid credentials are rejected with [`Rejection::Unauthorized`] which asks
+    /// browsers to prompt for the credentials.
+    ///
+    /// **Note!** Prefer constant time comparison when verifying the credentials.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fastapi_ui::guard::Guard;
+    /// let guard = Guard::basic("docs", |username, password| {
+    ///     username == "staff" && password == "secret"
+    /// });
+    /// ```
+    pub fn basic<R, F>(realm: R, verify: F) -> Self
+    where
+        R: Into<String>,
+        F: Fn(&str, &str) -> bool + Send + Sync + 'static,
+    {
+        let challenge = format!(
+            r#"Basic realm="{}", charset="UTF-8""#,
+            realm.into().replace(['"', '\\'], "")
+        );
+
+        Self::from_fn(move |request| {
+            let credentials = credentials(request.authorization, "Basic")
+                .and_then(decode_base64)
+                .and_then(|decoded| String::from_utf8(decoded).ok());
+
+            match credentials
+                .as_deref()
+                .and_then(|credentials| credentials.split_once(':'))
+            {
+                Some((username, password)) if verify(username, password) => Ok(()),
+                _ => Err(Rejection::Unauthorized(challenge.clone())),
+            }
+        })
+    }
+
+    /// Construct new bearer token authentication [`Guard`] with token `verify` function called
+    /// with the token of the request.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fastapi_ui::guard::Guard;
+    /// let guard = Guard::bearer(|token| token == "staff-token");
+    /// ```
+    pub fn bearer<F>(verify: F) -> Self
+    where
+        F: Fn(&str) -> bool + Send + Sync + 'static,
+    {
+        Self::from_fn(
+            move |request| match credentials(request.authorization, "Bearer") {
+                Some(token) if verify(token) => Ok(()),
+                Some(_) => Err(Rejection::Unauthorized(
+                    r#"Bearer error="invalid_token""#.to_string(),
+                )),
+                None => Err(Rejection::Unauthorized("Bearer".to_string())),
+            },
+        )
+    }
+
+    /// Construct new [`Guard`] allowing requests only from peers within given `networks`.
+    ///
+    /// Requests are rejected with [`Rejection::Forbidden`] if the peer address is not allowed or
+    /// the peer address is not known by the framework integration.
+    ///
+    /// # Examples
+    ///
+    /// _**Allow requests from loopback and private network.**_
+    /// ```rust
+    /// # use std::net::Ipv4Addr;
+    /// # use fastapi_ui::guard::{Guard, IpNetwork};
+    /// let guard = Guard::ip_allow_list([
+    ///     IpNetwork::from(Ipv4Addr::LOCALHOST),
+    ///     "192.168.0.0/16".parse().unwrap(),
+    /// ]);
+    /// ```
+    pub fn ip_allow_list<I: IntoIterator<Item = IpNetwork>>(networks: I) -> Self {
+        let networks = networks.into_iter().collect::<Vec<_>>();
+
+        Self::from_fn(move |request| match request.peer_addr {
+            Some(addr) if networks.iter().any(|network| network.contains(addr)) => Ok(()),
+            _ => Err(Rejection::Forbidden),
+        })
+    }
+
+    /// Combine this [`Guard`] with `other` guard. Request is allowed only if both guards allow it
+    /// and the [`Rejection`] of this guard is returned first.
+    pub fn and(self, other: Guard) -> Self {
+        Self::from_fn(move |request| {
+            self.check(request)?;
+            other.check(request)
+        })
+    }
+
+    /// Check whether the `request` is allowed by this [`Guard`].
+    pub fn check(&self, request: &GuardRequest<'_>) -> Result<(), Rejection> {
+        (self.0)(request)
+    }
+}
+
+impl fmt::Debug for Guard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Guard").finish_non_exhaustive()
+    }
+}
+
+/// IP network of [`Guard::ip_allow_list`] consisting of address and prefix length.
+///
+/// [`IpNetwork`] can be parsed from CIDR notation e.g. `10.0.0.0/8` or from plain IP address
+/// matching only the address itself.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IpNetwork {
+    addr: IpAddr,
+    prefix: u8,
+}
+
+impl IpNetwork {
+    /// Construct new [`IpNetwork`] from `addr` and `prefix` length. Returns `None` if the prefix
+    /// is longer than the address.
+    pub fn new(addr: IpAddr, prefix: u8) -> Option<Self> {
+        let max = match addr {
+            IpAddr::V4(_) => 32,
+            IpAddr::V6(_) => 128,
+        };
+
+        (prefix <= max).then_some(Self { addr, prefix })
+    }
+
+    /// Check whether the `addr` belongs to this [`IpNetwork`]. IPv4 mapped IPv6 addresses are
+    /// compared as IPv4 addresses if this is an IPv4 network.
+    pub fn contains(&self, addr: IpAddr) -> bool {
+        let addr = match (self.addr, addr) {
+            (IpAddr::V4(_), IpAddr::V6(v6)) => v6.to_ipv4_mapped().map(IpAddr::V4).unwrap_or(addr),
+            (_, addr) => addr,
+        };
+
+        match (self.addr, addr) {
+            (IpAddr::V4(network), IpAddr::V4(addr)) => {
+                let mask = u32::MAX.checked_shl(32 - self.prefix as u32).unwrap_or(0);
+                u32::from(network) & mask == u32::from(addr) & mask
+            }
+            (IpAddr::V6(network), IpAddr::V6(addr)) => {
+                let mask = u128::MAX.checked_shl(128 - self.prefix as u32).unwrap_or(0);
+                u128::from(network) & mask == u128::from(addr) & mask
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<T: Into<IpAddr>> From<T> for IpNetwork {
+    fn from(addr: T) -> Self {
+        let addr = addr.into();
+        let prefix = match addr {
+            IpAddr::V4(_) => 32,
+            IpAddr::V6(_) => 128,
+        };
+
+        Self { addr, prefix }
+    }
+}
+
+/// Error of parsing invalid [`IpNetwork`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseIpNetworkError(String);
+
+impl Display for ParseIpNetworkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid IP network: {}", self.0)
+    }
+}
+
+impl std::error::Error for ParseIpNetworkError {}
+
+impl FromStr for IpNetwork {
+    type Err = ParseIpNetworkError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let error = || ParseIpNetworkError(value.to_string());
+
+        match value.split_once('/') {
+            Some((addr, prefix)) => {
+                let addr = addr.parse::<IpAddr>().map_err(|_| error())?;
+                let prefix = prefix.parse::<u8>().map_err(|_| error())?;
+                Self::new(addr, prefix).ok_or_else(error)
+            }
+            None => value.parse::<IpAddr>().map(Self::from).map_err(|_| error()),
+        }
+    }
+}
+
+/// Get credentials of the `authorization` header value with case insensitive `scheme`.
+fn credentials<'a>(authorization: Option<&'a str>, scheme: &str) -> Option<&'a str> {
+    let (request_scheme, credentials) = authorization?.trim().split_once(' ')?;
+
+    request_scheme
+        .eq_ignore_ascii_case(scheme)
+        .then(|| credentials.trim())
+        .filter(|credentials| !credentials.is_empty())
+}
+
+/// Decode standard base64 with optional padding.
+fn decode_base64(value: &str) -> Option<Vec<u8>> {
+    fn sextet(byte: u8) -> Option<u32> {
+        match byte {
+            b'A'..=b'Z' => Some((byte - b'A') as u32),
+            b'a'..=b'z' => Some((byte - b'a' + 26) as u32),
+            b'0'..=b'9' => Some((byte - b'0' + 52) as u32),
+            b'+' => Some(62),
+            b'/' => Some(63),
+            _ => None,
+        }
+    }
+
+    let value = value.trim_end_matches('=').as_bytes();
+    if value.len() % 4 == 1 {
+        return None;
+    }
+
+    let mut decoded = Vec::with_capacity(value.len() * 3 / 4);
+    for chunk in value.chunks(4) {
+        let bits = chunk
+            .iter()
+            .enumerate()
+            .try_fold(0u32, |bits, (index, byte)| {
+                sextet(*byte).map(|sextet| bits | sextet << (18 - 6 * index))
+            })?;
+        decoded.extend_from_slice(&bits.to_be_bytes()[1..chunk.len()]);
+    }
+
+    Some(decoded)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    use super::*;
+
+    fn request(authorization: &str) -> GuardRequest<'_> {
+        GuardRequest::new(Some(authorization), None)
+    }
+
+    #[test]
+    fn decode_base64_with_and_without_padding() {
+        assert_eq!(decode_base64("c3RhZmY6c2VjcmV0").unwrap(), b"staff:secret");
+        assert_eq!(decode_base64("YTpi").unwrap(), b"a:b");
+        assert_eq!(decode_base64("YTpiYw==").unwrap(), b"a:bc");
+        assert_eq!(decode_base64("YTpiYw").unwrap(), b"a:bc");
+        assert_eq!(decode_base64("YTpiY").as_deref(), None);
+        assert_eq!(decode_base64("YT!i").as_deref(), None);
+    }
+
+    #[test]
+    fn basic_guard_verifies_credentials() {
+        let guard = Guard::basic("docs", |username, password| {
+            username == "staff" && password == "secret"
+        });
+
+        assert_eq!(guard.check(&request("Basic c3RhZmY6c2VjcmV0")), Ok(()));
+        assert_eq!(guard.check(&request("basic c3RhZmY6c2VjcmV0")), Ok(()));
+
+        let rejection = guard.check(&request("Basic YTpi")).unwrap_err();
+        assert_eq!(rejection.status_code(), 401);
+        assert_eq!(
+            rejection.www_authenticate(),
+            Some(r#"Basic realm="docs", charset="UTF-8""#)
+        );
+        assert!(guard.check(&GuardRequest::default()).is_err());
+        assert!(guard.check(&request("Bearer c3RhZmY6c2VjcmV0")).is_err());
+    }
+
+    #[test]
+    fn bearer_guard_verifies_token() {
+        let guard = Guard::bearer(|token| token == "token");
+
+        assert_eq!(guard.check(&request("Bearer token")), Ok(()));
+        assert_eq!(
+            guard.check(&request("Bearer other")),
+            Err(Rejection::Unauthorized(
+                r#"Bearer error="invalid_token""#.to_string()
+            ))
+        );
+        assert_eq!(
+            guard.check(&GuardRequest::default()),
+            Err(Rejection::Unauthorized("Bearer".to_string()))
+        );
+    }
+
+    #[test]
+    fn ip_allow_list_guard_checks_peer_addr() {
+        let guard = Guard::ip_allow_list([
+            "10.0.0.0/8".parse().unwrap(),
+            IpNetwork::from(Ipv6Addr::LOCALHOST),
+        ]);
+        let peer = |addr: IpAddr| GuardRequest::new(None, Some(addr));
+
+        assert_eq!(
+            guard.check(&peer(Ipv4Addr::new(10, 1, 2, 3).into())),
+            Ok(())
+        );
+        assert_eq!(
+            guard.check(&peer(Ipv4Addr::new(10, 1, 2, 3).to_ipv6_mapped().into())),
+            Ok(())
+        );
+        assert_eq!(guard.check(&peer(Ipv6Addr::LOCALHOST.into())), Ok(()));
+        assert_eq!(
+            guard.check(&peer(Ipv4Addr::new(11, 0, 0, 1).into())),
+            Err(Rejection::Forbidden)
+        );
+        assert_eq!(
+            guard.check(&GuardRequest::default()),
+            Err(Rejection::Forbidden)
+        );
+    }
+
+    #[test]
+    fn parse_ip_network() {
+        assert_eq!(
+            "0.0.0.0/0".parse::<IpNetwork>().unwrap(),
+            IpNetwork::new(Ipv4Addr::UNSPECIFIED.into(), 0).unwrap()
+        );
+        assert!("0.0.0.0/0"
+            .parse::<IpNetwork>()
+            .unwrap()
+            .contains(Ipv4Addr::BROADCAST.into()));
+        assert!("10.0.0.0/33".parse::<IpNetwork>().is_err());
+        assert!("localhost".parse::<IpNetwork>().is_err());
+    }
+
+    #[test]
+    fn ipv6_network_contains_ipv4_mapped_addr() {
+        let mapped = "::ffff:0:0/96".parse::<IpNetwork>().unwrap();
+        let addr = Ipv4Addr::new(10, 1, 2, 3).to_ipv6_mapped();
+
+        assert!(mapped.contains(addr.into()));
+        assert!(!mapped.contains(Ipv4Addr::new(10, 1, 2, 3).into()));
+        assert!(!mapped.contains(Ipv6Addr::LOCALHOST.into()));
+        assert!(!"::1/128"
+            .parse::<IpNetwork>()
+            .unwrap()
+            .contains(Ipv4Addr::new(0, 0, 0, 1).to_ipv6_mapped().into()));
+    }
+
+    #[test]
+    fn combined_guard_requires_both() {
+        let guard = Guard::ip_allow_list([IpNetwork::from(Ipv4Addr::LOCALHOST)])
+            .and(Guard::bearer(|token| token == "token"));
+
+        assert_eq!(
+            guard.check(&GuardRequest::new(
+                Some("Bearer token"),
+                Some(Ipv4Addr::LOCALHOST.into())
+            )),
+            Ok(())
+        );
+        assert_eq!(
+            guard.check(&GuardRequest::new(Some("Bearer token"), None)),
+            Err(Rejection::Forbidden)
+        );
+        assert!(guard
+            .check(&GuardRequest::new(None, Some(Ipv4Addr::LOCALHOST.into())))
+            .is_err());
+    }
+}
@@ -0,0 +1,85 @@
+//! Implements HTML template rendering helpers shared by the documentation UI integrations.
+//!
+//! Templates contain variables such as `$spec` or `$config` that are replaced with their values
+//! using [`render`]. Values must be escaped for the context they are placed in with
+//! [`escape_html`] or [`escape_script_json`] before rendering.
+
+/// Replace template `variables` with their values in single pass so that values are never
+/// interpreted as variables.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fastapi_ui::html::render;
+/// let html = render("<h1>$title</h1>$body", &[("$title", "$body"), ("$body", "Pets")]);
+///
+/// assert_eq!(html, "<h1>$body</h1>Pets");
+/// ```
+pub fn render(template: &str, variables: &[(&str, &str)]) -> String {
+    let mut html = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some((index, (variable, value))) = variables
+        .iter()
+        .filter_map(|variable| rest.find(variable.0).map(|index| (index, variable)))
+        .min_by_key(|(index, _)| *index)
+    {
+        html.push_str(&rest[..index]);
+        html.push_str(value);
+        rest = &rest[index + variable.len()..];
+    }
+    html.push_str(rest);
+
+    html
+}
+
+/// Escape text to be placed in HTML element content or in double or single quoted attribute
+/// value.
+pub fn escape_html(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Escape JSON to be placed inside `<script>` element. The escaped characters are still valid
+/// JSON string escapes.
+pub fn escape_script_json(json: &str) -> String {
+    json.replace('<', "\\u003c")
+        .replace('>', "\\u003e")
+        .replace('&', "\\u0026")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_does_not_substitute_variables_in_values() {
+        assert_eq!(
+            render(
+                "$spec|$config|$spec",
+                &[("$config", "$spec"), ("$spec", "{}")]
+            ),
+            "{}|$spec|{}"
+        );
+    }
+
+    #[test]
+    fn escape_html_escapes_markup_and_quotes() {
+        assert_eq!(
+            escape_html(r#"<a href="x">'&'</a>"#),
+            "&lt;a href=&quot;x&quot;&gt;&#39;&amp;&#39;&lt;/a&gt;"
+        );
+    }
+
+    #[test]
+    fn escape_script_json_escapes_closing_script() {
+        assert_eq!(
+            escape_script_json(r#"{"a":"</script>&"}"#),
+            r#"{"a":"\u003c/script\u003e\u0026"}"#
+        );
+    }
+}
@@ -0,0 +1,30 @@
+#![warn(missing_docs)]
+#![warn(rustdoc::broken_intra_doc_links)]
+#![cfg_attr(doc_cfg, feature(doc_cfg))]
+//! This crate provides the shared support for the documentation UI integrations of
+//! [`fastapi`](https://docs.rs/fastapi/latest/fastapi/) such as _`fastapi-swagger-ui`_,
+//! _`fastapi-redoc`_, _`fastapi-rapidoc`_ and _`fastapi-scalar`_.
+//!
+//! * [`guard`] restricts the access to the served UI and OpenAPI documents.
+//! * [`html`] renders the HTML templates of the UIs.
+//! * [`shared`] serves OpenAPI documents that can be changed at runtime.
+//! * `tower` checks the [`guard::Guard`] in the `tower::Service` implementations of the UIs.
+//!   Enabled with _`tower`_ feature, _`axum`_ feature reads the peer address from `axum` connect
+//!   info as well.
+//!
+//! The UI integrations re-export nothing from this crate, add it as dependency to use
+//! [`guard::Guard`] or [`shared::SharedOpenApi`] with them.
+//!
+//! # Install
+//!
+//! ```toml
+//! [dependencies]
+//! fastapi-ui = "0.1.1"
+//! ```
+
+pub mod guard;
+pub mod html;
+pub mod shared;
+#[cfg(feature = "tower")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "tower")))]
+pub mod tower;
@@ -0,0 +1,219 @@
+//! Implements OpenAPI documents that can be changed at runtime.
+//!
+//! By default the UI integrations capture an owned [`OpenApi`] when the router is built and the
+//! served document is frozen after that. [`SpecProvider`] allows serving a document that is read
+//! on each request instead. [`SharedOpenApi`] is the default [`SpecProvider`] implementation which
+//! can be updated through its cloneable handle e.g. when routes are registered at runtime.
+use std::sync::{Arc, OnceLock, PoisonError, RwLock};
+
+use serde::{Serialize, Serializer};
+
+use fastapi::openapi::OpenApi;
+
+/// Provides the current [`OpenApi`] document for serving.
+///
+/// Implementations are read on each request of the document and must therefore be cheap to read.
+/// Use [`SharedOpenApi`] unless a custom source of the document is needed.
+///
+/// # Examples
+///
+/// _**Implement [`SpecProvider`] serving the document from custom source.**_
+/// ```rust
+/// # use std::sync::Arc;
+/// # use fastapi::openapi::{OpenApi, OpenApiBuilder};
+/// # use fastapi_ui::shared::SpecProvider;
+/// struct Registry;
+///
+/// impl Registry {
+///     fn current(&self) -> Arc<OpenApi> {
+///         Arc::new(OpenApiBuilder::new().build())
+///     }
+/// }
+///
+/// impl SpecProvider for Registry {
+///     fn openapi(&self) -> Arc<OpenApi> {
+///         self.current()
+///     }
+/// }
+/// ```
+pub trait SpecProvider: Send + Sync {
+    /// Get the current [`OpenApi`] document.
+    fn openapi(&self) -> Arc<OpenApi>;
+
+    /// Get the current [`OpenApi`] document serialized to JSON.
+    ///
+    /// By default the document is serialized on each call. Implementations should return the same
+    /// [`Arc`] until the document changes so that consumers can cache content rendered from the
+    /// JSON by comparing the pointers with [`Arc::ptr_eq`].
+    fn to_json(&self) -> Result<Arc<str>, serde_json::Error> {
+        serde_json::to_string(self.openapi().as_ref()).map(Arc::from)
+    }
+}
+
+impl std::fmt::Debug for dyn SpecProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SpecProvider").finish_non_exhaustive()
+    }
+}
+
+impl<T: SpecProvider + ?Sized> SpecProvider for Arc<T> {
+    fn openapi(&self) -> Arc<OpenApi> {
+        self.as_ref().openapi()
+    }
+
+    fn to_json(&self) -> Result<Arc<str>, serde_json::Error> {
+        self.as_ref().to_json()
+    }
+}
+
+/// Shared handle to [`OpenApi`] document that can be updated at runtime.
+///
+/// Cloning [`SharedOpenApi`] is cheap and all the clones point to the same document. Updates
+/// through any of the clones are visible immediately to all readers. The JSON of the document is
+/// serialized once on first read and cached until the next update.
+///
+/// # Examples
+///
+/// _**Add paths of runtime loaded plugin to the served document.**_
+/// ```rust
+/// # use fastapi::openapi::OpenApiBuilder;
+/// # use fastapi_ui::shared::{SharedOpenApi, SpecProvider};
+/// let shared = SharedOpenApi::new(OpenApiBuilder::new().build());
+///
+/// // pass clone of the handle to the UI integration
+/// let served = shared.clone();
+///
+/// // later on when plugin is loaded
+/// let plugin = OpenApiBuilder::new().build();
+/// shared.update(|openapi| openapi.merge(plugin));
+///
+/// assert!(served.to_json().is_ok());
+/// ```
+#[derive(Clone, Default)]
+pub struct SharedOpenApi {
+    inner: Arc<RwLock<Arc<Snapshot>>>,
+}
+
+#[derive(Default)]
+struct Snapshot {
+    openapi: Arc<OpenApi>,
+    json: OnceLock<Arc<str>>,
+}
+
+impl SharedOpenApi {
+    /// Construct new [`SharedOpenApi`] with initial `openapi` document.
+    pub fn new(openapi: OpenApi) -> Self {
+        Self {
+            inner: Arc::new(RwLock::new(Arc::new(Snapshot {
+                openapi: Arc::new(openapi),
+                json: OnceLock::new(),
+            }))),
+        }
+    }
+
+    /// Get the current [`OpenApi`] document.
+    pub fn load(&self) -> Arc<OpenApi> {
+        self.snapshot().openapi.clone()
+    }
+
+    /// Replace the current document with given `openapi`.
+    pub fn store(&self, openapi: OpenApi) {
+        *self.inner.write().unwrap_or_else(PoisonError::into_inner) = Arc::new(Snapshot {
+            openapi: Arc::new(openapi),
+            json: OnceLock::new(),
+        });
+    }
+
+    /// Update the current document with given function.
+    ///
+    /// The function is called with a copy of the current document and the document is replaced
+    /// with the result atomically. Concurrent updates are serialized so that no update is lost.
+    pub fn update<F: FnOnce(&mut OpenApi)>(&self, update: F) {
+        let mut snapshot = self.inner.write().unwrap_or_else(PoisonError::into_inner);
+        let mut openapi = OpenApi::clone(&snapshot.openapi);
+        update(&mut openapi);
+
+        *snapshot = Arc::new(Snapshot {
+            openapi: Arc::new(openapi),
+            json: OnceLock::new(),
+        });
+    }
+
+    fn snapshot(&self) -> Arc<Snapshot> {
+        self.inner
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+}
+
+impl SpecProvider for SharedOpenApi {
+    fn openapi(&self) -> Arc<OpenApi> {
+        self.load()
+    }
+
+    fn to_json(&self) -> Result<Arc<str>, serde_json::Error> {
+        let snapshot = self.snapshot();
+        if let Some(json) = snapshot.json.get() {
+            return Ok(json.clone());
+        }
+
+        let json = Arc::from(serde_json::to_string(snapshot.openapi.as_ref())?);
+        Ok(snapshot.json.get_or_init(|| json).clone())
+    }
+}
+
+impl From<OpenApi> for SharedOpenApi {
+    fn from(openapi: OpenApi) -> Self {
+        Self::new(openapi)
+    }
+}
+
+impl Serialize for SharedOpenApi {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.load().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "debug")]
+impl std::fmt::Debug for SharedOpenApi {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("SharedOpenApi").field(&self.load()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fastapi::openapi::{InfoBuilder, OpenApiBuilder};
+
+    use super::*;
+
+    fn openapi(title: &str) -> OpenApi {
+        OpenApiBuilder::new()
+            .info(InfoBuilder::new().title(title).version("1.0.0"))
+            .build()
+    }
+
+    #[test]
+    fn shared_openapi_caches_json_until_update() {
+        let shared = SharedOpenApi::new(openapi("first"));
+        let reader = shared.clone();
+
+        let json = reader.to_json().unwrap();
+        assert!(json.contains(r#""title":"first""#));
+        assert!(Arc::ptr_eq(&json, &reader.to_json().unwrap()));
+
+        shared.update(|openapi| openapi.info.title = "second".to_string());
+
+        let updated = reader.to_json().unwrap();
+        assert!(!Arc::ptr_eq(&json, &updated));
+        assert!(updated.contains(r#""title":"second""#));
+        assert_eq!(reader.load().info.title, "second");
+
+        shared.store(openapi("third"));
+        assert_eq!(reader.openapi().info.title, "third");
+    }
+}
@@ -0,0 +1,114 @@
+//! Implements [`Guard`] support for the `tower::Service` implementations of the UI integrations.
+//!
+//! The UI integrations check each request with [`check_guard`] and translate the [`Rejection`] to
+//! response with [`rejected`] before any other handling of the request.
+
+use std::net::{IpAddr, SocketAddr};
+
+use bytes::Bytes;
+use http::{header, Request, Response, StatusCode};
+use http_body_util::Full;
+
+use crate::guard::{Guard, GuardRequest, Rejection, UnknownPath};
+
+/// Check the `request` against the `guard`.
+///
+/// The `Authorization` header of the `request` and the peer address read with [`peer_addr`] are
+/// given to the `guard`.
+///
+/// # Examples
+///
+/// _**Reject request without credentials.**_
+/// ```rust
+/// # use fastapi_ui::guard::Guard;
+/// use fastapi_ui::tower::check_guard;
+///
+/// let guard = Guard::bearer(|token| token == "secret");
+/// let request = http::Request::builder().uri("/redoc").body(()).unwrap();
+///
+/// assert!(check_guard(&guard, &request).is_err());
+/// ```
+pub fn check_guard<B>(guard: &Guard, request: &Request<B>) -> Result<(), Rejection> {
+    let authorization = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok());
+
+    guard.check(&GuardRequest::new(authorization, peer_addr(request)))
+}
+
+/// Get peer address of the `request` from the request extensions.
+///
+/// The address is read from `axum::extract::ConnectInfo<SocketAddr>` if _`axum`_ feature is
+/// enabled or from [`SocketAddr`] request extension.
+pub fn peer_addr<B>(request: &Request<B>) -> Option<IpAddr> {
+    #[cfg(feature = "axum")]
+    if let Some(axum::extract::ConnectInfo(addr)) = request
+        .extensions()
+        .get::<axum::extract::ConnectInfo<SocketAddr>>()
+    {
+        return Some(addr.ip());
+    }
+
+    request
+        .extensions()
+        .get::<SocketAddr>()
+        .map(|addr| addr.ip())
+}
+
+/// Create response for the `rejection` with empty body.
+///
+/// If the rejected request was not for a path `served` by the UI, the response is marked with
+/// [`UnknownPath`] extension so that framework integrations may fall through to other routes.
+pub fn rejected(rejection: &Rejection, served: bool) -> Response<Full<Bytes>> {
+    let builder = Response::builder()
+        .status(StatusCode::from_u16(rejection.status_code()).unwrap_or(StatusCode::FORBIDDEN));
+    let builder = match rejection.www_authenticate() {
+        Some(challenge) => builder.header(header::WWW_AUTHENTICATE, challenge),
+        None => builder,
+    };
+    let builder = if served {
+        builder
+    } else {
+        builder.extension(UnknownPath)
+    };
+
+    builder
+        .body(Full::default())
+        .expect("Guard rejection response must be valid")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::Ipv4Addr;
+
+    use crate::guard::IpNetwork;
+
+    use super::*;
+
+    #[test]
+    fn check_guard_reads_peer_addr_from_extensions() {
+        let guard = Guard::ip_allow_list(["10.0.0.0/8".parse::<IpNetwork>().unwrap()]);
+        let mut request = Request::new(());
+        assert_eq!(check_guard(&guard, &request), Err(Rejection::Forbidden));
+
+        request
+            .extensions_mut()
+            .insert(SocketAddr::from((Ipv4Addr::new(10, 1, 2, 3), 8080)));
+        assert_eq!(check_guard(&guard, &request), Ok(()));
+    }
+
+    #[test]
+    fn rejected_marks_unknown_paths() {
+        let rejection = Rejection::Unauthorized("Bearer".to_string());
+
+        let response = rejected(&rejection, true);
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        assert_eq!(response.headers()[header::WWW_AUTHENTICATE], "Bearer");
+        assert!(response.extensions().get::<UnknownPath>().is_none());
+
+        let response = rejected(&Rejection::Forbidden, false);
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        assert!(response.extensions().get::<UnknownPath>().is_some());
+    }
+}
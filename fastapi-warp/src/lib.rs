@@ -0,0 +1,513 @@
+//! This crate implements necessary bindings for automatically collecting `paths` and `schemas` from
+//! [`warp`] filters. It provides natural API reducing duplication while generating OpenAPI
+//! specification without the need to declare `paths` and `schemas` to `#[openapi(...)]` attribute
+//! of `OpenApi` derive.
+//!
+//! Filters are documented with [`FilterExt::documented`] which pairs the [`warp::Filter`] with
+//! the paths and schemas of the handlers collected with [`paths!`] macro. The resulting
+//! [`OpenApiFilter`]s can be combined with [`OpenApiFilter::or`] and nested under a path prefix
+//! with [`OpenApiFilter::nest`] in the same way plain filters are composed.
+//!
+//! Documentation UIs can be served with [`serve_docs`] filter from any of the UI crates
+//! implementing `tower::Service` via their `tower` feature.
+//!
+//! ## Install
+//!
+//! Add dependency declaration to `Cargo.toml`.
+//!
+//! ```toml
+//! [dependencies]
+//! fastapi-warp = "0.1.1"
+//! ```
+//!
+//! ## Examples
+//!
+//! _**Collect handlers annotated with `#[fastapi::path]` to compose OpenAPI spec.**_
+//!
+//! ```rust
+//! use std::convert::Infallible;
+//!
+//! use fastapi_warp::{paths, FilterExt};
+//! use warp::Filter;
+//!
+//! #[derive(fastapi::ToSchema, serde::Serialize)]
+//! struct User {
+//!     id: i32,
+//! }
+//!
+//! #[fastapi::path(get, path = "/user", responses((status = OK, body = User)))]
+//! async fn get_user() -> Result<impl warp::Reply, Infallible> {
+//!     Ok(warp::reply::json(&User { id: 1 }))
+//! }
+//!
+//! let user = warp::path("user")
+//!     .and(warp::get())
+//!     .and_then(get_user)
+//!     .documented(paths!(get_user));
+//!
+//! let (filter, api) = user.nest("/api/v1").split_for_parts();
+//! ```
+
+#![cfg_attr(doc_cfg, feature(doc_cfg))]
+#![warn(missing_docs)]
+#![warn(rustdoc::broken_intra_doc_links)]
+
+use std::convert::Infallible;
+use std::net::SocketAddr;
+
+use bytes::Bytes;
+use fastapi::openapi::OpenApi;
+use fastapi::routing::path_for_nested_route;
+use fastapi_ui::guard::UnknownPath;
+use http_body_util::{BodyExt, Full};
+use tower_service::Service;
+use warp::filters::BoxedFilter;
+use warp::{Filter, Rejection, Reply};
+
+/// Extends [`warp::Filter`] with `fastapi` related functionality.
+pub trait FilterExt: Filter + Sized {
+    /// Convert this [`warp::Filter`] to [`OpenApiFilter`] documented with given _`openapi`_.
+    ///
+    /// The _`openapi`_ is typically created with [`paths!`] macro from the handlers the filter
+    /// is calling.
+    ///
+    /// See usage from [`OpenApiFilter`].
+    fn documented(self, openapi: OpenApi) -> OpenApiFilter<Self>;
+}
+
+impl<F: Filter> FilterExt for F {
+    fn documented(self, openapi: OpenApi) -> OpenApiFilter<Self> {
+        let mut filter = OpenApiFilter::new(self);
+        filter.1.merge(openapi);
+
+        filter
+    }
+}
+
+/// Wrapper type for [`warp::Filter`] and [`fastapi::openapi::OpenApi`].
+///
+/// [`OpenApiFilter`] behaves same way as the wrapped [`warp::Filter`] but carries the OpenAPI
+/// paths and schemas of the handlers along with it. Combining [`OpenApiFilter`]s with
+/// [`OpenApiFilter::or`] and [`OpenApiFilter::nest`] composes both the filters and the
+/// [`OpenApi`] documents.
+///
+/// # Examples
+///
+/// _**Combine documented filters and nest them under `/api` path.**_
+/// ```rust
+/// # use std::convert::Infallible;
+/// use fastapi_warp::{paths, FilterExt};
+/// use warp::Filter;
+///
+/// #[fastapi::path(get, path = "/todo")]
+/// async fn list_todos() -> Result<impl warp::Reply, Infallible> {
+///     Ok(warp::reply())
+/// }
+///
+/// #[fastapi::path(delete, path = "/todo/{id}")]
+/// async fn delete_todo(id: i64) -> Result<impl warp::Reply, Infallible> {
+///     Ok(warp::reply())
+/// }
+///
+/// let list = warp::path!("todo")
+///     .and(warp::get())
+///     .and_then(list_todos)
+///     .documented(paths!(list_todos));
+/// let delete = warp::path!("todo" / i64)
+///     .and(warp::delete())
+///     .and_then(delete_todo)
+///     .documented(paths!(delete_todo));
+///
+/// let (filter, api) = list.or(delete).nest("/api").split_for_parts();
+/// ```
+pub struct OpenApiFilter<F>(F, OpenApi);
+
+impl<F> OpenApiFilter<F> {
+    /// Construct new [`OpenApiFilter`] from the _`filter`_ with empty [`OpenApi`].
+    ///
+    /// This is useful to combine undocumented filters with documented ones.
+    pub fn new(filter: F) -> Self {
+        #[derive(fastapi::OpenApi)]
+        struct Api;
+        Self(filter, <Api as fastapi::OpenApi>::openapi())
+    }
+
+    /// Use given _`openapi`_ as the base [`OpenApi`] of this [`OpenApiFilter`].
+    ///
+    /// Paths and schemas already collected to this [`OpenApiFilter`] are merged to the
+    /// _`openapi`_. This is useful to provide info, servers and other content that cannot be
+    /// collected from the filters.
+    pub fn openapi(self, openapi: OpenApi) -> Self {
+        Self(self.0, openapi.merge_from(self.1))
+    }
+
+    /// Get reference to the [`OpenApi`] of this [`OpenApiFilter`].
+    pub fn get_openapi(&self) -> &OpenApi {
+        &self.1
+    }
+
+    /// Consume this [`OpenApiFilter`] and return the [`OpenApi`].
+    pub fn into_openapi(self) -> OpenApi {
+        self.1
+    }
+
+    /// Split this [`OpenApiFilter`] to the wrapped [`warp::Filter`] and the [`OpenApi`].
+    ///
+    /// This is convenient to serve the filter with [`warp::serve`] and the [`OpenApi`] with
+    /// one of the UIs via [`serve_docs`].
+    pub fn split_for_parts(self) -> (F, OpenApi) {
+        (self.0, self.1)
+    }
+}
+
+impl<F, R> OpenApiFilter<F>
+where
+    F: Filter<Extract = (R,), Error = Rejection> + Clone + Send + Sync + 'static,
+    R: Reply,
+{
+    /// Combine this [`OpenApiFilter`] with _`other`_ like [`warp::Filter::or`] merging the
+    /// [`OpenApi`] of the _`other`_ to this [`OpenApiFilter`].
+    ///
+    /// Replies of both filters are converted to [`warp::reply::Response`] so that the combined
+    /// filter can be further combined with other [`OpenApiFilter`]s.
+    pub fn or<G, U>(
+        self,
+        other: OpenApiFilter<G>,
+    ) -> OpenApiFilter<impl Filter<Extract = (warp::reply::Response,), Error = Rejection> + Clone>
+    where
+        G: Filter<Extract = (U,), Error = Rejection> + Clone + Send + Sync + 'static,
+        U: Reply,
+    {
+        let filter = self
+            .0
+            .map(|reply: R| reply.into_response())
+            .or(other.0.map(|reply: U| reply.into_response()))
+            .unify();
+
+        OpenApiFilter(filter, self.1.merge_from(other.1))
+    }
+
+    /// Nest this [`OpenApiFilter`] under given _`path`_ prefix.
+    ///
+    /// The filter is prefixed with [`warp::path`] filter for each segment of the _`path`_ and
+    /// paths of the [`OpenApi`] are prefixed with the _`path`_ accordingly. The _`path`_ must
+    /// not contain path parameters.
+    ///
+    /// # Examples
+    ///
+    /// _**Nest documented filter under `/api/v1`.**_
+    /// ```rust
+    /// # use std::convert::Infallible;
+    /// # use fastapi_warp::{paths, FilterExt};
+    /// # use warp::Filter;
+    /// #[fastapi::path(get, path = "/health")]
+    /// async fn health() -> Result<impl warp::Reply, Infallible> {
+    ///     Ok(warp::reply())
+    /// }
+    ///
+    /// let api = warp::path!("health")
+    ///     .and_then(health)
+    ///     .documented(paths!(health))
+    ///     .nest("/api/v1")
+    ///     .into_openapi();
+    ///
+    /// assert!(api.paths.paths.contains_key("/api/v1/health"));
+    /// ```
+    pub fn nest(
+        self,
+        path: &'static str,
+    ) -> OpenApiFilter<impl Filter<Extract = (R,), Error = Rejection> + Clone> {
+        let prefix = path
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+            .fold(warp::any().boxed(), |prefix: BoxedFilter<()>, segment| {
+                prefix.and(warp::path(segment)).boxed()
+            });
+
+        let mut openapi = self.1;
+        let prefix_path = format!("/{}", path.trim_matches('/'));
+        openapi.paths.paths = std::mem::take(&mut openapi.paths.paths)
+            .into_iter()
+            .map(|(path, item)| (path_for_nested_route(&prefix_path, &path), item))
+            .collect();
+
+        OpenApiFilter(prefix.and(self.0), openapi)
+    }
+}
+
+/// Create [`warp::Filter`] serving documentation UI from given _`service`_.
+///
+/// The _`service`_ is any `tower::Service` of the UI crates, e.g.
+/// `fastapi_swagger_ui::tower::SwaggerUiService` or `fastapi_redoc::tower::RedocService` enabled
+/// with their `tower` feature. The service serves the UI and the OpenAPI documents registered to
+/// the UI from their own paths.
+///
+/// Only `GET` and `HEAD` requests are forwarded to the _`service`_ and requests the _`service`_
+/// does not know are rejected with [`warp::reject::not_found`] so the filter can be combined with
+/// other filters. Peer address of the request is passed to the _`service`_ for guards using IP
+/// allow-lists. Requests rejected by the guard of the UI are passed on as well if the
+/// _`service`_ does not know the path, see [`UnknownPath`][fastapi_ui::guard::UnknownPath].
+///
+/// # Examples
+///
+/// _**Serve Redoc along with the documented filters.**_
+/// ```rust
+/// # use std::convert::Infallible;
+/// use fastapi_redoc::{tower::RedocService, Redoc, Servable};
+/// use fastapi_warp::{paths, serve_docs, FilterExt};
+/// use warp::Filter;
+///
+/// #[fastapi::path(get, path = "/health")]
+/// async fn health() -> Result<impl warp::Reply, Infallible> {
+///     Ok(warp::reply())
+/// }
+///
+/// let (filter, api) = warp::path!("health")
+///     .and_then(health)
+///     .documented(paths!(health))
+///     .split_for_parts();
+///
+/// let routes = filter.or(serve_docs(RedocService::from(Redoc::with_url("/redoc", api))));
+/// ```
+pub fn serve_docs<S>(
+    service: S,
+) -> impl Filter<Extract = (warp::reply::Response,), Error = Rejection> + Clone
+where
+    S: Service<http::Request<()>, Response = http::Response<Full<Bytes>>, Error = Infallible>
+        + Clone
+        + Send
+        + Sync
+        + 'static,
+    S::Future: Send,
+{
+    warp::method()
+        .and(warp::path::full())
+        .and(warp::header::headers_cloned())
+        .and(warp::addr::remote())
+        .and_then(move |method, path, headers, remote| {
+            let service = service.clone();
+            async move { serve(service, method, path, headers, remote).await }
+        })
+}
+
+async fn serve<S>(
+    mut service: S,
+    method: warp::http::Method,
+    path: warp::path::FullPath,
+    headers: warp::http::HeaderMap,
+    remote: Option<SocketAddr>,
+) -> Result<warp::reply::Response, Rejection>
+where
+    S: Service<http::Request<()>, Response = http::Response<Full<Bytes>>, Error = Infallible>,
+{
+    if method != warp::http::Method::GET && method != warp::http::Method::HEAD {
+        return Err(warp::reject::not_found());
+    }
+
+    let mut request = headers.iter().fold(
+        http::Request::builder()
+            .method(method.as_str())
+            .uri(path.as_str()),
+        |request, (name, value)| request.header(name.as_str(), value.as_bytes()),
+    );
+    if let Some(remote) = remote {
+        request = request.extension(remote);
+    }
+    let request = request.body(()).map_err(|_| warp::reject::not_found())?;
+
+    let response = match service.call(request).await {
+        Ok(response) => response,
+        Err(never) => match never {},
+    };
+    if response.status() == http::StatusCode::NOT_FOUND
+        || response.extensions().get::<UnknownPath>().is_some()
+    {
+        return Err(warp::reject::not_found());
+    }
+
+    let (parts, body) = response.into_parts();
+    let body = match body.collect().await {
+        Ok(body) => body.to_bytes(),
+        Err(never) => match never {},
+    };
+
+    let response = parts.headers.iter().fold(
+        warp::http::Response::builder().status(parts.status.as_u16()),
+        |response, (name, value)| response.header(name.as_str(), value.as_bytes()),
+    );
+
+    Ok(response
+        .body(warp::hyper::Body::from(body))
+        .expect("Documentation UI response must be valid"))
+}
+
+/// Collect OpenAPI paths and schemas of handlers annotated with `#[fastapi::path]` to
+/// [`fastapi::openapi::OpenApi`].
+///
+/// The macro takes comma separated list of handlers which may be prefixed with the module path
+/// e.g. `paths!(todo::list_todos, todo::create_todo)`. Tags of the handlers are added to the
+/// operations same way as the `#[openapi(paths(...))]` attribute of `OpenApi` derive does.
+///
+/// The result is used with [`FilterExt::documented`] to document the [`warp::Filter`] calling
+/// the handlers.
+///
+/// # Examples
+///
+/// _**Collect paths of a handler from another module.**_
+/// ```rust
+/// # use fastapi_warp::paths;
+/// mod todo {
+///     # use std::convert::Infallible;
+///     #[fastapi::path(get, path = "/todo")]
+///     pub async fn list_todos() -> Result<impl warp::Reply, Infallible> {
+///         Ok(warp::reply())
+///     }
+/// }
+///
+/// let api = paths!(todo::list_todos);
+/// assert!(api.paths.paths.contains_key("/todo"));
+/// ```
+#[macro_export]
+macro_rules! paths {
+    ( $( $handler:path ),+ $(,)? ) => {
+        {
+            let mut openapi = fastapi::openapi::OpenApi::default();
+            $(
+                fastapi::routing::__document::<fastapi::__path_type!($handler)>(&mut openapi);
+            )+
+            openapi
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::Infallible;
+
+    use fastapi_redoc::{tower::RedocService, Redoc, Servable};
+    use warp::http::StatusCode;
+
+    use super::*;
+
+    #[derive(fastapi::ToSchema, serde::Serialize)]
+    struct Todo {
+        id: i64,
+    }
+
+    #[fastapi::path(get, path = "/todo", tag = "todo", responses((status = OK, body = [Todo])))]
+    async fn list_todos() -> Result<impl Reply, Infallible> {
+        Ok(warp::reply::json(&vec![Todo { id: 1 }]))
+    }
+
+    #[fastapi::path(delete, path = "/todo/{id}")]
+    async fn delete_todo(id: i64) -> Result<impl Reply, Infallible> {
+        Ok(warp::reply::with_status(
+            id.to_string(),
+            StatusCode::NO_CONTENT,
+        ))
+    }
+
+    #[fastapi::path(get, path = "/")]
+    async fn root() -> Result<impl Reply, Infallible> {
+        Ok(warp::reply())
+    }
+
+    fn todos(
+    ) -> OpenApiFilter<impl Filter<Extract = (warp::reply::Response,), Error = Rejection> + Clone>
+    {
+        let list = warp::path!("todo")
+            .and(warp::get())
+            .and_then(list_todos)
+            .documented(paths!(list_todos));
+        let delete = warp::path!("todo" / i64)
+            .and(warp::delete())
+            .and_then(delete_todo)
+            .documented(paths!(delete_todo));
+
+        list.or(delete)
+    }
+
+    #[tokio::test]
+    async fn openapi_filter_collects_nested_paths() {
+        let api = warp::path::end()
+            .and_then(root)
+            .documented(paths!(root))
+            .or(todos().nest("/api/v1/"))
+            .into_openapi();
+
+        assert_eq!(
+            api.paths.paths.keys().collect::<Vec<_>>(),
+            ["/", "/api/v1/todo", "/api/v1/todo/{id}"]
+        );
+        let operation = api.paths.paths["/api/v1/todo"].get.as_ref().unwrap();
+        assert_eq!(operation.tags, Some(vec!["todo".to_string()]));
+        assert!(api.components.unwrap().schemas.contains_key("Todo"));
+    }
+
+    #[tokio::test]
+    async fn openapi_filter_routes_nested_filters() {
+        let (filter, _) = todos().nest("/api/v1").split_for_parts();
+
+        let response = warp::test::request()
+            .path("/api/v1/todo")
+            .reply(&filter)
+            .await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.body().as_ref(), b"[{\"id\":1}]");
+
+        let response = warp::test::request()
+            .method("DELETE")
+            .path("/api/v1/todo/1")
+            .reply(&filter)
+            .await;
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+        let response = warp::test::request().path("/todo").reply(&filter).await;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn serve_docs_falls_through_unknown_paths() {
+        let (filter, api) = todos().split_for_parts();
+        let routes = serve_docs(RedocService::from(Redoc::with_url("/redoc", api))).or(filter);
+
+        let response = warp::test::request().path("/redoc").reply(&routes).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers()[warp::http::header::CONTENT_TYPE],
+            "text/html; charset=utf-8"
+        );
+
+        let response = warp::test::request().path("/todo").reply(&routes).await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = warp::test::request()
+            .method("DELETE")
+            .path("/todo/1")
+            .reply(&routes)
+            .await;
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    }
+
+    #[tokio::test]
+    async fn serve_docs_with_guard_falls_through_unknown_paths() {
+        use fastapi_ui::guard::Guard;
+
+        let (filter, api) = todos().split_for_parts();
+        let redoc = Redoc::with_url("/redoc", api).guard(Guard::bearer(|token| token == "secret"));
+        let routes = serve_docs(RedocService::from(redoc)).or(filter);
+
+        let response = warp::test::request().path("/redoc").reply(&routes).await;
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+        let response = warp::test::request()
+            .path("/redoc")
+            .header("authorization", "Bearer secret")
+            .reply(&routes)
+            .await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = warp::test::request().path("/todo").reply(&routes).await;
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+}
@@ -0,0 +1,622 @@
+//! Typed HTTP client for operations declared with [`#[fastapi::path]`][path].
+//!
+//! With `client` feature enabled the path macro generates an enum named after the handler
+//! function e.g. `get_pet` becomes `GetPetResponse`. The enum has one variant per response
+//! declared in `responses(...)` carrying the deserialized response body, and an associated
+//! `async fn send(...)` which takes a [`Transport`] followed by the same parameters the handler
+//! is documented with: inline parameters by their declared type, [`IntoParams`] types and the
+//! request body.
+//!
+//! The client itself does not depend on any HTTP library. Requests are handed over to a
+//! [`Transport`] implementation which is a small trait to adapt any HTTP client, or even an
+//! in-process router for tests.
+//!
+//! # Examples
+//!
+//! _**Call `get_pet` operation over user defined transport.**_
+//! ```rust
+//! # use fastapi::client::{Error, Request, Response, Transport};
+//! # use std::future::Future;
+//! #[derive(serde::Serialize, serde::Deserialize, fastapi::ToSchema)]
+//! struct Pet {
+//!     id: u64,
+//!     name: String,
+//! }
+//!
+//! #[fastapi::path(
+//!     get,
+//!     path = "/pets/{id}",
+//!     params(("id" = u64, Path, description = "Pet database id")),
+//!     responses(
+//!         (status = 200, description = "Pet found", body = Pet),
+//!         (status = 404, description = "Pet not found")
+//!     )
+//! )]
+//! async fn get_pet(id: u64) -> Pet {
+//!     Pet { id, name: "Lemon".to_string() }
+//! }
+//!
+//! struct StaticTransport;
+//!
+//! impl Transport for StaticTransport {
+//!     fn send(&self, request: Request) -> impl Future<Output = Result<Response, Error>> + Send {
+//!         assert_eq!(request.uri(), "/pets/1");
+//!         std::future::ready(Ok(Response::new(200, br#"{"id":1,"name":"Lemon"}"#.to_vec())))
+//!     }
+//! }
+//!
+//! # async fn call() -> Result<(), Error> {
+//! match GetPetResponse::send(&StaticTransport, 1).await? {
+//!     GetPetResponse::Ok(pet) => assert_eq!(pet.name, "Lemon"),
+//!     GetPetResponse::NotFound => panic!("pet should exist"),
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [path]: ../attr.path.html
+//! [`IntoParams`]: crate::IntoParams
+
+use std::fmt::{Display, Formatter};
+use std::future::Future;
+
+use serde::Serialize;
+use serde_json::Value;
+
+// Bound of the response body types of generated client functions.
+#[doc(hidden)]
+pub use serde::de::DeserializeOwned;
+
+use crate::openapi::path::{HttpMethod, Parameter, ParameterIn};
+
+/// Sends [`Request`]s produced by generated client functions.
+///
+/// Implement this for the HTTP client of your choice. The implementation is expected to send
+/// the request against its own base URL and return the response as is regardless of the
+/// status code. Status codes are handled by the generated response enums.
+pub trait Transport {
+    /// Send the request and resolve to the received [`Response`].
+    fn send(&self, request: Request) -> impl Future<Output = Result<Response, Error>> + Send;
+}
+
+impl<T: Transport + Sync> Transport for &T {
+    fn send(&self, request: Request) -> impl Future<Output = Result<Response, Error>> + Send {
+        T::send(self, request)
+    }
+}
+
+/// Argument of type `T` of a generated client function.
+///
+/// Arguments are declared as `impl Argument<fn(T)>` instead of `T` so that `T` is only required
+/// to implement [`Serialize`] when the function is actually called. `T` is wrapped in a function
+/// pointer because elided lifetimes, e.g. `Foo` for `Foo<'a>`, are not allowed in arguments of
+/// `async fn` but are allowed in function pointer types.
+#[doc(hidden)]
+pub trait Argument<F: ?Sized>: Serialize {}
+
+impl<T: Serialize, F: Fn(T)> Argument<F> for T {}
+
+/// HTTP request built by a generated client function.
+#[non_exhaustive]
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct Request {
+    /// Http method of the operation.
+    pub method: HttpMethod,
+
+    /// Percent encoded path of the request with path parameters already substituted.
+    pub path: String,
+
+    /// Query parameters of the request in order of declaration. Values are not encoded.
+    pub query: Vec<(String, String)>,
+
+    /// Header parameters of the request, including `content-type` of the body if any.
+    pub headers: Vec<(String, String)>,
+
+    /// Serialized request body if the operation declares one.
+    pub body: Option<Vec<u8>>,
+}
+
+impl Request {
+    /// Construct a new [`Request`] for given method and path template such as `/pets/{id}`.
+    pub fn new<P: Into<String>>(method: HttpMethod, path: P) -> Self {
+        Self {
+            method,
+            path: path.into(),
+            query: Vec::new(),
+            headers: Vec::new(),
+            body: None,
+        }
+    }
+
+    /// Get upper case name of the [`Request::method`] e.g. `GET`.
+    pub fn method_str(&self) -> &'static str {
+        match self.method {
+            HttpMethod::Get => "GET",
+            HttpMethod::Post => "POST",
+            HttpMethod::Put => "PUT",
+            HttpMethod::Delete => "DELETE",
+            HttpMethod::Options => "OPTIONS",
+            HttpMethod::Head => "HEAD",
+            HttpMethod::Patch => "PATCH",
+            HttpMethod::Trace => "TRACE",
+        }
+    }
+
+    /// Get path and percent encoded query of the request e.g. `/pets?name=Lemon%20Tree`.
+    pub fn uri(&self) -> String {
+        if self.query.is_empty() {
+            return self.path.clone();
+        }
+
+        let query = self
+            .query
+            .iter()
+            .map(|(name, value)| format!("{}={}", encode(name), encode(value)))
+            .collect::<Vec<_>>()
+            .join("&");
+
+        format!("{}?{query}", self.path)
+    }
+
+    /// Add single parameter with given name to the request.
+    ///
+    /// The value is serialized with [`serde`]. Scalar values are used as is, arrays are exploded
+    /// to repeated query parameters or comma separated values elsewhere. Objects are exploded to
+    /// query parameters named by the object keys. `null` values are omitted.
+    pub fn param<V: Serialize + ?Sized>(
+        &mut self,
+        parameter_in: ParameterIn,
+        name: &str,
+        value: &V,
+    ) -> Result<(), Error> {
+        let value = serde_json::to_value(value).map_err(Error::Serialize)?;
+        self.param_value(parameter_in, name, value);
+
+        Ok(())
+    }
+
+    /// Add parameters of a type implementing [`IntoParams`][into_params] to the request.
+    ///
+    /// The `parameters` are the ones returned by [`IntoParams::into_params`][into_params]. Each
+    /// parameter takes its value from the field of the same name in serialized `params`, or by
+    /// position if `params` serializes to an array as unnamed tuple structs do.
+    ///
+    /// [into_params]: crate::IntoParams::into_params
+    pub fn params<P: Serialize + ?Sized>(
+        &mut self,
+        params: &P,
+        parameters: Vec<Parameter>,
+    ) -> Result<(), Error> {
+        match serde_json::to_value(params).map_err(Error::Serialize)? {
+            Value::Object(mut object) => {
+                for parameter in parameters {
+                    if let Some(value) = object.remove(&parameter.name) {
+                        self.param_value(parameter.parameter_in, &parameter.name, value);
+                    }
+                }
+            }
+            Value::Array(values) => {
+                for (parameter, value) in parameters.into_iter().zip(values) {
+                    self.param_value(parameter.parameter_in, &parameter.name, value);
+                }
+            }
+            value => {
+                if let Some(parameter) = parameters.into_iter().next() {
+                    self.param_value(parameter.parameter_in, &parameter.name, value);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Set body of the request serialized according to the `content_type`.
+    ///
+    /// * JSON content types are serialized with [`serde_json`].
+    /// * `application/x-www-form-urlencoded` is serialized from the fields of the value.
+    /// * `text/*` content types use the string value as is.
+    /// * Other content types expect the value to serialize to bytes e.g. `Vec<u8>` or to string.
+    ///
+    /// Body serializing to `null` e.g. `None` of optional request body is omitted. Otherwise
+    /// existing `content-type` header is replaced with the given `content_type`.
+    pub fn body<B: Serialize + ?Sized>(
+        &mut self,
+        content_type: &str,
+        body: &B,
+    ) -> Result<(), Error> {
+        let bytes = if is_json(content_type) {
+            let bytes = serde_json::to_vec(body).map_err(Error::Serialize)?;
+            if bytes == b"null" {
+                return Ok(());
+            }
+            bytes
+        } else {
+            let value = serde_json::to_value(body).map_err(Error::Serialize)?;
+            if value.is_null() {
+                return Ok(());
+            }
+            if content_type.starts_with("application/x-www-form-urlencoded") {
+                let mut form = Vec::new();
+                push_exploded(&mut form, "", value);
+                form.iter()
+                    .map(|(name, value)| format!("{}={}", encode(name), encode(value)))
+                    .collect::<Vec<_>>()
+                    .join("&")
+                    .into_bytes()
+            } else {
+                let not_bytes = || {
+                    Error::Serialize(serde::ser::Error::custom(format!(
+                        "body with content type: {content_type} must serialize to bytes or string"
+                    )))
+                };
+                match value {
+                    Value::String(string) => string.into_bytes(),
+                    value if content_type.starts_with("text/") => value.to_string().into_bytes(),
+                    Value::Array(values) => values
+                        .iter()
+                        .map(|value| value.as_u64().and_then(|byte| u8::try_from(byte).ok()))
+                        .collect::<Option<Vec<u8>>>()
+                        .ok_or_else(not_bytes)?,
+                    _ => return Err(not_bytes()),
+                }
+            }
+        };
+
+        self.headers
+            .retain(|(name, _)| !name.eq_ignore_ascii_case("content-type"));
+        self.headers
+            .push(("content-type".to_string(), content_type.to_string()));
+        self.body = Some(bytes);
+
+        Ok(())
+    }
+
+    fn param_value(&mut self, parameter_in: ParameterIn, name: &str, value: Value) {
+        if value.is_null() {
+            return;
+        }
+
+        match parameter_in {
+            ParameterIn::Path => {
+                let value = encode(&to_delimited_string(value));
+                self.path = self.path.replace(&format!("{{{name}}}"), &value);
+            }
+            ParameterIn::Query => push_exploded(&mut self.query, name, value),
+            ParameterIn::Header => self
+                .headers
+                .push((name.to_string(), to_delimited_string(value))),
+            ParameterIn::Cookie => {
+                let cookie = format!("{name}={}", to_delimited_string(value));
+                match self
+                    .headers
+                    .iter_mut()
+                    .find(|(header, _)| header.eq_ignore_ascii_case("cookie"))
+                {
+                    Some((_, cookies)) => {
+                        cookies.push_str("; ");
+                        cookies.push_str(&cookie);
+                    }
+                    None => self.headers.push(("cookie".to_string(), cookie)),
+                }
+            }
+        }
+    }
+}
+
+/// HTTP response received from a [`Transport`].
+#[non_exhaustive]
+#[derive(Clone, PartialEq, Debug)]
+pub struct Response {
+    /// Status code of the response.
+    pub status: u16,
+
+    /// Headers of the response.
+    pub headers: Vec<(String, String)>,
+
+    /// Raw body of the response.
+    pub body: Vec<u8>,
+}
+
+impl Response {
+    /// Construct a new [`Response`] with given status code and body.
+    pub fn new(status: u16, body: Vec<u8>) -> Self {
+        Self {
+            status,
+            headers: Vec::new(),
+            body,
+        }
+    }
+
+    /// Add header to the response.
+    pub fn header<N: Into<String>, V: Into<String>>(mut self, name: N, value: V) -> Self {
+        self.headers.push((name.into(), value.into()));
+
+        self
+    }
+
+    /// Get value of the first header with given name. Header names are compared case
+    /// insensitively.
+    pub fn get_header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header, _)| header.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Deserialize the body of the response according to its `content-type` header.
+    ///
+    /// JSON is assumed when the response does not have `content-type` header. `text/*` content
+    /// types deserialize from the body as a string and other content types from the raw bytes
+    /// e.g. to `Vec<u8>`.
+    pub fn decode<T: DeserializeOwned>(&self) -> Result<T, Error> {
+        let content_type = self
+            .get_header("content-type")
+            .unwrap_or("application/json");
+
+        if is_json(content_type) {
+            serde_json::from_slice(&self.body)
+        } else if content_type.starts_with("text/") {
+            serde_json::from_value(Value::String(
+                String::from_utf8_lossy(&self.body).into_owned(),
+            ))
+        } else {
+            serde_json::from_value(Value::Array(
+                self.body.iter().map(|byte| Value::from(*byte)).collect(),
+            ))
+        }
+        .map_err(|error| Error::Deserialize {
+            status: self.status,
+            source: error,
+        })
+    }
+}
+
+/// Error returned by generated client functions.
+#[derive(Debug)]
+pub enum Error {
+    /// [`Transport`] failed to send the request or receive the response.
+    Transport(Box<dyn std::error::Error + Send + Sync>),
+
+    /// Parameters or body of the request could not be serialized.
+    Serialize(serde_json::Error),
+
+    /// Body of the response with given status could not be deserialized.
+    Deserialize {
+        /// Status code of the response.
+        status: u16,
+        /// Underlying deserialization error.
+        source: serde_json::Error,
+    },
+
+    /// Server responded with a status code that is not declared for the operation.
+    UnexpectedStatus(Response),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Transport(error) => write!(f, "transport error: {error}"),
+            Self::Serialize(error) => write!(f, "failed to serialize request: {error}"),
+            Self::Deserialize { status, source } => write!(
+                f,
+                "failed to deserialize response with status: {status}: {source}"
+            ),
+            Self::UnexpectedStatus(response) => {
+                write!(f, "unexpected response status: {}", response.status)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Transport(error) => Some(error.as_ref()),
+            Self::Serialize(error) => Some(error),
+            Self::Deserialize { source, .. } => Some(source),
+            Self::UnexpectedStatus(_) => None,
+        }
+    }
+}
+
+fn is_json(content_type: &str) -> bool {
+    let essence = content_type.split(';').next().unwrap_or_default().trim();
+    essence == "application/json" || essence.ends_with("+json")
+}
+
+fn to_plain_string(value: Value) -> String {
+    match value {
+        Value::String(string) => string,
+        value => value.to_string(),
+    }
+}
+
+fn to_delimited_string(value: Value) -> String {
+    match value {
+        Value::Array(values) => values
+            .into_iter()
+            .map(to_plain_string)
+            .collect::<Vec<_>>()
+            .join(","),
+        value => to_plain_string(value),
+    }
+}
+
+/// Push `value` as `form` style exploded name value pairs.
+fn push_exploded(pairs: &mut Vec<(String, String)>, name: &str, value: Value) {
+    match value {
+        Value::Null => (),
+        Value::Array(values) => {
+            for value in values {
+                push_exploded(pairs, name, value)
+            }
+        }
+        Value::Object(object) => {
+            for (name, value) in object {
+                push_exploded(pairs, &name, value)
+            }
+        }
+        value => pairs.push((name.to_string(), to_plain_string(value))),
+    }
+}
+
+/// Percent encode everything except unreserved characters of RFC 3986.
+fn encode(value: &str) -> String {
+    value
+        .bytes()
+        .fold(String::with_capacity(value.len()), |mut encoded, byte| {
+            if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+                encoded.push(byte as char);
+            } else {
+                encoded.push_str(&format!("%{byte:02X}"));
+            }
+            encoded
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Serialize;
+
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Filter {
+        name: Option<String>,
+        tags: Vec<&'static str>,
+        #[serde(rename = "x-api-key")]
+        api_key: &'static str,
+    }
+
+    fn filter_parameters() -> Vec<Parameter> {
+        [
+            ("name", ParameterIn::Query),
+            ("tags", ParameterIn::Query),
+            ("x-api-key", ParameterIn::Header),
+        ]
+        .into_iter()
+        .map(|(name, parameter_in)| {
+            crate::openapi::path::ParameterBuilder::new()
+                .name(name)
+                .parameter_in(parameter_in)
+                .build()
+        })
+        .collect()
+    }
+
+    #[test]
+    fn request_with_parameters() {
+        let mut request = Request::new(HttpMethod::Get, "/pets/{id}/owners/{owner}");
+        request.param(ParameterIn::Path, "id", &1).unwrap();
+        request
+            .param(ParameterIn::Path, "owner", "John Doe")
+            .unwrap();
+        request
+            .params(
+                &Filter {
+                    name: Some("a&b".to_string()),
+                    tags: vec!["cat", "dog"],
+                    api_key: "secret",
+                },
+                filter_parameters(),
+            )
+            .unwrap();
+        request.param(ParameterIn::Cookie, "session", "s1").unwrap();
+        request.param(ParameterIn::Cookie, "theme", "dark").unwrap();
+
+        assert_eq!(
+            request.uri(),
+            "/pets/1/owners/John%20Doe?name=a%26b&tags=cat&tags=dog"
+        );
+        assert_eq!(
+            request.headers,
+            vec![
+                ("x-api-key".to_string(), "secret".to_string()),
+                ("cookie".to_string(), "session=s1; theme=dark".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn request_omits_null_parameters() {
+        let mut request = Request::new(HttpMethod::Get, "/pets");
+        request
+            .params(
+                &Filter {
+                    name: None,
+                    tags: Vec::new(),
+                    api_key: "secret",
+                },
+                filter_parameters(),
+            )
+            .unwrap();
+
+        assert_eq!(request.uri(), "/pets");
+    }
+
+    #[test]
+    fn request_body_by_content_type() {
+        #[derive(Serialize)]
+        struct Pet {
+            name: &'static str,
+            age: u8,
+        }
+        let pet = Pet {
+            name: "Lemon Tree",
+            age: 3,
+        };
+
+        let mut request = Request::new(HttpMethod::Post, "/pets");
+        request.body("application/json", &pet).unwrap();
+        assert_eq!(
+            request.body.as_deref(),
+            Some(&br#"{"name":"Lemon Tree","age":3}"#[..])
+        );
+
+        request
+            .body("application/x-www-form-urlencoded", &pet)
+            .unwrap();
+        assert_eq!(
+            request.body.as_deref(),
+            Some(&b"age=3&name=Lemon%20Tree"[..])
+        );
+
+        request.body("text/plain", "plain text").unwrap();
+        assert_eq!(request.body.as_deref(), Some(&b"plain text"[..]));
+
+        request
+            .body("application/octet-stream", &vec![0_u8, 255])
+            .unwrap();
+        assert_eq!(request.body.as_deref(), Some(&[0_u8, 255][..]));
+        assert!(request.body("application/octet-stream", &pet).is_err());
+
+        assert_eq!(
+            request.headers,
+            vec![(
+                "content-type".to_string(),
+                "application/octet-stream".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn response_decode_by_content_type() {
+        let response = Response::new(200, b"[1,2]".to_vec());
+        assert_eq!(response.decode::<Vec<u8>>().unwrap(), vec![1, 2]);
+
+        let response = Response::new(200, b"[1,2]".to_vec())
+            .header("Content-Type", "text/plain; charset=utf-8");
+        assert_eq!(response.decode::<String>().unwrap(), "[1,2]");
+
+        let response = Response::new(200, b"[1,2]".to_vec())
+            .header("content-type", "application/octet-stream");
+        assert_eq!(response.decode::<Vec<u8>>().unwrap(), b"[1,2]".to_vec());
+
+        let response = Response::new(200, b"not json".to_vec());
+        assert!(matches!(
+            response.decode::<Vec<u8>>(),
+            Err(Error::Deserialize { status: 200, .. })
+        ));
+    }
+}
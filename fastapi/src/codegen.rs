@@ -0,0 +1,7 @@
+//! Generate source code from OpenAPI definitions.
+//!
+//! Code generators in this module take the types defined in [`crate::openapi`] as input and
+//! produce source code for other languages. The output is deterministic for a given input so
+//! it can be committed to version control and diffed in CI.
+
+pub mod typescript;
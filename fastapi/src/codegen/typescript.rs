@@ -0,0 +1,939 @@
+//! Generate TypeScript type declarations from [`Components`].
+//!
+//! Every schema in [`Components::schemas`] is rendered either as `export interface` _(plain
+//! objects)_ or `export type` _(anything else)_ declaration.
+//!
+//! * [`OneOf`] and [`AnyOf`] are rendered as unions and [`AllOf`] as intersection.
+//! * [`OneOf`] with [`Discriminator`] is rendered as discriminated union where each referenced
+//!   schema is intersected with an object having the discriminator property as string literal.
+//! * [`Object::enum_values`] are rendered as union of literal types.
+//! * [`SchemaType::Array`] is rendered as union of its types, e.g. `[Type::String, Type::Null]`
+//!   becomes `string | null`.
+//! * Schemas having `readOnly` or `writeOnly` properties, or referencing such schemas, are
+//!   rendered twice. `{Name}Input` omits `readOnly` properties and `{Name}Output` omits
+//!   `writeOnly` properties.
+//! * Objects having both properties and `additionalProperties` get an index signature typed as
+//!   union of the property types and the additional properties type, so that every property is
+//!   assignable to it.
+//!
+//! # Examples
+//!
+//! _**Generate TypeScript declarations for `Pet` schema.**_
+//! ```rust
+//! # use fastapi::openapi::{ComponentsBuilder, ObjectBuilder, Type};
+//! let components = ComponentsBuilder::new()
+//!     .schema(
+//!         "Pet",
+//!         ObjectBuilder::new()
+//!             .property("age", ObjectBuilder::new().schema_type(Type::Integer))
+//!             .property("name", ObjectBuilder::new().schema_type(Type::String))
+//!             .required("name"),
+//!     )
+//!     .build();
+//!
+//! let declarations = fastapi::codegen::typescript::generate(&components);
+//!
+//! assert_eq!(
+//!     declarations,
+//!     "export interface Pet {\n  age?: number;\n  name: string;\n}\n"
+//! );
+//! ```
+//!
+//! [`OneOf`]: crate::openapi::schema::OneOf
+//! [`AnyOf`]: crate::openapi::schema::AnyOf
+//! [`AllOf`]: crate::openapi::schema::AllOf
+//! [`Discriminator`]: crate::openapi::schema::Discriminator
+//! [`Object::enum_values`]: crate::openapi::schema::Object::enum_values
+//! [`SchemaType::Array`]: crate::openapi::schema::SchemaType::Array
+
+use std::collections::BTreeSet;
+use std::fmt::Write;
+
+use serde_json::Value;
+
+use crate::openapi::schema::{
+    AdditionalProperties, Array, ArrayItems, Discriminator, Object, Schema, SchemaType, Type,
+};
+use crate::openapi::{Components, Deprecated, RefOr};
+
+const SCHEMA_REF_PREFIX: &str = "#/components/schemas/";
+
+const INDENT: &str = "  ";
+
+/// Render TypeScript declarations for all schemas of given [`Components`].
+///
+/// Declarations are written in order of [`Components::schemas`] and separated by empty line.
+/// See [module level documentation][self] for details of the type mapping.
+pub fn generate(components: &Components) -> String {
+    let generator = Generator::new(components);
+
+    let mut declarations = Vec::new();
+    for (name, schema) in &components.schemas {
+        if generator.split.contains(name.as_str()) {
+            declarations.push(generator.declaration(name, schema, Mode::Input));
+            declarations.push(generator.declaration(name, schema, Mode::Output));
+        } else {
+            declarations.push(generator.declaration(name, schema, Mode::Plain));
+        }
+    }
+
+    declarations.join("\n")
+}
+
+/// Direction in which the declaration is used. Determines which properties get omitted and
+/// which name suffix is used when referencing split schemas.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Mode {
+    Plain,
+    Input,
+    Output,
+}
+
+impl Mode {
+    fn suffix(&self) -> &'static str {
+        match self {
+            Self::Plain => "",
+            Self::Input => "Input",
+            Self::Output => "Output",
+        }
+    }
+
+    fn is_omitted(&self, property: &RefOr<Schema>) -> bool {
+        match (self, property) {
+            (Self::Input, RefOr::T(Schema::Object(object))) => object.read_only == Some(true),
+            (Self::Output, RefOr::T(Schema::Object(object))) => object.write_only == Some(true),
+            _ => false,
+        }
+    }
+}
+
+struct Generator<'c> {
+    /// Names of schemas that must be rendered separately as input and output types.
+    split: BTreeSet<&'c str>,
+}
+
+impl<'c> Generator<'c> {
+    fn new(components: &'c Components) -> Self {
+        let mut split = components
+            .schemas
+            .iter()
+            .filter(|(_, schema)| has_access_modifier(schema))
+            .map(|(name, _)| name.as_str())
+            .collect::<BTreeSet<_>>();
+
+        // schemas referencing split schemas must be split as well
+        loop {
+            let referencing = components
+                .schemas
+                .iter()
+                .filter(|(name, _)| !split.contains(name.as_str()))
+                .filter(|(_, schema)| {
+                    let mut references = BTreeSet::new();
+                    collect_references(schema, &mut references);
+                    references.iter().any(|reference| split.contains(reference))
+                })
+                .map(|(name, _)| name.as_str())
+                .collect::<Vec<_>>();
+
+            if referencing.is_empty() {
+                break;
+            }
+            split.extend(referencing);
+        }
+
+        Self { split }
+    }
+
+    fn type_name(&self, schema_name: &str, mode: Mode) -> String {
+        let mode = match mode {
+            Mode::Plain if self.split.contains(schema_name) => Mode::Output,
+            _ if !self.split.contains(schema_name) => Mode::Plain,
+            mode => mode,
+        };
+
+        format!("{}{}", to_identifier(schema_name), mode.suffix())
+    }
+
+    fn declaration(&self, name: &str, schema: &RefOr<Schema>, mode: Mode) -> String {
+        let type_name = self.type_name(name, mode);
+        let mut declaration = String::new();
+
+        if let RefOr::T(schema) = schema {
+            let (description, deprecated) = describe(schema);
+            write_doc_comment(&mut declaration, description, deprecated, "");
+        }
+
+        match schema {
+            RefOr::T(Schema::Object(object)) if is_interface(object) => {
+                let _ = writeln!(declaration, "export interface {type_name} {{");
+                self.write_members(&mut declaration, object, mode, 1);
+                declaration.push_str("}\n");
+            }
+            schema => {
+                let _ = writeln!(
+                    declaration,
+                    "export type {type_name} = {};",
+                    self.type_of(schema, mode, 0)
+                );
+            }
+        }
+
+        declaration
+    }
+
+    fn type_of(&self, schema: &RefOr<Schema>, mode: Mode, indent: usize) -> String {
+        match schema {
+            RefOr::Ref(reference) => match reference.ref_location.strip_prefix(SCHEMA_REF_PREFIX) {
+                Some(name) => self.type_name(name, mode),
+                None => String::from("unknown"),
+            },
+            RefOr::T(schema) => self.schema_type_of(schema, mode, indent),
+        }
+    }
+
+    fn schema_type_of(&self, schema: &Schema, mode: Mode, indent: usize) -> String {
+        match schema {
+            Schema::Object(object) => self.object_type(object, mode, indent),
+            Schema::Array(array) => self.array_type(array, mode, indent),
+            Schema::OneOf(one_of) => match &one_of.discriminator {
+                Some(discriminator) => union(
+                    one_of
+                        .items
+                        .iter()
+                        .map(|item| self.discriminated_type(item, discriminator, mode, indent)),
+                ),
+                None => union(
+                    one_of
+                        .items
+                        .iter()
+                        .map(|item| self.type_of(item, mode, indent)),
+                ),
+            },
+            Schema::AnyOf(any_of) => union(
+                any_of
+                    .items
+                    .iter()
+                    .map(|item| self.type_of(item, mode, indent)),
+            ),
+            Schema::AllOf(all_of) => {
+                let items = all_of
+                    .items
+                    .iter()
+                    .map(|item| parenthesize(self.type_of(item, mode, indent)))
+                    .collect::<Vec<_>>();
+
+                if items.is_empty() {
+                    String::from("unknown")
+                } else {
+                    items.join(" & ")
+                }
+            }
+        }
+    }
+
+    fn discriminated_type(
+        &self,
+        item: &RefOr<Schema>,
+        discriminator: &Discriminator,
+        mode: Mode,
+        indent: usize,
+    ) -> String {
+        match item {
+            RefOr::Ref(reference) => {
+                let value = discriminator
+                    .mapping
+                    .iter()
+                    .find(|(_, location)| {
+                        *location == &reference.ref_location
+                            || reference.ref_location.strip_prefix(SCHEMA_REF_PREFIX)
+                                == Some(location.as_str())
+                    })
+                    .map(|(value, _)| value.as_str())
+                    .or_else(|| reference.ref_location.strip_prefix(SCHEMA_REF_PREFIX));
+
+                match value {
+                    Some(value) => format!(
+                        "({{ {}: {} }} & {})",
+                        to_property_name(&discriminator.property_name),
+                        to_literal(&Value::String(value.to_string())),
+                        self.type_of(item, mode, indent)
+                    ),
+                    None => self.type_of(item, mode, indent),
+                }
+            }
+            item => self.type_of(item, mode, indent),
+        }
+    }
+
+    fn object_type(&self, object: &Object, mode: Mode, indent: usize) -> String {
+        let types = match &object.schema_type {
+            SchemaType::Type(schema_type) => vec![schema_type.clone()],
+            SchemaType::Array(types) => types.clone(),
+            SchemaType::AnyValue if !object.properties.is_empty() => vec![Type::Object],
+            SchemaType::AnyValue => Vec::new(),
+        };
+        let nullable = types.contains(&Type::Null);
+
+        if let Some(enum_values) = &object.enum_values {
+            let mut literals = enum_values.iter().map(to_literal).collect::<Vec<_>>();
+            if nullable && !enum_values.contains(&Value::Null) {
+                literals.push(String::from("null"));
+            }
+            return union(literals);
+        }
+
+        if types.is_empty() {
+            return String::from("unknown");
+        }
+
+        union(types.iter().map(|schema_type| match schema_type {
+            Type::Object => self.object_literal(object, mode, indent),
+            Type::String => String::from("string"),
+            Type::Integer | Type::Number => String::from("number"),
+            Type::Boolean => String::from("boolean"),
+            Type::Array => String::from("unknown[]"),
+            Type::Null => String::from("null"),
+        }))
+    }
+
+    fn object_literal(&self, object: &Object, mode: Mode, indent: usize) -> String {
+        if object.properties.is_empty() {
+            let value_type = match object.additional_properties.as_deref() {
+                Some(AdditionalProperties::RefOr(schema)) => self.type_of(schema, mode, indent),
+                _ => String::from("unknown"),
+            };
+            return format!("Record<string, {value_type}>");
+        }
+
+        let mut literal = String::from("{\n");
+        self.write_members(&mut literal, object, mode, indent + 1);
+        literal.push_str(&INDENT.repeat(indent));
+        literal.push('}');
+
+        literal
+    }
+
+    fn array_type(&self, array: &Array, mode: Mode, indent: usize) -> String {
+        let items = match &array.items {
+            ArrayItems::RefOrSchema(items) => Some(self.type_of(items, mode, indent)),
+            ArrayItems::False => None,
+        };
+
+        let array_type = if array.prefix_items.is_empty() {
+            format!(
+                "{}[]",
+                parenthesize(items.unwrap_or_else(|| String::from("never")))
+            )
+        } else {
+            let mut elements = array
+                .prefix_items
+                .iter()
+                .map(|item| self.schema_type_of(item, mode, indent))
+                .collect::<Vec<_>>();
+            if let Some(items) = items {
+                elements.push(format!("...{}[]", parenthesize(items)));
+            }
+            format!("[{}]", elements.join(", "))
+        };
+
+        match &array.schema_type {
+            SchemaType::Array(types) if types.contains(&Type::Null) => {
+                format!("{array_type} | null")
+            }
+            _ => array_type,
+        }
+    }
+
+    fn write_members(&self, output: &mut String, object: &Object, mode: Mode, indent: usize) {
+        let prefix = INDENT.repeat(indent);
+        let mut member_types = Vec::new();
+
+        for (name, property) in &object.properties {
+            if mode.is_omitted(property) {
+                continue;
+            }
+
+            if let RefOr::T(schema) = property {
+                let (description, deprecated) = describe(schema);
+                write_doc_comment(output, description, deprecated, &prefix);
+            }
+
+            let optional = !object.required.contains(name);
+            let property_type = self.type_of(property, mode, indent);
+            let _ = writeln!(
+                output,
+                "{prefix}{}{}: {property_type};",
+                to_property_name(name),
+                if optional { "?" } else { "" }
+            );
+
+            member_types.extend(union_members(&property_type));
+            if optional {
+                member_types.push(String::from("undefined"));
+            }
+        }
+
+        let value_type = match object.additional_properties.as_deref() {
+            Some(AdditionalProperties::RefOr(schema)) => self.type_of(schema, mode, indent),
+            Some(AdditionalProperties::FreeForm(true)) => String::from("unknown"),
+            _ => return,
+        };
+        // every property must be assignable to the index signature
+        let index_type = if value_type == "unknown" {
+            value_type
+        } else {
+            union(member_types.into_iter().chain(union_members(&value_type)))
+        };
+        let _ = writeln!(output, "{prefix}[key: string]: {index_type};");
+    }
+}
+
+/// Plain object schemas with properties are rendered as interfaces.
+fn is_interface(object: &Object) -> bool {
+    object.enum_values.is_none()
+        && object.schema_type == SchemaType::Type(Type::Object)
+        && !object.properties.is_empty()
+}
+
+fn describe(schema: &Schema) -> (Option<&str>, bool) {
+    match schema {
+        Schema::Object(object) => (
+            object.description.as_deref(),
+            matches!(object.deprecated, Some(Deprecated::True)),
+        ),
+        Schema::Array(array) => (
+            array.description.as_deref(),
+            matches!(array.deprecated, Some(Deprecated::True)),
+        ),
+        Schema::OneOf(one_of) => (one_of.description.as_deref(), false),
+        Schema::AllOf(all_of) => (all_of.description.as_deref(), false),
+        Schema::AnyOf(any_of) => (any_of.description.as_deref(), false),
+    }
+}
+
+fn write_doc_comment(
+    output: &mut String,
+    description: Option<&str>,
+    deprecated: bool,
+    prefix: &str,
+) {
+    let mut lines = description
+        .map(|description| description.trim().replace("*/", "*\\/"))
+        .filter(|description| !description.is_empty())
+        .map(|description| description.lines().map(str::to_string).collect::<Vec<_>>())
+        .unwrap_or_default();
+    if deprecated {
+        lines.push(String::from("@deprecated"));
+    }
+
+    match lines.as_slice() {
+        [] => (),
+        [line] => {
+            let _ = writeln!(output, "{prefix}/** {line} */");
+        }
+        lines => {
+            let _ = writeln!(output, "{prefix}/**");
+            for line in lines {
+                let _ = writeln!(output, "{prefix} * {line}");
+            }
+            let _ = writeln!(output, "{prefix} */");
+        }
+    }
+}
+
+/// Join types to union moving `null` last and removing duplicates.
+fn union<I: IntoIterator<Item = String>>(types: I) -> String {
+    let mut members = Vec::<String>::new();
+    let mut nullable = false;
+
+    for member in types {
+        if member == "null" {
+            nullable = true;
+        } else if !members.contains(&member) {
+            members.push(member);
+        }
+    }
+    if nullable {
+        members.push(String::from("null"));
+    }
+
+    if members.is_empty() {
+        String::from("never")
+    } else {
+        members.join(" | ")
+    }
+}
+
+/// Split `type_name` to members of its top level union.
+fn union_members(type_name: &str) -> Vec<String> {
+    let mut members = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    let mut literal = false;
+    let mut escaped = false;
+
+    for (index, character) in type_name.char_indices() {
+        if literal {
+            match character {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => literal = false,
+                _ => (),
+            }
+            continue;
+        }
+
+        match character {
+            '"' => literal = true,
+            '(' | '[' | '{' | '<' => depth += 1,
+            ')' | ']' | '}' | '>' => depth = depth.saturating_sub(1),
+            '|' if depth == 0 => {
+                members.push(type_name[start..index].trim().to_string());
+                start = index + 1;
+            }
+            _ => (),
+        }
+    }
+    members.push(type_name[start..].trim().to_string());
+
+    members
+}
+
+fn parenthesize(type_name: String) -> String {
+    if type_name.contains(" | ") || type_name.contains(" & ") {
+        format!("({type_name})")
+    } else {
+        type_name
+    }
+}
+
+fn to_literal(value: &Value) -> String {
+    match value {
+        Value::Null | Value::Bool(_) | Value::Number(_) | Value::String(_) => value.to_string(),
+        Value::Array(_) | Value::Object(_) => String::from("unknown"),
+    }
+}
+
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic() || first == '_' || first == '$')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$')
+}
+
+fn to_property_name(name: &str) -> String {
+    if is_identifier(name) {
+        name.to_string()
+    } else {
+        Value::String(name.to_string()).to_string()
+    }
+}
+
+/// Convert schema name to valid TypeScript identifier e.g. `path.to.Pet` -> `path_to_Pet`.
+fn to_identifier(name: &str) -> String {
+    let identifier = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '_' || c == '$' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect::<String>();
+
+    if identifier.starts_with(|c: char| c.is_ascii_digit()) || identifier.is_empty() {
+        format!("_{identifier}")
+    } else {
+        identifier
+    }
+}
+
+fn has_access_modifier(schema: &RefOr<Schema>) -> bool {
+    match schema {
+        RefOr::Ref(_) => false,
+        RefOr::T(schema) => schema_has_access_modifier(schema),
+    }
+}
+
+fn schema_has_access_modifier(schema: &Schema) -> bool {
+    match schema {
+        Schema::Object(object) => {
+            object.read_only == Some(true)
+                || object.write_only == Some(true)
+                || object.properties.values().any(has_access_modifier)
+                || matches!(
+                    object.additional_properties.as_deref(),
+                    Some(AdditionalProperties::RefOr(schema)) if has_access_modifier(schema)
+                )
+        }
+        Schema::Array(array) => {
+            matches!(&array.items, ArrayItems::RefOrSchema(items) if has_access_modifier(items))
+                || array.prefix_items.iter().any(schema_has_access_modifier)
+        }
+        Schema::OneOf(one_of) => one_of.items.iter().any(has_access_modifier),
+        Schema::AllOf(all_of) => all_of.items.iter().any(has_access_modifier),
+        Schema::AnyOf(any_of) => any_of.items.iter().any(has_access_modifier),
+    }
+}
+
+fn collect_references<'s>(schema: &'s RefOr<Schema>, references: &mut BTreeSet<&'s str>) {
+    match schema {
+        RefOr::Ref(reference) => {
+            if let Some(name) = reference.ref_location.strip_prefix(SCHEMA_REF_PREFIX) {
+                references.insert(name);
+            }
+        }
+        RefOr::T(schema) => collect_schema_references(schema, references),
+    }
+}
+
+fn collect_schema_references<'s>(schema: &'s Schema, references: &mut BTreeSet<&'s str>) {
+    match schema {
+        Schema::Object(object) => {
+            object
+                .properties
+                .values()
+                .for_each(|property| collect_references(property, references));
+            if let Some(AdditionalProperties::RefOr(schema)) =
+                object.additional_properties.as_deref()
+            {
+                collect_references(schema, references);
+            }
+        }
+        Schema::Array(array) => {
+            if let ArrayItems::RefOrSchema(items) = &array.items {
+                collect_references(items, references);
+            }
+            array
+                .prefix_items
+                .iter()
+                .for_each(|item| collect_schema_references(item, references));
+        }
+        Schema::OneOf(one_of) => one_of
+            .items
+            .iter()
+            .for_each(|item| collect_references(item, references)),
+        Schema::AllOf(all_of) => all_of
+            .items
+            .iter()
+            .for_each(|item| collect_references(item, references)),
+        Schema::AnyOf(any_of) => any_of
+            .items
+            .iter()
+            .for_each(|item| collect_references(item, references)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::openapi::schema::{AllOfBuilder, OneOfBuilder};
+    use crate::openapi::{ArrayBuilder, ComponentsBuilder, ObjectBuilder, Ref};
+
+    #[test]
+    fn generate_object_interface() {
+        let components = ComponentsBuilder::new()
+            .schema(
+                "path.to.Pet",
+                ObjectBuilder::new()
+                    .description(Some("Pet in the store"))
+                    .property(
+                        "age",
+                        ObjectBuilder::new()
+                            .schema_type(Type::Integer)
+                            .deprecated(Some(Deprecated::True)),
+                    )
+                    .property(
+                        "name",
+                        ObjectBuilder::new()
+                            .schema_type(Type::String)
+                            .description(Some("Name of the pet")),
+                    )
+                    .required("name")
+                    .property(
+                        "nick-name",
+                        ObjectBuilder::new().schema_type(
+                            [Type::String, Type::Null]
+                                .into_iter()
+                                .collect::<SchemaType>(),
+                        ),
+                    )
+                    .property(
+                        "owner",
+                        OneOfBuilder::new()
+                            .item(ObjectBuilder::new().schema_type(Type::Null))
+                            .item(Ref::from_schema_name("Owner")),
+                    )
+                    .property(
+                        "tags",
+                        ArrayBuilder::new().items(ObjectBuilder::new().schema_type(Type::String)),
+                    )
+                    .required("tags")
+                    .additional_properties(Some(ObjectBuilder::new().schema_type(Type::Boolean))),
+            )
+            .build();
+
+        assert_eq!(
+            generate(&components),
+            r#"/** Pet in the store */
+export interface path_to_Pet {
+  /** @deprecated */
+  age?: number;
+  /** Name of the pet */
+  name: string;
+  "nick-name"?: string | null;
+  owner?: Owner | null;
+  tags: string[];
+  [key: string]: number | undefined | string | Owner | string[] | boolean | null;
+}
+"#
+        );
+    }
+
+    #[test]
+    fn generate_index_signature_from_property_unions() {
+        let components = ComponentsBuilder::new()
+            .schema(
+                "Flags",
+                ObjectBuilder::new()
+                    .property(
+                        "mode",
+                        ObjectBuilder::new()
+                            .schema_type(Type::String)
+                            .enum_values(Some(["a|b", "c"])),
+                    )
+                    .required("mode")
+                    .additional_properties(Some(
+                        ObjectBuilder::new().schema_type(
+                            [Type::Boolean, Type::Null]
+                                .into_iter()
+                                .collect::<SchemaType>(),
+                        ),
+                    )),
+            )
+            .build();
+
+        assert_eq!(
+            generate(&components),
+            r#"export interface Flags {
+  mode: "a|b" | "c";
+  [key: string]: "a|b" | "c" | boolean | null;
+}
+"#
+        );
+    }
+
+    #[test]
+    fn generate_type_aliases() {
+        let components = ComponentsBuilder::new()
+            .schema(
+                "Status",
+                ObjectBuilder::new()
+                    .schema_type(Type::String)
+                    .enum_values(Some(["Active", "Locked"])),
+            )
+            .schema(
+                "Code",
+                ObjectBuilder::new()
+                    .schema_type(
+                        [Type::Integer, Type::Null]
+                            .into_iter()
+                            .collect::<SchemaType>(),
+                    )
+                    .enum_values(Some([json!(1), json!(2)])),
+            )
+            .schema(
+                "Point",
+                ArrayBuilder::new().items(ArrayItems::False).prefix_items([
+                    ObjectBuilder::new().schema_type(Type::Number).build(),
+                    ObjectBuilder::new().schema_type(Type::Number).build(),
+                ]),
+            )
+            .schema(
+                "Labels",
+                ObjectBuilder::new()
+                    .additional_properties(Some(ObjectBuilder::new().schema_type(Type::String))),
+            )
+            .schema(
+                "Named",
+                AllOfBuilder::new()
+                    .item(Ref::from_schema_name("Point"))
+                    .item(Ref::from_schema_name("Labels")),
+            )
+            .schema(
+                "Values",
+                ArrayBuilder::new()
+                    .schema_type(
+                        [Type::Array, Type::Null]
+                            .into_iter()
+                            .collect::<SchemaType>(),
+                    )
+                    .items(
+                        OneOfBuilder::new()
+                            .item(Ref::from_schema_name("Status"))
+                            .item(Ref::from_schema_name("Code")),
+                    ),
+            )
+            .schema(
+                "Any",
+                ObjectBuilder::new().schema_type(SchemaType::AnyValue),
+            )
+            .build();
+
+        assert_eq!(
+            generate(&components),
+            r#"export type Any = unknown;
+
+export type Code = 1 | 2 | null;
+
+export type Labels = Record<string, string>;
+
+export type Named = Point & Labels;
+
+export type Point = [number, number];
+
+export type Status = "Active" | "Locked";
+
+export type Values = (Status | Code)[] | null;
+"#
+        );
+    }
+
+    #[test]
+    fn generate_discriminated_union() {
+        let components = ComponentsBuilder::new()
+            .schema(
+                "Cat",
+                ObjectBuilder::new()
+                    .property("meow", ObjectBuilder::new().schema_type(Type::Boolean))
+                    .required("meow"),
+            )
+            .schema(
+                "Dog",
+                ObjectBuilder::new()
+                    .property("bark", ObjectBuilder::new().schema_type(Type::Boolean))
+                    .required("bark"),
+            )
+            .schema(
+                "Pet",
+                OneOfBuilder::new()
+                    .item(Ref::from_schema_name("Cat"))
+                    .item(Ref::from_schema_name("Dog"))
+                    .discriminator(Some(Discriminator::with_mapping(
+                        "pet_type",
+                        [("dog", "#/components/schemas/Dog")],
+                    ))),
+            )
+            .build();
+
+        assert_eq!(
+            generate(&components),
+            r#"export interface Cat {
+  meow: boolean;
+}
+
+export interface Dog {
+  bark: boolean;
+}
+
+export type Pet = ({ pet_type: "Cat" } & Cat) | ({ pet_type: "dog" } & Dog);
+"#
+        );
+    }
+
+    #[test]
+    fn generate_input_and_output_types() {
+        let components = ComponentsBuilder::new()
+            .schema(
+                "User",
+                ObjectBuilder::new()
+                    .property(
+                        "id",
+                        ObjectBuilder::new()
+                            .schema_type(Type::Integer)
+                            .read_only(Some(true)),
+                    )
+                    .required("id")
+                    .property("name", ObjectBuilder::new().schema_type(Type::String))
+                    .required("name")
+                    .property(
+                        "password",
+                        ObjectBuilder::new()
+                            .schema_type(Type::String)
+                            .write_only(Some(true)),
+                    )
+                    .required("password"),
+            )
+            .schema(
+                "Team",
+                ObjectBuilder::new()
+                    .property(
+                        "members",
+                        ArrayBuilder::new().items(Ref::from_schema_name("User")),
+                    )
+                    .required("members"),
+            )
+            .schema(
+                "Color",
+                ObjectBuilder::new()
+                    .schema_type(Type::String)
+                    .enum_values(Some(["Red"])),
+            )
+            .build();
+
+        assert_eq!(
+            generate(&components),
+            r#"export type Color = "Red";
+
+export interface TeamInput {
+  members: UserInput[];
+}
+
+export interface TeamOutput {
+  members: UserOutput[];
+}
+
+export interface UserInput {
+  name: string;
+  password: string;
+}
+
+export interface UserOutput {
+  id: number;
+  name: string;
+}
+"#
+        );
+    }
+
+    #[test]
+    fn generate_nested_object_literal() {
+        let components = ComponentsBuilder::new()
+            .schema(
+                "Wrapper",
+                ObjectBuilder::new()
+                    .property(
+                        "inner",
+                        ObjectBuilder::new()
+                            .property("value", ObjectBuilder::new().schema_type(Type::String))
+                            .required("value"),
+                    )
+                    .required("inner"),
+            )
+            .build();
+
+        assert_eq!(
+            generate(&components),
+            r#"export interface Wrapper {
+  inner: {
+    value: string;
+  };
+}
+"#
+        );
+    }
+}
@@ -51,6 +51,7 @@
 //!   * Response body from response `body` attribute or response `content` attribute.
 //! * Various OpenAPI visualization tools supported out of the box.
 //! * Rust type aliases via [`fastapi-config`][fastapi_config].
+//! * Standalone JSON Schema 2020-12 documents for any [`ToSchema`] type via [`json_schema`].
 //!
 //! # What's up with the word play?
 //!
@@ -113,6 +114,20 @@
 //!   serialization and deserialization of `Arc<T>` and `Rc<T>` types. See more about [serde feature flags](https://serde.rs/feature-flags.html).
 //! * **`config`** Enables [`fastapi-config`](https://docs.rs/fastapi-config/) for the project which allows
 //!   defining global configuration options for `fastapi`.
+//! * **`typescript`** Enables TypeScript type declaration generation from OpenAPI components. See
+//!   [`codegen::typescript`](https://docs.rs/fastapi/latest/fastapi/codegen/typescript/index.html) for more details.
+//! * **`client`** Generates a typed async client for each `#[fastapi::path(...)]` operation. See
+//!   [`client`](https://docs.rs/fastapi/latest/fastapi/client/index.html) for more details.
+//! * **`infer_responses`** Infer responses of `#[fastapi::path(...)]` operations from the return type of the
+//!   handler function, e.g. `Json<T>`, `Option<T>`, `Result<T, E>` and `(StatusCode, T)`. See
+//!   [path macro](https://docs.rs/fastapi/latest/fastapi/attr.path.html#inferred-responses) for more details.
+//!   _`auto_into_responses`_ is a deprecated alias of this feature.
+//! * **`axum`** Implements `axum::response::IntoResponse` for [`Problem`](https://docs.rs/fastapi/latest/fastapi/problem/struct.Problem.html)
+//!   and [`PagedResponse`](https://docs.rs/fastapi/latest/fastapi/pagination/struct.PagedResponse.html).
+//!   Enabled by [`fastapi-axum`](https://docs.rs/fastapi-axum/).
+//! * **`actix-web`** Implements `actix_web::ResponseError` for [`Problem`](https://docs.rs/fastapi/latest/fastapi/problem/struct.Problem.html)
+//!   and `actix_web::Responder` for [`PagedResponse`](https://docs.rs/fastapi/latest/fastapi/pagination/struct.PagedResponse.html).
+//!   Enabled by [`fastapi-actix-web`](https://docs.rs/fastapi-actix-web/).
 //!
 //! ### Default Library Support
 //!
@@ -233,6 +248,20 @@
 //! [to_schema_derive]: derive.ToSchema.html
 
 pub mod openapi;
+pub mod problem;
+pub mod routing;
+
+#[cfg(feature = "macros")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "macros")))]
+pub mod pagination;
+
+#[cfg(feature = "typescript")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "typescript")))]
+pub mod codegen;
+
+#[cfg(feature = "client")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "client")))]
+pub mod client;
 
 use std::borrow::Cow;
 use std::collections::BTreeMap;
@@ -464,6 +493,119 @@ impl<T: ToSchema> From<T> for openapi::RefOr<openapi::schema::Schema> {
     }
 }
 
+/// Dialect URI of JSON Schema 2020-12 set as `$schema` of documents created with [`json_schema`].
+pub const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Create standalone [JSON Schema 2020-12][json_schema] document for given [`ToSchema`] type.
+///
+/// Unlike OpenAPI components the returned document is self-contained. All schemas collected
+/// with [`ToSchema::schemas`] are bundled under `$defs` and references pointing to
+/// `#/components/schemas/{name}` are rewritten to point to `#/$defs/{name}`. A reference to the
+/// type itself _(recursive types)_ is rewritten to point to the document root `#`.
+///
+/// Generic types used as field types are composed the same way as with [`OpenApi`] derive.
+/// However generic arguments of `T` itself are not known at runtime, thus prefer a concrete
+/// wrapper type over using generic type directly as `T`.
+///
+/// # Examples
+///
+/// _**Create JSON Schema document for a `Pet` type.**_
+/// ```rust
+/// # use fastapi::ToSchema;
+/// #[derive(ToSchema)]
+/// struct Owner {
+///     name: String,
+/// }
+///
+/// #[derive(ToSchema)]
+/// struct Pet {
+///     name: String,
+///     owner: Owner,
+/// }
+///
+/// let schema = fastapi::json_schema::<Pet>();
+///
+/// assert_eq!(schema["$schema"], fastapi::JSON_SCHEMA_DIALECT);
+/// assert_eq!(schema["properties"]["owner"]["$ref"], "#/$defs/Owner");
+/// assert_eq!(schema["$defs"]["Owner"]["type"], "object");
+/// ```
+///
+/// [json_schema]: https://json-schema.org/draft/2020-12/json-schema-core
+pub fn json_schema<T: ToSchema>() -> serde_json::Value {
+    const COMPONENTS_PREFIX: &str = "#/components/schemas/";
+
+    fn rewrite_refs(value: &mut serde_json::Value, root_name: &str) {
+        let rewrite = |reference: &mut String| {
+            if let Some(name) = reference.strip_prefix(COMPONENTS_PREFIX) {
+                *reference = if name == root_name {
+                    String::from("#")
+                } else {
+                    format!("#/$defs/{name}")
+                };
+            }
+        };
+
+        match value {
+            serde_json::Value::Object(object) => {
+                for (key, value) in object.iter_mut() {
+                    if let ("$ref", serde_json::Value::String(reference)) =
+                        (key.as_str(), &mut *value)
+                    {
+                        rewrite(reference);
+                        continue;
+                    }
+                    if let Some(serde_json::Value::Object(mapping)) =
+                        value.get_mut("mapping").filter(|_| key == "discriminator")
+                    {
+                        mapping.values_mut().for_each(|reference| {
+                            if let serde_json::Value::String(reference) = reference {
+                                rewrite(reference)
+                            }
+                        });
+                    }
+                    rewrite_refs(value, root_name);
+                }
+            }
+            serde_json::Value::Array(array) => array
+                .iter_mut()
+                .for_each(|value| rewrite_refs(value, root_name)),
+            _ => (),
+        }
+    }
+
+    let root_name = T::name();
+    let mut schemas = Vec::new();
+    T::schemas(&mut schemas);
+
+    let mut defs = serde_json::Map::new();
+    for (name, schema) in schemas {
+        if name != root_name {
+            defs.insert(
+                name,
+                serde_json::to_value(schema).expect("schema must be JSON serializable"),
+            );
+        }
+    }
+
+    let mut document =
+        match serde_json::to_value(T::schema()).expect("schema must be JSON serializable") {
+            serde_json::Value::Object(object) => object,
+            _ => unreachable!("schema is always serialized as JSON object"),
+        };
+    document.insert(
+        String::from("$schema"),
+        serde_json::Value::String(JSON_SCHEMA_DIALECT.to_string()),
+    );
+    if !defs.is_empty() {
+        document.insert(String::from("$defs"), serde_json::Value::Object(defs));
+    }
+
+    let mut document = serde_json::Value::Object(document);
+    rewrite_refs(&mut document, &root_name);
+
+    document
+}
+
 /// Represents _`nullable`_ type. This can be used anywhere where "nothing" needs to be evaluated.
 /// This will serialize to _`null`_ in JSON and [`openapi::schema::empty`] is used to create the
 /// [`openapi::schema::Schema`] for the type.
@@ -789,6 +931,63 @@ impl PartialSchema for serde_json::Value {
 
 impl ToSchema for serde_json::Value {}
 
+/// Represents a file part of _`multipart/form-data`_ request body.
+///
+/// The type is documented as _`string`_ with [`KnownFormat::Binary`][binary] format which tells
+/// the OpenAPI tooling to render a file upload for the field. The type must be used with its
+/// qualified path _`fastapi::UploadFile`_ so that user defined types of the same name are not
+/// mistaken for it, bare imported _`UploadFile`_ fails to compile. The schema is inlined and the
+/// default format can be changed with _`#[schema(format = ...)]`_ attribute e.g. to
+/// [`KnownFormat::Byte`][byte] for base64 encoded content.
+///
+/// # Examples
+///
+/// _**Define upload form with JSON metadata part and a file part.**_
+/// ```rust
+/// # use fastapi::ToSchema;
+/// #[derive(ToSchema)]
+/// struct Metadata {
+///     name: String,
+/// }
+///
+/// #[derive(ToSchema)]
+/// struct Upload {
+///     metadata: Metadata,
+///     file: fastapi::UploadFile,
+/// }
+///
+/// #[fastapi::path(
+///     post,
+///     path = "/upload",
+///     request_body(
+///         content = Upload,
+///         content_type = "multipart/form-data",
+///         encoding(part = "metadata", content_type = "application/json")
+///     ),
+///     responses((status = 201, description = "File uploaded"))
+/// )]
+/// async fn upload() {}
+/// ```
+///
+/// [binary]: openapi/schema/enum.KnownFormat.html#variant.Binary
+/// [byte]: openapi/schema/enum.KnownFormat.html#variant.Byte
+#[derive(Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct UploadFile(pub Vec<u8>);
+
+impl PartialSchema for UploadFile {
+    fn schema() -> openapi::RefOr<openapi::schema::Schema> {
+        openapi::schema::ObjectBuilder::new()
+            .schema_type(openapi::schema::Type::String)
+            .format(Some(openapi::schema::SchemaFormat::KnownFormat(
+                openapi::schema::KnownFormat::Binary,
+            )))
+            .into()
+    }
+}
+
+impl ToSchema for UploadFile {}
+
 // Create `fastapi` module so we can use `fastapi-gen` directly from `fastapi` crate.
 // ONLY for internal use!
 #[doc(hidden)]
@@ -1103,6 +1302,76 @@ pub trait IntoParams {
     ) -> Vec<openapi::path::Parameter>;
 }
 
+/// Document a custom request extractor used as an argument of a handler function.
+///
+/// When an argument type of a handler function implements [`FastapiExtractor`] the parameters,
+/// request body and security requirements provided by the extractor are added to the operation
+/// generated by [`#[fastapi::path(...)]`][path] attribute macro. Argument types not implementing
+/// the trait are silently ignored. Extractors are resolved with the _`axum_extras`_ feature.
+///
+/// All methods have a default implementation so only the relevant ones need to be implemented.
+///
+/// # Examples
+///
+/// _**Document API key header and security requirement of a custom extractor.**_
+/// ```rust
+/// use fastapi::openapi::path::{Parameter, ParameterBuilder, ParameterIn};
+/// use fastapi::openapi::security::SecurityRequirement;
+/// use fastapi::openapi::{ObjectBuilder, Required, Type};
+///
+/// struct ApiKey(String);
+///
+/// impl fastapi::FastapiExtractor for ApiKey {
+///     fn parameters() -> Vec<Parameter> {
+///         vec![ParameterBuilder::new()
+///             .name("x-api-key")
+///             .parameter_in(ParameterIn::Header)
+///             .required(Required::True)
+///             .schema(Some(ObjectBuilder::new().schema_type(Type::String)))
+///             .build()]
+///     }
+///
+///     fn security() -> Vec<SecurityRequirement> {
+///         vec![SecurityRequirement::new::<_, _, &str>("api_key", [])]
+///     }
+/// }
+/// ```
+///
+/// [path]: attr.path.html
+pub trait FastapiExtractor {
+    /// Parameters extracted from the request. Parameters already defined for the operation with
+    /// the same name and location take precedence.
+    fn parameters() -> Vec<openapi::path::Parameter> {
+        Vec::new()
+    }
+
+    /// Request body consumed by the extractor. It is only used if the operation does not already
+    /// define a request body.
+    fn request_body() -> Option<openapi::request_body::RequestBody> {
+        None
+    }
+
+    /// Security requirements enforced by the extractor. Security requirement objects are
+    /// alternatives of each other, thus the schemes of the extractor are merged to each security
+    /// requirement object of the operation making them required in addition to the schemes of
+    /// the operation. If the operation does not have security requirements these are used as is.
+    fn security() -> Vec<openapi::security::SecurityRequirement> {
+        Vec::new()
+    }
+
+    /// Schemas referenced by the parameters or request body of the extractor. These are
+    /// collected to the OpenAPI components in the same manner as [`ToSchema::schemas`].
+    #[allow(unused)]
+    fn schemas(
+        schemas: &mut Vec<(
+            String,
+            fastapi::openapi::RefOr<fastapi::openapi::schema::Schema>,
+        )>,
+    ) {
+        // nothing by default
+    }
+}
+
 /// This trait is implemented to document a type (like an enum) which can represent multiple
 /// responses, to be used in operation.
 ///
@@ -1135,7 +1404,7 @@ pub trait IntoResponses {
     fn responses() -> BTreeMap<String, openapi::RefOr<openapi::response::Response>>;
 }
 
-#[cfg(feature = "auto_into_responses")]
+#[cfg(feature = "infer_responses")]
 impl<T: IntoResponses, E: IntoResponses> IntoResponses for Result<T, E> {
     fn responses() -> BTreeMap<String, openapi::RefOr<openapi::response::Response>> {
         let mut responses = T::responses();
@@ -1145,7 +1414,7 @@ impl<T: IntoResponses, E: IntoResponses> IntoResponses for Result<T, E> {
     }
 }
 
-#[cfg(feature = "auto_into_responses")]
+#[cfg(feature = "infer_responses")]
 impl IntoResponses for () {
     fn responses() -> BTreeMap<String, openapi::RefOr<openapi::response::Response>> {
         BTreeMap::new()
@@ -1252,6 +1521,8 @@ impl_from_for_number!(
 #[cfg(feature = "macros")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "macros")))]
 pub mod __dev {
+    use std::collections::BTreeMap;
+
     use fastapi_gen::schema;
 
     use crate::{fastapi, OpenApi, PartialSchema};
@@ -1268,6 +1539,13 @@ pub mod __dev {
         fn tags() -> Vec<&'t str>;
     }
 
+    /// Resolve the generated path type of a generic handler from the type arguments given to
+    /// the handler. Type parameters not used by the documentation are dropped, so `get::<S, T>`
+    /// resolves to `__path_get<T>` when only `T` is documented.
+    pub trait HandlerPath<Args> {
+        type Path;
+    }
+
     impl<T: PathConfig> fastapi::Path for T {
         fn path() -> String {
             <Self as PathConfig>::path()
@@ -1287,19 +1565,87 @@ pub mod __dev {
         }
     }
 
+    pub trait SecuritySchemeNames {
+        /// Names of the security schemes referenced from `security(...)` of the path.
+        const SECURITY_SCHEME_NAMES: &'static [&'static str];
+    }
+
+    /// Fail compilation if any of the `required` security scheme names is not found from `defined`
+    /// security scheme names.
+    pub const fn assert_security_schemes_defined(defined: &[&str], required: &[&str]) {
+        const fn str_eq(left: &str, right: &str) -> bool {
+            let (left, right) = (left.as_bytes(), right.as_bytes());
+            if left.len() != right.len() {
+                return false;
+            }
+            let mut index = 0;
+            while index < left.len() {
+                if left[index] != right[index] {
+                    return false;
+                }
+                index += 1;
+            }
+            true
+        }
+
+        const fn undefined_security_scheme(name: &str) {
+            const PREFIX: &[u8] = b"undefined security scheme `";
+            const SUFFIX: &[u8] =
+                b"` in `security(...)`, it must be declared in `components(security_schemes(...))`, schemes added at runtime can be declared as `(\"name\" = External)`";
+            let mut message = [0u8; 512];
+            let mut len = 0;
+            let parts = [PREFIX, name.as_bytes(), SUFFIX];
+            let mut part = 0;
+            while part < parts.len() {
+                let mut index = 0;
+                while index < parts[part].len() && len < message.len() {
+                    message[len] = parts[part][index];
+                    len += 1;
+                    index += 1;
+                }
+                part += 1;
+            }
+            match core::str::from_utf8(message.split_at(len).0) {
+                Ok(message) => panic!("{}", message),
+                Err(_) => panic!("{}", name),
+            }
+        }
+
+        let mut required_index = 0;
+        while required_index < required.len() {
+            let mut defined_index = 0;
+            let mut found = false;
+            while defined_index < defined.len() {
+                if str_eq(defined[defined_index], required[required_index]) {
+                    found = true;
+                    break;
+                }
+                defined_index += 1;
+            }
+            if !found {
+                undefined_security_scheme(required[required_index]);
+            }
+            required_index += 1;
+        }
+    }
+
     pub trait NestedApiConfig {
-        fn config() -> (fastapi::openapi::OpenApi, Vec<&'static str>, &'static str);
+        fn config() -> (
+            fastapi::openapi::OpenApi,
+            fastapi::openapi::NestOptions,
+            &'static str,
+        );
     }
 
     impl<T: NestedApiConfig> OpenApi for T {
         fn openapi() -> crate::openapi::OpenApi {
-            let (mut api, tags, module_path) = T::config();
+            let (mut api, options, module_path) = T::config();
+            options.apply(&mut api.paths);
 
             api.paths.paths.iter_mut().for_each(|(_, path_item)| {
                 let update_tags = |operation: Option<&mut crate::openapi::path::Operation>| {
                     if let Some(operation) = operation {
                         let operation_tags = operation.tags.get_or_insert(Vec::new());
-                        operation_tags.extend(tags.iter().map(ToString::to_string));
                         if operation_tags.is_empty() && !module_path.is_empty() {
                             operation_tags.push(module_path.to_string());
                         }
@@ -1545,6 +1891,123 @@ pub mod __dev {
         }
     }
 
+    /// Handler function argument of type `T` which is documented with [`crate::FastapiExtractor`]
+    /// if the type implements it.
+    ///
+    /// Resolution is done with autoref specialization where [`DocumentExtractor`] is implemented
+    /// for `Extractor<T>` and [`SkipExtractor`] for `&Extractor<T>`. Calling methods on
+    /// `&Extractor<T>` will pick the former if `T` implements [`crate::FastapiExtractor`].
+    pub struct Extractor<T>(pub core::marker::PhantomData<T>);
+
+    pub trait DocumentExtractor {
+        fn document_operation(&self, operation: &mut fastapi::openapi::path::Operation);
+
+        fn extractor_schemas(
+            &self,
+            schemas: &mut Vec<(
+                String,
+                fastapi::openapi::RefOr<fastapi::openapi::schema::Schema>,
+            )>,
+        );
+    }
+
+    impl<T: crate::FastapiExtractor> DocumentExtractor for Extractor<T> {
+        fn document_operation(&self, operation: &mut fastapi::openapi::path::Operation) {
+            let parameters = T::parameters();
+            if !parameters.is_empty() {
+                let operation_parameters = operation.parameters.get_or_insert(Vec::new());
+                for parameter in parameters {
+                    if !operation_parameters.iter().any(|existing| {
+                        existing.name == parameter.name
+                            && existing.parameter_in == parameter.parameter_in
+                    }) {
+                        operation_parameters.push(parameter);
+                    }
+                }
+            }
+
+            if operation.request_body.is_none() {
+                operation.request_body = T::request_body();
+            }
+
+            let security = T::security();
+            if !security.is_empty() {
+                // requirements of the extractor must be satisfied in addition to the requirements
+                // of the operation, thus they are merged to each existing requirement object
+                operation.security = Some(match operation.security.take() {
+                    Some(requirements) if !requirements.is_empty() => requirements
+                        .into_iter()
+                        .flat_map(|requirement| {
+                            security
+                                .iter()
+                                .map(move |extractor| requirement.clone().merge(extractor))
+                        })
+                        .collect(),
+                    _ => security,
+                });
+            }
+        }
+
+        fn extractor_schemas(
+            &self,
+            schemas: &mut Vec<(
+                String,
+                fastapi::openapi::RefOr<fastapi::openapi::schema::Schema>,
+            )>,
+        ) {
+            T::schemas(schemas);
+        }
+    }
+
+    pub trait SkipExtractor {
+        fn document_operation(&self, _: &mut fastapi::openapi::path::Operation) {}
+
+        fn extractor_schemas(
+            &self,
+            _: &mut Vec<(
+                String,
+                fastapi::openapi::RefOr<fastapi::openapi::schema::Schema>,
+            )>,
+        ) {
+        }
+    }
+
+    impl<T> SkipExtractor for &Extractor<T> {}
+
+    /// Wrapper for return type of handler function used to resolve the responses of
+    /// [`crate::IntoResponses`] if the type implements it.
+    ///
+    /// Resolution is done with autoref specialization same way as with [`Extractor`] where
+    /// [`DocumentReturnType`] is implemented for `ReturnType<T>` and [`SkipReturnType`] for
+    /// `&ReturnType<T>`.
+    pub struct ReturnType<T>(pub core::marker::PhantomData<T>);
+
+    pub trait DocumentReturnType {
+        fn return_type_responses(
+            &self,
+        ) -> BTreeMap<String, fastapi::openapi::RefOr<fastapi::openapi::response::Response>>;
+    }
+
+    impl<T: crate::IntoResponses> DocumentReturnType for ReturnType<T> {
+        fn return_type_responses(
+            &self,
+        ) -> BTreeMap<String, fastapi::openapi::RefOr<fastapi::openapi::response::Response>>
+        {
+            T::responses()
+        }
+    }
+
+    pub trait SkipReturnType {
+        fn return_type_responses(
+            &self,
+        ) -> BTreeMap<String, fastapi::openapi::RefOr<fastapi::openapi::response::Response>>
+        {
+            BTreeMap::new()
+        }
+    }
+
+    impl<T> SkipReturnType for &ReturnType<T> {}
+
     // For types not implementing `ToSchema`
     pub trait SchemaReferences {
         fn schemas(
@@ -318,6 +318,241 @@ impl OpenApi {
         other_api.paths.paths = PathsMap::new();
         self.merge_from(other_api)
     }
+
+    /// Nest `other` [`OpenApi`] to this [`OpenApi`] like [`OpenApi::nest`] applying given
+    /// [`NestOptions`] to the nested paths first.
+    ///
+    /// # Examples
+    ///
+    /// _**Nest `admin_api` under `/admin` tagging all its operations with `admin`.**_
+    /// ```rust
+    ///  # use fastapi::openapi::{NestOptions, OpenApi, OpenApiBuilder};
+    ///  # use fastapi::openapi::path::{PathsBuilder, PathItem, HttpMethod, OperationBuilder};
+    ///  let admin_api = OpenApiBuilder::new()
+    ///     .paths(
+    ///         PathsBuilder::new().path(
+    ///             "/users",
+    ///             PathItem::new(HttpMethod::Get, OperationBuilder::new().build()),
+    ///         )
+    ///     )
+    ///     .build();
+    ///  let api = OpenApi::default().nest_with("/admin", admin_api, NestOptions::new().tag("admin"));
+    /// ```
+    pub fn nest_with<P: Into<String>, O: Into<OpenApi>>(
+        self,
+        path: P,
+        other: O,
+        options: NestOptions,
+    ) -> Self {
+        let mut other: OpenApi = other.into();
+        options.apply(&mut other.paths);
+
+        self.nest(path, other)
+    }
+}
+
+/// Options applied to every path and operation of nested routes.
+///
+/// This is used to share common OpenAPI metadata between all routes nested under the same
+/// path with [`OpenApi::nest_with`] or with the nesting functionality of framework integrations.
+///
+/// * [`Tag`]s are appended to the tags of every [`path::Operation`]. Tags the operation already
+///   has are not added twice.
+/// * [`SecurityRequirement`]s are set to every [`path::Operation`] that does not declare
+///   security requirements of its own.
+/// * [`path::Parameter`]s and [`Server`]s are added to every [`PathItem`] and thus shared by all
+///   operations of the path. Parameters already defined for the path are not overridden.
+/// * [`Response`]s are added to every [`path::Operation`] that does not define response for
+///   the same status code.
+///
+/// # Examples
+///
+/// _**Require `admin_auth` security, `X-Tenant` header and document `403` for admin routes.**_
+/// ```rust
+/// # use fastapi::openapi::{NestOptions, ResponseBuilder, SecurityRequirement};
+/// # use fastapi::openapi::path::{ParameterBuilder, ParameterIn};
+/// # use fastapi::openapi::Required;
+/// let options = NestOptions::new()
+///     .tag("admin")
+///     .security(SecurityRequirement::new("admin_auth", Vec::<String>::new()))
+///     .parameter(
+///         ParameterBuilder::new()
+///             .name("X-Tenant")
+///             .parameter_in(ParameterIn::Header)
+///             .required(Required::True),
+///     )
+///     .response("403", ResponseBuilder::new().description("Forbidden"));
+/// ```
+#[derive(Default, Clone, PartialEq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct NestOptions {
+    /// Tags appended to every nested operation.
+    pub tags: Vec<String>,
+
+    /// Security requirements of nested operations without their own security requirements.
+    pub security: Vec<SecurityRequirement>,
+
+    /// Parameters shared by every nested path.
+    pub parameters: Vec<path::Parameter>,
+
+    /// Responses added to every nested operation.
+    pub responses: Responses,
+
+    /// Alternative servers of every nested path.
+    pub servers: Vec<Server>,
+}
+
+impl NestOptions {
+    /// Construct a new empty [`NestOptions`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add iterator of tags to the nested operations.
+    pub fn tags<I: IntoIterator<Item = V>, V: Into<String>>(mut self, tags: Option<I>) -> Self {
+        self.tags.extend(tags.into_iter().flatten().map(Into::into));
+
+        self
+    }
+
+    /// Add tag to the nested operations.
+    pub fn tag<S: Into<String>>(mut self, tag: S) -> Self {
+        self.tags.push(tag.into());
+
+        self
+    }
+
+    /// Add iterator of [`SecurityRequirement`]s to the nested operations.
+    pub fn securities<I: IntoIterator<Item = SecurityRequirement>>(
+        mut self,
+        securities: Option<I>,
+    ) -> Self {
+        self.security.extend(securities.into_iter().flatten());
+
+        self
+    }
+
+    /// Add [`SecurityRequirement`] to the nested operations.
+    pub fn security(mut self, security: SecurityRequirement) -> Self {
+        self.security.push(security);
+
+        self
+    }
+
+    /// Add iterator of [`path::Parameter`]s to the nested paths.
+    pub fn parameters<I: IntoIterator<Item = P>, P: Into<path::Parameter>>(
+        mut self,
+        parameters: Option<I>,
+    ) -> Self {
+        self.parameters
+            .extend(parameters.into_iter().flatten().map(Into::into));
+
+        self
+    }
+
+    /// Add [`path::Parameter`] to the nested paths.
+    pub fn parameter<P: Into<path::Parameter>>(mut self, parameter: P) -> Self {
+        self.parameters.push(parameter.into());
+
+        self
+    }
+
+    /// Add [`Responses`] to the nested operations.
+    pub fn responses<R: Into<Responses>>(mut self, responses: R) -> Self {
+        let responses = responses.into();
+        self.responses.responses.extend(responses.responses);
+
+        self
+    }
+
+    /// Add [`Response`] with status `code` to the nested operations.
+    pub fn response<S: Into<String>, R: Into<RefOr<Response>>>(
+        mut self,
+        code: S,
+        response: R,
+    ) -> Self {
+        self.responses
+            .responses
+            .insert(code.into(), response.into());
+
+        self
+    }
+
+    /// Add iterator of [`Server`]s to the nested paths.
+    pub fn servers<I: IntoIterator<Item = Server>>(mut self, servers: Option<I>) -> Self {
+        self.servers.extend(servers.into_iter().flatten());
+
+        self
+    }
+
+    /// Add [`Server`] to the nested paths.
+    pub fn server(mut self, server: Server) -> Self {
+        self.servers.push(server);
+
+        self
+    }
+
+    /// Check whether [`NestOptions`] has nothing to apply.
+    pub fn is_empty(&self) -> bool {
+        self.tags.is_empty()
+            && self.security.is_empty()
+            && self.parameters.is_empty()
+            && self.responses.responses.is_empty()
+            && self.servers.is_empty()
+    }
+
+    /// Apply the [`NestOptions`] to every [`PathItem`] and operation of the given `paths`.
+    pub fn apply(&self, paths: &mut Paths) {
+        if self.is_empty() {
+            return;
+        }
+
+        for item in paths.paths.values_mut() {
+            if !self.servers.is_empty() {
+                let servers = item.servers.get_or_insert(Vec::new());
+                for server in &self.servers {
+                    if !servers.contains(server) {
+                        servers.push(server.clone());
+                    }
+                }
+            }
+
+            if !self.parameters.is_empty() {
+                let parameters = item.parameters.get_or_insert(Vec::new());
+                for parameter in &self.parameters {
+                    if !parameters.iter().any(|existing| {
+                        existing.name == parameter.name
+                            && existing.parameter_in == parameter.parameter_in
+                    }) {
+                        parameters.push(parameter.clone());
+                    }
+                }
+            }
+
+            item.operations_mut().for_each(|(_, operation)| {
+                if !self.tags.is_empty() {
+                    let tags = operation.tags.get_or_insert(Vec::new());
+                    for tag in &self.tags {
+                        if !tags.contains(tag) {
+                            tags.push(tag.clone());
+                        }
+                    }
+                }
+
+                if !self.security.is_empty() && operation.security.is_none() {
+                    operation.security = Some(self.security.clone());
+                }
+
+                for (code, response) in &self.responses.responses {
+                    operation
+                        .responses
+                        .responses
+                        .entry(code.clone())
+                        .or_insert_with(|| response.clone());
+                }
+            });
+        }
+    }
 }
 
 impl OpenApiBuilder {
@@ -1051,6 +1286,76 @@ mod tests {
         )
     }
 
+    #[test]
+    fn nest_with_options_applies_to_nested_paths() {
+        let admin_api = OpenApiBuilder::new()
+            .paths(
+                PathsBuilder::new()
+                    .path(
+                        "/users",
+                        PathItem::new(
+                            HttpMethod::Get,
+                            OperationBuilder::new().response("403", Response::new("No access")),
+                        ),
+                    )
+                    .path(
+                        "/users",
+                        PathItem::new(
+                            HttpMethod::Delete,
+                            OperationBuilder::new()
+                                .tag("users")
+                                .tag("admin")
+                                .security(SecurityRequirement::default()),
+                        ),
+                    ),
+            )
+            .build();
+
+        let options = NestOptions::new()
+            .tag("admin")
+            .security(SecurityRequirement::new("admin_auth", Vec::<String>::new()))
+            .parameter(
+                crate::openapi::path::ParameterBuilder::new()
+                    .name("X-Tenant")
+                    .parameter_in(crate::openapi::path::ParameterIn::Header)
+                    .required(Required::True),
+            )
+            .response("403", Response::new("Forbidden"))
+            .server(Server::new("/admin-server"));
+        let api = OpenApi::default().nest_with("/admin", admin_api, options);
+        let value = serde_json::to_value(api).expect("should serialize as json");
+
+        assert_json_eq!(
+            value
+                .pointer("/paths")
+                .expect("paths should exist in openapi"),
+            json!({
+                "/admin/users": {
+                    "servers": [{ "url": "/admin-server" }],
+                    "parameters": [{
+                        "name": "X-Tenant",
+                        "in": "header",
+                        "required": true
+                    }],
+                    "get": {
+                        "tags": ["admin"],
+                        "security": [{ "admin_auth": [] }],
+                        "responses": {
+                            "403": { "description": "No access" }
+                        }
+                    },
+                    "delete": {
+                        "tags": ["users", "admin"],
+                        "security": [{}],
+                        "responses": {
+                            "403": { "description": "Forbidden" }
+                        }
+                    }
+                }
+            })
+        )
+    }
+
     #[test]
     fn openapi_custom_extension() {
         let mut api = OpenApiBuilder::new().build();
@@ -8,6 +8,7 @@ use serde_json::Value;
 use super::builder;
 use super::example::Example;
 use super::extensions::Extensions;
+use super::schema::{ObjectBuilder, Type};
 use super::{encoding::Encoding, set_value, RefOr, Schema};
 
 builder! {
@@ -21,12 +22,20 @@ builder! {
     /// [media_type]: <https://spec.openapis.org/oas/latest.html#media-type-object>
     #[derive(Serialize, Deserialize, Default, Clone, PartialEq)]
     #[cfg_attr(feature = "debug", derive(Debug))]
+    #[serde(rename_all = "camelCase")]
     #[non_exhaustive]
     pub struct Content {
         /// Schema used in response body or request body.
         #[serde(skip_serializing_if = "Option::is_none")]
         pub schema: Option<RefOr<Schema>>,
 
+        /// Schema of a single item of a streamed response body or request body such as a
+        /// single event of _`text/event-stream`_ or a single line of _`application/x-ndjson`_.
+        ///
+        /// See [`server_sent_event`] for creating the schema of a single Server-Sent Event.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub item_schema: Option<RefOr<Schema>>,
+
         /// Example for request body or response body.
         #[serde(skip_serializing_if = "Option::is_none")]
         pub example: Option<Value>,
@@ -71,6 +80,11 @@ impl ContentBuilder {
         set_value!(self schema schema.map(|schema| schema.into()))
     }
 
+    /// Add schema of a single item of streamed content. See [`Content::item_schema`].
+    pub fn item_schema<I: Into<RefOr<Schema>>>(mut self, item_schema: Option<I>) -> Self {
+        set_value!(self item_schema item_schema.map(|item_schema| item_schema.into()))
+    }
+
     /// Add example of schema.
     pub fn example(mut self, example: Option<Value>) -> Self {
         set_value!(self example example)
@@ -122,3 +136,98 @@ impl ContentBuilder {
         set_value!(self extensions extensions)
     }
 }
+
+/// Create schema of a single Server-Sent Event to be used as [`Content::item_schema`] of
+/// _`text/event-stream`_ content.
+///
+/// The event is an object with `data`, `event`, `id` and `retry` fields as defined in
+/// [HTML Living Standard][sse]. The given _`data`_ schema describes the `data` field. If
+/// _`event`_ name is given, the `event` field is required and restricted to the name so that
+/// multiple named events can be combined with [`OneOf`][one_of].
+///
+/// # Examples
+///
+/// _**Create schema of an event named `created` carrying a `Pet`.**_
+/// ```rust
+/// # use fastapi::openapi::content::{server_sent_event, ContentBuilder};
+/// # use fastapi::openapi::Ref;
+/// let content = ContentBuilder::new()
+///     .item_schema(Some(server_sent_event(Some("created"), Ref::from_schema_name("Pet"))))
+///     .build();
+/// ```
+///
+/// [sse]: <https://html.spec.whatwg.org/multipage/server-sent-events.html>
+/// [one_of]: crate::openapi::schema::OneOf
+pub fn server_sent_event<S: Into<String>, D: Into<RefOr<Schema>>>(
+    event: Option<S>,
+    data: D,
+) -> Schema {
+    let event_name = event.map(Into::into);
+    let event_schema = ObjectBuilder::new()
+        .schema_type(Type::String)
+        .enum_values(event_name.as_ref().map(|event_name| [event_name.clone()]));
+
+    let mut builder = ObjectBuilder::new()
+        .schema_type(Type::Object)
+        .property("data", data)
+        .required("data")
+        .property("event", event_schema)
+        .property("id", ObjectBuilder::new().schema_type(Type::String))
+        .property(
+            "retry",
+            ObjectBuilder::new()
+                .schema_type(Type::Integer)
+                .minimum(Some(0)),
+        );
+    if event_name.is_some() {
+        builder = builder.required("event");
+    }
+
+    builder.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_json_diff::assert_json_eq;
+    use serde_json::json;
+
+    use crate::openapi::schema::{Object, Type};
+
+    use super::*;
+
+    #[test]
+    fn content_with_server_sent_event_item_schema() {
+        let content = ContentBuilder::new()
+            .item_schema(Some(server_sent_event(
+                Some("created"),
+                Object::with_type(Type::String),
+            )))
+            .build();
+
+        assert_json_eq!(
+            content,
+            json!({
+                "itemSchema": {
+                    "type": "object",
+                    "properties": {
+                        "data": {
+                            "type": "string"
+                        },
+                        "event": {
+                            "type": "string",
+                            "enum": ["created"]
+                        },
+                        "id": {
+                            "type": "string"
+                        },
+                        "retry": {
+                            "type": "integer",
+                            "minimum": 0
+                        }
+                    },
+                    "required": ["data", "event"]
+                }
+            })
+        )
+    }
+}
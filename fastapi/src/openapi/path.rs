@@ -329,6 +329,40 @@ impl PathItem {
         path_item
     }
 
+    /// Get iterator of the defined [`Operation`]s of this [`PathItem`] along with the
+    /// [`HttpMethod`] each of them is defined for.
+    pub fn operations(&self) -> impl Iterator<Item = (HttpMethod, &Operation)> {
+        [
+            (HttpMethod::Get, &self.get),
+            (HttpMethod::Put, &self.put),
+            (HttpMethod::Post, &self.post),
+            (HttpMethod::Delete, &self.delete),
+            (HttpMethod::Options, &self.options),
+            (HttpMethod::Head, &self.head),
+            (HttpMethod::Patch, &self.patch),
+            (HttpMethod::Trace, &self.trace),
+        ]
+        .into_iter()
+        .filter_map(|(method, operation)| Some((method, operation.as_ref()?)))
+    }
+
+    /// Get iterator of mutable references to the defined [`Operation`]s of this [`PathItem`]
+    /// along with the [`HttpMethod`] each of them is defined for.
+    pub fn operations_mut(&mut self) -> impl Iterator<Item = (HttpMethod, &mut Operation)> {
+        [
+            (HttpMethod::Get, &mut self.get),
+            (HttpMethod::Put, &mut self.put),
+            (HttpMethod::Post, &mut self.post),
+            (HttpMethod::Delete, &mut self.delete),
+            (HttpMethod::Options, &mut self.options),
+            (HttpMethod::Head, &mut self.head),
+            (HttpMethod::Patch, &mut self.patch),
+            (HttpMethod::Trace, &mut self.trace),
+        ]
+        .into_iter()
+        .filter_map(|(method, operation)| Some((method, operation.as_mut()?)))
+    }
+
     /// Merge all defined [`Operation`]s from given [`PathItem`] to `self` if `self` does not have
     /// existing operation.
     pub fn merge_operations(&mut self, path_item: PathItem) {
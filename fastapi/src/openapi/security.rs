@@ -133,6 +133,44 @@ impl SecurityRequirement {
 
         self
     }
+
+    /// Check whether this is an empty [`SecurityRequirement`] which makes the security optional.
+    pub fn is_empty(&self) -> bool {
+        self.value.is_empty()
+    }
+
+    /// Get iterator over the security scheme names and required scopes of this
+    /// [`SecurityRequirement`]. All of the schemes must be satisfied simultaneously.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use fastapi::openapi::security::SecurityRequirement;
+    /// let requirement = SecurityRequirement::new("oauth", ["read:items"]);
+    ///
+    /// let names = requirement.iter().map(|(name, _)| name).collect::<Vec<_>>();
+    /// assert_eq!(names, ["oauth"]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &[String])> {
+        self.value
+            .iter()
+            .map(|(name, scopes)| (name.as_str(), scopes.as_slice()))
+    }
+
+    /// Merge schemes of `other` to this [`SecurityRequirement`] so that both requirements must be
+    /// satisfied. Scopes of schemes defined in both requirements are combined.
+    pub(crate) fn merge(mut self, other: &SecurityRequirement) -> Self {
+        for (name, scopes) in &other.value {
+            let merged = self.value.entry(name.clone()).or_default();
+            for scope in scopes {
+                if !merged.contains(scope) {
+                    merged.push(scope.clone());
+                }
+            }
+        }
+
+        self
+    }
 }
 
 /// OpenAPI [security scheme][security] for path operations.
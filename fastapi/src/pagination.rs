@@ -0,0 +1,607 @@
+//! Implements pagination primitives for list operations.
+//!
+//! * [`OffsetParams`] and [`CursorParams`] are [`IntoParams`] types for _`page`_ / _`per_page`_
+//!   and _`cursor`_ / _`limit`_ query parameters. Out of range values are clamped to the
+//!   documented _`minimum`_ and _`maximum`_ by the accessor methods.
+//! * [`Page<T>`] is a [`ToSchema`] envelope for a single page of items.
+//! * [`PagedResponse<T>`] implements [`IntoResponses`] documenting the _`Link`_ and
+//!   _`X-Total-Count`_ headers of the response.
+//!
+//! With **`axum`** feature [`PagedResponse`] implements `axum::response::IntoResponse` and with
+//! **`actix-web`** feature it implements `actix_web::Responder`. Both emit [RFC 8288][rfc] _`Link`_
+//! header with the pagination links and _`X-Total-Count`_ header if the total is known.
+//!
+//! # Examples
+//!
+//! _**Paginate list of pets with page numbers.**_
+//! ```rust
+//! # use fastapi::pagination::{OffsetParams, Page, PagedResponse};
+//! #[derive(serde::Serialize, fastapi::ToSchema)]
+//! struct Pet {
+//!     name: String,
+//! }
+//!
+//! #[fastapi::path(
+//!     get,
+//!     path = "/pets",
+//!     params(OffsetParams),
+//!     responses(PagedResponse<Pet>)
+//! )]
+//! fn list_pets(params: OffsetParams) -> PagedResponse<Pet> {
+//!     let pets = vec![Pet { name: "Lemon".to_string() }];
+//!     PagedResponse::offset(Page::offset(pets, &params, 31), "/pets")
+//! }
+//!
+//! let response = list_pets(OffsetParams { page: Some(2), per_page: Some(10) });
+//! assert_eq!(
+//!     response.link_header().as_deref(),
+//!     Some(concat!(
+//!         r#"</pets?page=1&per_page=10>; rel="first", "#,
+//!         r#"</pets?page=1&per_page=10>; rel="prev", "#,
+//!         r#"</pets?page=3&per_page=10>; rel="next", "#,
+//!         r#"</pets?page=4&per_page=10>; rel="last""#
+//!     ))
+//! );
+//! ```
+//!
+//! [rfc]: https://www.rfc-editor.org/rfc/rfc8288
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use serde::{Deserialize, Serialize};
+
+use crate::__dev::ComposeSchema;
+use crate::fastapi;
+use crate::openapi::content::ContentBuilder;
+use crate::openapi::header::HeaderBuilder;
+use crate::openapi::response::ResponseBuilder;
+use crate::openapi::schema::{ObjectBuilder, Type};
+use crate::openapi::{RefOr, Response};
+use crate::{IntoParams, IntoResponses, ToSchema};
+
+/// Name of the header holding total number of items.
+pub const TOTAL_COUNT_HEADER: &str = "X-Total-Count";
+
+/// Page number based pagination query parameters.
+///
+/// Out of range values are not rejected. [`OffsetParams::page`] and [`OffsetParams::per_page`]
+/// clamp them to the documented _`minimum`_ and _`maximum`_ instead.
+#[derive(Serialize, Deserialize, IntoParams, Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[into_params(parameter_in = Query)]
+pub struct OffsetParams {
+    /// Page number starting from 1.
+    #[param(minimum = 1, default = 1)]
+    pub page: Option<u32>,
+    /// Number of items per page.
+    #[param(minimum = 1, maximum = 100, default = 20)]
+    pub per_page: Option<u32>,
+}
+
+impl OffsetParams {
+    /// Number of items per page when _`per_page`_ is not defined.
+    pub const DEFAULT_PER_PAGE: u32 = 20;
+
+    /// Maximum number of items per page.
+    pub const MAX_PER_PAGE: u32 = 100;
+
+    /// Get page number starting from 1. Page 0 is clamped to 1.
+    pub fn page(&self) -> u32 {
+        self.page.unwrap_or(1).max(1)
+    }
+
+    /// Get number of items per page clamped between 1 and [`OffsetParams::MAX_PER_PAGE`].
+    pub fn per_page(&self) -> u32 {
+        self.per_page
+            .unwrap_or(Self::DEFAULT_PER_PAGE)
+            .clamp(1, Self::MAX_PER_PAGE)
+    }
+
+    /// Get number of items to skip before the page.
+    pub fn offset(&self) -> u64 {
+        u64::from(self.page() - 1) * u64::from(self.per_page())
+    }
+}
+
+/// Cursor based pagination query parameters.
+///
+/// Out of range _`limit`_ is not rejected. [`CursorParams::limit`] clamps it to the documented
+/// _`minimum`_ and _`maximum`_ instead.
+#[derive(Serialize, Deserialize, IntoParams, Clone, PartialEq, Eq, Debug, Default)]
+#[into_params(parameter_in = Query)]
+pub struct CursorParams {
+    /// Opaque cursor of the page returned as _`next_cursor`_ of the previous page.
+    pub cursor: Option<String>,
+    /// Maximum number of items to return.
+    #[param(minimum = 1, maximum = 100, default = 20)]
+    pub limit: Option<u32>,
+}
+
+impl CursorParams {
+    /// Number of items when _`limit`_ is not defined.
+    pub const DEFAULT_LIMIT: u32 = 20;
+
+    /// Maximum number of items.
+    pub const MAX_LIMIT: u32 = 100;
+
+    /// Get number of items clamped between 1 and [`CursorParams::MAX_LIMIT`].
+    pub fn limit(&self) -> u32 {
+        self.limit
+            .unwrap_or(Self::DEFAULT_LIMIT)
+            .clamp(1, Self::MAX_LIMIT)
+    }
+}
+
+/// Single page of _`items`_.
+///
+/// Offset pages have _`page`_ and _`per_page`_ and cursor pages have _`next_cursor`_ defined.
+/// _`total`_ is the total number of items if it is known.
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, ToSchema, Clone, PartialEq, Eq, Debug)]
+pub struct Page<T> {
+    /// Items of the page.
+    pub items: Vec<T>,
+    /// Total number of items.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total: Option<u64>,
+    /// Page number starting from 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page: Option<u32>,
+    /// Number of items per page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub per_page: Option<u32>,
+    /// Cursor of the next page. Not defined for the last page.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<String>,
+}
+
+impl<T> Page<T> {
+    /// Construct new [`Page`] of _`items`_.
+    pub fn new(items: Vec<T>) -> Self {
+        Self {
+            items,
+            total: None,
+            page: None,
+            per_page: None,
+            next_cursor: None,
+        }
+    }
+
+    /// Construct new offset [`Page`] of _`items`_ requested with _`params`_ having _`total`_ items
+    /// in all pages.
+    pub fn offset(items: Vec<T>, params: &OffsetParams, total: u64) -> Self {
+        Self {
+            total: Some(total),
+            page: Some(params.page()),
+            per_page: Some(params.per_page()),
+            ..Self::new(items)
+        }
+    }
+
+    /// Construct new cursor [`Page`] of _`items`_ followed by page at _`next_cursor`_.
+    pub fn cursor(items: Vec<T>, next_cursor: Option<String>) -> Self {
+        Self {
+            next_cursor,
+            ..Self::new(items)
+        }
+    }
+
+    /// Add total number of items in all pages.
+    pub fn total(mut self, total: u64) -> Self {
+        self.total = Some(total);
+
+        self
+    }
+}
+
+/// [`Page`] response with [RFC 8288][rfc] _`Link`_ and _`X-Total-Count`_ headers.
+///
+/// [rfc]: https://www.rfc-editor.org/rfc/rfc8288
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct PagedResponse<T> {
+    page: Page<T>,
+    links: Vec<(String, String)>,
+}
+
+impl<T> PagedResponse<T> {
+    /// Construct new [`PagedResponse`] for _`page`_ without links.
+    pub fn new(page: Page<T>) -> Self {
+        Self {
+            page,
+            links: Vec::new(),
+        }
+    }
+
+    /// Construct new [`PagedResponse`] for offset _`page`_ with _`first`_, _`prev`_, _`next`_ and
+    /// _`last`_ links to _`base_uri`_ with _`page`_ and _`per_page`_ query parameters. Query
+    /// parameters are appended to the existing query of _`base_uri`_ if it has one replacing
+    /// existing _`page`_ and _`per_page`_ parameters.
+    pub fn offset(page: Page<T>, base_uri: &str) -> Self {
+        let current = page.page.unwrap_or(1).max(1);
+        let per_page = page
+            .per_page
+            .unwrap_or(OffsetParams::DEFAULT_PER_PAGE)
+            .max(1);
+        let last = page
+            .total
+            .map(|total| total.div_ceil(u64::from(per_page)).max(1));
+        let uri = |page: u64| with_query(base_uri, &format!("page={page}&per_page={per_page}"));
+        let current = u64::from(current);
+
+        let mut response = Self::new(page).link("first", uri(1));
+        if current > 1 {
+            response = response.link("prev", uri(current - 1));
+        }
+        if last.map_or(true, |last| current < last) {
+            response = response.link("next", uri(current + 1));
+        }
+        if let Some(last) = last {
+            response = response.link("last", uri(last));
+        }
+
+        response
+    }
+
+    /// Construct new [`PagedResponse`] for cursor _`page`_ with _`next`_ link to _`base_uri`_
+    /// with _`cursor`_ and _`limit`_ query parameters. Query parameters are appended to the
+    /// existing query of _`base_uri`_ if it has one replacing existing _`cursor`_ and _`limit`_
+    /// parameters.
+    pub fn cursor(page: Page<T>, base_uri: &str, limit: u32) -> Self {
+        let next = page.next_cursor.as_deref().map(|cursor| {
+            with_query(
+                base_uri,
+                &format!("cursor={}&limit={limit}", encode(cursor)),
+            )
+        });
+
+        let response = Self::new(page);
+        match next {
+            Some(next) => response.link("next", next),
+            None => response,
+        }
+    }
+
+    /// Add link to _`uri`_ with relation type _`rel`_ e.g. _`next`_.
+    pub fn link<R: Into<String>, U: Into<String>>(mut self, rel: R, uri: U) -> Self {
+        self.links.push((rel.into(), uri.into()));
+
+        self
+    }
+
+    /// Get the [`Page`] of the response.
+    pub fn page(&self) -> &Page<T> {
+        &self.page
+    }
+
+    /// Get value of the _`Link`_ header or `None` if there are no links.
+    pub fn link_header(&self) -> Option<String> {
+        if self.links.is_empty() {
+            return None;
+        }
+
+        let mut header = String::new();
+        for (index, (rel, uri)) in self.links.iter().enumerate() {
+            if index > 0 {
+                header.push_str(", ");
+            }
+            let _ = write!(header, r#"<{uri}>; rel="{rel}""#);
+        }
+
+        Some(header)
+    }
+}
+
+/// Append _`query`_ to the query of _`base_uri`_ or start a new query if it has none. Existing
+/// parameters of _`base_uri`_ having same name as parameters of _`query`_ are removed.
+fn with_query(base_uri: &str, query: &str) -> String {
+    fn name(pair: &str) -> &str {
+        pair.split_once('=').map_or(pair, |(name, _)| name)
+    }
+
+    let (path, existing) = base_uri.split_once('?').unwrap_or((base_uri, ""));
+    let names = query.split('&').map(name).collect::<Vec<_>>();
+    let query = existing
+        .split('&')
+        .filter(|pair| !pair.is_empty() && !names.contains(&name(pair)))
+        .chain(query.split('&'))
+        .collect::<Vec<_>>()
+        .join("&");
+
+    format!("{path}?{query}")
+}
+
+impl<T> From<Page<T>> for PagedResponse<T> {
+    fn from(page: Page<T>) -> Self {
+        Self::new(page)
+    }
+}
+
+impl<T: ToSchema> IntoResponses for PagedResponse<T> {
+    fn responses() -> BTreeMap<String, RefOr<Response>> {
+        let response = ResponseBuilder::new()
+            .description("Page of items")
+            .header(
+                "Link",
+                HeaderBuilder::new()
+                    .schema(ObjectBuilder::new().schema_type(Type::String))
+                    .description(Some(
+                        "Links to other pages with `first`, `prev`, `next` and `last` relation types as defined in RFC 8288.",
+                    ))
+                    .build(),
+            )
+            .header(
+                TOTAL_COUNT_HEADER,
+                HeaderBuilder::new()
+                    .schema(ObjectBuilder::new().schema_type(Type::Integer).minimum(Some(0)))
+                    .description(Some("Total number of items in all pages."))
+                    .build(),
+            )
+            .content(
+                "application/json",
+                ContentBuilder::new()
+                    .schema(Some(<Page<T> as ComposeSchema>::compose(vec![T::schema()])))
+                    .build(),
+            )
+            .build();
+
+        BTreeMap::from_iter([("200".to_string(), RefOr::T(response))])
+    }
+}
+
+/// Percent encode _`value`_ for query string leaving only unreserved characters as is.
+fn encode(value: &str) -> String {
+    value.bytes().fold(String::new(), |mut encoded, byte| {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            encoded.push(byte as char);
+        } else {
+            let _ = write!(encoded, "%{byte:02X}");
+        }
+        encoded
+    })
+}
+
+#[cfg(feature = "axum")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "axum")))]
+impl<T: Serialize> axum::response::IntoResponse for PagedResponse<T> {
+    fn into_response(self) -> axum::response::Response {
+        use axum::http::{header, HeaderMap, HeaderValue, StatusCode};
+
+        let body = match serde_json::to_vec(&self.page) {
+            Ok(body) => body,
+            Err(error) => {
+                return (StatusCode::INTERNAL_SERVER_ERROR, error.to_string()).into_response()
+            }
+        };
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/json"),
+        );
+        if let Some(link) = self
+            .link_header()
+            .and_then(|link| HeaderValue::try_from(link).ok())
+        {
+            headers.insert(header::LINK, link);
+        }
+        if let Some(total) = self.page.total {
+            headers.insert(TOTAL_COUNT_HEADER, HeaderValue::from(total));
+        }
+
+        (headers, body).into_response()
+    }
+}
+
+#[cfg(feature = "actix-web")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "actix-web")))]
+impl<T: Serialize> actix_web::Responder for PagedResponse<T> {
+    type Body = actix_web::body::BoxBody;
+
+    fn respond_to(self, _: &actix_web::HttpRequest) -> actix_web::HttpResponse<Self::Body> {
+        use actix_web::http::header;
+
+        let body = match serde_json::to_vec(&self.page) {
+            Ok(body) => body,
+            Err(error) => {
+                return actix_web::HttpResponse::InternalServerError().body(error.to_string())
+            }
+        };
+
+        let mut response = actix_web::HttpResponse::Ok();
+        response.content_type("application/json");
+        if let Some(link) = self.link_header() {
+            response.insert_header((header::LINK, link));
+        }
+        if let Some(total) = self.page.total {
+            response.insert_header((TOTAL_COUNT_HEADER, total));
+        }
+
+        response.body(body)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_json_diff::assert_json_eq;
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn offset_params_are_clamped() {
+        let params = OffsetParams {
+            page: Some(0),
+            per_page: Some(1000),
+        };
+
+        assert_eq!(params.page(), 1);
+        assert_eq!(params.per_page(), OffsetParams::MAX_PER_PAGE);
+        assert_eq!(params.offset(), 0);
+        assert_eq!(
+            CursorParams {
+                cursor: None,
+                limit: Some(0)
+            }
+            .limit(),
+            1
+        );
+    }
+
+    #[test]
+    fn offset_params_into_params() {
+        let parameters = serde_json::to_value(OffsetParams::into_params(|| None)).unwrap();
+
+        assert_json_eq!(
+            parameters,
+            json!([
+                {
+                    "name": "page",
+                    "in": "query",
+                    "description": "Page number starting from 1.",
+                    "required": false,
+                    "schema": {
+                        "type": ["integer", "null"],
+                        "format": "int32",
+                        "minimum": 1,
+                        "default": 1
+                    }
+                },
+                {
+                    "name": "per_page",
+                    "in": "query",
+                    "description": "Number of items per page.",
+                    "required": false,
+                    "schema": {
+                        "type": ["integer", "null"],
+                        "format": "int32",
+                        "minimum": 1,
+                        "maximum": 100,
+                        "default": 20
+                    }
+                }
+            ])
+        );
+    }
+
+    #[test]
+    fn offset_response_links() {
+        let params = OffsetParams {
+            page: Some(1),
+            per_page: Some(20),
+        };
+        let response = PagedResponse::offset(Page::offset(vec![1, 2], &params, 20), "/items");
+
+        assert_eq!(
+            response.link_header().as_deref(),
+            Some(
+                r#"</items?page=1&per_page=20>; rel="first", </items?page=1&per_page=20>; rel="last""#
+            )
+        );
+    }
+
+    #[test]
+    fn cursor_response_links() {
+        let response = PagedResponse::cursor(
+            Page::cursor(vec!["a"], Some("b 1/2".to_string())),
+            "/items",
+            10,
+        );
+        assert_eq!(
+            response.link_header().as_deref(),
+            Some(r#"</items?cursor=b%201%2F2&limit=10>; rel="next""#)
+        );
+
+        let last = PagedResponse::cursor(Page::<u8>::cursor(Vec::new(), None), "/items", 10);
+        assert_eq!(last.link_header(), None);
+    }
+
+    #[test]
+    fn response_links_append_to_existing_query() {
+        let params = OffsetParams {
+            page: Some(2),
+            per_page: Some(10),
+        };
+        let offset =
+            PagedResponse::offset(Page::offset(vec![1, 2], &params, 20), "/items?sort=name");
+        assert_eq!(
+            offset.link_header().as_deref(),
+            Some(
+                r#"</items?sort=name&page=1&per_page=10>; rel="first", </items?sort=name&page=1&per_page=10>; rel="prev", </items?sort=name&page=2&per_page=10>; rel="last""#
+            )
+        );
+
+        let cursor = PagedResponse::cursor(
+            Page::cursor(vec!["a"], Some("b".to_string())),
+            "/items?",
+            10,
+        );
+        assert_eq!(
+            cursor.link_header().as_deref(),
+            Some(r#"</items?cursor=b&limit=10>; rel="next""#)
+        );
+    }
+
+    #[test]
+    fn response_links_replace_existing_pagination_query() {
+        let params = OffsetParams {
+            page: Some(1),
+            per_page: Some(10),
+        };
+        let offset = PagedResponse::offset(
+            Page::offset(vec![1, 2], &params, 20),
+            "/items?page=1&sort=name&per_page=10",
+        );
+        assert_eq!(
+            offset.link_header().as_deref(),
+            Some(
+                r#"</items?sort=name&page=1&per_page=10>; rel="first", </items?sort=name&page=2&per_page=10>; rel="next", </items?sort=name&page=2&per_page=10>; rel="last""#
+            )
+        );
+
+        let cursor = PagedResponse::cursor(
+            Page::cursor(vec!["a"], Some("b".to_string())),
+            "/items?cursor=a&limit=10",
+            10,
+        );
+        assert_eq!(
+            cursor.link_header().as_deref(),
+            Some(r#"</items?cursor=b&limit=10>; rel="next""#)
+        );
+    }
+
+    #[test]
+    fn paged_response_into_responses() {
+        let responses = serde_json::to_value(PagedResponse::<String>::responses()).unwrap();
+
+        assert_json_eq!(
+            responses.pointer("/200/headers").unwrap(),
+            json!({
+                "Link": {
+                    "description": "Links to other pages with `first`, `prev`, `next` and `last` relation types as defined in RFC 8288.",
+                    "schema": {
+                        "type": "string"
+                    }
+                },
+                "X-Total-Count": {
+                    "description": "Total number of items in all pages.",
+                    "schema": {
+                        "type": "integer",
+                        "minimum": 0
+                    }
+                }
+            })
+        );
+        assert_json_eq!(
+            responses
+                .pointer("/200/content/application~1json/schema/properties/items")
+                .unwrap(),
+            json!({
+                "type": "array",
+                "items": {
+                    "type": "string"
+                },
+                "description": "Items of the page."
+            })
+        );
+    }
+}
@@ -0,0 +1,363 @@
+//! Implements [RFC 9457][rfc] Problem Details for HTTP APIs.
+//!
+//! [`Problem`] is a ready made error response body which is documented with
+//! _`application/problem+json`_ content type. It implements [`ToSchema`] and [`ToResponse`] so
+//! it can be used as _`body`_ or _`response`_ of [`#[fastapi::path]`][path] responses, or with
+//! _`problem(...)`_ shorthand.
+//!
+//! With **`axum`** feature [`Problem`] implements `axum::response::IntoResponse` and with
+//! **`actix-web`** feature it implements `actix_web::ResponseError`. These features are enabled
+//! by _`fastapi-axum`_ and _`fastapi-actix-web`_ crates respectively.
+//!
+//! # Examples
+//!
+//! _**Create problem with extension members.**_
+//! ```rust
+//! # use fastapi::problem::Problem;
+//! #[derive(serde::Serialize, fastapi::ToSchema)]
+//! struct OutOfCredit {
+//!     balance: u64,
+//! }
+//!
+//! let problem = Problem::new(403)
+//!     .problem_type("https://example.com/probs/out-of-credit")
+//!     .title("You do not have enough credit.")
+//!     .extensions(OutOfCredit { balance: 30 });
+//!
+//! assert_eq!(
+//!     serde_json::to_value(&problem).unwrap(),
+//!     serde_json::json!({
+//!         "type": "https://example.com/probs/out-of-credit",
+//!         "title": "You do not have enough credit.",
+//!         "status": 403,
+//!         "balance": 30
+//!     })
+//! );
+//! ```
+//!
+//! _**Document `404` problem response with `problem(...)` shorthand.**_
+//! ```rust
+//! #[fastapi::path(
+//!     get,
+//!     path = "/pets/{id}",
+//!     responses(
+//!         problem(status = 404, type = "https://example.com/probs/pet-not-found", title = "Pet not found")
+//!     )
+//! )]
+//! async fn get_pet(id: u64) {}
+//! ```
+//!
+//! [rfc]: https://www.rfc-editor.org/rfc/rfc9457
+//! [path]: ../attr.path.html
+
+use std::borrow::Cow;
+use std::fmt::{Debug, Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+use crate::openapi::content::ContentBuilder;
+use crate::openapi::response::{Response, ResponseBuilder};
+use crate::openapi::schema::{self, AllOfBuilder, ObjectBuilder, Schema, SchemaFormat, Type};
+use crate::openapi::RefOr;
+use crate::{PartialSchema, ToResponse, ToSchema};
+
+/// Content type of [`Problem`] responses.
+pub const CONTENT_TYPE: &str = "application/problem+json";
+
+/// Problem Details object as defined in [RFC 9457][rfc].
+///
+/// All standard members are optional. Additional members specific to the problem type are given
+/// with _`Ext`_ type which is flattened to the problem object. _`Ext`_ defaults to `()` which
+/// adds no members.
+///
+/// [rfc]: https://www.rfc-editor.org/rfc/rfc9457#name-members-of-a-problem-details
+#[non_exhaustive]
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug, Default)]
+pub struct Problem<Ext = ()> {
+    /// URI reference that identifies the problem type. When omitted it is assumed to be
+    /// _`about:blank`_.
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub problem_type: Option<String>,
+
+    /// Short, human-readable summary of the problem type.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    /// HTTP status code of the response.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<u16>,
+
+    /// Human-readable explanation specific to this occurrence of the problem.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+
+    /// URI reference that identifies the specific occurrence of the problem.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instance: Option<String>,
+
+    /// Extension members of the problem.
+    #[serde(flatten)]
+    pub extensions: Ext,
+}
+
+impl Problem {
+    /// Construct a new [`Problem`] with given HTTP _`status`_ code.
+    pub fn new(status: u16) -> Self {
+        Self {
+            status: Some(status),
+            ..Default::default()
+        }
+    }
+}
+
+impl<Ext> Problem<Ext> {
+    /// Add URI reference identifying the problem type.
+    pub fn problem_type<S: Into<String>>(mut self, problem_type: S) -> Self {
+        self.problem_type = Some(problem_type.into());
+
+        self
+    }
+
+    /// Add short, human-readable summary of the problem type.
+    pub fn title<S: Into<String>>(mut self, title: S) -> Self {
+        self.title = Some(title.into());
+
+        self
+    }
+
+    /// Add HTTP status code of the response.
+    pub fn status(mut self, status: u16) -> Self {
+        self.status = Some(status);
+
+        self
+    }
+
+    /// Add human-readable explanation specific to this occurrence of the problem.
+    pub fn detail<S: Into<String>>(mut self, detail: S) -> Self {
+        self.detail = Some(detail.into());
+
+        self
+    }
+
+    /// Add URI reference identifying the specific occurrence of the problem.
+    pub fn instance<S: Into<String>>(mut self, instance: S) -> Self {
+        self.instance = Some(instance.into());
+
+        self
+    }
+
+    /// Replace extension members of the problem with _`extensions`_.
+    pub fn extensions<E>(self, extensions: E) -> Problem<E> {
+        Problem {
+            problem_type: self.problem_type,
+            title: self.title,
+            status: self.status,
+            detail: self.detail,
+            instance: self.instance,
+            extensions,
+        }
+    }
+
+    /// Get HTTP status code of the problem. Defaults to _`500`_ if status is not defined.
+    pub fn status_code(&self) -> u16 {
+        self.status.unwrap_or(500)
+    }
+}
+
+impl<Ext> Display for Problem<Ext> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let title = self
+            .title
+            .as_deref()
+            .or(self.problem_type.as_deref())
+            .unwrap_or("about:blank");
+        write!(f, "{} {title}", self.status_code())?;
+        if let Some(detail) = &self.detail {
+            write!(f, ": {detail}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<Ext: Debug> std::error::Error for Problem<Ext> {}
+
+impl<Ext: ToSchema> PartialSchema for Problem<Ext> {
+    fn schema() -> RefOr<Schema> {
+        let uri_reference = || {
+            ObjectBuilder::new()
+                .schema_type(Type::String)
+                .format(Some(SchemaFormat::Custom("uri-reference".to_string())))
+        };
+        let problem = ObjectBuilder::new()
+            .description(Some(
+                "Problem Details for HTTP APIs as defined in RFC 9457.",
+            ))
+            .property(
+                "type",
+                uri_reference()
+                    .description(Some("URI reference that identifies the problem type."))
+                    .default(Some("about:blank".into())),
+            )
+            .property(
+                "title",
+                ObjectBuilder::new()
+                    .schema_type(Type::String)
+                    .description(Some("Short, human-readable summary of the problem type.")),
+            )
+            .property(
+                "status",
+                ObjectBuilder::new()
+                    .schema_type(Type::Integer)
+                    .format(Some(SchemaFormat::KnownFormat(schema::KnownFormat::Int32)))
+                    .minimum(Some(100))
+                    .maximum(Some(599))
+                    .description(Some("HTTP status code of the response.")),
+            )
+            .property(
+                "detail",
+                ObjectBuilder::new()
+                    .schema_type(Type::String)
+                    .description(Some(
+                        "Human-readable explanation specific to this occurrence of the problem.",
+                    )),
+            )
+            .property(
+                "instance",
+                uri_reference().description(Some(
+                    "URI reference that identifies the specific occurrence of the problem.",
+                )),
+            );
+
+        let extensions = Ext::schema();
+        if extensions == RefOr::T(schema::empty()) {
+            problem.into()
+        } else {
+            AllOfBuilder::new().item(problem).item(extensions).into()
+        }
+    }
+}
+
+impl<Ext: ToSchema> ToSchema for Problem<Ext> {
+    fn name() -> Cow<'static, str> {
+        if Ext::schema() == RefOr::T(schema::empty()) {
+            Cow::Borrowed("Problem")
+        } else {
+            Cow::Owned(format!("Problem_{}", Ext::name()))
+        }
+    }
+
+    fn schemas(schemas: &mut Vec<(String, RefOr<Schema>)>) {
+        Ext::schemas(schemas);
+    }
+}
+
+impl<'r, Ext: ToSchema> ToResponse<'r> for Problem<Ext> {
+    fn response() -> (&'r str, RefOr<Response>) {
+        (
+            "Problem",
+            ResponseBuilder::new()
+                .description("Problem Details for HTTP APIs")
+                .content(
+                    CONTENT_TYPE,
+                    ContentBuilder::new().schema(Some(Self::schema())).build(),
+                )
+                .build()
+                .into(),
+        )
+    }
+}
+
+#[cfg(feature = "axum")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "axum")))]
+impl<Ext: Serialize> axum::response::IntoResponse for Problem<Ext> {
+    fn into_response(self) -> axum::response::Response {
+        use axum::http::{header, HeaderValue, StatusCode};
+
+        let status =
+            StatusCode::from_u16(self.status_code()).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+        match serde_json::to_vec(&self) {
+            Ok(body) => (
+                status,
+                [(header::CONTENT_TYPE, HeaderValue::from_static(CONTENT_TYPE))],
+                body,
+            )
+                .into_response(),
+            Err(error) => (StatusCode::INTERNAL_SERVER_ERROR, error.to_string()).into_response(),
+        }
+    }
+}
+
+#[cfg(feature = "actix-web")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "actix-web")))]
+impl<Ext: Serialize + Debug> actix_web::ResponseError for Problem<Ext> {
+    fn status_code(&self) -> actix_web::http::StatusCode {
+        actix_web::http::StatusCode::from_u16(Problem::status_code(self))
+            .unwrap_or(actix_web::http::StatusCode::INTERNAL_SERVER_ERROR)
+    }
+
+    fn error_response(&self) -> actix_web::HttpResponse {
+        match serde_json::to_vec(self) {
+            Ok(body) => actix_web::HttpResponse::build(actix_web::ResponseError::status_code(self))
+                .content_type(CONTENT_TYPE)
+                .body(body),
+            Err(error) => actix_web::HttpResponse::InternalServerError().body(error.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_json_diff::assert_json_eq;
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn problem_without_extensions_roundtrip() {
+        let problem = Problem::new(404)
+            .title("Not Found")
+            .detail("Pet 1 does not exist");
+
+        let value = serde_json::to_value(&problem).unwrap();
+        assert_json_eq!(
+            value,
+            json!({"title": "Not Found", "status": 404, "detail": "Pet 1 does not exist"})
+        );
+        assert_eq!(serde_json::from_value::<Problem>(value).unwrap(), problem);
+        assert_eq!(problem.to_string(), "404 Not Found: Pet 1 does not exist");
+    }
+
+    #[test]
+    fn problem_schema_with_extensions() {
+        struct Balance;
+
+        impl PartialSchema for Balance {
+            fn schema() -> RefOr<Schema> {
+                ObjectBuilder::new()
+                    .property("balance", ObjectBuilder::new().schema_type(Type::Integer))
+                    .into()
+            }
+        }
+
+        impl ToSchema for Balance {}
+
+        assert_eq!(Problem::<()>::name(), "Problem");
+        assert_eq!(Problem::<Balance>::name(), "Problem_Balance");
+
+        let schema = serde_json::to_value(Problem::<Balance>::schema()).unwrap();
+        assert_json_eq!(
+            schema.pointer("/allOf/1").unwrap(),
+            json!({"type": "object", "properties": {"balance": {"type": "integer"}}})
+        );
+        assert_json_eq!(
+            schema.pointer("/allOf/0/properties/type").unwrap(),
+            json!({
+                "type": "string",
+                "format": "uri-reference",
+                "default": "about:blank",
+                "description": "URI reference that identifies the problem type."
+            })
+        );
+    }
+}
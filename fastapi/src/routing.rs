@@ -0,0 +1,336 @@
+//! Implements route bookkeeping shared by the framework integrations.
+//!
+//! Framework integrations such as _`fastapi-axum`_ and _`fastapi-actix-web`_ record every route
+//! registered to their routers with [`RouteRecord`]. Routes that have no matching
+//! [`Operation`][operation] in the [`OpenApi`] are reported as undocumented unless the route has
+//! been explicitly marked as intentionally undocumented.
+//!
+//! # Examples
+//!
+//! _**Find undocumented route.**_
+//! ```rust
+//! # use fastapi::openapi::path::{HttpMethod, OperationBuilder, PathItem, PathsBuilder};
+//! # use fastapi::routing::{RegisteredRoute, RouteRecord};
+//! let paths = PathsBuilder::new()
+//!     .path("/user", PathItem::new(HttpMethod::Get, OperationBuilder::new()))
+//!     .build();
+//!
+//! let mut record = RouteRecord::new();
+//! record.record([RegisteredRoute::new("/user", Some(HttpMethod::Get))]);
+//! record.record([RegisteredRoute::new("/user", Some(HttpMethod::Delete))]);
+//! record.record([RegisteredRoute::new("/health", None)]);
+//! record.undocumented();
+//!
+//! let undocumented = record
+//!     .undocumented_routes(&paths)
+//!     .map(ToString::to_string)
+//!     .collect::<Vec<_>>();
+//! assert_eq!(undocumented, ["DELETE /user"]);
+//! ```
+//!
+//! [operation]: crate::openapi::path::Operation
+
+use std::fmt;
+
+use crate::openapi::path::{HttpMethod, Paths};
+use crate::openapi::OpenApi;
+
+/// Compose path of a route nested under `prefix`.
+///
+/// This is the same composition `axum` uses for nested routes: the `path` is appended to the
+/// `prefix` without duplicating the `/` between them, and the root `path` `/` resolves to the
+/// `prefix` itself. Framework integrations use this to nest the paths of the [`OpenApi`] along
+/// with the routes.
+///
+/// # Examples
+///
+/// ```rust
+/// # use fastapi::routing::path_for_nested_route;
+/// assert_eq!(path_for_nested_route("/api", "/user"), "/api/user");
+/// assert_eq!(path_for_nested_route("/api/", "/user"), "/api/user");
+/// assert_eq!(path_for_nested_route("/api", "/"), "/api");
+/// assert_eq!(path_for_nested_route("/", "/user"), "/user");
+/// ```
+pub fn path_for_nested_route(prefix: &str, path: &str) -> String {
+    if prefix.ends_with('/') {
+        format!("{prefix}{}", path.trim_start_matches('/'))
+    } else if path == "/" {
+        prefix.into()
+    } else {
+        format!("{prefix}{path}")
+    }
+}
+
+/// Get operation of handler `P` with the tags of the handler and collect the schemas the handler
+/// references to `schemas`. Used by the routing macros of the framework integrations.
+#[doc(hidden)]
+#[cfg(feature = "macros")]
+pub fn __operation<'t, P>(
+    schemas: &mut Vec<(
+        String,
+        crate::openapi::RefOr<crate::openapi::schema::Schema>,
+    )>,
+) -> crate::openapi::path::Operation
+where
+    P: crate::Path + crate::__dev::SchemaReferences + crate::__dev::Tags<'t>,
+{
+    let mut operation = P::operation();
+    let tags = P::tags();
+    if !tags.is_empty() {
+        let operation_tags = operation.tags.get_or_insert(Vec::new());
+        operation_tags.extend(tags.into_iter().map(ToString::to_string));
+    }
+    P::schemas(schemas);
+
+    operation
+}
+
+/// Add the operation and the schemas of handler `P` to the `openapi`. Used by the routing macros
+/// of the framework integrations.
+#[doc(hidden)]
+#[cfg(feature = "macros")]
+pub fn __document<'t, P>(openapi: &mut OpenApi)
+where
+    P: crate::Path + crate::__dev::SchemaReferences + crate::__dev::Tags<'t>,
+{
+    let mut schemas = Vec::new();
+    let operation = __operation::<P>(&mut schemas);
+    openapi
+        .paths
+        .add_path_operation(P::path(), P::methods(), operation);
+
+    if !schemas.is_empty() {
+        openapi
+            .components
+            .get_or_insert_with(Default::default)
+            .schemas
+            .extend(schemas);
+    }
+}
+
+/// Route registered to a router of a framework integration.
+///
+/// See [`RouteRecord::undocumented_routes`] for more details.
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[non_exhaustive]
+pub struct RegisteredRoute {
+    /// Path of the route in OpenAPI format e.g. `/user/{id}`.
+    pub path: String,
+    /// Http method of the route or `None` if the route handles any method.
+    pub method: Option<HttpMethod>,
+}
+
+impl RegisteredRoute {
+    /// Construct new [`RegisteredRoute`] with `path` and `method`.
+    pub fn new<P: Into<String>>(path: P, method: Option<HttpMethod>) -> Self {
+        Self {
+            path: path.into(),
+            method,
+        }
+    }
+
+    /// Check whether the route has matching [`Operation`][operation] in the `paths`.
+    ///
+    /// Route without method is documented if the path has any operation.
+    ///
+    /// [operation]: crate::openapi::path::Operation
+    pub fn is_documented(&self, paths: &Paths) -> bool {
+        let Some(item) = paths.paths.get(&self.path).or_else(|| {
+            // paths with empty path are routed to "/"
+            if self.path == "/" {
+                paths.paths.get("")
+            } else {
+                None
+            }
+        }) else {
+            return false;
+        };
+
+        item.operations()
+            .any(|(method, _)| self.method.as_ref().map_or(true, |m| *m == method))
+    }
+}
+
+impl fmt::Display for RegisteredRoute {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let method = match self.method {
+            Some(HttpMethod::Get) => "GET ",
+            Some(HttpMethod::Put) => "PUT ",
+            Some(HttpMethod::Post) => "POST ",
+            Some(HttpMethod::Delete) => "DELETE ",
+            Some(HttpMethod::Options) => "OPTIONS ",
+            Some(HttpMethod::Head) => "HEAD ",
+            Some(HttpMethod::Patch) => "PATCH ",
+            Some(HttpMethod::Trace) => "TRACE ",
+            None => "",
+        };
+        write!(f, "{method}{}", self.path)
+    }
+}
+
+/// Record of the [`RegisteredRoute`]s of a router along with the intentionally undocumented
+/// flag of each route.
+///
+/// Routes are recorded per registration. [`RouteRecord::undocumented`] marks the routes of the
+/// latest registration done with [`RouteRecord::record`], nested and merged records are not
+/// affected by it.
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct RouteRecord {
+    routes: Vec<(RegisteredRoute, bool)>,
+    last: usize,
+    strict: bool,
+}
+
+impl RouteRecord {
+    /// Construct new empty [`RouteRecord`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enable or disable strict mode of the record. See [`RouteRecord::assert_documented`].
+    pub fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
+    /// Check whether strict mode is enabled.
+    pub fn is_strict(&self) -> bool {
+        self.strict
+    }
+
+    /// Record `routes` of a single registration to the router.
+    pub fn record<I: IntoIterator<Item = RegisteredRoute>>(&mut self, routes: I) {
+        self.last = self.routes.len();
+        self.routes
+            .extend(routes.into_iter().map(|route| (route, false)));
+    }
+
+    /// Mark the routes of the latest [`RouteRecord::record`] call as intentionally undocumented.
+    /// If routes were nested or merged after it, this does nothing.
+    pub fn undocumented(&mut self) {
+        self.routes[self.last..]
+            .iter_mut()
+            .for_each(|(_, undocumented)| *undocumented = true);
+    }
+
+    /// Nest routes of `other` record composing the paths with `composer` e.g. prefixing them with
+    /// the path the router is nested to. Strict mode of `other` is inherited.
+    pub fn nest<F: Fn(&str) -> String>(&mut self, other: RouteRecord, composer: F) {
+        self.routes
+            .extend(other.routes.into_iter().map(|(mut route, undocumented)| {
+                route.path = composer(&route.path);
+                (route, undocumented)
+            }));
+        self.last = self.routes.len();
+        self.strict |= other.strict;
+    }
+
+    /// Merge routes of `other` record. Strict mode of `other` is inherited.
+    pub fn merge(&mut self, other: RouteRecord) {
+        self.nest(other, ToString::to_string);
+    }
+
+    /// Get routes that have no matching [`Operation`][operation] in the `paths` and are not
+    /// marked as intentionally undocumented.
+    ///
+    /// [operation]: crate::openapi::path::Operation
+    pub fn undocumented_routes<'a>(
+        &'a self,
+        paths: &'a Paths,
+    ) -> impl Iterator<Item = &'a RegisteredRoute> {
+        self.routes
+            .iter()
+            .filter(|(route, undocumented)| !undocumented && !route.is_documented(paths))
+            .map(|(route, _)| route)
+    }
+
+    /// Assert that all routes are documented in the `openapi` if the record is in strict mode.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the record is in strict mode and has undocumented routes. The `router` is the
+    /// name of the router used in the panic message.
+    pub fn assert_documented(&self, openapi: &OpenApi, router: &str) {
+        if !self.strict {
+            return;
+        }
+
+        let undocumented = self
+            .undocumented_routes(&openapi.paths)
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        if !undocumented.is_empty() {
+            panic!(
+                "{router} in strict mode has undocumented routes: {}; document the routes or mark them with `.undocumented()`",
+                undocumented.join(", ")
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::openapi::path::{OperationBuilder, PathItem, PathsBuilder};
+
+    use super::*;
+
+    fn paths() -> Paths {
+        PathsBuilder::new()
+            .path(
+                "/user",
+                PathItem::new(HttpMethod::Get, OperationBuilder::new()),
+            )
+            .build()
+    }
+
+    #[test]
+    fn route_with_method_is_documented_by_same_method() {
+        let paths = paths();
+
+        assert!(RegisteredRoute::new("/user", Some(HttpMethod::Get)).is_documented(&paths));
+        assert!(RegisteredRoute::new("/user", None).is_documented(&paths));
+        assert!(!RegisteredRoute::new("/user", Some(HttpMethod::Post)).is_documented(&paths));
+        assert!(!RegisteredRoute::new("/pet", None).is_documented(&paths));
+    }
+
+    #[test]
+    fn undocumented_after_nest_and_merge_marks_nothing() {
+        let mut nested = RouteRecord::new();
+        nested.record([RegisteredRoute::new("/health", None)]);
+        let mut merged = RouteRecord::new();
+        merged.record([RegisteredRoute::new("/metrics", None)]);
+
+        let mut record = RouteRecord::new();
+        record.record([RegisteredRoute::new("/status", None)]);
+        record.nest(nested, |path| format!("/api{path}"));
+        record.undocumented();
+        record.merge(merged);
+        record.undocumented();
+
+        let paths = paths();
+        let undocumented = record
+            .undocumented_routes(&paths)
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        assert_eq!(undocumented, ["/status", "/api/health", "/metrics"]);
+    }
+
+    #[test]
+    fn undocumented_marks_latest_registration() {
+        let mut record = RouteRecord::new();
+        record.record([RegisteredRoute::new("/status", None)]);
+        record.record([
+            RegisteredRoute::new("/health", Some(HttpMethod::Get)),
+            RegisteredRoute::new("/health", Some(HttpMethod::Head)),
+        ]);
+        record.undocumented();
+
+        let paths = paths();
+        let undocumented = record
+            .undocumented_routes(&paths)
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        assert_eq!(undocumented, ["/status"]);
+    }
+}